@@ -1,5 +1,8 @@
-use crate::CapabilityAction;
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+use crate::{
+    AdminAction, CapabilityAction, DataKey, DataKeyExt, Escrow, EscrowStatus, OperationLogEntry,
+    MAX_OPERATION_LOG,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
 pub const EVENT_VERSION_V2: u32 = 2;
 
@@ -25,11 +28,180 @@ pub struct FundsLocked {
     pub amount: i128,
     pub depositor: Address,
     pub deadline: u64,
+    /// Optional depositor note; set only by `lock_funds_memo`.
+    pub memo: Option<String>,
+}
+
+/// Escrow event topics a depositor can subscribe to with `set_notify_preference`:
+///
+/// | Topic      | Emitted when                                       |
+/// |------------|----------------------------------------------------|
+/// | `f_lock`   | funds are locked (`lock_funds*`, `batch_lock_funds`) |
+/// | `f_sched`  | a future lock is scheduled                         |
+/// | `f_active` | a scheduled lock is activated                      |
+/// | `f_topup`  | an escrow is topped up                             |
+/// | `f_rel`    | funds are released, claimed or redeemed            |
+/// | `f_ref`    | funds are refunded                                 |
+pub const NOTIFY_TOPICS: [Symbol; 6] = [
+    symbol_short!("f_lock"),
+    symbol_short!("f_sched"),
+    symbol_short!("f_active"),
+    symbol_short!("f_topup"),
+    symbol_short!("f_rel"),
+    symbol_short!("f_ref"),
+];
+
+/// Copy of a subscribed escrow event, published under `("targeted", depositor)`
+/// so an indexer can follow one depositor by topic alone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationTargeted {
+    pub topic: Symbol,
+    pub bounty_id: u64,
+    pub timestamp: u64,
+}
+
+/// Publish a `targeted` event when the escrow's depositor subscribed to `topic`.
+fn notify_depositor(env: &Env, topic: Symbol, bounty_id: u64) {
+    let Some(escrow) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+    else {
+        return;
+    };
+    let subscribed: Option<Vec<Symbol>> = env
+        .storage()
+        .persistent()
+        .get(&DataKeyExt::NotifyTopics(escrow.depositor.clone()));
+    if subscribed.map(|topics| topics.contains(&topic)) != Some(true) {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("targeted"), escrow.depositor),
+        NotificationTargeted {
+            topic,
+            bounty_id,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Append a fund movement to the operation log read by `export_operations`,
+/// dropping the entry that falls out of the `MAX_OPERATION_LOG` window.
+pub(crate) fn record_operation(
+    env: &Env,
+    op_type: Symbol,
+    bounty_id: u64,
+    amount: i128,
+    actor: Address,
+) {
+    let seq: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKeyExt::OperationLogSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(
+        &DataKeyExt::OperationLog(seq),
+        &OperationLogEntry {
+            seq,
+            op_type,
+            bounty_id,
+            amount,
+            actor,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKeyExt::OperationLogSeq, &seq);
+    if seq > MAX_OPERATION_LOG {
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::OperationLog(seq - MAX_OPERATION_LOG));
+    }
 }
 
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
+    record_operation(
+        env,
+        symbol_short!("lock"),
+        event.bounty_id,
+        event.amount,
+        event.depositor.clone(),
+    );
     let topics = (symbol_short!("f_lock"), event.bounty_id);
     env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_lock"), event.bounty_id);
+}
+
+/// Emitted by `schedule_future_lock` when funds are deposited into a `Pending`
+/// escrow that goes live at `start_time`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FutureLockScheduled {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub depositor: Address,
+    pub start_time: u64,
+    pub deadline: u64,
+}
+
+pub fn emit_future_lock_scheduled(env: &Env, event: FutureLockScheduled) {
+    record_operation(
+        env,
+        symbol_short!("lock"),
+        event.bounty_id,
+        event.amount,
+        event.depositor.clone(),
+    );
+    let topics = (symbol_short!("f_sched"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_sched"), event.bounty_id);
+}
+
+/// Emitted when a scheduled escrow moves from `Pending` to `Locked`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockActivated {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_lock_activated(env: &Env, event: LockActivated) {
+    let topics = (symbol_short!("f_active"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_active"), event.bounty_id);
+}
+
+/// Emitted when additional funds are added to an existing escrow.
+///
+/// Carries the same payload as [`FundsLocked`] (with `amount` set to the
+/// top-up only) under a distinct topic so indexers can tell it apart from
+/// the original lock.
+pub fn emit_funds_topped_up(env: &Env, event: FundsLocked) {
+    let topics = (symbol_short!("f_topup"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_topup"), event.bounty_id);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_deadline_extended(env: &Env, event: DeadlineExtended) {
+    let topics = (symbol_short!("dl_ext"), event.bounty_id);
+    env.events().publish(topics, event);
 }
 
 #[contracttype]
@@ -40,11 +212,33 @@ pub struct FundsReleased {
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
+    /// Early-release bonus included in `amount`; see `set_early_bonus`.
+    pub bonus: i128,
 }
 
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
+    record_operation(
+        env,
+        symbol_short!("release"),
+        event.bounty_id,
+        event.amount,
+        event.recipient.clone(),
+    );
     let topics = (symbol_short!("f_rel"), event.bounty_id);
     env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_rel"), event.bounty_id);
+}
+
+/// What caused a refund to be paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundTrigger {
+    /// The escrow deadline passed and the depositor reclaimed the funds.
+    DeadlineExpiry,
+    /// An admin-issued refund approval (or refund capability) authorized it.
+    AdminApproval,
+    /// The admin drained the contract via `emergency_withdraw`.
+    EmergencyWithdraw,
 }
 
 #[contracttype]
@@ -55,11 +249,20 @@ pub struct FundsRefunded {
     pub amount: i128,
     pub refund_to: Address,
     pub timestamp: u64,
+    pub trigger: RefundTrigger,
 }
 
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
+    record_operation(
+        env,
+        symbol_short!("refund"),
+        event.bounty_id,
+        event.amount,
+        event.refund_to.clone(),
+    );
     let topics = (symbol_short!("f_ref"), event.bounty_id);
     env.events().publish(topics, event.clone());
+    notify_depositor(env, symbol_short!("f_ref"), event.bounty_id);
 }
 
 #[contracttype]
@@ -67,6 +270,7 @@ pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
 pub enum FeeOperationType {
     Lock,
     Release,
+    Refund,
 }
 
 #[contracttype]
@@ -104,6 +308,7 @@ pub struct FeeConfigUpdated {
     pub release_fee_rate: i128,
     pub fee_recipient: Address,
     pub fee_enabled: bool,
+    pub refund_fee_rate: i128,
     pub timestamp: u64,
 }
 
@@ -193,6 +398,22 @@ pub struct ClaimExecuted {
     pub claimed_at: u64,
 }
 
+/// Emitted alongside `ClaimExecuted` when a claim lands inside its grace
+/// period, i.e. after `expires_at`, so it can be flagged for review.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateClaim {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub expires_at: u64,
+    pub claimed_at: u64,
+}
+
+pub fn emit_late_claim(env: &Env, event: LateClaim) {
+    let topics = (symbol_short!("claim"), symbol_short!("late"));
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ClaimCancelled {
@@ -239,6 +460,13 @@ pub struct FundsLockedAnon {
 }
 
 pub fn emit_funds_locked_anon(env: &Env, event: FundsLockedAnon) {
+    record_operation(
+        env,
+        symbol_short!("lock"),
+        event.bounty_id,
+        event.amount,
+        env.current_contract_address(),
+    );
     let topics = (symbol_short!("f_lkanon"), event.bounty_id);
     env.events().publish(topics, event);
 }
@@ -270,6 +498,19 @@ pub fn emit_maintenance_mode_changed(env: &Env, event: MaintenanceModeChanged) {
     env.events().publish(topics, event);
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoRefundPolicyChanged {
+    pub enabled: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_auto_refund_policy_changed(env: &Env, event: AutoRefundPolicyChanged) {
+    let topics = (symbol_short!("arf_pol"),);
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ParticipantFilterModeChanged {
@@ -284,6 +525,21 @@ pub fn emit_participant_filter_mode_changed(env: &Env, event: ParticipantFilterM
     env.events().publish(topics, event);
 }
 
+/// Audit record for an anti-abuse whitelist change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistChanged {
+    pub address: Address,
+    pub whitelisted: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_whitelist_changed(env: &Env, event: WhitelistChanged) {
+    let topics = (symbol_short!("wl_chg"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RiskFlagsUpdated {
@@ -338,7 +594,10 @@ pub fn emit_ticket_issued(env: &Env, event: TicketIssued) {
 pub struct TicketClaimed {
     pub ticket_id: u64,
     pub bounty_id: u64,
+    /// Ticket beneficiary who authorized the claim.
     pub claimer: Address,
+    /// Address that received the funds; differs from `claimer` only for redirected claims.
+    pub destination: Address,
     pub claimed_at: u64,
 }
 
@@ -347,6 +606,263 @@ pub fn emit_ticket_claimed(env: &Env, event: TicketClaimed) {
     env.events().publish(topics, event);
 }
 
+/// Emitted when an expired, unused ticket is retired by `reap_expired_tickets`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketReaped {
+    pub ticket_id: u64,
+    pub bounty_id: u64,
+    pub beneficiary: Address,
+    pub expires_at: u64,
+    pub reaped_at: u64,
+}
+
+pub fn emit_ticket_reaped(env: &Env, event: TicketReaped) {
+    let topics = (symbol_short!("ticket_r"), event.ticket_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowArchived {
+    pub bounty_id: u64,
+    pub archived_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_archived(env: &Env, event: EscrowArchived) {
+    let topics = (symbol_short!("archived"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPurged {
+    pub bounty_id: u64,
+    pub purged_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_purged(env: &Env, event: EscrowPurged) {
+    let topics = (symbol_short!("purged"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMadeNonRefundable {
+    pub bounty_id: u64,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_made_non_refundable(env: &Env, event: EscrowMadeNonRefundable) {
+    let topics = (symbol_short!("no_rfnd"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyScheduleCreated {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_schedule_created(env: &Env, event: BountyScheduleCreated) {
+    let topics = (symbol_short!("bs_new"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyScheduleReleased {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub released_at: u64,
+}
+
+pub fn emit_bounty_schedule_released(env: &Env, event: BountyScheduleReleased) {
+    let topics = (symbol_short!("bs_rel"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyScheduleCancelled {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_schedule_cancelled(env: &Env, event: BountyScheduleCancelled) {
+    let topics = (symbol_short!("bs_cncl"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a partial release sweeps an escrow's sub-threshold residual
+/// to the fee recipient and closes it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DustSwept {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_dust_swept(env: &Env, event: DustSwept) {
+    let topics = (symbol_short!("dust"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when `finalize_escrow` closes an escrow the contract can no longer cover.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForceFinalized {
+    pub bounty_id: u64,
+    /// `remaining_amount` on record before it was zeroed.
+    pub written_off: i128,
+    /// Contract balance of the escrow token at finalization.
+    pub contract_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_force_finalized(env: &Env, event: ForceFinalized) {
+    let topics = (symbol_short!("f_final"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when `merge_escrows` folds one escrow into another.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowsMerged {
+    pub source_bounty_id: u64,
+    pub target_bounty_id: u64,
+    /// Source `remaining_amount` moved into the target.
+    pub amount: i128,
+    pub target_remaining: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrows_merged(env: &Env, event: EscrowsMerged) {
+    let topics = (symbol_short!("merged"), event.target_bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when `split_escrow` carves a new escrow out of an existing one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSplit {
+    pub source_bounty_id: u64,
+    pub new_bounty_id: u64,
+    /// Amount moved from the source into the new escrow.
+    pub amount: i128,
+    pub source_remaining: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_split(env: &Env, event: EscrowSplit) {
+    let topics = (symbol_short!("split"), event.source_bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when `release_with_holdback` parks part of a release for review.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldbackRecorded {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub releasable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_holdback_recorded(env: &Env, event: HoldbackRecorded) {
+    let topics = (symbol_short!("hb_rec"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a held-back share is paid to the contributor or, if
+/// `revoked`, returned to the depositor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldbackSettled {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub revoked: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_holdback_settled(env: &Env, event: HoldbackSettled) {
+    let topics = (symbol_short!("hb_set"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted for the penalty leg of a post-deadline refund; see `set_refund_penalty`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPenaltyPaid {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub penalty_bps: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_penalty_paid(env: &Env, event: RefundPenaltyPaid) {
+    let topics = (symbol_short!("ref_pen"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when an address is added to or removed from the release denylist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressDenialUpdated {
+    pub address: Address,
+    pub denied: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_address_denial_updated(env: &Env, event: AddressDenialUpdated) {
+    let topics = (symbol_short!("deny"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    pub bounty_id: u64,
+    pub previous_depositor: Address,
+    pub new_depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_ownership_transferred(env: &Env, event: OwnershipTransferred) {
+    let topics = (symbol_short!("own_xfer"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientReleaseApproved {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_recipient_release_approved(env: &Env, event: RecipientReleaseApproved) {
+    let topics = (symbol_short!("rcp_appr"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
 pub fn emit_pause_state_changed(env: &Env, event: crate::PauseStateChanged) {
     let topics = (symbol_short!("pause"), event.operation.clone());
     env.events().publish(topics, event);
@@ -366,6 +882,53 @@ pub fn emit_emergency_withdraw(env: &Env, event: EmergencyWithdrawEvent) {
     env.events().publish(topics, event.clone());
 }
 
+/// Emitted by `emergency_withdraw` for each non-default escrow token drained.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyTokenWithdrawn {
+    pub admin: Address,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_token_withdrawn(env: &Env, event: EmergencyTokenWithdrawn) {
+    let topics = (symbol_short!("em_tok"), event.token.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a multisig signer approves an emergency withdrawal to `target`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawApproved {
+    pub target: Address,
+    pub approver: Address,
+    /// Approvals recorded for `target` including this one.
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_withdraw_approved(env: &Env, event: EmergencyWithdrawApproved) {
+    let topics = (symbol_short!("em_appr"), event.target.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForeignTokenRescued {
+    pub admin: Address,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_foreign_token_rescued(env: &Env, event: ForeignTokenRescued) {
+    let topics = (symbol_short!("tok_resc"), event.token.clone());
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CapabilityIssued {
@@ -415,3 +978,130 @@ pub fn emit_capability_revoked(env: &Env, event: CapabilityRevoked) {
     let topics = (symbol_short!("cap_rev"), event.capability_id);
     env.events().publish(topics, event);
 }
+
+/// Emitted when `batch_extend_capabilities` pushes a capability's expiry forward.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapabilityExtended {
+    pub capability_id: u64,
+    pub owner: Address,
+    pub old_expiry: u64,
+    pub new_expiry: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_capability_extended(env: &Env, event: CapabilityExtended) {
+    let topics = (symbol_short!("cap_ext"), event.capability_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a capability is revoked because its last use closed the escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapabilityAutoRevoked {
+    pub capability_id: u64,
+    pub bounty_id: u64,
+    pub escrow_status: EscrowStatus,
+    pub revoked_at: u64,
+}
+
+pub fn emit_capability_auto_revoked(env: &Env, event: CapabilityAutoRevoked) {
+    let topics = (symbol_short!("cap_arev"), event.capability_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when an INV-2 breach halts the contract instead of panicking.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantBreachHalt {
+    pub version: u32,
+    pub operation: Symbol,
+    pub escrow_sum: i128,
+    pub token_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_invariant_breach_halt(env: &Env, event: InvariantBreachHalt) {
+    let topics = (symbol_short!("inv_halt"), event.operation.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when the error-rate circuit breaker pauses the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerTripped {
+    /// Operation whose outcome pushed the rate over the limit.
+    pub operation: Symbol,
+    pub samples: u64,
+    pub errors: u64,
+    /// Error rate of the window in basis points.
+    pub error_rate: u32,
+    pub max_rate_bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_circuit_breaker_tripped(env: &Env, event: CircuitBreakerTripped) {
+    let topics = (symbol_short!("cb_trip"), event.operation.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted the first time a lock or disbursement finds the contract holding
+/// more of a token than its active escrows account for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SurplusDetected {
+    pub version: u32,
+    pub token: Address,
+    pub surplus: i128,
+    pub escrow_sum: i128,
+    pub token_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_surplus_detected(env: &Env, event: SurplusDetected) {
+    let topics = (symbol_short!("surplus"), event.token.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a high-risk admin action is queued; see `queue_admin_action`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionQueued {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub execute_after: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_action_queued(env: &Env, event: AdminActionQueued) {
+    let topics = (symbol_short!("adm_q"), event.action_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a queued admin action takes effect.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionExecuted {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_action_executed(env: &Env, event: AdminActionExecuted) {
+    let topics = (symbol_short!("adm_exec"), event.action_id);
+    env.events().publish(topics, event);
+}
+
+/// Emitted when a queued admin action is withdrawn before execution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionCancelled {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_action_cancelled(env: &Env, event: AdminActionCancelled) {
+    let topics = (symbol_short!("adm_cncl"), event.action_id);
+    env.events().publish(topics, event);
+}
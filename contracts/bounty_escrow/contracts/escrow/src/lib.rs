@@ -5,12 +5,82 @@ mod invariants;
 mod multitoken_invariants;
 mod reentrancy_guard;
 #[cfg(test)]
+mod test_support;
+#[cfg(test)]
 mod test_metadata;
 
 mod test_cross_contract_interface;
 #[cfg(test)]
 mod test_deterministic_randomness;
 #[cfg(test)]
+mod test_lock_duration;
+#[cfg(test)]
+mod test_release_from_many;
+#[cfg(test)]
+mod test_recipient_tiers;
+#[cfg(test)]
+mod test_scheduled_lock;
+#[cfg(test)]
+mod test_ownership_transfer;
+#[cfg(test)]
+mod test_non_refundable;
+#[cfg(test)]
+mod test_vouchers;
+#[cfg(test)]
+mod test_release_approval;
+#[cfg(test)]
+mod test_notify_preferences;
+#[cfg(test)]
+mod test_reentrancy_guard;
+#[cfg(test)]
+mod test_denylist;
+#[cfg(test)]
+mod test_bounty_schedules;
+#[cfg(test)]
+mod test_activity_extension;
+#[cfg(test)]
+mod test_finalize_escrow;
+#[cfg(test)]
+mod test_circuit_breaker;
+#[cfg(test)]
+mod test_escrow_views;
+#[cfg(test)]
+mod test_token_info;
+#[cfg(test)]
+mod test_escrow_templates;
+#[cfg(test)]
+mod test_refund_penalty;
+#[cfg(test)]
+mod test_compliance_log;
+#[cfg(test)]
+mod test_bounty_dependency;
+#[cfg(test)]
+mod test_early_bonus;
+#[cfg(test)]
+mod test_escrow_recipient_allowlist;
+#[cfg(test)]
+mod test_merge_escrows;
+#[cfg(test)]
+mod test_split_escrow;
+#[cfg(test)]
+mod test_fee_discount_tiers;
+#[cfg(test)]
+mod test_effective_fee_rate;
+#[cfg(test)]
+mod test_contributor_reputation;
+#[cfg(test)]
+mod test_holdback;
+#[cfg(test)]
+mod test_recipient_history;
+#[cfg(test)]
+mod test_escrow_solvency;
+#[cfg(test)]
+mod test_admin_action_queue;
+#[cfg(test)]
+mod test_operation_log;
+#[cfg(test)]
+mod test_multi_token_escrow;
+#[cfg(test)]
 mod test_multi_token_fees;
 #[cfg(test)]
 mod test_rbac;
@@ -31,11 +101,12 @@ use events::{
     emit_funds_locked_anon, emit_funds_refunded, emit_funds_released,
     emit_maintenance_mode_changed, emit_notification_preferences_updated,
     emit_participant_filter_mode_changed, emit_risk_flags_updated, emit_ticket_claimed,
-    emit_ticket_issued, BatchFundsLocked, BatchFundsReleased, BountyEscrowInitialized,
-    ClaimCancelled, ClaimCreated, ClaimExecuted, CriticalOperationOutcome, DeprecationStateChanged,
-    DeterministicSelectionDerived, FundsLocked, FundsLockedAnon, FundsRefunded, FundsReleased,
-    MaintenanceModeChanged, NotificationPreferencesUpdated, ParticipantFilterModeChanged,
-    RiskFlagsUpdated, TicketClaimed, TicketIssued, EVENT_VERSION_V2,
+    emit_ticket_issued, emit_ticket_reaped, BatchFundsLocked, BatchFundsReleased,
+    BountyEscrowInitialized, ClaimCancelled, ClaimCreated, ClaimExecuted, CriticalOperationOutcome,
+    DeprecationStateChanged, DeterministicSelectionDerived, FundsLocked, FundsLockedAnon,
+    FundsRefunded, FundsReleased, MaintenanceModeChanged, NotificationPreferencesUpdated,
+    ParticipantFilterModeChanged, RefundTrigger, RiskFlagsUpdated, TicketClaimed, TicketIssued,
+    TicketReaped, EVENT_VERSION_V2,
 };
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
@@ -90,7 +161,7 @@ mod validation {
 }
 
 mod monitoring {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
     // Storage keys
     #[allow(dead_code)]
@@ -99,6 +170,13 @@ mod monitoring {
     const USER_COUNT: &str = "usr_count";
     #[allow(dead_code)]
     const ERROR_COUNT: &str = "err_count";
+    const VOLUME_BUCKETS: &str = "vol_bkts";
+
+    /// Width of one rolling volume bucket (1 hour).
+    pub const VOLUME_BUCKET_SECONDS: u64 = 3_600;
+    /// Maximum number of volume buckets retained (7 days of hourly buckets).
+    /// The oldest bucket is evicted once this cap is exceeded.
+    pub const MAX_VOLUME_BUCKETS: u32 = 168;
 
     // Event: Operation metric
     #[contracttype]
@@ -166,12 +244,14 @@ mod monitoring {
         let key = Symbol::new(env, OPERATION_COUNT);
         let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
         env.storage().persistent().set(&key, &(count + 1));
+        record_volume(env);
 
         if !success {
             let err_key = Symbol::new(env, ERROR_COUNT);
             let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
+        record_breaker_samples(env, &operation, 1, if success { 0 } else { 1 });
 
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
@@ -184,6 +264,104 @@ mod monitoring {
         );
     }
 
+    // Count failures reported by the admin as failed breaker samples; see
+    // `report_operation_failures`.
+    pub fn record_reported_failures(env: &Env, operation: &Symbol, failures: u64) {
+        record_breaker_samples(env, operation, failures, failures);
+    }
+
+    // Feed the error-rate circuit breaker, pausing lock, release and refund when
+    // the window's error rate exceeds the configured limit. The window restarts
+    // after a trip so a manual resume is not immediately undone.
+    fn record_breaker_samples(env: &Env, operation: &Symbol, samples: u64, errors: u64) {
+        let key = crate::DataKeyExt::ErrorRateBreaker;
+        let mut breaker: crate::ErrorRateBreaker = match env.storage().instance().get(&key) {
+            Some(breaker) => breaker,
+            None => return,
+        };
+        breaker.samples += samples;
+        breaker.errors += errors;
+
+        let rate = ((breaker.errors as u128 * 10000) / breaker.samples as u128) as u32;
+        if breaker.samples >= breaker.min_samples && rate > breaker.max_rate_bps {
+            let now = env.ledger().timestamp();
+            let mut flags = crate::BountyEscrowContract::get_pause_flags(env);
+            flags.lock_paused = true;
+            flags.release_paused = true;
+            flags.refund_paused = true;
+            flags.pause_reason = Some(String::from_str(env, "error rate circuit breaker"));
+            if flags.paused_at == 0 {
+                flags.paused_at = now;
+            }
+            env.storage()
+                .instance()
+                .set(&crate::DataKey::PauseFlags, &flags);
+
+            crate::events::emit_circuit_breaker_tripped(
+                env,
+                crate::events::CircuitBreakerTripped {
+                    operation: operation.clone(),
+                    samples: breaker.samples,
+                    errors: breaker.errors,
+                    error_rate: rate,
+                    max_rate_bps: breaker.max_rate_bps,
+                    timestamp: now,
+                },
+            );
+            breaker.samples = 0;
+            breaker.errors = 0;
+        }
+        env.storage().instance().set(&key, &breaker);
+    }
+
+    // Add one operation to the rolling hourly bucket for the current ledger time.
+    // Buckets are stored oldest-first as (bucket_start, count) pairs.
+    fn record_volume(env: &Env) {
+        let key = Symbol::new(env, VOLUME_BUCKETS);
+        let mut buckets: Vec<(u64, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        let now = env.ledger().timestamp();
+        let bucket_start = now - (now % VOLUME_BUCKET_SECONDS);
+
+        match buckets.last() {
+            Some((start, count)) if start == bucket_start => {
+                buckets.set(buckets.len() - 1, (start, count + 1));
+            }
+            _ => {
+                buckets.push_back((bucket_start, 1));
+                while buckets.len() > MAX_VOLUME_BUCKETS {
+                    buckets.pop_front();
+                }
+            }
+        }
+
+        env.storage().persistent().set(&key, &buckets);
+    }
+
+    // Sum operations in every bucket overlapping the last `window_seconds`.
+    // Resolution is one bucket, so the oldest bucket may be partially outside the window.
+    pub fn get_volume_in_window(env: &Env, window_seconds: u64) -> u64 {
+        let key = Symbol::new(env, VOLUME_BUCKETS);
+        let buckets: Vec<(u64, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        let cutoff = env.ledger().timestamp().saturating_sub(window_seconds);
+        let mut total: u64 = 0;
+        for (start, count) in buckets.iter() {
+            if start.saturating_add(VOLUME_BUCKET_SECONDS) > cutoff {
+                total = total.saturating_add(count);
+            }
+        }
+        total
+    }
+
     // Track performance
     #[allow(dead_code)]
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
@@ -218,7 +396,7 @@ mod monitoring {
             is_healthy: true,
             last_operation: env.ledger().timestamp(),
             total_operations: ops,
-            contract_version: String::from_str(env, "1.0.0"),
+            contract_version: String::from_str(env, crate::CONTRACT_SEMVER),
         }
     }
 
@@ -286,7 +464,7 @@ mod monitoring {
 }
 
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -312,6 +490,14 @@ mod anti_abuse {
         Whitelist(Address),
         Blocklist(Address),
         Admin,
+        WhitelistIndex,
+        /// When set, the stored contract admin skips the cooldown check
+        /// (the window cap still applies). Kept outside `Config` so existing
+        /// config records still decode.
+        ExemptAdminCooldown,
+        /// Cooldown/window state for `record_view`, kept apart from `State`
+        /// so viewing an escrow never delays the viewer's own operations.
+        ViewState(Address),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -330,6 +516,19 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    pub fn is_admin_cooldown_exempt(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::ExemptAdminCooldown)
+            .unwrap_or(false)
+    }
+
+    pub fn set_admin_cooldown_exempt(env: &Env, exempt: bool) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::ExemptAdminCooldown, &exempt);
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
@@ -337,15 +536,45 @@ mod anti_abuse {
     }
 
     pub fn set_whitelist(env: &Env, address: Address, whitelisted: bool) {
+        let mut index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(Vec::new(env));
+        let position = index.first_index_of(&address);
         if whitelisted {
             env.storage()
                 .instance()
-                .set(&AntiAbuseKey::Whitelist(address), &true);
+                .set(&AntiAbuseKey::Whitelist(address.clone()), &true);
+            if position.is_none() {
+                index.push_back(address);
+            }
         } else {
             env.storage()
                 .instance()
                 .remove(&AntiAbuseKey::Whitelist(address));
+            if let Some(i) = position {
+                index.remove(i);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&AntiAbuseKey::WhitelistIndex, &index);
+    }
+
+    /// Whitelisted addresses in insertion order, paginated.
+    pub fn list_whitelisted(env: &Env, offset: u32, limit: u32) -> Vec<Address> {
+        let index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(Vec::new(env));
+        let mut results = Vec::new(env);
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            results.push_back(index.get(i).unwrap());
         }
+        results
     }
 
     pub fn is_blocklisted(env: &Env, address: Address) -> bool {
@@ -374,14 +603,48 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    /// Returns `true` when cooldown exemption is enabled and `address` is the
+    /// stored contract admin (`DataKey::Admin`).
+    fn is_cooldown_exempt(env: &Env, address: &Address) -> bool {
+        if !is_admin_cooldown_exempt(env) {
+            return false;
+        }
+        env.storage()
+            .instance()
+            .get::<crate::DataKey, Address>(&crate::DataKey::Admin)
+            .map(|admin| &admin == address)
+            .unwrap_or(false)
+    }
+
+    pub fn check_rate_limit(env: &Env, operation: Symbol, address: Address) {
+        let key = AntiAbuseKey::State(address.clone());
+        enforce_rate_limit(env, key, address, Some(operation));
+    }
+
+    /// Rate limit for `record_view`. Uses the same config as operations but
+    /// its own per-address state, and rejections are not written to the
+    /// compliance log.
+    pub fn check_view_rate_limit(env: &Env, address: Address) {
+        let key = AntiAbuseKey::ViewState(address.clone());
+        enforce_rate_limit(env, key, address, None);
+    }
+
+    /// Cooldown and window checks against the state stored under `key`.
+    /// Rejections are recorded in the compliance log under `operation` when
+    /// one is given.
+    fn enforce_rate_limit(
+        env: &Env,
+        key: AntiAbuseKey,
+        address: Address,
+        operation: Option<Symbol>,
+    ) {
         if is_whitelisted(env, address.clone()) {
             return;
         }
 
         let config = get_config(env);
+        let cooldown_exempt = is_cooldown_exempt(env, &address);
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
 
         let mut state: AddressState =
             env.storage()
@@ -393,8 +656,9 @@ mod anti_abuse {
                     operation_count: 0,
                 });
 
-        // 1. Cooldown check
-        if state.last_operation_timestamp > 0
+        // 1. Cooldown check (skipped for the admin when exempt_admin_cooldown is set)
+        if !cooldown_exempt
+            && state.last_operation_timestamp > 0
             && now
                 < state
                     .last_operation_timestamp
@@ -404,6 +668,14 @@ mod anti_abuse {
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
+            if let Some(operation) = operation {
+                crate::BountyEscrowContract::record_compliance_rejection(
+                    env,
+                    operation,
+                    address,
+                    crate::COMPLIANCE_COOLDOWN,
+                );
+            }
             panic!("Operation in cooldown period");
         }
 
@@ -423,6 +695,14 @@ mod anti_abuse {
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
+                if let Some(operation) = operation {
+                    crate::BountyEscrowContract::record_compliance_rejection(
+                        env,
+                        operation,
+                        address,
+                        crate::COMPLIANCE_RATE_LIMIT,
+                    );
+                }
                 panic!("Rate limit exceeded");
             }
             state.operation_count += 1;
@@ -503,6 +783,32 @@ pub mod rbac {
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 5_000; // 50% max fee
 const MAX_BATCH_SIZE: u32 = 20;
+/// Maximum ids resolved by one `get_escrows_batch` call; further ids are ignored.
+const MAX_ESCROW_VIEW_BATCH: u32 = 50;
+/// Maximum index entries examined by one `refund_next_expired` sweep.
+const MAX_KEEPER_SCAN: u32 = 100;
+/// Default lifetime of a refund approval when `approve_refund` is given no expiry (30 days).
+const DEFAULT_REFUND_APPROVAL_WINDOW: u64 = 30 * 24 * 60 * 60;
+/// How long a large-release approval keeps accepting signatures after the first one (7 days).
+const RELEASE_APPROVAL_WINDOW: u64 = 7 * 24 * 60 * 60;
+/// Maximum status transitions retained per escrow; the oldest entries are dropped first.
+const MAX_STATUS_HISTORY: u32 = 50;
+/// Maximum uses retained in a capability's usage log; the oldest entries are dropped first.
+const MAX_CAPABILITY_USAGE: u32 = 50;
+/// Maximum length in bytes of an escrow memo attached via `lock_funds_memo`.
+const MAX_MEMO_LEN: u32 = 128;
+/// Maximum entries retained in the compliance log; the oldest entries are dropped first.
+const MAX_COMPLIANCE_LOG: u32 = 200;
+/// Maximum lock-fee discount tiers accepted by `set_fee_discount_tiers`.
+const MAX_FEE_DISCOUNT_TIERS: u32 = 10;
+/// Maximum payouts retained per recipient history; the oldest entries are dropped first.
+const MAX_RECIPIENT_HISTORY: u32 = 100;
+/// Shortest delay `queue_admin_action` accepts between queueing and execution (1 day).
+const MIN_ADMIN_ACTION_DELAY: u64 = 24 * 60 * 60;
+/// Operation log entries retained for `export_operations`; older entries are dropped.
+const MAX_OPERATION_LOG: u64 = 500;
+/// Semantic version of the contract code, reported by `health_check` and `get_capabilities`.
+pub const CONTRACT_SEMVER: &str = "1.0.0";
 
 extern crate grainlify_core;
 use grainlify_core::asset;
@@ -529,6 +835,25 @@ pub enum DisputeReason {
     Other = 5,
 }
 
+/// Running totals of pending-claim disputes, by reason and by outcome.
+///
+/// Reasons are counted when `authorize_claim` opens a claim; outcomes when it
+/// is claimed (`ResolvedInFavorOfContributor`) or cancelled with
+/// `cancel_pending_claim`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DisputeStats {
+    pub reason_expired: u32,
+    pub reason_unsatisfactory_work: u32,
+    pub reason_fraud: u32,
+    pub reason_quality_issue: u32,
+    pub reason_other: u32,
+    pub outcome_contributor: u32,
+    pub outcome_depositor: u32,
+    pub outcome_cancelled_by_admin: u32,
+    pub outcome_refunded: u32,
+}
+
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -553,14 +878,17 @@ pub enum Error {
     InvalidBatchSize = 10,
     BatchSizeMismatch = 11,
     DuplicateBountyId = 12,
-    /// Returned when amount is invalid (zero, negative, or exceeds available)
+    /// Returned when amount is invalid (zero, negative, or exceeds available),
+    /// or when `finalize_escrow` targets an escrow the balance still covers
     InvalidAmount = 13,
     /// Returned when deadline is invalid (in the past or too far in the future)
     InvalidDeadline = 14,
-    /// Returned when contract has insufficient funds for the operation
+    /// Returned when contract has insufficient funds for the operation, or when
+    /// the funds are reserved by unreleased release schedules
     InsufficientFunds = 16,
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
+    /// Returned when the operation is paused
     FundsPaused = 18,
     /// Returned when lock amount is below the configured policy minimum (Issue #62)
     AmountBelowMinimum = 19,
@@ -571,9 +899,9 @@ pub enum Error {
     ClaimPending = 22,
     /// Returned when claim ticket is not found
     TicketNotFound = 23,
-    /// Returned when claim ticket has already been used (replay prevention)
+    /// Returned when claim ticket or payout voucher has already been used (replay prevention)
     TicketAlreadyUsed = 24,
-    /// Returned when claim ticket has expired
+    /// Returned when claim ticket or payout voucher has expired
     TicketExpired = 25,
     CapabilityNotFound = 26,
     CapabilityExpired = 27,
@@ -587,7 +915,8 @@ pub enum Error {
     ContractDeprecated = 34,
     /// Returned when participant filtering is blocklist-only and the address is blocklisted
     ParticipantBlocked = 35,
-    /// Returned when participant filtering is allowlist-only and the address is not allowlisted
+    /// Returned when participant filtering is allowlist-only and the address is not allowlisted,
+    /// or when a recipient is missing from a capability's or escrow's recipient allowlist
     ParticipantNotAllowed = 36,
     /// Refund for anonymous escrow must go through refund_resolved (resolver provides recipient)
     AnonymousRefundRequiresResolution = 39,
@@ -595,11 +924,31 @@ pub enum Error {
     AnonymousResolverNotSet = 40,
     /// Bounty exists but is not an anonymous escrow (for refund_resolved)
     NotAnonymousEscrow = 41,
-    /// Use get_escrow_info_v2 for anonymous escrows
-    UseGetEscrowInfoV2ForAnonymous = 37,
+    // 37 (UseGetEscrowInfoV2ForAnonymous) was never returned and is retired;
+    // do not reuse it.
     InvalidSelectionInput = 42,
     /// Returned when an upgrade safety pre-check fails
     UpgradeSafetyCheckFailed = 43,
+    /// Escrow memo exceeds `MAX_MEMO_LEN` bytes
+    MemoTooLong = 44,
+    /// Ticket requires admin confirmation that has not been given yet
+    TicketNotConfirmed = 45,
+    /// Escrow has not been locked for the configured minimum duration yet
+    LockDurationNotMet = 46,
+    /// Operation needs an escrow that is `Released` or `Refunded`
+    EscrowNotTerminal = 47,
+    /// Escrow must be archived before it can be purged
+    EscrowNotArchived = 48,
+    /// Recipient's risk tier needs an `approve_release_to` confirmation first
+    RecipientApprovalRequired = 49,
+    /// Scheduled escrow cannot be activated before its start time
+    LockNotStarted = 50,
+    /// Escrow was marked non-refundable; it can only be released
+    RefundForbidden = 51,
+    /// Recipient is on the admin-maintained release denylist
+    RecipientDenied = 52,
+    /// Escrow's release dependency has not been reported `Completed`
+    DependencyNotSatisfied = 54,
 }
 
 pub const RISK_FLAG_HIGH_RISK: u32 = 1 << 0;
@@ -613,6 +962,48 @@ pub const NOTIFY_ON_RELEASE: u32 = 1 << 1;
 pub const NOTIFY_ON_DISPUTE: u32 = 1 << 2;
 pub const NOTIFY_ON_EXPIRATION: u32 = 1 << 3;
 
+/// Compliance log reason codes (`ComplianceLogEntry::reason_code`).
+pub const COMPLIANCE_DENYLIST: u32 = 1;
+pub const COMPLIANCE_COOLDOWN: u32 = 2;
+pub const COMPLIANCE_RATE_LIMIT: u32 = 3;
+pub const COMPLIANCE_HALT: u32 = 4;
+
+/// An operation rejected for a policy reason; see `get_compliance_log`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceLogEntry {
+    pub operation: Symbol,
+    pub caller: Address,
+    pub reason_code: u32,
+    pub timestamp: u64,
+}
+
+/// A fund movement mirrored for off-chain indexers; see `export_operations`.
+///
+/// `op_type` is one of `lock`, `release`, `refund` or `claim`. `actor` is the
+/// address on the other side of the movement: the depositor for locks, the
+/// recipient for releases and claims, and the refund target for refunds.
+/// Anonymous locks have no public depositor and record the contract address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationLogEntry {
+    pub seq: u64,
+    pub op_type: Symbol,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Outcome of an external milestone a bounty's release waits on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DependencyStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EscrowMetadata {
@@ -624,6 +1015,83 @@ pub struct EscrowMetadata {
     pub reference_hash: Option<soroban_sdk::Bytes>,
 }
 
+/// Share of a post-deadline refund paid to `penalty_recipient` instead of the depositor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPenalty {
+    pub penalty_bps: i128,
+    pub penalty_recipient: Address,
+}
+
+/// Bonus paid on partial releases within `window_seconds` of lock; see `set_early_bonus`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyBonus {
+    pub bonus_bps: i128,
+    pub window_seconds: u64,
+}
+
+/// Payout terms signed off-chain by a registered voucher signer; see `redeem_voucher`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    /// Ledger timestamp after which the voucher can no longer be redeemed.
+    pub expiry: u64,
+    /// Chosen by the signer so the same terms can be issued more than once.
+    pub nonce: u64,
+}
+
+/// Share of a released escrow held back for review; see `release_with_holdback`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Holdback {
+    pub contributor: Address,
+    pub depositor: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Earliest time `release_holdback` may pay the contributor.
+    pub releasable_at: u64,
+}
+
+/// High-risk admin setting change that must sit in the queue before taking
+/// effect; see `queue_admin_action`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// Same effect as `set_amount_policy(min_amount, max_amount)`.
+    AmountPolicy(i128, i128),
+    /// Same effect as `set_address_denied(address, denied)`.
+    AddressDenied(Address, bool),
+    /// Same effect as `set_blocklist_entry(address, blocked)`.
+    BlocklistEntry(Address, bool),
+}
+
+/// An `AdminAction` waiting in the queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdminAction {
+    pub action: AdminAction,
+    pub queued_at: u64,
+    /// Earliest time `execute_admin_action` may apply the action.
+    pub execute_after: u64,
+}
+
+/// Reusable escrow settings applied by `lock_from_template`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTemplate {
+    pub name: soroban_sdk::String,
+    /// Seconds from lock time to the escrow deadline.
+    pub deadline_horizon: u64,
+    pub min_amount: i128,
+    pub max_amount: i128,
+    /// Default `bounty_type` written to the new escrow's metadata.
+    pub bounty_type: soroban_sdk::String,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EscrowStatus {
@@ -631,6 +1099,12 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Funded by `schedule_future_lock` but not yet live; `activate_lock`
+    /// moves it to `Locked` once its start time is reached.
+    Pending,
+    /// Folded into another escrow by `merge_escrows`. Nothing was paid out;
+    /// the funds are counted under the target, so aggregate stats skip it.
+    Merged,
 }
 
 #[contracttype]
@@ -676,6 +1150,41 @@ pub struct DeprecationStatus {
     pub migration_target: Option<Address>,
 }
 
+/// Feature-detection view returned by `get_capabilities`.
+///
+/// Lets integrators check which optional feature sets a deployment supports
+/// instead of probing entrypoints by trial and error.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCapabilities {
+    /// Persisted contract version (`DataKey::Version`), bumped by admin on upgrade.
+    pub version: u32,
+    /// Semantic version of the deployed code.
+    pub semver: soroban_sdk::String,
+    /// `issue_claim_ticket` / deterministic claim ticket selection.
+    pub claim_tickets: bool,
+    /// Delegated capabilities (`issue_capability`, `*_with_capability`).
+    pub capability_tokens: bool,
+    /// Commitment-based anonymous escrows (`lock_funds_anonymous`).
+    pub anonymous_escrow: bool,
+    /// Escrows in tokens other than the default (`lock_funds_token`).
+    pub multi_token: bool,
+    /// Incremental payouts via `partial_release`.
+    pub partial_release: bool,
+    /// `batch_lock_funds` / `batch_release_funds`.
+    pub batch_operations: bool,
+    /// Read-only simulation (`dry_run_lock`, `dry_run_release`, `dry_run_refund`).
+    pub dry_run: bool,
+    /// Multisig approval for large releases.
+    pub multisig_release: bool,
+    /// Per-token fee configuration and fee-exempt allowlist.
+    pub token_fees: bool,
+    /// Per-escrow risk flags.
+    pub risk_flags: bool,
+    /// Auditor state export (`export_state_summary`, `export_state_digest`).
+    pub state_export: bool,
+}
+
 /// Anonymous escrow: only a 32-byte depositor commitment is stored on-chain.
 /// Refunds require the configured resolver to call `refund_resolved(bounty_id, recipient)`.
 #[contracttype]
@@ -721,12 +1230,14 @@ pub enum DataKey {
     DepositorIndex(Address), // Vec<u64> of bounty_ids by depositor
     FeeConfig,               // Fee configuration
     RefundApproval(u64),     // bounty_id -> RefundApproval
+    RefundApprovalWindow,    // u64 seconds before a refund approval expires
     ReentrancyGuard,
     MultisigConfig,
     ReleaseApproval(u64),        // bounty_id -> ReleaseApproval
     PendingClaim(u64),           // bounty_id -> ClaimRecord
     TicketCounter,               // monotonic claim ticket id
     ClaimTicket(u64),            // ticket_id -> ClaimTicket
+    TicketConfirmed(u64),        // ticket_id -> bool (admin second factor)
     ClaimTicketIndex,            // Vec<u64> all ticket ids
     BeneficiaryTickets(Address), // beneficiary -> Vec<u64>
     ClaimWindow,                 // u64 seconds (global config)
@@ -750,10 +1261,149 @@ pub enum DataKey {
     /// Chain identifier (e.g., "stellar", "ethereum") for cross-network protection
     /// Per-token fee configuration keyed by token contract address.
     TokenFeeConfig(Address),
+    /// Depositors exempt from lock and release fees (address -> bool).
+    FeeExempt(Address),
     ChainId,
     NetworkId,
 
     MaintenanceMode, // bool flag
+    /// Seconds added to `now` by `lock_funds_default_deadline` (u64).
+    DefaultDeadlineHorizon,
+    /// Pause everything instead of panicking on an INV-2 breach (bool flag).
+    InvariantAutoHalt,
+    /// Bounded `(status, timestamp)` trail for an escrow (bounty_id -> Vec).
+    StatusHistory(u64),
+    /// Optional depositor note attached at lock time (bounty_id -> String).
+    EscrowMemo(u64),
+    /// Running dispute counters by reason and outcome (DisputeStats).
+    DisputeStats,
+    /// Bounded usage log for a capability (capability_id -> Vec<CapabilityUse>).
+    CapabilityUsage(u64),
+    /// Minimum seconds between lock and release (u64, default 0).
+    MinLockDuration,
+    /// Let anyone refund escrows past their deadline to the depositor (bool flag).
+    AutoRefundPolicy,
+    /// Set once `SurplusDetected` has been emitted; cleared when balances match (bool).
+    SurplusReported,
+    /// Archival flag for a finished escrow (bounty_id -> bool).
+    Archived(u64),
+    /// Durable record of a purged escrow; keeps the bounty id reserved (PurgeRecord).
+    Purged(u64),
+    /// Risk tier assigned to a recipient (address -> u32, default 0).
+    RecipientTier(Address),
+    /// Whether releases to recipients in a tier need approval (tier -> bool).
+    TierApprovalRequired(u32),
+    /// Recipient confirmed by the admin for the next release of an escrow (bounty_id -> Address).
+    RecipientApproval(u64),
+    /// One-way flag blocking every refund path for an escrow (bounty_id -> bool).
+    NonRefundable(u64),
+}
+
+/// Storage keys added once `DataKey` reached the 50-variant limit of contract
+/// enums. Used exactly like `DataKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKeyExt {
+    /// Ed25519 public key allowed to sign payout vouchers (pubkey -> bool).
+    VoucherSigner(BytesN<32>),
+    /// SHA-256 of a redeemed voucher payload, kept for replay protection (-> bool).
+    VoucherRedeemed(BytesN<32>),
+    /// Incrementally maintained `AggregateStats` (see `get_aggregate_stats_cached`).
+    AggregateStatsCache,
+    /// Event topics a depositor subscribed to for `targeted` events (address -> Vec<Symbol>).
+    NotifyTopics(Address),
+    /// Release denylist entry (address -> bool); see `set_address_denied`.
+    DeniedAddress(Address),
+    /// Milestone payout of an escrow ((bounty_id, schedule_id) -> BountyReleaseSchedule).
+    BountySchedule(u64, u64),
+    /// Number of release schedules created for an escrow (bounty_id -> u64).
+    BountyScheduleCount(u64),
+    /// Sum of not-yet-released schedule amounts for an escrow (bounty_id -> i128).
+    BountySchedulePending(u64),
+    /// Deadline auto-extension policy for `record_activity` (ActivityExtensionPolicy).
+    ActivityExtension,
+    /// Seconds an escrow's deadline has been extended by activity (bounty_id -> u64).
+    ActivityExtended(u64),
+    /// Grace period copied into new pending claims (u64 seconds); see `set_claim_grace`.
+    ClaimGrace,
+    /// Residual at or below which a partial release sweeps and closes the escrow (i128).
+    DustThreshold,
+    /// Multisig signers who approved an emergency withdrawal to a target (address -> Vec<Address>).
+    EmergencyWithdrawApprovals(Address),
+    /// Error-rate circuit breaker config and sample window (ErrorRateBreaker).
+    ErrorRateBreaker,
+    /// Number of recorded views of an escrow (bounty_id -> u64); see `record_view`.
+    EscrowViews(u64),
+    /// Cached `(decimals, symbol)` of the configured token; see `get_token_info`.
+    TokenInfo,
+    /// Named escrow template (name -> EscrowTemplate); see `save_escrow_template`.
+    EscrowTemplate(soroban_sdk::String),
+    /// Names of saved escrow templates in first-save order (Vec<String>).
+    EscrowTemplateIndex,
+    /// Share of post-deadline refunds diverted to a penalty recipient (RefundPenalty).
+    RefundPenalty,
+    /// Bounded log of policy rejections, oldest first (Vec<ComplianceLogEntry>).
+    ComplianceLog,
+    /// External dependency a release waits on (bounty_id -> String).
+    BountyDependency(u64),
+    /// Reported status of an external dependency (dependency_id -> DependencyStatus).
+    DependencyStatus(soroban_sdk::String),
+    /// Early-release bonus of an escrow (bounty_id -> EarlyBonus).
+    EarlyBonus(u64),
+    /// Recipients an escrow may pay out to (bounty_id -> Vec<Address>).
+    EscrowRecipientAllowlist(u64),
+    /// Lock-fee volume discounts as ascending `(min_amount, discount_bps)` (Vec<(i128, i128)>).
+    FeeDiscountTiers,
+    /// Completed payouts received by an address (address -> (u64 count, i128 total)).
+    ContributorReputation(Address),
+    /// Pending held-back share of a released escrow (bounty_id -> Holdback).
+    Holdback(u64),
+    /// Payouts received by an address, oldest first (address -> Vec<(bounty_id, amount, timestamp)>).
+    RecipientHistory(Address),
+    /// Queued admin action awaiting its delay (action_id -> PendingAdminAction).
+    PendingAdminAction(u64),
+    /// Last id handed out by `queue_admin_action` (u64).
+    AdminActionCounter,
+    /// Operation log entry by sequence number (seq -> OperationLogEntry).
+    OperationLog(u64),
+    /// Sequence number of the latest operation log entry (u64).
+    OperationLogSeq,
+    /// Optional claim requirements of a ticket (ticket_id -> ClaimTicketFlags).
+    TicketFlags(u64),
+    /// Timestamp after which a refund approval can no longer be executed (bounty_id -> u64).
+    /// Absent for approvals granted before expiry existed, which never expire.
+    RefundApprovalExpiry(u64),
+    /// Token an escrow was funded in (bounty_id -> Address); see `escrow_token`.
+    /// Absent for escrows locked before per-escrow tokens, which use `DataKey::Token`.
+    EscrowToken(u64),
+    /// Position in `ClaimTicketIndex` where the next `reap_expired_tickets` call resumes (u32).
+    TicketReapCursor,
+    /// Ledger timestamp at which an escrow's funds were locked (bounty_id -> u64).
+    /// Absent for escrows locked before it was recorded; see `escrow_locked_at`.
+    EscrowLockedAt(u64),
+    /// Fee rate on admin-approved refunds in basis points (i128); see `set_refund_fee_rate`.
+    RefundFeeRate,
+    /// Expiry and consumption of a large-release approval (bounty_id -> ReleaseApprovalStatus).
+    ReleaseApprovalStatus(u64),
+    /// Contributors a `Release` capability may pay out to (capability_id -> Vec<Address>).
+    /// Absent when the capability may pay anyone.
+    CapabilityRecipients(u64),
+    /// Seconds after a pending claim's `expires_at` during which `claim` still
+    /// succeeds as a late claim (bounty_id -> u64). Absent means no grace.
+    ClaimGracePeriod(u64),
+}
+
+/// Audit record left behind when an archived escrow is purged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurgeRecord {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub final_status: EscrowStatus,
+    pub token: Address,
+    pub purged_by: Address,
+    pub purged_at: u64,
 }
 
 #[contracttype]
@@ -763,6 +1413,25 @@ pub struct EscrowWithId {
     pub escrow: Escrow,
 }
 
+/// Everything a frontend needs about one escrow, returned by `get_escrow_bundle`.
+///
+/// `metadata` and `pending_claim` hold at most one entry and are empty when the
+/// record does not exist (contract types cannot nest `Option<struct>`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowBundle {
+    pub bounty_id: u64,
+    pub escrow: Escrow,
+    pub metadata: Vec<EscrowMetadata>,
+    pub refund_history: Vec<RefundRecord>,
+    pub pending_claim: Vec<ClaimRecord>,
+    /// Whether the escrow still holds funds (`Locked`, `PartiallyRefunded` or `Pending`).
+    pub funds_locked: bool,
+    pub archived: bool,
+    /// When the escrow reached `Released` or `Refunded`; `None` while it is open.
+    pub completed_at: Option<u64>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseFlags {
@@ -801,6 +1470,7 @@ pub struct AntiAbuseConfigView {
     pub window_size: u64,
     pub max_operations: u32,
     pub cooldown_period: u64,
+    pub exempt_admin_cooldown: bool,
 }
 
 #[contracttype]
@@ -851,6 +1521,55 @@ pub struct ReleaseApproval {
     pub approvals: Vec<Address>,
 }
 
+/// Window and use of a large-release approval, stored beside it under
+/// `DataKeyExt::ReleaseApprovalStatus`. Approvals recorded without an entry
+/// never expire and are not yet consumed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApprovalStatus {
+    /// Set from the first approval; no approvals are accepted after this time.
+    pub expires_at: u64,
+    /// Set once `release_funds` has paid out the escrow this approval covers.
+    pub consumed: bool,
+}
+
+/// Error-rate circuit breaker fed by `track_operation`; see `set_error_rate_breaker`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorRateBreaker {
+    /// Error rate in basis points that must be exceeded to trip.
+    pub max_rate_bps: u32,
+    /// Operations the window must hold before the rate is evaluated.
+    pub min_samples: u64,
+    /// Operations tracked since the breaker was configured or last tripped.
+    pub samples: u64,
+    /// Failed operations among `samples`.
+    pub errors: u64,
+}
+
+/// Deadline auto-extension applied by `record_activity`; see `set_activity_extension`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityExtensionPolicy {
+    /// Seconds from the activity time the deadline is pushed to; 0 disables.
+    pub extend_by: u64,
+    /// Most seconds an escrow's deadline may be extended by activity in total.
+    pub max_total_extension: u64,
+}
+
+/// Milestone payout of part of a bounty escrow, created by
+/// `create_bounty_release_schedule`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyReleaseSchedule {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub released: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ClaimRecord {
@@ -874,6 +1593,17 @@ pub struct ClaimTicket {
     pub issued_at: u64,
 }
 
+/// Optional claim requirements of a ticket, stored beside it under
+/// `DataKeyExt::TicketFlags`. Tickets without an entry have neither flag set.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClaimTicketFlags {
+    /// When set, `claim_with_ticket` also needs `confirm_claim_ticket` from the admin.
+    pub requires_confirmation: bool,
+    /// When set, the beneficiary may direct the payout elsewhere via `claim_with_ticket_to`.
+    pub redirectable: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CapabilityAction {
@@ -896,6 +1626,15 @@ pub struct Capability {
     pub revoked: bool,
 }
 
+/// One recorded use of a capability, kept in its on-chain usage log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapabilityUse {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RefundMode {
@@ -970,6 +1709,45 @@ pub struct SimulationResult {
     pub remaining_amount: i128,
 }
 
+/// Per-token part of an `EmergencyPreview`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyTokenPreview {
+    pub token: Address,
+    /// Contract balance of `token` that would be sent to the target.
+    pub balance: i128,
+    /// Amount open escrows and pending holdbacks still claim in `token`.
+    pub total_remaining: i128,
+}
+
+/// Read-only projection of `emergency_withdraw`; see `simulate_emergency_withdraw`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyPreview {
+    /// Address the balances would be sent to.
+    pub target: Address,
+    /// False when the contract has not been initialized; all other fields are then empty.
+    pub initialized: bool,
+    /// Whether `lock_paused` is set, i.e. whether the drain could be triggered now.
+    pub lock_paused: bool,
+    /// One entry per tracked token, default token first.
+    pub tokens: Vec<EmergencyTokenPreview>,
+    /// Escrows in the index that would be wiped, whatever their token or status.
+    pub escrows: u32,
+    /// Open escrows with a non-zero `remaining_amount`, in any token.
+    pub active_escrows: u32,
+}
+
+/// Optional inputs to `lock_funds_logic` beyond the `lock_funds` arguments.
+#[derive(Default)]
+struct LockOptions {
+    /// Token to lock; the contract's default token when `None`.
+    token: Option<Address>,
+    memo: Option<String>,
+    /// Creates the escrow `Pending` until this time; see `schedule_future_lock`.
+    start_time: Option<u64>,
+}
+
 #[contract]
 pub struct BountyEscrowContract;
 
@@ -983,6 +1761,14 @@ impl BountyEscrowContract {
         monitoring::get_analytics(&env)
     }
 
+    /// Number of tracked operations in the last `window_seconds`.
+    ///
+    /// Counts are kept in hourly buckets (up to 7 days are retained), so the
+    /// result includes the whole bucket that straddles the window start.
+    pub fn get_volume_in_window(env: Env, window_seconds: u64) -> u64 {
+        monitoring::get_volume_in_window(&env, window_seconds)
+    }
+
     pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
         monitoring::get_state_snapshot(&env)
     }
@@ -1038,6 +1824,7 @@ impl BountyEscrowContract {
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
         env.storage().instance().set(&DataKey::Version, &1u32);
+        Self::cache_token_info(&env, &token);
 
         events::emit_bounty_initialized(
             &env,
@@ -1080,30 +1867,102 @@ impl BountyEscrowContract {
         (Self::get_chain_id(env.clone()), Self::get_network_id(env))
     }
 
-    /// Return the persisted contract version.
-    pub fn get_version(env: Env) -> u32 {
-        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    /// Configured token address with its decimals and symbol.
+    ///
+    /// Served from the cache filled at `init` (or by `refresh_token_info`); the
+    /// token contract is only queried when nothing has been cached yet.
+    pub fn get_token_info(env: Env) -> Result<(Address, u32, soroban_sdk::String), Error> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let (decimals, symbol) = match env.storage().instance().get(&DataKeyExt::TokenInfo) {
+            Some(info) => info,
+            None => {
+                let client = token::Client::new(&env, &token);
+                (client.decimals(), client.symbol())
+            }
+        };
+        Ok((token, decimals, symbol))
     }
 
-    /// Update the persisted contract version (admin only).
-    pub fn set_version(env: Env, new_version: u32) -> Result<(), Error> {
+    /// Re-read decimals and symbol from the token contract into the cache (admin only).
+    pub fn refresh_token_info(env: Env) -> Result<(Address, u32, soroban_sdk::String), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
+
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let client = token::Client::new(&env, &token);
+        let (decimals, symbol) = (client.decimals(), client.symbol());
         env.storage()
             .instance()
-            .set(&DataKey::Version, &new_version);
-        Ok(())
+            .set(&DataKeyExt::TokenInfo, &(decimals, symbol.clone()));
+        Ok((token, decimals, symbol))
     }
 
-    /// Calculate fee amount based on rate (in basis points), using **ceiling division**.
-    ///
-    /// Ceiling division ensures that a non-zero fee rate always produces at least
-    /// 1 stroop of fee, regardless of how small the individual amount is.  This
-    /// closes the principal-drain vector where an attacker breaks a large deposit
+    /// Best-effort cache fill at init; an address that is not (yet) a token
+    /// contract leaves the cache empty rather than failing initialization.
+    fn cache_token_info(env: &Env, token: &Address) {
+        let client = token::Client::new(env, token);
+        if let (Ok(Ok(decimals)), Ok(Ok(symbol))) = (client.try_decimals(), client.try_symbol()) {
+            env.storage()
+                .instance()
+                .set(&DataKeyExt::TokenInfo, &(decimals, symbol));
+        }
+    }
+
+    /// Return the persisted contract version.
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    /// Update the persisted contract version (admin only).
+    pub fn set_version(env: Env, new_version: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &new_version);
+        Ok(())
+    }
+
+    /// Report the contract version and which optional feature sets this build supports.
+    pub fn get_capabilities(env: Env) -> ContractCapabilities {
+        ContractCapabilities {
+            version: Self::get_version(env.clone()),
+            semver: soroban_sdk::String::from_str(&env, CONTRACT_SEMVER),
+            claim_tickets: true,
+            capability_tokens: true,
+            anonymous_escrow: true,
+            multi_token: true,
+            partial_release: true,
+            batch_operations: true,
+            dry_run: true,
+            multisig_release: true,
+            token_fees: true,
+            risk_flags: true,
+            state_export: true,
+        }
+    }
+
+    /// Calculate fee amount based on rate (in basis points), using **ceiling division**.
+    ///
+    /// Ceiling division ensures that a non-zero fee rate always produces at least
+    /// 1 stroop of fee, regardless of how small the individual amount is.  This
+    /// closes the principal-drain vector where an attacker breaks a large deposit
     /// into dust amounts that each round down to a zero fee.
     ///
     /// Formula: ceil(amount * fee_rate / BASIS_POINTS)
@@ -1195,6 +2054,7 @@ impl BountyEscrowContract {
                 release_fee_rate: fee_config.release_fee_rate,
                 fee_recipient: fee_config.fee_recipient.clone(),
                 fee_enabled: fee_config.fee_enabled,
+                refund_fee_rate: Self::get_refund_fee_rate(env.clone()),
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -1202,6 +2062,193 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Set volume discounts on the lock fee (admin only).
+    ///
+    /// Each tier is `(min_amount, discount_bps)`. A lock of at least
+    /// `min_amount` has its lock fee rate cut by `discount_bps` of itself,
+    /// using the highest tier it reaches. Tiers must be sorted by strictly
+    /// increasing `min_amount` with non-decreasing discounts. An empty list
+    /// removes all discounts.
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - more than `MAX_FEE_DISCOUNT_TIERS` tiers
+    /// * `InvalidAmount` - a `min_amount` is not positive or not above the previous one
+    /// * `InvalidFeeRate` - a discount is outside `[0, BASIS_POINTS]` or below the previous one
+    pub fn set_fee_discount_tiers(env: Env, tiers: Vec<(i128, i128)>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if tiers.len() > MAX_FEE_DISCOUNT_TIERS {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut previous: Option<(i128, i128)> = None;
+        for (min_amount, discount_bps) in tiers.iter() {
+            if !(0..=BASIS_POINTS).contains(&discount_bps) {
+                return Err(Error::InvalidFeeRate);
+            }
+            match previous {
+                Some((prev_min, prev_discount)) => {
+                    if min_amount <= prev_min {
+                        return Err(Error::InvalidAmount);
+                    }
+                    if discount_bps < prev_discount {
+                        return Err(Error::InvalidFeeRate);
+                    }
+                }
+                None => {
+                    if min_amount <= 0 {
+                        return Err(Error::InvalidAmount);
+                    }
+                }
+            }
+            previous = Some((min_amount, discount_bps));
+        }
+
+        if tiers.is_empty() {
+            env.storage()
+                .instance()
+                .remove(&DataKeyExt::FeeDiscountTiers);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKeyExt::FeeDiscountTiers, &tiers);
+        }
+        Ok(())
+    }
+
+    /// Configured lock-fee discount tiers, ascending by `min_amount`.
+    pub fn get_fee_discount_tiers(env: Env) -> Vec<(i128, i128)> {
+        env.storage()
+            .instance()
+            .get(&DataKeyExt::FeeDiscountTiers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Fee rate in basis points that `operation` would charge `depositor` on
+    /// `amount` of the default token.
+    ///
+    /// `operation` is `lock`, `release` or `refund`; anything else carries no
+    /// fee and returns 0. The rate is resolved in this order, and it is the
+    /// same resolution the lock, release and refund paths use:
+    ///
+    /// 1. Fees disabled in the governing config: 0.
+    /// 2. Fee-exempt depositor: 0.
+    /// 3. Base rate: a per-token `TokenFeeConfig` overrides the global
+    ///    `FeeConfig` for lock and release; refunds always use the rate set by
+    ///    `set_refund_fee_rate`.
+    /// 4. Lock only: the rate is cut by the highest volume discount tier
+    ///    `amount` reaches (see `set_fee_discount_tiers`).
+    ///
+    /// The refund rate only applies to admin-approved early refunds; refunds
+    /// after the deadline are always fee-free.
+    pub fn get_effective_fee_rate(
+        env: Env,
+        depositor: Address,
+        amount: i128,
+        operation: Symbol,
+    ) -> i128 {
+        let token_addr: Address = match env.storage().instance().get(&DataKey::Token) {
+            Some(token_addr) => token_addr,
+            None => return 0,
+        };
+        Self::fee_rate_for(&env, &token_addr, &depositor, amount, operation)
+    }
+
+    /// Effective fee rate resolution shared by the fee-charging paths; see
+    /// `get_effective_fee_rate` for the precedence.
+    fn fee_rate_for(
+        env: &Env,
+        token_addr: &Address,
+        depositor: &Address,
+        amount: i128,
+        operation: Symbol,
+    ) -> i128 {
+        let (lock_fee_rate, release_fee_rate, _fee_recipient, fee_enabled) =
+            Self::resolve_fee_config(env, token_addr);
+        let (base_rate, enabled) = if operation == symbol_short!("lock") {
+            (lock_fee_rate, fee_enabled)
+        } else if operation == symbol_short!("release") {
+            (release_fee_rate, fee_enabled)
+        } else if operation == symbol_short!("refund") {
+            (
+                Self::get_refund_fee_rate(env.clone()),
+                Self::get_fee_config_internal(env).fee_enabled,
+            )
+        } else {
+            return 0;
+        };
+        if !enabled || base_rate <= 0 || Self::is_fee_exempt(env.clone(), depositor.clone()) {
+            return 0;
+        }
+        if operation == symbol_short!("lock") {
+            Self::discounted_lock_fee_rate(env, amount, base_rate)
+        } else {
+            base_rate
+        }
+    }
+
+    /// `lock_fee_rate` reduced by the discount of the highest tier `amount` reaches.
+    fn discounted_lock_fee_rate(env: &Env, amount: i128, lock_fee_rate: i128) -> i128 {
+        let mut discount_bps = 0;
+        for (min_amount, tier_discount) in Self::get_fee_discount_tiers(env.clone()).iter() {
+            if amount < min_amount {
+                break;
+            }
+            discount_bps = tier_discount;
+        }
+        lock_fee_rate - lock_fee_rate * discount_bps / BASIS_POINTS
+    }
+
+    /// Set the fee charged on admin-approved refunds (admin only).
+    ///
+    /// The fee uses the global `FeeConfig` recipient and `fee_enabled` flag and
+    /// is deducted from the refunded amount. Refunds after the deadline are
+    /// never charged.
+    ///
+    /// # Errors
+    /// * `InvalidFeeRate` - `rate` is outside `[0, MAX_FEE_RATE]`
+    pub fn set_refund_fee_rate(env: Env, rate: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !(0..=MAX_FEE_RATE).contains(&rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKeyExt::RefundFeeRate, &rate);
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        events::emit_fee_config_updated(
+            &env,
+            events::FeeConfigUpdated {
+                lock_fee_rate: fee_config.lock_fee_rate,
+                release_fee_rate: fee_config.release_fee_rate,
+                fee_recipient: fee_config.fee_recipient.clone(),
+                fee_enabled: fee_config.fee_enabled,
+                refund_fee_rate: rate,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Fee rate in basis points charged on admin-approved refunds (0 when unset).
+    pub fn get_refund_fee_rate(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKeyExt::RefundFeeRate)
+            .unwrap_or(0)
+    }
+
     /// Updates the granular pause state and metadata for the contract.
     ///
     /// # Arguments
@@ -1296,12 +2343,22 @@ impl BountyEscrowContract {
     /// This is an emergency recovery function and should only be used as a last resort.
     /// The contract MUST have `lock_paused = true` before calling this.
     ///
+    /// Every token in use is drained: the default token and any token an
+    /// escrow was funded in. The default token is reported by
+    /// `EmergencyWithdrawEvent`, every other token by `EmergencyTokenWithdrawn`.
+    ///
+    /// When any drained balance exceeds the multisig `threshold_amount`, at least
+    /// `required_signatures` current signers must first have called
+    /// [`approve_emergency_withdraw`](Self::approve_emergency_withdraw) for
+    /// `target`. Recorded approvals for `target` are cleared once the drain runs.
+    ///
     /// # Arguments
     /// * `target` - The address that will receive the full contract balance.
     ///
     /// # Errors
     /// Returns `Error::NotPaused` if `lock_paused` is false.
-    /// Returns `Error::Unauthorized` if the caller is not the admin.
+    /// Returns `Error::Unauthorized` if the caller is not the admin, or if the
+    /// balance is above the multisig threshold without enough approvals.
     pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), Error> {
         let admin: Address = env
             .storage()
@@ -1316,22 +2373,46 @@ impl BountyEscrowContract {
         }
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::TokenClient::new(&env, &token_address);
-
         let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
+        let mut balances: Vec<(Address, i128)> = Vec::new(&env);
+        for token in multitoken_invariants::tracked_tokens(&env).iter() {
+            let token_balance = token::Client::new(&env, &token).balance(&contract_address);
+            balances.push_back((token, token_balance));
+        }
+        let balance = balances.get(0).map(|(_, b)| b).unwrap_or(0);
+
+        let approvals_key = DataKeyExt::EmergencyWithdrawApprovals(target.clone());
+        let multisig_config = Self::get_multisig_config(env.clone());
+        if balances
+            .iter()
+            .any(|(_, token_balance)| token_balance > multisig_config.threshold_amount)
+        {
+            let approvals: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&approvals_key)
+                .unwrap_or(Vec::new(&env));
+            // Only count approvers that are still signers under the current config.
+            let mut valid: u32 = 0;
+            for approver in approvals.iter() {
+                if multisig_config.signers.contains(&approver) {
+                    valid += 1;
+                }
+            }
+            if valid < multisig_config.required_signatures {
+                return Err(Error::Unauthorized);
+            }
+        }
+        env.storage().persistent().remove(&approvals_key);
 
-        if balance > 0 {
-            token_client.transfer(&contract_address, &target, &balance);
-            events::emit_emergency_withdraw(
-                &env,
-                events::EmergencyWithdrawEvent {
-                    admin,
-                    recipient: target,
-                    amount: balance,
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+        for (token, token_balance) in balances.iter() {
+            if token_balance > 0 {
+                token::Client::new(&env, &token).transfer(
+                    &contract_address,
+                    &target,
+                    &token_balance,
+                );
+            }
         }
 
         // Clear all escrow-related storage so the contract can be safely reused.
@@ -1341,12 +2422,33 @@ impl BountyEscrowContract {
             .get(&DataKey::EscrowIndex)
             .unwrap_or(Vec::new(&env));
         let mut seen_depositors: Vec<Address> = Vec::new(&env);
+        let now = env.ledger().timestamp();
         for bounty_id in index.iter() {
             if let Some(escrow) = env
                 .storage()
                 .persistent()
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
+                // Record the swept remainder of each live escrow as a refund to
+                // `target` so indexers can close it out.
+                if escrow.remaining_amount > 0
+                    && (escrow.status == EscrowStatus::Locked
+                        || escrow.status == EscrowStatus::PartiallyRefunded
+                        || escrow.status == EscrowStatus::Pending)
+                {
+                    emit_funds_refunded(
+                        &env,
+                        FundsRefunded {
+                            version: EVENT_VERSION_V2,
+                            bounty_id,
+                            amount: escrow.remaining_amount,
+                            refund_to: target.clone(),
+                            timestamp: now,
+                            trigger: RefundTrigger::EmergencyWithdraw,
+                        },
+                    );
+                }
+
                 let mut known = false;
                 for depositor in seen_depositors.iter() {
                     if depositor.clone() == escrow.depositor {
@@ -1365,19 +2467,54 @@ impl BountyEscrowContract {
             env.storage()
                 .persistent()
                 .remove(&DataKey::Metadata(bounty_id));
+            Self::remove_refund_approval(&env, bounty_id);
             env.storage()
                 .persistent()
-                .remove(&DataKey::RefundApproval(bounty_id));
+                .remove(&DataKey::ReleaseApproval(bounty_id));
             env.storage()
                 .persistent()
-                .remove(&DataKey::ReleaseApproval(bounty_id));
+                .remove(&DataKeyExt::ReleaseApprovalStatus(bounty_id));
             env.storage()
                 .persistent()
                 .remove(&DataKey::PendingClaim(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::ClaimGracePeriod(bounty_id));
             env.storage()
                 .persistent()
                 .remove(&DataKey::NonTransferableRewards(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::StatusHistory(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::EscrowToken(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::EscrowLockedAt(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::Holdback(bounty_id));
+            let schedules: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKeyExt::BountyScheduleCount(bounty_id))
+                .unwrap_or(0);
+            for schedule_id in 1..=schedules {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKeyExt::BountySchedule(bounty_id, schedule_id));
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::BountyScheduleCount(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKeyExt::BountySchedulePending(bounty_id));
         }
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::AggregateStatsCache);
 
         for depositor in seen_depositors.iter() {
             env.storage()
@@ -1389,1822 +2526,5724 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::EscrowIndex, &Vec::<u64>::new(&env));
 
+        if balance > 0 {
+            events::emit_emergency_withdraw(
+                &env,
+                events::EmergencyWithdrawEvent {
+                    admin: admin.clone(),
+                    recipient: target.clone(),
+                    amount: balance,
+                    timestamp: now,
+                },
+            );
+        }
+        for (token, token_balance) in balances.iter() {
+            if token != token_address && token_balance > 0 {
+                events::emit_emergency_token_withdrawn(
+                    &env,
+                    events::EmergencyTokenWithdrawn {
+                        admin: admin.clone(),
+                        token,
+                        recipient: target.clone(),
+                        amount: token_balance,
+                        timestamp: now,
+                    },
+                );
+            }
+        }
+
         Ok(())
     }
 
-    /// Returns current deprecation state (internal). When deprecated is true, new locks are blocked.
-    fn get_deprecation_state(env: &Env) -> DeprecationState {
-        env.storage()
-            .instance()
-            .get(&DataKey::DeprecationState)
-            .unwrap_or(DeprecationState {
-                deprecated: false,
-                migration_target: None,
-            })
-    }
+    /// Preview what [`emergency_withdraw`](Self::emergency_withdraw) would move and close.
+    ///
+    /// Reports the balance of every tracked token and counts every escrow the
+    /// withdraw would wipe. Does not require authorization or `lock_paused`,
+    /// and never writes storage; an uninitialized contract yields an empty
+    /// preview.
+    pub fn simulate_emergency_withdraw(env: Env, target: Address) -> EmergencyPreview {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return EmergencyPreview {
+                target,
+                initialized: false,
+                lock_paused: false,
+                tokens: Vec::new(&env),
+                escrows: 0,
+                active_escrows: 0,
+            };
+        }
 
-    fn get_participant_filter_mode(env: &Env) -> ParticipantFilterMode {
-        env.storage()
-            .instance()
-            .get(&DataKey::ParticipantFilterMode)
-            .unwrap_or(ParticipantFilterMode::Disabled)
-    }
+        let contract_address = env.current_contract_address();
+        let mut tokens: Vec<EmergencyTokenPreview> = Vec::new(&env);
+        for token in multitoken_invariants::tracked_tokens(&env).iter() {
+            tokens.push_back(EmergencyTokenPreview {
+                balance: token::Client::new(&env, &token).balance(&contract_address),
+                total_remaining: multitoken_invariants::sum_active_escrow_balances(&env, &token),
+                token,
+            });
+        }
 
-    /// Enforces participant filtering: returns Err if the address is not allowed to participate
-    /// (lock_funds / batch_lock_funds) under the current filter mode.
-    fn check_participant_filter(env: &Env, address: Address) -> Result<(), Error> {
-        let mode = Self::get_participant_filter_mode(env);
-        match mode {
-            ParticipantFilterMode::Disabled => Ok(()),
-            ParticipantFilterMode::BlocklistOnly => {
-                if anti_abuse::is_blocklisted(env, address) {
-                    return Err(Error::ParticipantBlocked);
-                }
-                Ok(())
-            }
-            ParticipantFilterMode::AllowlistOnly => {
-                if !anti_abuse::is_whitelisted(env, address) {
-                    return Err(Error::ParticipantNotAllowed);
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut escrows: u32 = 0;
+        let mut active_escrows: u32 = 0;
+        for bounty_id in index.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                escrows += 1;
+                if escrow.remaining_amount > 0
+                    && (escrow.status == EscrowStatus::Locked
+                        || escrow.status == EscrowStatus::PartiallyRefunded
+                        || escrow.status == EscrowStatus::Pending)
+                {
+                    active_escrows += 1;
                 }
-                Ok(())
             }
         }
-    }
 
-    /// Set deprecation (kill switch) and optional migration target. Admin only.
-    /// When deprecated is true: new lock_funds and batch_lock_funds are blocked; existing escrows
-    /// can still release, refund, or be migrated off-chain. Emits DeprecationStateChanged.
-    pub fn set_deprecated(
-        env: Env,
-        deprecated: bool,
-        migration_target: Option<Address>,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        EmergencyPreview {
+            target,
+            initialized: true,
+            lock_paused: Self::get_pause_flags(&env).lock_paused,
+            tokens,
+            escrows,
+            active_escrows,
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    }
+
+    /// Force an open escrow into a terminal state once its funds are gone (admin only).
+    ///
+    /// For escrows whose tokens left the contract through an external path,
+    /// so the record still reads open with a stale `remaining_amount`. Only an
+    /// escrow that `is_escrow_solvent` reports as uncovered can be finalized:
+    /// it is marked `Refunded` with `remaining_amount` zeroed, the status
+    /// change is recorded (setting its completion time), and `ForceFinalized`
+    /// is emitted.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is already `Released`, `Refunded` or `Merged`
+    /// * `InvalidAmount` - the contract balance still covers the escrow
+    pub fn finalize_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        let state = DeprecationState {
-            deprecated,
-            migration_target: migration_target.clone(),
-        };
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status == EscrowStatus::Released
+            || escrow.status == EscrowStatus::Refunded
+            || escrow.status == EscrowStatus::Merged
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let token = Self::escrow_token(&env, bounty_id);
+        let (own_claim, cumulative) =
+            multitoken_invariants::claims_through(&env, &token, bounty_id);
+        let contract_balance = multitoken_invariants::get_contract_token_balance(&env, &token);
+        if own_claim == 0 || cumulative <= contract_balance {
+            return Err(Error::InvalidAmount);
+        }
+
+        let written_off = escrow.remaining_amount;
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
         env.storage()
-            .instance()
-            .set(&DataKey::DeprecationState, &state);
-        emit_deprecation_state_changed(
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        events::emit_force_finalized(
             &env,
-            DeprecationStateChanged {
-                deprecated: state.deprecated,
-                migration_target: state.migration_target,
-                admin,
+            events::ForceFinalized {
+                bounty_id,
+                written_off,
+                contract_balance,
                 timestamp: env.ledger().timestamp(),
             },
         );
         Ok(())
     }
 
-    /// View: returns whether the contract is deprecated and the optional migration target address.
-    pub fn get_deprecation_status(env: Env) -> DeprecationStatus {
-        let s = Self::get_deprecation_state(&env);
-        DeprecationStatus {
-            deprecated: s.deprecated,
-            migration_target: s.migration_target,
-        }
-    }
-
-    /// Get current pause flags
-    pub fn get_pause_flags(env: &Env) -> PauseFlags {
-        env.storage()
+    /// Fold one escrow into another (admin only).
+    ///
+    /// The source's `remaining_amount` is added to the target's `amount` and
+    /// `remaining_amount`, and the source is closed as `Merged` with nothing
+    /// remaining. Both escrows are held by this contract, so no tokens move.
+    /// The target takes on the source's restrictions (see
+    /// `carry_escrow_policies`) so the moved funds stay as restricted as before.
+    ///
+    /// # Errors
+    /// * `DuplicateBountyId` - source and target are the same escrow
+    /// * `BountyNotFound` - either escrow does not exist
+    /// * `FundsNotLocked` - either escrow is not `Locked`
+    /// * `Unauthorized` - the escrows have different depositors
+    /// * `InvalidAssetId` - the escrows hold different tokens
+    /// * `ClaimPending` - either escrow has an unclaimed claim
+    /// * `InsufficientFunds` - the source has unreleased schedules
+    /// * `DependencyNotSatisfied` - the escrows wait on different unfinished
+    ///   release dependencies
+    /// * `ParticipantNotAllowed` - the escrows' recipient allowlists have no
+    ///   recipient in common
+    pub fn merge_escrows(
+        env: Env,
+        source_bounty_id: u64,
+        target_bounty_id: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
             .instance()
-            .get(&DataKey::PauseFlags)
-            .unwrap_or(PauseFlags {
-                lock_paused: false,
-                release_paused: false,
-                refund_paused: false,
-                pause_reason: None,
-                paused_at: 0,
-            })
-    }
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if source_bounty_id == target_bounty_id {
+            return Err(Error::DuplicateBountyId);
+        }
 
-    /// Check if an operation is paused
-    fn check_paused(env: &Env, operation: Symbol) -> bool {
-        let flags = Self::get_pause_flags(env);
-        if operation == symbol_short!("lock") {
-            if Self::is_maintenance_mode(env.clone()) {
-                return true;
+        let mut source: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(source_bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let mut target: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(target_bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if source.status != EscrowStatus::Locked || target.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if source.depositor != target.depositor {
+            return Err(Error::Unauthorized);
+        }
+        if Self::escrow_token(&env, source_bounty_id) != Self::escrow_token(&env, target_bounty_id)
+        {
+            return Err(Error::InvalidAssetId);
+        }
+        for bounty_id in [source_bounty_id, target_bounty_id] {
+            if let Some(claim) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            {
+                if !claim.claimed {
+                    return Err(Error::ClaimPending);
+                }
             }
-            return flags.lock_paused;
-        } else if operation == symbol_short!("release") {
-            return flags.release_paused;
-        } else if operation == symbol_short!("refund") {
-            return flags.refund_paused;
         }
-        false
-    }
-
-    /// Check if the contract is in maintenance mode
-    pub fn is_maintenance_mode(env: Env) -> bool {
-        env.storage()
-            .instance()
-            .get(&DataKey::MaintenanceMode)
-            .unwrap_or(false)
-    }
-
-    /// Update maintenance mode (admin only)
-    pub fn set_maintenance_mode(env: Env, enabled: bool) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        let scheduled = Self::scheduled_amount(&env, source_bounty_id);
+        if scheduled > 0 {
+            return Err(Error::InsufficientFunds);
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Self::carry_escrow_policies(&env, source_bounty_id, target_bounty_id)?;
+
+        let moved = source.remaining_amount;
+        target.amount = target
+            .amount
+            .checked_add(moved)
+            .ok_or(Error::InvalidAmount)?;
+        target.remaining_amount = target
+            .remaining_amount
+            .checked_add(moved)
+            .ok_or(Error::InvalidAmount)?;
+        source.remaining_amount = 0;
+        source.status = EscrowStatus::Merged;
+        invariants::assert_escrow(&env, &source);
+        invariants::assert_escrow(&env, &target);
 
         env.storage()
-            .instance()
-            .set(&DataKey::MaintenanceMode, &enabled);
+            .persistent()
+            .set(&DataKey::Escrow(target_bounty_id), &target);
+        Self::adjust_stats_cache(&env, &target.status, moved, 0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(source_bounty_id), &source);
+        Self::record_status_change(&env, source_bounty_id, source.status.clone());
+        multitoken_invariants::assert_after_disbursement(&env);
 
-        events::emit_maintenance_mode_changed(
+        events::emit_escrows_merged(
             &env,
-            MaintenanceModeChanged {
-                enabled,
-                admin: admin.clone(),
+            events::EscrowsMerged {
+                source_bounty_id,
+                target_bounty_id,
+                amount: moved,
+                target_remaining: target.remaining_amount,
                 timestamp: env.ledger().timestamp(),
             },
         );
         Ok(())
     }
 
-    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Make escrow `to` at least as restricted as escrow `from`, before funds
+    /// from `from` end up in it: the non-refundable and non-transferable flags,
+    /// an unfinished release dependency and the recipient allowlist carry over
+    /// (an allowlist already on `to` is narrowed to the recipients both allow).
+    ///
+    /// # Errors
+    /// * `DependencyNotSatisfied` - the escrows wait on different unfinished
+    ///   dependencies, which one escrow cannot hold at once
+    /// * `ParticipantNotAllowed` - the allowlists have no recipient in common
+    fn carry_escrow_policies(env: &Env, from: u64, to: u64) -> Result<(), Error> {
+        if Self::is_non_refundable(env.clone(), from) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::NonRefundable(to), &true);
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        anti_abuse::set_whitelist(&env, address, whitelisted);
-        Ok(())
-    }
-
-    fn next_capability_id(env: &Env) -> u64 {
-        let last_id: u64 = env
+        if env
             .storage()
-            .instance()
-            .get(&DataKey::CapabilityNonce)
-            .unwrap_or(0);
-        let next_id = last_id.saturating_add(1);
-        env.storage()
-            .instance()
-            .set(&DataKey::CapabilityNonce, &next_id);
-        next_id
-    }
-
-    fn record_receipt(
-        _env: &Env,
-        _outcome: CriticalOperationOutcome,
-        _bounty_id: u64,
-        _amount: i128,
-        _recipient: Address,
-    ) {
-        // Backward-compatible no-op until receipt storage/events are fully wired.
-    }
-
-    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, Error> {
-        env.storage()
             .persistent()
-            .get(&DataKey::Capability(capability_id))
-            .ok_or(Error::CapabilityNotFound)
-    }
-
-    fn validate_capability_scope_at_issue(
-        env: &Env,
-        owner: &Address,
-        action: &CapabilityAction,
-        bounty_id: u64,
-        amount_limit: i128,
-    ) -> Result<(), Error> {
-        if amount_limit <= 0 {
-            return Err(Error::InvalidAmount);
+            .get(&DataKey::NonTransferableRewards(from))
+            .unwrap_or(false)
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKey::NonTransferableRewards(to), &true);
         }
 
-        match action {
-            CapabilityAction::Claim => {
-                let claim: ClaimRecord = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::PendingClaim(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
-                if claim.recipient != owner.clone() {
-                    return Err(Error::Unauthorized);
-                }
-                if amount_limit > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+        if let Some((dependency_id, status)) = Self::get_bounty_dependency(env.clone(), from) {
+            if status != DependencyStatus::Completed {
+                match Self::get_bounty_dependency(env.clone(), to) {
+                    Some((existing, existing_status))
+                        if existing != dependency_id
+                            && existing_status != DependencyStatus::Completed =>
+                    {
+                        return Err(Error::DependencyNotSatisfied);
+                    }
+                    _ => env
+                        .storage()
+                        .persistent()
+                        .set(&DataKeyExt::BountyDependency(to), &dependency_id),
                 }
             }
-            CapabilityAction::Release => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
-                }
-                if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+        }
+
+        let from_list = Self::get_escrow_recipient_allowlist(env.clone(), from);
+        if !from_list.is_empty() {
+            let to_list = Self::get_escrow_recipient_allowlist(env.clone(), to);
+            let mut allowed = Vec::new(env);
+            for recipient in from_list.iter() {
+                if to_list.is_empty() || to_list.contains(&recipient) {
+                    allowed.push_back(recipient);
                 }
             }
-            CapabilityAction::Refund => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
-                    return Err(Error::FundsNotLocked);
-                }
-                if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
+            if allowed.is_empty() {
+                return Err(Error::ParticipantNotAllowed);
             }
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::EscrowRecipientAllowlist(to), &allowed);
         }
-
         Ok(())
     }
 
-    fn ensure_owner_still_authorized(
-        env: &Env,
-        capability: &Capability,
-        requested_amount: i128,
+    /// Carve `split_amount` out of an escrow into a new one (admin or depositor).
+    ///
+    /// The new escrow gets the source's depositor, token, deadline and lock
+    /// time, and the source's `amount` and `remaining_amount` shrink by
+    /// `split_amount`. Both escrows stay held by this contract, so no tokens
+    /// move. The source must keep a non-zero remainder that still covers its
+    /// unreleased schedules.
+    ///
+    /// The new escrow also inherits the source's per-escrow policies: the
+    /// non-refundable and non-transferable flags, release dependency, recipient
+    /// allowlist, tier approval, early-release bonus and used deadline
+    /// extension. Large-release and refund approvals are not copied; the new
+    /// escrow collects its own.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `source_bounty_id`
+    /// * `Unauthorized` - `caller` is neither the admin nor the depositor
+    /// * `FundsNotLocked` - source escrow is not `Locked`
+    /// * `BountyExists` - `new_bounty_id` is taken or was purged
+    /// * `InvalidAmount` - `split_amount` is not positive
+    /// * `InsufficientFunds` - the split would leave nothing in the source, or
+    ///   less than its unreleased schedules
+    /// * `ClaimPending` - the source has an unclaimed claim
+    pub fn split_escrow(
+        env: Env,
+        caller: Address,
+        source_bounty_id: u64,
+        new_bounty_id: u64,
+        split_amount: i128,
     ) -> Result<(), Error> {
-        if requested_amount <= 0 {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        let mut source: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(source_bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if caller != admin && caller != source.depositor {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        if source.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Escrow(new_bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(new_bounty_id))
+            || Self::is_escrow_purged(&env, new_bounty_id)
+        {
+            return Err(Error::BountyExists);
+        }
+        if split_amount <= 0 {
             return Err(Error::InvalidAmount);
         }
-
-        match capability.action {
-            CapabilityAction::Claim => {
-                let claim: ClaimRecord = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::PendingClaim(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
-                if claim.recipient != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                if requested_amount > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
+        let scheduled = Self::scheduled_amount(&env, source_bounty_id);
+        if split_amount >= source.remaining_amount
+            || source.remaining_amount - split_amount < scheduled
+        {
+            return Err(Error::InsufficientFunds);
+        }
+        if let Some(claim) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(source_bounty_id))
+        {
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
             }
-            CapabilityAction::Release => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
-                }
-                if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+        }
+
+        source.amount -= split_amount;
+        source.remaining_amount -= split_amount;
+        let split = Escrow {
+            depositor: source.depositor.clone(),
+            amount: split_amount,
+            remaining_amount: split_amount,
+            status: EscrowStatus::Locked,
+            deadline: source.deadline,
+            refund_history: vec![&env],
+        };
+        invariants::assert_escrow(&env, &source);
+        invariants::assert_escrow(&env, &split);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(source_bounty_id), &source);
+        Self::adjust_stats_cache(&env, &source.status, -split_amount, 0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(new_bounty_id), &split);
+        env.storage().persistent().set(
+            &DataKeyExt::EscrowToken(new_bounty_id),
+            &Self::escrow_token(&env, source_bounty_id),
+        );
+        env.storage().persistent().set(
+            &DataKeyExt::EscrowLockedAt(new_bounty_id),
+            &Self::escrow_locked_at(&env, source_bounty_id),
+        );
+        Self::carry_escrow_policies(&env, source_bounty_id, new_bounty_id)?;
+        if let Some(recipient) = Self::get_recipient_approval(env.clone(), source_bounty_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::RecipientApproval(new_bounty_id), &recipient);
+        }
+        if let Some(bonus) = Self::get_early_bonus(env.clone(), source_bounty_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::EarlyBonus(new_bounty_id), &bonus);
+        }
+        if let Some(extended) = env
+            .storage()
+            .persistent()
+            .get::<DataKeyExt, u64>(&DataKeyExt::ActivityExtended(source_bounty_id))
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::ActivityExtended(new_bounty_id), &extended);
+        }
+        Self::record_status_change(&env, new_bounty_id, split.status.clone());
+
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(new_bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+        let depositor_key = DataKey::DepositorIndex(split.depositor.clone());
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&depositor_key)
+            .unwrap_or(Vec::new(&env));
+        depositor_index.push_back(new_bounty_id);
+        env.storage()
+            .persistent()
+            .set(&depositor_key, &depositor_index);
+        multitoken_invariants::assert_after_lock(&env);
+
+        events::emit_escrow_split(
+            &env,
+            events::EscrowSplit {
+                source_bounty_id,
+                new_bounty_id,
+                amount: split_amount,
+                source_remaining: source.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Transfers the contract's full balance of a non-escrow token to `recipient` (admin only).
+    ///
+    /// Recovers tokens that were sent to the contract address by mistake. The default escrow
+    /// token, and any token an escrow was funded in, can never be rescued this way, so
+    /// escrowed funds cannot be drained.
+    ///
+    /// # Returns
+    /// The amount rescued (0 if the contract holds none of `token_address`).
+    ///
+    /// # Errors
+    /// Returns `Error::NotInitialized` if the admin has not been set.
+    /// Returns `Error::InvalidAssetId` if `token_address` is used by any escrow.
+    pub fn rescue_foreign_token(
+        env: Env,
+        token_address: Address,
+        recipient: Address,
+    ) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if multitoken_invariants::tracked_tokens(&env).contains(&token_address) {
+            return Err(Error::InvalidAssetId);
+        }
+
+        let client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let balance = client.balance(&contract_address);
+
+        if balance > 0 {
+            client.transfer(&contract_address, &recipient, &balance);
+            events::emit_foreign_token_rescued(
+                &env,
+                events::ForeignTokenRescued {
+                    admin,
+                    token: token_address,
+                    recipient,
+                    amount: balance,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        Ok(balance)
+    }
+
+    /// Returns current deprecation state (internal). When deprecated is true, new locks are blocked.
+    fn get_deprecation_state(env: &Env) -> DeprecationState {
+        env.storage()
+            .instance()
+            .get(&DataKey::DeprecationState)
+            .unwrap_or(DeprecationState {
+                deprecated: false,
+                migration_target: None,
+            })
+    }
+
+    fn get_participant_filter_mode(env: &Env) -> ParticipantFilterMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::ParticipantFilterMode)
+            .unwrap_or(ParticipantFilterMode::Disabled)
+    }
+
+    /// Enforces participant filtering: returns Err if the address is not allowed to participate
+    /// (lock_funds / batch_lock_funds) under the current filter mode.
+    fn check_participant_filter(env: &Env, address: Address) -> Result<(), Error> {
+        let mode = Self::get_participant_filter_mode(env);
+        match mode {
+            ParticipantFilterMode::Disabled => Ok(()),
+            ParticipantFilterMode::BlocklistOnly => {
+                if anti_abuse::is_blocklisted(env, address) {
+                    return Err(Error::ParticipantBlocked);
                 }
+                Ok(())
             }
-            CapabilityAction::Refund => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
-                    return Err(Error::FundsNotLocked);
-                }
-                if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+            ParticipantFilterMode::AllowlistOnly => {
+                if !anti_abuse::is_whitelisted(env, address) {
+                    return Err(Error::ParticipantNotAllowed);
                 }
+                Ok(())
             }
         }
+    }
+
+    /// Set deprecation (kill switch) and optional migration target. Admin only.
+    /// When deprecated is true: new lock_funds and batch_lock_funds are blocked; existing escrows
+    /// can still release, refund, or be migrated off-chain. Emits DeprecationStateChanged.
+    pub fn set_deprecated(
+        env: Env,
+        deprecated: bool,
+        migration_target: Option<Address>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let state = DeprecationState {
+            deprecated,
+            migration_target: migration_target.clone(),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::DeprecationState, &state);
+        emit_deprecation_state_changed(
+            &env,
+            DeprecationStateChanged {
+                deprecated: state.deprecated,
+                migration_target: state.migration_target,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: returns whether the contract is deprecated and the optional migration target address.
+    pub fn get_deprecation_status(env: Env) -> DeprecationStatus {
+        let s = Self::get_deprecation_state(&env);
+        DeprecationStatus {
+            deprecated: s.deprecated,
+            migration_target: s.migration_target,
+        }
+    }
+
+    /// Get current pause flags
+    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+        env.storage()
+            .instance()
+            .get(&DataKey::PauseFlags)
+            .unwrap_or(PauseFlags {
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                pause_reason: None,
+                paused_at: 0,
+            })
+    }
+
+    /// Check if an operation is paused
+    fn check_paused(env: &Env, operation: Symbol) -> bool {
+        let flags = Self::get_pause_flags(env);
+        if operation == symbol_short!("lock") {
+            if Self::is_maintenance_mode(env.clone()) {
+                return true;
+            }
+            return flags.lock_paused;
+        } else if operation == symbol_short!("release") {
+            return flags.release_paused;
+        } else if operation == symbol_short!("refund") {
+            return flags.refund_paused;
+        }
+        false
+    }
+
+    /// Check if the contract is in maintenance mode
+    pub fn is_maintenance_mode(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaintenanceMode)
+            .unwrap_or(false)
+    }
+
+    /// Update maintenance mode (admin only)
+    pub fn set_maintenance_mode(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaintenanceMode, &enabled);
+
+        events::emit_maintenance_mode_changed(
+            &env,
+            MaintenanceModeChanged {
+                enabled,
+                admin: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Check if an INV-2 breach automatically halts the contract
+    pub fn is_auto_halt_on_breach(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::InvariantAutoHalt)
+            .unwrap_or(false)
+    }
+
+    /// Opt in to halting on invariant breach (admin only).
+    ///
+    /// When enabled, an aggregate-to-ledger (INV-2) violation detected after a
+    /// lock or disbursement pauses lock, release and refund and emits
+    /// `InvariantBreachHalt` instead of panicking. Off by default.
+    pub fn set_auto_halt_on_breach(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::InvariantAutoHalt, &enabled);
+        Ok(())
+    }
+
+    /// Configure the error-rate circuit breaker (admin only).
+    ///
+    /// Once at least `min_samples` operations have been tracked, an error rate
+    /// above `max_rate_bps` pauses lock, release and refund and emits
+    /// `CircuitBreakerTripped`; operations stay paused until the admin resumes
+    /// them with `set_paused`. Reconfiguring restarts the sample window, and a
+    /// `max_rate_bps` of 0 turns the breaker off.
+    ///
+    /// A failed call reverts its own sample along with its other writes, so
+    /// only operations that return normally are sampled on-chain. Failures
+    /// observed off-chain are fed in with `report_operation_failures`.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `max_rate_bps` is above 10_000
+    pub fn set_error_rate_breaker(
+        env: Env,
+        max_rate_bps: u32,
+        min_samples: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if max_rate_bps > 10_000 {
+            return Err(Error::InvalidAmount);
+        }
+        if max_rate_bps == 0 {
+            env.storage()
+                .instance()
+                .remove(&DataKeyExt::ErrorRateBreaker);
+            return Ok(());
+        }
+        env.storage().instance().set(
+            &DataKeyExt::ErrorRateBreaker,
+            &ErrorRateBreaker {
+                max_rate_bps,
+                min_samples,
+                samples: 0,
+                errors: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Current error-rate circuit breaker config and window, if one is set.
+    pub fn get_error_rate_breaker(env: Env) -> Option<ErrorRateBreaker> {
+        env.storage().instance().get(&DataKeyExt::ErrorRateBreaker)
+    }
+
+    /// Count `failures` failed `operation` calls in the breaker window (admin only).
+    ///
+    /// For failures a backend saw off-chain, such as rejected submissions,
+    /// which cannot leave a sample of their own. They may trip the breaker like
+    /// tracked failures do. Does nothing while no breaker is configured.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - contract not initialized
+    /// * `InvalidAmount` - `failures` is zero
+    pub fn report_operation_failures(
+        env: Env,
+        operation: Symbol,
+        failures: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if failures == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        monitoring::record_reported_failures(&env, &operation, failures as u64);
+        Ok(())
+    }
+
+    /// Whether expired escrows may be refunded to their depositor by anyone.
+    pub fn is_auto_refund_enabled(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AutoRefundPolicy)
+            .unwrap_or(false)
+    }
+
+    /// Enable or disable the auto-refund policy (admin only).
+    ///
+    /// When enabled, `refund` needs no authorization for an escrow past its
+    /// deadline without a pending refund approval, so keepers can return
+    /// "use it or lose it" bounties to their depositors. Pause flags still
+    /// apply. Off by default, in which case `refund` keeps requiring both the
+    /// admin and the depositor.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is not the admin
+    pub fn set_auto_refund_policy(env: Env, caller: Address, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AutoRefundPolicy, &enabled);
+        events::emit_auto_refund_policy_changed(
+            &env,
+            events::AutoRefundPolicyChanged {
+                enabled,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address.clone(), whitelisted);
+        events::emit_whitelist_changed(
+            &env,
+            events::WhitelistChanged {
+                address,
+                whitelisted,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    fn next_capability_id(env: &Env) -> u64 {
+        let last_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CapabilityNonce)
+            .unwrap_or(0);
+        let next_id = last_id.saturating_add(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::CapabilityNonce, &next_id);
+        next_id
+    }
+
+    fn record_receipt(
+        _env: &Env,
+        _outcome: CriticalOperationOutcome,
+        _bounty_id: u64,
+        _amount: i128,
+        _recipient: Address,
+    ) {
+        // Backward-compatible no-op until receipt storage/events are fully wired.
+    }
+
+    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Capability(capability_id))
+            .ok_or(Error::CapabilityNotFound)
+    }
+
+    fn validate_capability_scope_at_issue(
+        env: &Env,
+        owner: &Address,
+        action: &CapabilityAction,
+        bounty_id: u64,
+        amount_limit: i128,
+    ) -> Result<(), Error> {
+        if amount_limit <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        match action {
+            CapabilityAction::Claim => {
+                let claim: ClaimRecord = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::PendingClaim(bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if claim.claimed {
+                    return Err(Error::FundsNotLocked);
+                }
+                if env.ledger().timestamp() > claim.expires_at {
+                    return Err(Error::DeadlineNotPassed);
+                }
+                if claim.recipient != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                if amount_limit > claim.amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Release => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked {
+                    return Err(Error::FundsNotLocked);
+                }
+                if amount_limit > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Refund => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked
+                    && escrow.status != EscrowStatus::PartiallyRefunded
+                {
+                    return Err(Error::FundsNotLocked);
+                }
+                if amount_limit > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ensure_owner_still_authorized(
+        env: &Env,
+        capability: &Capability,
+        requested_amount: i128,
+    ) -> Result<(), Error> {
+        if requested_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        match capability.action {
+            CapabilityAction::Claim => {
+                let claim: ClaimRecord = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::PendingClaim(capability.bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if claim.claimed {
+                    return Err(Error::FundsNotLocked);
+                }
+                if env.ledger().timestamp() > claim.expires_at {
+                    return Err(Error::DeadlineNotPassed);
+                }
+                if claim.recipient != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                if requested_amount > claim.amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Release => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(capability.bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked {
+                    return Err(Error::FundsNotLocked);
+                }
+                if requested_amount > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Refund => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(capability.bounty_id))
+                    .ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked
+                    && escrow.status != EscrowStatus::PartiallyRefunded
+                {
+                    return Err(Error::FundsNotLocked);
+                }
+                if requested_amount > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks a capability use against its own limits, without auth or owner re-validation.
+    fn check_capability_use(
+        env: &Env,
+        capability: &Capability,
+        holder: &Address,
+        expected_action: CapabilityAction,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if capability.revoked {
+            return Err(Error::CapabilityRevoked);
+        }
+        if capability.action != expected_action {
+            return Err(Error::CapabilityActionMismatch);
+        }
+        if capability.bounty_id != bounty_id {
+            return Err(Error::CapabilityActionMismatch);
+        }
+        if capability.holder != holder.clone() {
+            return Err(Error::Unauthorized);
+        }
+        if env.ledger().timestamp() > capability.expiry {
+            return Err(Error::CapabilityExpired);
+        }
+        if capability.remaining_uses == 0 {
+            return Err(Error::CapabilityUsesExhausted);
+        }
+        if amount > capability.remaining_amount {
+            return Err(Error::CapabilityAmountExceeded);
+        }
+        Ok(())
+    }
+
+    /// Charges one use of a capability against its limits and logs it.
+    ///
+    /// `terminal_status` is the escrow status this use leaves the bounty in
+    /// when it ends the escrow (`Released` or `Refunded`); the capability is
+    /// then revoked since it has nothing left to authorize.
+    fn consume_capability(
+        env: &Env,
+        holder: &Address,
+        capability_id: u64,
+        expected_action: CapabilityAction,
+        bounty_id: u64,
+        amount: i128,
+        terminal_status: Option<EscrowStatus>,
+    ) -> Result<Capability, Error> {
+        let mut capability = Self::load_capability(env, capability_id)?;
+        Self::check_capability_use(env, &capability, holder, expected_action, bounty_id, amount)?;
+
+        holder.require_auth();
+        Self::ensure_owner_still_authorized(env, &capability, amount)?;
+
+        capability.remaining_amount -= amount;
+        capability.remaining_uses -= 1;
+        if terminal_status.is_some() {
+            capability.revoked = true;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        let usage_key = DataKey::CapabilityUsage(capability_id);
+        let mut usage: Vec<CapabilityUse> = env
+            .storage()
+            .persistent()
+            .get(&usage_key)
+            .unwrap_or(Vec::new(env));
+        usage.push_back(CapabilityUse {
+            bounty_id,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        while usage.len() > MAX_CAPABILITY_USAGE {
+            usage.pop_front();
+        }
+        env.storage().persistent().set(&usage_key, &usage);
+
+        events::emit_capability_used(
+            env,
+            events::CapabilityUsed {
+                capability_id,
+                holder: holder.clone(),
+                action: capability.action.clone(),
+                bounty_id,
+                amount_used: amount,
+                remaining_amount: capability.remaining_amount,
+                remaining_uses: capability.remaining_uses,
+                used_at: env.ledger().timestamp(),
+            },
+        );
+
+        if let Some(status) = terminal_status {
+            events::emit_capability_auto_revoked(
+                env,
+                events::CapabilityAutoRevoked {
+                    capability_id,
+                    bounty_id,
+                    escrow_status: status,
+                    revoked_at: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        Ok(capability)
+    }
+
+    /// Simulate a capability use without consuming it or moving funds.
+    ///
+    /// Runs the same checks as a capability-based claim, release or refund
+    /// (revocation, action, bounty, holder, expiry, remaining uses, amount
+    /// limit and the owner's current authority) and reports the first failure
+    /// in `error_code`. On success `remaining_amount` is the capability's
+    /// remaining amount after this use. Does not require authorization.
+    pub fn simulate_capability_use(
+        env: Env,
+        holder: Address,
+        capability_id: u64,
+        bounty_id: u64,
+        amount: i128,
+        action: CapabilityAction,
+    ) -> SimulationResult {
+        let status = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|e| e.status)
+            .unwrap_or(EscrowStatus::Locked);
+        let result = Self::load_capability(&env, capability_id).and_then(|capability| {
+            Self::check_capability_use(&env, &capability, &holder, action, bounty_id, amount)?;
+            Self::ensure_owner_still_authorized(&env, &capability, amount)?;
+            Ok(capability.remaining_amount - amount)
+        });
+        match result {
+            Ok(remaining_amount) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount,
+                resulting_status: status,
+                remaining_amount,
+            },
+            Err(e) => SimulationResult {
+                success: false,
+                error_code: e as u32,
+                amount: 0,
+                resulting_status: status,
+                remaining_amount: 0,
+            },
+        }
+    }
+
+    pub fn issue_capability(
+        env: Env,
+        owner: Address,
+        holder: Address,
+        action: CapabilityAction,
+        bounty_id: u64,
+        amount_limit: i128,
+        expiry: u64,
+        max_uses: u32,
+    ) -> Result<u64, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if max_uses == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let now = env.ledger().timestamp();
+        if expiry <= now {
+            return Err(Error::InvalidDeadline);
+        }
+
+        owner.require_auth();
+        Self::validate_capability_scope_at_issue(&env, &owner, &action, bounty_id, amount_limit)?;
+
+        let capability_id = Self::next_capability_id(&env);
+        let capability = Capability {
+            owner: owner.clone(),
+            holder: holder.clone(),
+            action: action.clone(),
+            bounty_id,
+            amount_limit,
+            remaining_amount: amount_limit,
+            expiry,
+            remaining_uses: max_uses,
+            revoked: false,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        events::emit_capability_issued(
+            &env,
+            events::CapabilityIssued {
+                capability_id,
+                owner,
+                holder,
+                action,
+                bounty_id,
+                amount_limit,
+                expires_at: expiry,
+                max_uses,
+                timestamp: now,
+            },
+        );
+
+        Ok(capability_id)
+    }
+
+    pub fn revoke_capability(env: Env, owner: Address, capability_id: u64) -> Result<(), Error> {
+        let mut capability = Self::load_capability(&env, capability_id)?;
+        if capability.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        owner.require_auth();
+
+        if capability.revoked {
+            return Ok(());
+        }
+
+        capability.revoked = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        events::emit_capability_revoked(
+            &env,
+            events::CapabilityRevoked {
+                capability_id,
+                owner,
+                revoked_at: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Restrict a `Release` capability to paying out to `recipients` (owner only).
+    ///
+    /// `release_with_capability` rejects any other contributor with
+    /// `ParticipantNotAllowed`. An empty list removes the restriction.
+    ///
+    /// # Errors
+    /// * `CapabilityNotFound` - no capability with `capability_id`
+    /// * `Unauthorized` - `owner` did not issue the capability
+    pub fn set_capability_recipients(
+        env: Env,
+        owner: Address,
+        capability_id: u64,
+        recipients: Vec<Address>,
+    ) -> Result<(), Error> {
+        let capability = Self::load_capability(&env, capability_id)?;
+        if capability.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        owner.require_auth();
+
+        let key = DataKeyExt::CapabilityRecipients(capability_id);
+        if recipients.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &recipients);
+        }
+        Ok(())
+    }
+
+    /// Push the expiry of several capabilities owned by `owner` out to `new_expiry`.
+    ///
+    /// Capabilities that are missing, owned by someone else, revoked, or whose
+    /// expiry is already at or past `new_expiry` are skipped. Emits
+    /// `CapabilityExtended` for each capability updated and returns their count.
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - `capability_ids` is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `InvalidDeadline` - `new_expiry` is not in the future
+    pub fn batch_extend_capabilities(
+        env: Env,
+        owner: Address,
+        capability_ids: Vec<u64>,
+        new_expiry: u64,
+    ) -> Result<u32, Error> {
+        if capability_ids.is_empty() || capability_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let now = env.ledger().timestamp();
+        if new_expiry <= now {
+            return Err(Error::InvalidDeadline);
+        }
+        owner.require_auth();
+
+        let mut extended = 0u32;
+        for capability_id in capability_ids.iter() {
+            let Ok(mut capability) = Self::load_capability(&env, capability_id) else {
+                continue;
+            };
+            if capability.owner != owner || capability.revoked || capability.expiry >= new_expiry {
+                continue;
+            }
+
+            let old_expiry = capability.expiry;
+            capability.expiry = new_expiry;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Capability(capability_id), &capability);
+            extended += 1;
+
+            events::emit_capability_extended(
+                &env,
+                events::CapabilityExtended {
+                    capability_id,
+                    owner: owner.clone(),
+                    old_expiry,
+                    new_expiry,
+                    timestamp: now,
+                },
+            );
+        }
+
+        Ok(extended)
+    }
+
+    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, Error> {
+        Self::load_capability(&env, capability_id)
+    }
+
+    /// Contributors a `Release` capability may pay out to; empty means any.
+    pub fn get_capability_recipients(env: Env, capability_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::CapabilityRecipients(capability_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Recorded uses of a capability, oldest first.
+    ///
+    /// Only the latest `MAX_CAPABILITY_USAGE` uses are kept.
+    pub fn get_capability_usage(env: Env, capability_id: u64) -> Vec<CapabilityUse> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CapabilityUsage(capability_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Set a per-token fee configuration (admin only).
+    ///
+    /// When a `TokenFeeConfig` is set for a given token address it takes
+    /// precedence over the global `FeeConfig` for all escrows denominated
+    /// in that token.
+    ///
+    /// # Arguments
+    /// * `token`            – the token contract address this config applies to
+    /// * `lock_fee_rate`    – fee rate on lock in basis points (0 – 5 000)
+    /// * `release_fee_rate` – fee rate on release in basis points (0 – 5 000)
+    /// * `fee_recipient`    – address that receives fees for this token
+    /// * `fee_enabled`      – whether fee collection is active
+    ///
+    /// # Errors
+    /// * `NotInitialized`  – contract not yet initialised
+    /// * `InvalidFeeRate`  – any rate is outside `[0, MAX_FEE_RATE]`
+    pub fn set_token_fee_config(
+        env: Env,
+        token: Address,
+        lock_fee_rate: i128,
+        release_fee_rate: i128,
+        fee_recipient: Address,
+        fee_enabled: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !(0..=MAX_FEE_RATE).contains(&lock_fee_rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+        if !(0..=MAX_FEE_RATE).contains(&release_fee_rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        let config = TokenFeeConfig {
+            lock_fee_rate,
+            release_fee_rate,
+            fee_recipient,
+            fee_enabled,
+        };
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenFeeConfig(token), &config);
+
+        Ok(())
+    }
+
+    /// Get the per-token fee configuration for `token`, if one has been set.
+    ///
+    /// Returns `None` when no token-specific config exists; callers should
+    /// fall back to the global `FeeConfig` in that case.
+    pub fn get_token_fee_config(env: Env, token: Address) -> Option<TokenFeeConfig> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenFeeConfig(token))
+    }
+
+    /// Add or remove `address` from the fee-exempt allowlist (admin only).
+    ///
+    /// Exempt depositors pay no lock fee on their deposits and no release fee
+    /// when their escrows are paid out, regardless of the global or per-token
+    /// fee configuration.
+    ///
+    /// # Errors
+    /// * `NotInitialized` – contract not yet initialised
+    pub fn set_fee_exempt(env: Env, address: Address, exempt: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if exempt {
+            env.storage()
+                .instance()
+                .set(&DataKey::FeeExempt(address), &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::FeeExempt(address));
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `address` is on the fee-exempt allowlist.
+    pub fn is_fee_exempt(env: Env, address: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeExempt(address))
+            .unwrap_or(false)
+    }
+
+    /// Token `bounty_id` was funded in. Escrows locked before per-escrow tokens
+    /// were recorded fall back to the default token set at init.
+    pub(crate) fn escrow_token(env: &Env, bounty_id: u64) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EscrowToken(bounty_id))
+            .unwrap_or_else(|| env.storage().instance().get(&DataKey::Token).unwrap())
+    }
+
+    /// When `bounty_id` was locked. Escrows locked before lock times were
+    /// recorded read as 0: their age is unknown and they are never held back by
+    /// the minimum lock duration.
+    pub(crate) fn escrow_locked_at(env: &Env, bounty_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EscrowLockedAt(bounty_id))
+            .unwrap_or(0)
+    }
+
+    /// Internal: resolve the effective fee config for an escrow token.
+    ///
+    /// Precedence: `TokenFeeConfig(token)` > global `FeeConfig`.
+    fn resolve_fee_config(env: &Env, token_addr: &Address) -> (i128, i128, Address, bool) {
+        if let Some(tok_cfg) = env
+            .storage()
+            .instance()
+            .get::<DataKey, TokenFeeConfig>(&DataKey::TokenFeeConfig(token_addr.clone()))
+        {
+            (
+                tok_cfg.lock_fee_rate,
+                tok_cfg.release_fee_rate,
+                tok_cfg.fee_recipient,
+                tok_cfg.fee_enabled,
+            )
+        } else {
+            let global = Self::get_fee_config_internal(env);
+            (
+                global.lock_fee_rate,
+                global.release_fee_rate,
+                global.fee_recipient,
+                global.fee_enabled,
+            )
+        }
+    }
+
+    /// Update multisig configuration (admin only)
+    pub fn update_multisig_config(
+        env: Env,
+        threshold_amount: i128,
+        signers: Vec<Address>,
+        required_signatures: u32,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if required_signatures > signers.len() {
+            return Err(Error::InvalidAmount);
+        }
+
+        let config = MultisigConfig {
+            threshold_amount,
+            signers,
+            required_signatures,
+        };
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MultisigConfig, &config);
+
+        Ok(())
+    }
+
+    /// Get multisig configuration
+    pub fn get_multisig_config(env: Env) -> MultisigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::MultisigConfig)
+            .unwrap_or(MultisigConfig {
+                threshold_amount: i128::MAX,
+                signers: vec![&env],
+                required_signatures: 0,
+            })
+    }
+
+    /// Approve release for large amount (requires multisig)
+    ///
+    /// The first approval opens a `RELEASE_APPROVAL_WINDOW` during which the
+    /// other signers can add theirs. The approval is marked consumed when the
+    /// escrow is released and cannot be extended afterwards.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `approver` is not a multisig signer
+    /// * `TicketExpired` - the approval window has closed
+    /// * `TicketAlreadyUsed` - the approval was already consumed by a release
+    pub fn approve_large_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
+
+        let mut is_signer = false;
+        for signer in multisig_config.signers.iter() {
+            if signer == approver {
+                is_signer = true;
+                break;
+            }
+        }
+
+        if !is_signer {
+            return Err(Error::Unauthorized);
+        }
+
+        approver.require_auth();
+
+        let now = env.ledger().timestamp();
+        let approval_key = DataKey::ReleaseApproval(bounty_id);
+        let existing: Option<ReleaseApproval> = env.storage().persistent().get(&approval_key);
+        let is_new = existing.is_none();
+        let mut approval = existing.unwrap_or(ReleaseApproval {
+            bounty_id,
+            contributor: contributor.clone(),
+            approvals: vec![&env],
+        });
+        let status = if is_new {
+            ReleaseApprovalStatus {
+                expires_at: now.saturating_add(RELEASE_APPROVAL_WINDOW),
+                consumed: false,
+            }
+        } else {
+            Self::get_release_approval_status(env.clone(), bounty_id)
+        };
+        if status.consumed {
+            return Err(Error::TicketAlreadyUsed);
+        }
+        if now > status.expires_at {
+            return Err(Error::TicketExpired);
+        }
+
+        for existing in approval.approvals.iter() {
+            if existing == approver {
+                return Ok(());
+            }
+        }
+
+        approval.approvals.push_back(approver.clone());
+        env.storage().persistent().set(&approval_key, &approval);
+        if is_new {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::ReleaseApprovalStatus(bounty_id), &status);
+        }
+
+        events::emit_approval_added(
+            &env,
+            events::ApprovalAdded {
+                bounty_id,
+                contributor: contributor.clone(),
+                approver,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Approve an emergency withdrawal of the contract balance to `target`.
+    ///
+    /// Needed only when the balance exceeds the multisig `threshold_amount`;
+    /// see [`emergency_withdraw`](Self::emergency_withdraw). Approving twice is a no-op.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `approver` is not a multisig signer
+    pub fn approve_emergency_withdraw(
+        env: Env,
+        target: Address,
+        approver: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let multisig_config = Self::get_multisig_config(env.clone());
+        if !multisig_config.signers.contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+        approver.require_auth();
+
+        let key = DataKeyExt::EmergencyWithdrawApprovals(target.clone());
+        let mut approvals: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        if approvals.contains(&approver) {
+            return Ok(());
+        }
+        approvals.push_back(approver.clone());
+        env.storage().persistent().set(&key, &approvals);
+
+        events::emit_emergency_withdraw_approved(
+            &env,
+            events::EmergencyWithdrawApproved {
+                target,
+                approver,
+                approvals: approvals.len(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Signers who have approved an emergency withdrawal to `target`.
+    pub fn get_emergency_withdraw_approvals(env: Env, target: Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EmergencyWithdrawApprovals(target))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the large-release approval recorded for `bounty_id`, if any.
+    pub fn get_release_approval(env: Env, bounty_id: u64) -> Option<ReleaseApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseApproval(bounty_id))
+    }
+
+    /// Expiry and consumption of the large-release approval for `bounty_id`.
+    /// Approvals recorded before these were tracked never expire.
+    pub fn get_release_approval_status(env: Env, bounty_id: u64) -> ReleaseApprovalStatus {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::ReleaseApprovalStatus(bounty_id))
+            .unwrap_or(ReleaseApprovalStatus {
+                expires_at: u64::MAX,
+                consumed: false,
+            })
+    }
+
+    /// Lock funds for a specific bounty.
+    /// Lock funds for a bounty. When `non_transferable_rewards` is true, the escrow is marked
+    /// as using soulbound/non-transferable tokens; the token contract must disallow further
+    /// transfers after the recipient claims. Claim and release still perform a single transfer
+    /// from the contract to the recipient; no further transfers are required.
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            LockOptions::default(),
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Lock funds for a bounty in a token other than the contract's default token.
+    ///
+    /// Behaves exactly like [`lock_funds`](Self::lock_funds), but the deposit is
+    /// pulled from `token` and the escrow remembers it, so every later release,
+    /// claim or refund for this bounty pays out in that same token.
+    /// Per-token fee configuration (`set_token_fee_config`) applies when present.
+    pub fn lock_funds_token(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            LockOptions {
+                token: Some(token),
+                ..LockOptions::default()
+            },
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Lock funds for a bounty with an optional short on-chain memo.
+    ///
+    /// Behaves exactly like [`lock_funds`](Self::lock_funds); the memo is stored
+    /// under `DataKey::EscrowMemo` and included in the `FundsLocked` event.
+    ///
+    /// # Errors
+    /// * `MemoTooLong` - `memo` is longer than `MAX_MEMO_LEN` bytes
+    pub fn lock_funds_memo(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        memo: Option<String>,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            LockOptions {
+                memo,
+                ..LockOptions::default()
+            },
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Get the memo attached to an escrow at lock time, if any.
+    pub fn get_escrow_memo(env: Env, bounty_id: u64) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowMemo(bounty_id))
+    }
+
+    /// Lock funds using the admin-configured default deadline horizon.
+    ///
+    /// The deadline is `now + horizon` (see `set_default_deadline_horizon`);
+    /// otherwise behaves exactly like [`lock_funds`](Self::lock_funds).
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - No default horizon has been configured
+    pub fn lock_funds_default_deadline(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let horizon: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DefaultDeadlineHorizon)
+            .ok_or(Error::InvalidDeadline)?;
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .checked_add(horizon)
+            .ok_or(Error::InvalidDeadline)?;
+        Self::lock_funds(env, depositor, bounty_id, amount, deadline)
+    }
+
+    /// Deposit funds now for an escrow that goes live at `start_time`.
+    ///
+    /// The escrow is created in `Pending` status, which blocks releases,
+    /// claims and refunds until anyone calls [`activate_lock`](Self::activate_lock)
+    /// at or after `start_time`. Otherwise behaves like
+    /// [`lock_funds`](Self::lock_funds), including fees and the amount policy;
+    /// the minimum lock duration counts from `start_time`.
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `start_time` is not in the future or `deadline`
+    ///   is not after `start_time`
+    pub fn schedule_future_lock(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        start_time: u64,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        if start_time <= env.ledger().timestamp() || deadline <= start_time {
+            return Err(Error::InvalidDeadline);
+        }
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            LockOptions {
+                start_time: Some(start_time),
+                ..LockOptions::default()
+            },
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Move a scheduled escrow from `Pending` to `Locked`. Callable by anyone
+    /// once the escrow's start time has been reached.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Pending`
+    /// * `LockNotStarted` - the start time has not been reached yet
+    pub fn activate_lock(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Pending {
+            return Err(Error::FundsNotLocked);
+        }
+        let now = env.ledger().timestamp();
+        if now < Self::escrow_locked_at(&env, bounty_id) {
+            return Err(Error::LockNotStarted);
+        }
+
+        escrow.status = EscrowStatus::Locked;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        events::emit_lock_activated(
+            &env,
+            events::LockActivated {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: escrow.amount,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    fn lock_funds_logic(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        options: LockOptions,
+    ) -> Result<(), Error> {
+        let LockOptions {
+            token,
+            memo,
+            start_time,
+        } = options;
+        // Validation precedence (deterministic ordering):
+        // 1. Reentrancy guard
+        // 2. Contract initialized
+        // 3. Paused / deprecated (operational state)
+        // 4. Participant filter + rate limiting
+        // 5. Authorization
+        // 6. Input validation (amount policy)
+        // 7. Business logic (bounty uniqueness)
+
+        // 1. GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        // 2. Contract must be initialized before any other check
+        if !env.storage().instance().has(&DataKey::Admin) {
+            reentrancy_guard::release(&env);
+            return Err(Error::NotInitialized);
+        }
+        soroban_sdk::log!(&env, "admin ok");
+
+        // 3. Operational state: paused / deprecated
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            reentrancy_guard::release(&env);
+            return Err(Error::FundsPaused);
+        }
+        if Self::get_deprecation_state(&env).deprecated {
+            reentrancy_guard::release(&env);
+            return Err(Error::ContractDeprecated);
+        }
+        soroban_sdk::log!(&env, "check paused ok");
+
+        // 4. Participant filtering and rate limiting
+        Self::check_participant_filter(&env, depositor.clone())?;
+        soroban_sdk::log!(&env, "start lock_funds");
+        anti_abuse::check_rate_limit(&env, symbol_short!("lock"), depositor.clone());
+        soroban_sdk::log!(&env, "rate limit ok");
+
+        let _start = env.ledger().timestamp();
+        let _caller = depositor.clone();
+
+        // 5. Authorization
+        depositor.require_auth();
+        soroban_sdk::log!(&env, "auth ok");
+
+        // 6. Input validation: amount policy
+        // Enforce min/max amount policy if one has been configured (Issue #62).
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountBelowMinimum);
+            }
+            if amount > max_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+        soroban_sdk::log!(&env, "amount policy ok");
+        if let Some(m) = &memo {
+            if m.len() > MAX_MEMO_LEN {
+                reentrancy_guard::release(&env);
+                return Err(Error::MemoTooLong);
+            }
+        }
+
+        // 7. Business logic: bounty must not already exist (or have been purged)
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || Self::is_escrow_purged(&env, bounty_id)
+        {
+            reentrancy_guard::release(&env);
+            return Err(Error::BountyExists);
+        }
+        soroban_sdk::log!(&env, "bounty exists ok");
+
+        // `None` means the contract's default token configured at init.
+        let token_addr: Address =
+            token.unwrap_or_else(|| env.storage().instance().get(&DataKey::Token).unwrap());
+        let client = token::Client::new(&env, &token_addr);
+        soroban_sdk::log!(&env, "token client ok");
+
+        // Transfer full gross amount from depositor to contract first.
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+        soroban_sdk::log!(&env, "transfer ok");
+
+        // Resolve effective fee config (per-token takes precedence over global).
+        let (_lock_fee_rate, _release_fee_rate, fee_recipient, _fee_enabled) =
+            Self::resolve_fee_config(&env, &token_addr);
+        let lock_fee_rate =
+            Self::fee_rate_for(&env, &token_addr, &depositor, amount, symbol_short!("lock"));
+
+        // Deduct lock fee from the escrowed principal.
+        // Ceiling division ensures fee >= 1 stroop whenever rate > 0,
+        // preventing principal drain via dust-amount splitting.
+        // Fee-exempt depositors get a zero rate and skip the lock fee entirely.
+        let fee_amount = Self::calculate_fee(amount, lock_fee_rate);
+
+        // Net amount stored in escrow after fee.
+        // Fee must never exceed the deposit; guard against misconfiguration.
+        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
+        if net_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Transfer fee to recipient immediately (separate transfer so it is
+        // visible as a distinct on-chain operation).
+        if fee_amount > 0 {
+            client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Lock,
+                    amount: fee_amount,
+                    fee_rate: lock_fee_rate,
+                    recipient: fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        soroban_sdk::log!(&env, "fee ok");
+
+        // Scheduled locks stay `Pending` and count as locked from their start time.
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: net_amount,
+            status: if start_time.is_some() {
+                EscrowStatus::Pending
+            } else {
+                EscrowStatus::Locked
+            },
+            deadline,
+            refund_history: vec![&env],
+            remaining_amount: net_amount,
+        };
+        invariants::assert_escrow(&env, &escrow);
+
+        // Extend the TTL of the storage entry to ensure it lives long enough
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::EscrowToken(bounty_id), &token_addr);
+        env.storage().persistent().set(
+            &DataKeyExt::EscrowLockedAt(bounty_id),
+            &start_time.unwrap_or(env.ledger().timestamp()),
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+        if let Some(m) = &memo {
+            env.storage()
+                .persistent()
+                .set(&DataKey::EscrowMemo(bounty_id), m);
+        }
+
+        // Update indexes
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor.clone()))
+            .unwrap_or(Vec::new(&env));
+        depositor_index.push_back(bounty_id);
+        env.storage().persistent().set(
+            &DataKey::DepositorIndex(depositor.clone()),
+            &depositor_index,
+        );
+
+        // Emit value allows for off-chain indexing
+        if let Some(start_time) = start_time {
+            events::emit_future_lock_scheduled(
+                &env,
+                events::FutureLockScheduled {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: net_amount,
+                    depositor: depositor.clone(),
+                    start_time,
+                    deadline,
+                },
+            );
+        } else {
+            emit_funds_locked(
+                &env,
+                FundsLocked {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    depositor: depositor.clone(),
+                    deadline,
+                    memo,
+                },
+            );
+        }
+
+        // INV-2: Verify the contract balance still covers every escrow after lock
+        multitoken_invariants::assert_after_lock(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Add funds to a locked escrow and move its deadline forward in one call.
+    ///
+    /// The depositor tops up `additional_amount` (added to both `amount` and
+    /// `remaining_amount`) and sets the deadline to `new_deadline`, which must
+    /// not be earlier than the current one. The amount policy, if configured,
+    /// is enforced against the new escrow total. No lock fee is charged on the
+    /// top-up.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `FundsPaused` / `ContractDeprecated` - Locking is not currently allowed
+    /// * `BountyNotFound` - Escrow does not exist
+    /// * `FundsNotLocked` - Escrow is not in `Locked` status
+    /// * `InvalidAmount` - `additional_amount` is not positive
+    /// * `InvalidDeadline` - `new_deadline` is earlier than the current deadline
+    /// * `AmountAboveMaximum` / `AmountBelowMinimum` - New total violates the amount policy
+    ///
+    /// # Events
+    /// Emits a top-up `FundsLocked` payload (topic `f_topup`) and `DeadlineExtended`.
+    pub fn topup_and_extend(
+        env: Env,
+        bounty_id: u64,
+        additional_amount: i128,
+        new_deadline: u64,
+    ) -> Result<(), Error> {
+        Self::topup_logic(&env, bounty_id, additional_amount, Some(new_deadline))
+    }
+
+    /// Add funds to a locked escrow without changing its deadline.
+    ///
+    /// Increases both `amount` and `remaining_amount` by `additional_amount`.
+    /// Requires depositor authorization; the amount policy, if configured, is
+    /// enforced against the new escrow total.
+    ///
+    /// # Errors
+    /// Same as [`Self::topup_and_extend`], minus the deadline check.
+    ///
+    /// # Events
+    /// Emits a top-up `FundsLocked` payload under the `f_topup` topic.
+    pub fn topup_escrow(env: Env, bounty_id: u64, additional_amount: i128) -> Result<(), Error> {
+        Self::topup_logic(&env, bounty_id, additional_amount, None)
+    }
+
+    /// Shared top-up path. `new_deadline` of `None` keeps the current deadline.
+    fn topup_logic(
+        env: &Env,
+        bounty_id: u64,
+        additional_amount: i128,
+        new_deadline: Option<u64>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if Self::check_paused(env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        if Self::get_deprecation_state(env).deprecated {
+            return Err(Error::ContractDeprecated);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if additional_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let old_deadline = escrow.deadline;
+        let deadline = new_deadline.unwrap_or(old_deadline);
+        if deadline < old_deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let new_total = escrow
+            .amount
+            .checked_add(additional_amount)
+            .ok_or(Error::InvalidAmount)?;
+        let new_remaining = escrow
+            .remaining_amount
+            .checked_add(additional_amount)
+            .ok_or(Error::InvalidAmount)?;
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if new_total < min_amount {
+                return Err(Error::AmountBelowMinimum);
+            }
+            if new_total > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+
+        reentrancy_guard::acquire(env);
+
+        // EFFECTS
+        escrow.amount = new_total;
+        escrow.remaining_amount = new_remaining;
+        escrow.deadline = deadline;
+        invariants::assert_escrow(env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::adjust_stats_cache(env, &escrow.status, additional_amount, 0);
+
+        // INTERACTION: pull the additional tokens in last
+        let client = token::Client::new(env, &Self::escrow_token(env, bounty_id));
+        client.transfer(
+            &escrow.depositor,
+            &env.current_contract_address(),
+            &additional_amount,
+        );
+
+        events::emit_funds_topped_up(
+            env,
+            FundsLocked {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: additional_amount,
+                depositor: escrow.depositor.clone(),
+                deadline,
+                memo: None,
+            },
+        );
+        if deadline != old_deadline {
+            events::emit_deadline_extended(
+                env,
+                events::DeadlineExtended {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    old_deadline,
+                    new_deadline: deadline,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        multitoken_invariants::assert_after_lock(env);
+
+        reentrancy_guard::release(env);
+        Ok(())
+    }
+
+    /// Configure deadline auto-extension on recorded activity (admin only).
+    ///
+    /// While `extend_by_seconds` is non-zero, each `record_activity` call moves
+    /// an escrow's deadline to `now + extend_by_seconds` (never backwards),
+    /// until `max_total_extension` seconds have been added to that escrow.
+    /// Passing `0` disables auto-extension.
+    pub fn set_activity_extension(
+        env: Env,
+        extend_by_seconds: u64,
+        max_total_extension: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage().instance().set(
+            &DataKeyExt::ActivityExtension,
+            &ActivityExtensionPolicy {
+                extend_by: extend_by_seconds,
+                max_total_extension,
+            },
+        );
+        Ok(())
+    }
+
+    /// Current activity extension policy (all zero when never configured).
+    pub fn get_activity_extension(env: Env) -> ActivityExtensionPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKeyExt::ActivityExtension)
+            .unwrap_or(ActivityExtensionPolicy {
+                extend_by: 0,
+                max_total_extension: 0,
+            })
+    }
+
+    /// Record off-chain progress on an escrow (admin only), extending its
+    /// deadline under the activity extension policy.
+    ///
+    /// Returns the escrow's deadline after the call. The deadline is left
+    /// unchanged when the policy is disabled, the escrow's extension budget is
+    /// used up, or the deadline is already later than `now + extend_by`.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    ///
+    /// # Events
+    /// Emits `DeadlineExtended` when the deadline moves.
+    pub fn record_activity(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let policy = Self::get_activity_extension(env.clone());
+        let now = env.ledger().timestamp();
+        let target = now.saturating_add(policy.extend_by);
+        if policy.extend_by == 0 || target <= escrow.deadline {
+            return Ok(escrow.deadline);
+        }
+
+        let extended_key = DataKeyExt::ActivityExtended(bounty_id);
+        let extended: u64 = env.storage().persistent().get(&extended_key).unwrap_or(0);
+        let budget = policy.max_total_extension.saturating_sub(extended);
+        let added = (target - escrow.deadline).min(budget);
+        if added == 0 {
+            return Ok(escrow.deadline);
+        }
+
+        let old_deadline = escrow.deadline;
+        escrow.deadline += added;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .set(&extended_key, &(extended + added));
+
+        events::emit_deadline_extended(
+            &env,
+            events::DeadlineExtended {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                old_deadline,
+                new_deadline: escrow.deadline,
+                timestamp: now,
+            },
+        );
+        Ok(escrow.deadline)
+    }
+
+    /// Simulate lock operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    ///
+    /// # Arguments
+    /// * `depositor` - Address that would lock funds
+    /// * `bounty_id` - Bounty identifier
+    /// * `amount` - Amount to lock
+    /// * `deadline` - Deadline timestamp
+    ///
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_lock(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> SimulationResult {
+        fn err_result(e: Error) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            }
+        }
+        match Self::dry_run_lock_impl(&env, depositor, bounty_id, amount, deadline) {
+            Ok((net_amount,)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount: net_amount,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: net_amount,
+            },
+            Err(e) => err_result(e),
+        }
+    }
+
+    fn dry_run_lock_impl(
+        env: &Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        _deadline: u64,
+    ) -> Result<(i128,), Error> {
+        // 1. Contract must be initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        // 2. Operational state: paused / deprecated
+        if Self::check_paused(env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        if Self::get_deprecation_state(env).deprecated {
+            return Err(Error::ContractDeprecated);
+        }
+        // 3. Participant filtering (read-only)
+        Self::check_participant_filter(env, depositor.clone())?;
+        // 4. Amount policy
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                return Err(Error::AmountBelowMinimum);
+            }
+            if amount > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+        // 5. Bounty must not already exist (or have been purged)
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || Self::is_escrow_purged(env, bounty_id)
+        {
+            return Err(Error::BountyExists);
+        }
+        // 6. Amount validation
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        // 7. Sufficient balance (read-only)
+        let balance = client.balance(&depositor);
+        if balance < amount {
+            return Err(Error::InsufficientFunds);
+        }
+        // 8. Fee computation (pure)
+        let lock_fee_rate =
+            Self::fee_rate_for(env, &token_addr, &depositor, amount, symbol_short!("lock"));
+        let fee_amount = Self::calculate_fee(amount, lock_fee_rate);
+        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
+        if net_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Ok((net_amount,))
+    }
+
+    /// Returns whether the given bounty escrow is marked as using non-transferable (soulbound)
+    /// reward tokens. When true, the token is expected to disallow further transfers after claim.
+    pub fn get_non_transferable_rewards(env: Env, bounty_id: u64) -> Result<bool, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::NonTransferableRewards(bounty_id))
+            .unwrap_or(false))
+    }
+
+    /// Lock funds for a bounty in anonymous mode: only a 32-byte depositor commitment is stored.
+    /// The depositor must authorize and transfer; their address is used only for the transfer
+    /// in this call and is not stored on-chain. Refunds require the configured anonymous
+    /// resolver to call `refund_resolved(bounty_id, recipient)`.
+    pub fn lock_funds_anonymous(
+        env: Env,
+        depositor: Address,
+        depositor_commitment: BytesN<32>,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        // Validation precedence (deterministic ordering):
+        // 1. Reentrancy guard
+        // 2. Contract initialized
+        // 3. Paused (operational state)
+        // 4. Rate limiting
+        // 5. Authorization
+        // 6. Business logic (bounty uniqueness, amount policy)
+
+        // 1. Reentrancy guard
+        reentrancy_guard::acquire(&env);
+
+        // 2. Contract must be initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            reentrancy_guard::release(&env);
+            return Err(Error::NotInitialized);
+        }
+
+        // 3. Operational state: paused
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            reentrancy_guard::release(&env);
+            return Err(Error::FundsPaused);
+        }
+
+        // 4. Rate limiting
+        anti_abuse::check_rate_limit(&env, symbol_short!("lock"), depositor.clone());
+
+        // 5. Authorization
+        depositor.require_auth();
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(bounty_id))
+            || Self::is_escrow_purged(&env, bounty_id)
+        {
+            reentrancy_guard::release(&env);
+            return Err(Error::BountyExists);
+        }
+
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountBelowMinimum);
+            }
+            if amount > max_amount {
+                reentrancy_guard::release(&env);
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+
+        let escrow_anon = AnonymousEscrow {
+            depositor_commitment: depositor_commitment.clone(),
+            amount,
+            remaining_amount: amount,
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowAnon(bounty_id), &escrow_anon);
+        Self::record_status_change(&env, bounty_id, escrow_anon.status.clone());
+
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        emit_funds_locked_anon(
+            &env,
+            FundsLockedAnon {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                depositor_commitment,
+                deadline,
+            },
+        );
+
+        multitoken_invariants::assert_after_lock(&env);
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Release funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let caller = env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::Admin)
+            .unwrap_or(contributor.clone());
+        let res = Self::release_funds_logic(env.clone(), bounty_id, contributor);
+        monitoring::track_operation(&env, symbol_short!("release"), caller, res.is_ok());
+        res
+    }
+
+    fn release_funds_logic(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        // Validation precedence (deterministic ordering):
+        // 0. Recipient denylist (before any state is touched)
+        // 1. Reentrancy guard
+        // 2. Contract initialized
+        // 3. Paused (operational state)
+        // 4. Authorization
+        // 5. Business logic (bounty exists, funds locked)
+        Self::ensure_recipient_not_denied(&env, symbol_short!("release"), &contributor)?;
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &contributor)?;
+
+        // 1. Reentrancy guard (manual inline guard used here for release_funds)
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        // 2. Contract must be initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotInitialized);
+        }
+
+        // 3. Operational state: paused
+        if Self::check_paused(&env, symbol_short!("release")) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::FundsPaused);
+        }
+
+        let _start = env.ledger().timestamp();
+
+        // 4. Authorization
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // 5. Business logic: bounty must exist and be locked
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if !Self::lock_duration_met(&env, bounty_id) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::LockDurationNotMet);
+        }
+        if !Self::recipient_release_approved(&env, bounty_id, &contributor) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::RecipientApprovalRequired);
+        }
+        if !Self::dependency_satisfied(&env, bounty_id) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::DependencyNotSatisfied);
+        }
+        if Self::scheduled_amount(&env, bounty_id) > 0 {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InsufficientFunds);
+        }
+
+        let token_addr = Self::escrow_token(&env, bounty_id);
+        let client = token::Client::new(&env, &token_addr);
+
+        // Resolve effective fee config for release.
+        let (_lock_fee_rate, _release_fee_rate, fee_recipient, _fee_enabled) =
+            Self::resolve_fee_config(&env, &token_addr);
+
+        // Escrows funded by a fee-exempt depositor are released without a fee.
+        let release_fee_rate = Self::fee_rate_for(
+            &env,
+            &token_addr,
+            &escrow.depositor,
+            escrow.amount,
+            symbol_short!("release"),
+        );
+        let release_fee = Self::calculate_fee(escrow.amount, release_fee_rate);
+
+        // Net payout to contributor after release fee.
+        let net_payout = escrow
+            .amount
+            .checked_sub(release_fee)
+            .unwrap_or(escrow.amount);
+        if net_payout <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if release_fee > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_recipient,
+                &release_fee,
+            );
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: release_fee,
+                    fee_rate: release_fee_rate,
+                    recipient: fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Transfer net amount to contributor
+        client.transfer(&env.current_contract_address(), &contributor, &net_payout);
+        Self::record_contributor_payout(&env, bounty_id, &contributor, net_payout);
+
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RecipientApproval(bounty_id));
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseApproval(bounty_id))
+        {
+            let mut status = Self::get_release_approval_status(env.clone(), bounty_id);
+            status.consumed = true;
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::ReleaseApprovalStatus(bounty_id), &status);
+        }
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: escrow.amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+                bonus: 0,
+            },
+        );
+
+        // Clear reentrancy guard
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(())
+    }
+
+    /// Simulate release operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - Bounty identifier
+    /// * `contributor` - Recipient address
+    ///
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
+        fn err_result(e: Error) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Released,
+                remaining_amount: 0,
+            }
+        }
+        match Self::dry_run_release_impl(&env, bounty_id, contributor) {
+            Ok((amount,)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount,
+                resulting_status: EscrowStatus::Released,
+                remaining_amount: 0,
+            },
+            Err(e) => err_result(e),
+        }
+    }
+
+    fn dry_run_release_impl(
+        env: &Env,
+        bounty_id: u64,
+        _contributor: Address,
+    ) -> Result<(i128,), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if Self::check_paused(env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        let release_fee_rate = Self::fee_rate_for(
+            env,
+            &Self::escrow_token(env, bounty_id),
+            &escrow.depositor,
+            escrow.amount,
+            symbol_short!("release"),
+        );
+        let release_fee = Self::calculate_fee(escrow.amount, release_fee_rate);
+        let net_payout = escrow
+            .amount
+            .checked_sub(release_fee)
+            .unwrap_or(escrow.amount);
+        if net_payout <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Ok((escrow.amount,))
+    }
+
+    /// Delegated release flow using a capability instead of admin auth.
+    /// The capability amount limit is consumed by `payout_amount`.
+    pub fn release_with_capability(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if payout_amount > escrow.remaining_amount - Self::scheduled_amount(&env, bounty_id) {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::ensure_recipient_not_denied(&env, symbol_short!("release"), &contributor)?;
+        // A non-empty allowlist restricts who this capability may pay out to.
+        let allowed = Self::get_capability_recipients(env.clone(), capability_id);
+        if !allowed.is_empty() && !allowed.contains(&contributor) {
+            return Err(Error::ParticipantNotAllowed);
+        }
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &contributor)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Release,
+            bounty_id,
+            payout_amount,
+            if payout_amount == escrow.remaining_amount {
+                Some(EscrowStatus::Released)
+            } else {
+                None
+            },
+        )?;
+
+        let client = token::Client::new(&env, &Self::escrow_token(&env, bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &payout_amount,
+        );
+        Self::record_contributor_payout(&env, bounty_id, &contributor, payout_amount);
+
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: payout_amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+                bonus: 0,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Set the claim window duration (admin only).
+    /// claim_window: seconds beneficiary has to claim after release is authorized.
+    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimWindow, &claim_window);
+        Ok(())
+    }
+
+    /// Set the grace period given to claims authorized from now on (admin only).
+    ///
+    /// A claim made within `grace_seconds` after its window closes still
+    /// succeeds but also emits `LateClaim`. Defaults to zero (no grace).
+    pub fn set_claim_grace(env: Env, grace_seconds: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKeyExt::ClaimGrace, &grace_seconds);
+        Ok(())
+    }
+
+    /// Admin can authorize a release as a pending claim instead of immediate transfer.
+    pub fn authorize_claim(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        reason: DisputeReason,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        let claim_window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(0);
+        let claim = ClaimRecord {
+            bounty_id,
+            recipient: recipient.clone(),
+            amount: escrow.amount,
+            expires_at: now.saturating_add(claim_window),
+            claimed: false,
+            reason: reason.clone(),
+        };
+        let grace_period: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKeyExt::ClaimGrace)
+            .unwrap_or(0);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::ClaimGracePeriod(bounty_id), &grace_period);
+        Self::record_dispute_reason(&env, reason);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("created")),
+            ClaimCreated {
+                bounty_id,
+                recipient,
+                amount: escrow.amount,
+                expires_at: claim.expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Beneficiary calls this to claim their authorized funds within the window.
+    ///
+    /// Claims up to `get_claim_grace_period` seconds past `expires_at` are
+    /// still paid but additionally emit `LateClaim`.
+    pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound);
+        }
+        let mut claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
+
+        claim.recipient.require_auth();
+        Self::ensure_recipient_not_denied(&env, symbol_short!("claim"), &claim.recipient)?;
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &claim.recipient)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
+
+        let now = env.ledger().timestamp();
+        let grace_period = Self::get_claim_grace_period(env.clone(), bounty_id);
+        if now > claim.expires_at.saturating_add(grace_period) {
+            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::scheduled_amount(&env, bounty_id) > 0 {
+            return Err(Error::InsufficientFunds);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        // Update escrow status before the transfer (CEI)
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        claim.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        Self::record_dispute_outcome(&env, DisputeOutcome::ResolvedInFavorOfContributor);
+
+        let client = token::Client::new(&env, &Self::escrow_token(&env, bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &claim.recipient,
+            &claim.amount,
+        );
+        Self::record_contributor_payout(&env, bounty_id, &claim.recipient, claim.amount);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                bounty_id,
+                recipient: claim.recipient.clone(),
+                amount: claim.amount,
+                claimed_at: now,
+            },
+        );
+        events::record_operation(
+            &env,
+            symbol_short!("claim"),
+            bounty_id,
+            claim.amount,
+            claim.recipient.clone(),
+        );
+        if now > claim.expires_at {
+            events::emit_late_claim(
+                &env,
+                events::LateClaim {
+                    bounty_id,
+                    recipient: claim.recipient,
+                    expires_at: claim.expires_at,
+                    claimed_at: now,
+                },
+            );
+        }
         Ok(())
     }
 
-    fn consume_capability(
-        env: &Env,
-        holder: &Address,
-        capability_id: u64,
-        expected_action: CapabilityAction,
+    /// Delegated claim execution using a capability.
+    /// Funds are still transferred to the pending claim recipient.
+    pub fn claim_with_capability(
+        env: Env,
         bounty_id: u64,
-        amount: i128,
-    ) -> Result<Capability, Error> {
-        let mut capability = Self::load_capability(env, capability_id)?;
-
-        if capability.revoked {
-            return Err(Error::CapabilityRevoked);
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
-        if capability.action != expected_action {
-            return Err(Error::CapabilityActionMismatch);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound);
         }
-        if capability.bounty_id != bounty_id {
-            return Err(Error::CapabilityActionMismatch);
+
+        let mut claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed);
         }
-        if capability.holder != holder.clone() {
-            return Err(Error::Unauthorized);
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
         }
-        if env.ledger().timestamp() > capability.expiry {
-            return Err(Error::CapabilityExpired);
+        Self::ensure_recipient_not_denied(&env, symbol_short!("claim"), &claim.recipient)?;
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &claim.recipient)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
+        if Self::scheduled_amount(&env, bounty_id) > 0 {
+            return Err(Error::InsufficientFunds);
         }
-        if capability.remaining_uses == 0 {
-            return Err(Error::CapabilityUsesExhausted);
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Claim,
+            bounty_id,
+            claim.amount,
+            Some(EscrowStatus::Released),
+        )?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        let client = token::Client::new(&env, &Self::escrow_token(&env, bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &claim.recipient,
+            &claim.amount,
+        );
+        Self::record_contributor_payout(&env, bounty_id, &claim.recipient, claim.amount);
+
+        escrow.status = EscrowStatus::Released;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        claim.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        Self::record_dispute_outcome(&env, DisputeOutcome::ResolvedInFavorOfContributor);
+
+        events::record_operation(
+            &env,
+            symbol_short!("claim"),
+            bounty_id,
+            claim.amount,
+            claim.recipient.clone(),
+        );
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                bounty_id,
+                recipient: claim.recipient,
+                amount: claim.amount,
+                claimed_at: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
+    pub fn cancel_pending_claim(
+        env: Env,
+        bounty_id: u64,
+        outcome: DisputeOutcome,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
-        if amount > capability.remaining_amount {
-            return Err(Error::CapabilityAmountExceeded);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            return Err(Error::BountyNotFound);
         }
+        let claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .unwrap();
 
-        holder.require_auth();
-        Self::ensure_owner_still_authorized(env, &capability, amount)?;
+        let now = env.ledger().timestamp(); // Added this line
+        let recipient = claim.recipient.clone(); // Added this line
+        let amount = claim.amount; // Added this line
 
-        capability.remaining_amount -= amount;
-        capability.remaining_uses -= 1;
         env.storage()
             .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
+            .remove(&DataKey::PendingClaim(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::ClaimGracePeriod(bounty_id));
+        Self::record_dispute_outcome(&env, outcome);
 
-        events::emit_capability_used(
-            env,
-            events::CapabilityUsed {
-                capability_id,
-                holder: holder.clone(),
-                action: capability.action.clone(),
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("cancel")),
+            ClaimCancelled {
                 bounty_id,
-                amount_used: amount,
-                remaining_amount: capability.remaining_amount,
-                remaining_uses: capability.remaining_uses,
-                used_at: env.ledger().timestamp(),
+                recipient,
+                amount,
+                cancelled_at: now,
+                cancelled_by: admin,
             },
         );
+        Ok(())
+    }
 
-        Ok(capability)
+    /// View: dispute counts by reason and outcome since deployment.
+    pub fn get_dispute_stats(env: Env) -> DisputeStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeStats)
+            .unwrap_or_default()
+    }
+
+    fn record_dispute_reason(env: &Env, reason: DisputeReason) {
+        let mut stats = Self::get_dispute_stats(env.clone());
+        let counter = match reason {
+            DisputeReason::Expired => &mut stats.reason_expired,
+            DisputeReason::UnsatisfactoryWork => &mut stats.reason_unsatisfactory_work,
+            DisputeReason::Fraud => &mut stats.reason_fraud,
+            DisputeReason::QualityIssue => &mut stats.reason_quality_issue,
+            DisputeReason::Other => &mut stats.reason_other,
+        };
+        *counter = counter.saturating_add(1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeStats, &stats);
     }
 
-    pub fn issue_capability(
-        env: Env,
-        owner: Address,
-        holder: Address,
-        action: CapabilityAction,
-        bounty_id: u64,
-        amount_limit: i128,
-        expiry: u64,
-        max_uses: u32,
-    ) -> Result<u64, Error> {
+    fn record_dispute_outcome(env: &Env, outcome: DisputeOutcome) {
+        let mut stats = Self::get_dispute_stats(env.clone());
+        let counter = match outcome {
+            DisputeOutcome::ResolvedInFavorOfContributor => &mut stats.outcome_contributor,
+            DisputeOutcome::ResolvedInFavorOfDepositor => &mut stats.outcome_depositor,
+            DisputeOutcome::CancelledByAdmin => &mut stats.outcome_cancelled_by_admin,
+            DisputeOutcome::Refunded => &mut stats.outcome_refunded,
+        };
+        *counter = counter.saturating_add(1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeStats, &stats);
+    }
+
+    /// View: get pending claim for a bounty.
+    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// Grace period of the pending claim on `bounty_id`, fixed when the claim
+    /// was authorized; zero for claims authorized without one.
+    pub fn get_claim_grace_period(env: Env, bounty_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::ClaimGracePeriod(bounty_id))
+            .unwrap_or(0)
+    }
+
+    /// Set how long refund approvals stay valid when no explicit expiry is given (admin only).
+    pub fn set_refund_approval_window(env: Env, window_seconds: u64) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
-        if max_uses == 0 {
-            return Err(Error::InvalidAmount);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if window_seconds == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundApprovalWindow, &window_seconds);
+        Ok(())
+    }
+
+    /// View: default lifetime (seconds) applied to new refund approvals.
+    pub fn get_refund_approval_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundApprovalWindow)
+            .unwrap_or(DEFAULT_REFUND_APPROVAL_WINDOW)
+    }
+
+    /// View: stored refund approval for a bounty, including expired ones.
+    pub fn get_refund_approval(env: Env, bounty_id: u64) -> Option<RefundApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id))
+    }
+
+    /// View: expiry of the stored refund approval for a bounty. `None` when no
+    /// approval exists or it predates approval expiry (such approvals never expire).
+    pub fn get_refund_approval_expiry(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::RefundApprovalExpiry(bounty_id))
+    }
+
+    /// Internal: the refund approval for `bounty_id`, if one exists and has not expired.
+    fn active_refund_approval(env: &Env, bounty_id: u64) -> Option<RefundApproval> {
+        let approval: RefundApproval = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id))?;
+        if let Some(expires_at) = Self::get_refund_approval_expiry(env.clone(), bounty_id) {
+            if env.ledger().timestamp() > expires_at {
+                return None;
+            }
+        }
+        Some(approval)
+    }
+
+    /// Internal: drop a refund approval together with its expiry.
+    fn remove_refund_approval(env: &Env, bounty_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundApproval(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::RefundApprovalExpiry(bounty_id));
+    }
+
+    /// Permanently forbid refunds for an escrow (admin or depositor).
+    ///
+    /// Once set, `refund`, `refund_with_capability`, `approve_refund`, the
+    /// keeper and batch refund paths all reject the escrow with
+    /// `RefundForbidden`, so its funds can only be released. The flag cannot
+    /// be cleared.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `Unauthorized` - `caller` is neither the admin nor the depositor
+    /// * `FundsNotLocked` - escrow has already been released or refunded
+    pub fn set_non_refundable(env: Env, bounty_id: u64, caller: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if caller != admin && caller != escrow.depositor {
+            return Err(Error::Unauthorized);
         }
-
-        let now = env.ledger().timestamp();
-        if expiry <= now {
-            return Err(Error::InvalidDeadline);
+        caller.require_auth();
+        if escrow.status == EscrowStatus::Released
+            || escrow.status == EscrowStatus::Refunded
+            || escrow.status == EscrowStatus::Merged
+        {
+            return Err(Error::FundsNotLocked);
         }
 
-        owner.require_auth();
-        Self::validate_capability_scope_at_issue(&env, &owner, &action, bounty_id, amount_limit)?;
-
-        let capability_id = Self::next_capability_id(&env);
-        let capability = Capability {
-            owner: owner.clone(),
-            holder: holder.clone(),
-            action: action.clone(),
-            bounty_id,
-            amount_limit,
-            remaining_amount: amount_limit,
-            expiry,
-            remaining_uses: max_uses,
-            revoked: false,
-        };
-
         env.storage()
             .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
-
-        events::emit_capability_issued(
+            .set(&DataKey::NonRefundable(bounty_id), &true);
+        events::emit_escrow_made_non_refundable(
             &env,
-            events::CapabilityIssued {
-                capability_id,
-                owner,
-                holder,
-                action,
+            events::EscrowMadeNonRefundable {
                 bounty_id,
-                amount_limit,
-                expires_at: expiry,
-                max_uses,
-                timestamp: now,
+                set_by: caller,
+                timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
+    }
 
-        Ok(capability_id)
+    /// Completed payouts to `address` through any release path, as
+    /// `(count, total_received)`. Zero for unknown addresses.
+    pub fn get_contributor_reputation(env: Env, address: Address) -> (u64, i128) {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::ContributorReputation(address))
+            .unwrap_or((0, 0))
     }
 
-    pub fn revoke_capability(env: Env, owner: Address, capability_id: u64) -> Result<(), Error> {
-        let mut capability = Self::load_capability(&env, capability_id)?;
-        if capability.owner != owner {
+    /// Payouts received by `address` as `(bounty_id, amount, timestamp)`,
+    /// oldest first, paginated.
+    ///
+    /// Covers the same payouts as `get_contributor_reputation`. Only the
+    /// latest `MAX_RECIPIENT_HISTORY` entries per address are kept; the
+    /// reputation counter still includes older ones.
+    pub fn get_recipient_history(
+        env: Env,
+        address: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(u64, i128, u64)> {
+        let history: Vec<(u64, i128, u64)> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::RecipientHistory(address))
+            .unwrap_or(Vec::new(&env));
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(history.len());
+        for i in offset..end {
+            page.push_back(history.get(i).unwrap());
+        }
+        page
+    }
+
+    /// Credit a completed payout to the recipient's reputation counter and
+    /// append it to their payout history.
+    fn record_contributor_payout(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+        let key = DataKeyExt::ContributorReputation(recipient.clone());
+        let (count, total): (u64, i128) = env.storage().persistent().get(&key).unwrap_or((0, 0));
+        env.storage().persistent().set(
+            &key,
+            &(count.saturating_add(1), total.saturating_add(amount)),
+        );
+
+        let key = DataKeyExt::RecipientHistory(recipient.clone());
+        let mut history: Vec<(u64, i128, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        history.push_back((bounty_id, amount, env.ledger().timestamp()));
+        while history.len() > MAX_RECIPIENT_HISTORY {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&key, &history);
+    }
+
+    /// Restrict who may be paid from an escrow (admin or depositor).
+    ///
+    /// While the list is non-empty every release path — `release_funds`,
+    /// `partial_release`, claims, tickets, capabilities, vouchers, schedules
+    /// and batch releases — rejects a recipient not on it with
+    /// `ParticipantNotAllowed`. An empty list removes the restriction.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `Unauthorized` - `caller` is neither the admin nor the depositor
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    pub fn set_escrow_recipient_allowlist(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        recipients: Vec<Address>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if caller != admin && caller != escrow.depositor {
             return Err(Error::Unauthorized);
         }
-        owner.require_auth();
+        caller.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
 
-        if capability.revoked {
-            return Ok(());
+        let key = DataKeyExt::EscrowRecipientAllowlist(bounty_id);
+        if recipients.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &recipients);
         }
+        Ok(())
+    }
 
-        capability.revoked = true;
+    /// Recipients `bounty_id` may pay out to; empty when unrestricted.
+    pub fn get_escrow_recipient_allowlist(env: Env, bounty_id: u64) -> Vec<Address> {
         env.storage()
             .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
-
-        events::emit_capability_revoked(
-            &env,
-            events::CapabilityRevoked {
-                capability_id,
-                owner,
-                revoked_at: env.ledger().timestamp(),
-            },
-        );
+            .get(&DataKeyExt::EscrowRecipientAllowlist(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
+    fn ensure_escrow_recipient_allowed(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        let allowed = Self::get_escrow_recipient_allowlist(env.clone(), bounty_id);
+        if !allowed.is_empty() && !allowed.contains(recipient) {
+            return Err(Error::ParticipantNotAllowed);
+        }
         Ok(())
     }
 
-    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, Error> {
-        Self::load_capability(&env, capability_id)
+    /// Per-escrow checks shared by release paths before paying `recipient`:
+    /// the escrow's recipient allowlist, tier approval and release dependency.
+    fn ensure_payout_gates(env: &Env, bounty_id: u64, recipient: &Address) -> Result<(), Error> {
+        Self::ensure_escrow_recipient_allowed(env, bounty_id, recipient)?;
+        if !Self::recipient_release_approved(env, bounty_id, recipient) {
+            return Err(Error::RecipientApprovalRequired);
+        }
+        Self::ensure_dependency_satisfied(env, bounty_id)
     }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+    /// Amount of `bounty_id` reserved by unreleased release schedules.
+    fn scheduled_amount(env: &Env, bounty_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::BountySchedulePending(bounty_id))
+            .unwrap_or(0)
     }
 
-    /// Set a per-token fee configuration (admin only).
+    /// Whether refunds are permanently forbidden for `bounty_id`.
+    pub fn is_non_refundable(env: Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::NonRefundable(bounty_id))
+            .unwrap_or(false)
+    }
+
+    /// Add `address` to (or remove it from) the release denylist (admin only).
     ///
-    /// When a `TokenFeeConfig` is set for a given token address it takes
-    /// precedence over the global `FeeConfig` for all escrows denominated
-    /// in that token.
+    /// Every payout path — `release_funds`, `partial_release`, claims, tickets,
+    /// capabilities, vouchers, schedules and batch releases — rejects a denied
+    /// recipient with `RecipientDenied` before touching state.
     ///
-    /// # Arguments
-    /// * `token`            – the token contract address this config applies to
-    /// * `lock_fee_rate`    – fee rate on lock in basis points (0 – 5 000)
-    /// * `release_fee_rate` – fee rate on release in basis points (0 – 5 000)
-    /// * `fee_recipient`    – address that receives fees for this token
-    /// * `fee_enabled`      – whether fee collection is active
+    /// Once the admin action queue is in use this setter is disabled and the
+    /// change must be queued as `AdminAction::AddressDenied`.
     ///
     /// # Errors
-    /// * `NotInitialized`  – contract not yet initialised
-    /// * `InvalidFeeRate`  – any rate is outside `[0, MAX_FEE_RATE]`
-    pub fn set_token_fee_config(
-        env: Env,
-        token: Address,
-        lock_fee_rate: i128,
-        release_fee_rate: i128,
-        fee_recipient: Address,
-        fee_enabled: bool,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    /// * `NotInitialized` - contract has no admin
+    /// * `Unauthorized` - the admin action queue is in use
+    pub fn set_address_denied(env: Env, address: Address, denied: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        if !(0..=MAX_FEE_RATE).contains(&lock_fee_rate) {
-            return Err(Error::InvalidFeeRate);
-        }
-        if !(0..=MAX_FEE_RATE).contains(&release_fee_rate) {
-            return Err(Error::InvalidFeeRate);
+        if Self::admin_timelock_engaged(&env) {
+            return Err(Error::Unauthorized);
         }
+        Self::write_address_denied(&env, address, denied);
+        Ok(())
+    }
 
-        let config = TokenFeeConfig {
-            lock_fee_rate,
-            release_fee_rate,
-            fee_recipient,
-            fee_enabled,
-        };
+    fn write_address_denied(env: &Env, address: Address, denied: bool) {
+        let key = DataKeyExt::DeniedAddress(address.clone());
+        if denied {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::emit_address_denial_updated(
+            env,
+            events::AddressDenialUpdated {
+                address,
+                denied,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
 
+    /// Whether `address` is on the release denylist.
+    pub fn is_address_denied(env: Env, address: Address) -> bool {
         env.storage()
-            .instance()
-            .set(&DataKey::TokenFeeConfig(token), &config);
+            .persistent()
+            .get(&DataKeyExt::DeniedAddress(address))
+            .unwrap_or(false)
+    }
 
+    fn ensure_recipient_not_denied(
+        env: &Env,
+        operation: Symbol,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        if Self::is_address_denied(env.clone(), recipient.clone()) {
+            Self::record_compliance_rejection(
+                env,
+                operation,
+                recipient.clone(),
+                COMPLIANCE_DENYLIST,
+            );
+            return Err(Error::RecipientDenied);
+        }
         Ok(())
     }
 
-    /// Get the per-token fee configuration for `token`, if one has been set.
+    /// Policy rejections in the order they were recorded, paginated.
     ///
-    /// Returns `None` when no token-specific config exists; callers should
-    /// fall back to the global `FeeConfig` in that case.
-    pub fn get_token_fee_config(env: Env, token: Address) -> Option<TokenFeeConfig> {
-        env.storage()
-            .instance()
-            .get(&DataKey::TokenFeeConfig(token))
+    /// Denylist hits, anti-abuse cooldown / rate-limit blocks and invariant
+    /// auto-halts are recorded with one of the `COMPLIANCE_*` reason codes.
+    /// Only the latest `MAX_COMPLIANCE_LOG` entries are kept.
+    ///
+    /// The log is best-effort. An entry is written by the invocation that was
+    /// rejected, so it is only retained when that invocation itself completes,
+    /// as an invariant auto-halt does. Denylist and anti-abuse rejections fail
+    /// the transaction, which rolls back their entry along with everything else.
+    pub fn get_compliance_log(env: Env, offset: u32, limit: u32) -> Vec<ComplianceLogEntry> {
+        let log: Vec<ComplianceLogEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::ComplianceLog)
+            .unwrap_or(Vec::new(&env));
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(log.len());
+        for i in offset..end {
+            page.push_back(log.get(i).unwrap());
+        }
+        page
     }
 
-    /// Internal: resolve the effective fee config for the escrow token.
+    /// Operation log entries with a sequence number above `since_seq`, oldest
+    /// first, at most `limit` of them.
     ///
-    /// Precedence: `TokenFeeConfig(token)` > global `FeeConfig`.
-    fn resolve_fee_config(env: &Env) -> (i128, i128, Address, bool) {
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        if let Some(tok_cfg) = env
+    /// Every lock, release, refund and claim is appended with a monotonic
+    /// `seq` starting at 1, so an indexer can resume from the last `seq` it
+    /// stored and spot a gap when the first entry returned is not
+    /// `since_seq + 1`. This is a best-effort mirror of recent history, not a
+    /// full record: only the latest `MAX_OPERATION_LOG` entries are kept, and
+    /// older ones must be recovered from events. Each entry is a separate
+    /// storage read, so page through with a modest `limit`.
+    pub fn export_operations(env: Env, since_seq: u64, limit: u32) -> Vec<OperationLogEntry> {
+        let latest: u64 = env
             .storage()
-            .instance()
-            .get::<DataKey, TokenFeeConfig>(&DataKey::TokenFeeConfig(token_addr))
-        {
-            (
-                tok_cfg.lock_fee_rate,
-                tok_cfg.release_fee_rate,
-                tok_cfg.fee_recipient,
-                tok_cfg.fee_enabled,
-            )
-        } else {
-            let global = Self::get_fee_config_internal(env);
-            (
-                global.lock_fee_rate,
-                global.release_fee_rate,
-                global.fee_recipient,
-                global.fee_enabled,
-            )
+            .persistent()
+            .get(&DataKeyExt::OperationLogSeq)
+            .unwrap_or(0);
+        let oldest = latest.saturating_sub(MAX_OPERATION_LOG) + 1;
+        let start = since_seq.saturating_add(1).max(oldest);
+        let end = start
+            .saturating_add(limit as u64)
+            .min(latest.saturating_add(1));
+
+        let mut page = Vec::new(&env);
+        for seq in start..end {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<DataKeyExt, OperationLogEntry>(&DataKeyExt::OperationLog(seq))
+            {
+                page.push_back(entry);
+            }
         }
+        page
     }
 
-    /// Update multisig configuration (admin only)
-    pub fn update_multisig_config(
+    fn record_compliance_rejection(
+        env: &Env,
+        operation: Symbol,
+        caller: Address,
+        reason_code: u32,
+    ) {
+        let mut log: Vec<ComplianceLogEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::ComplianceLog)
+            .unwrap_or(Vec::new(env));
+        log.push_back(ComplianceLogEntry {
+            operation,
+            caller,
+            reason_code,
+            timestamp: env.ledger().timestamp(),
+        });
+        while log.len() > MAX_COMPLIANCE_LOG {
+            log.pop_front();
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::ComplianceLog, &log);
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    ///
+    /// The approval expires at `expires_at`, or after the configured refund approval
+    /// window when `None`. An expired approval is ignored and the standard deadline
+    /// rule applies again.
+    pub fn approve_refund(
         env: Env,
-        threshold_amount: i128,
-        signers: Vec<Address>,
-        required_signatures: u32,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+        expires_at: Option<u64>,
     ) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::is_non_refundable(env.clone(), bounty_id) {
+            return Err(Error::RefundForbidden);
+        }
 
-        if required_signatures > signers.len() {
+        if amount <= 0 || amount > escrow.remaining_amount {
             return Err(Error::InvalidAmount);
         }
 
-        let config = MultisigConfig {
-            threshold_amount,
-            signers,
-            required_signatures,
+        let now = env.ledger().timestamp();
+        let expires_at = match expires_at {
+            Some(expires_at) => expires_at,
+            None => now.saturating_add(Self::get_refund_approval_window(env.clone())),
+        };
+        if expires_at <= now {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: now,
         };
 
         env.storage()
-            .instance()
-            .set(&DataKey::MultisigConfig, &config);
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::RefundApprovalExpiry(bounty_id), &expires_at);
 
         Ok(())
     }
 
-    /// Get multisig configuration
-    pub fn get_multisig_config(env: Env) -> MultisigConfig {
-        env.storage()
-            .instance()
-            .get(&DataKey::MultisigConfig)
-            .unwrap_or(MultisigConfig {
-                threshold_amount: i128::MAX,
-                signers: vec![&env],
-                required_signatures: 0,
-            })
-    }
-
-    /// Approve release for large amount (requires multisig)
-    pub fn approve_large_release(
+    /// Release a partial amount of the locked funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
+    /// - `remaining_amount` is decremented by `payout_amount` after each call.
+    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
+    /// - The bounty stays Locked while any funds remain unreleased.
+    pub fn partial_release(
         env: Env,
         bounty_id: u64,
         contributor: Address,
-        approver: Address,
+        payout_amount: i128,
     ) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
 
-        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
-
-        let mut is_signer = false;
-        for signer in multisig_config.signers.iter() {
-            if signer == approver {
-                is_signer = true;
-                break;
-            }
-        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_recipient_not_denied(&env, symbol_short!("p_release"), &contributor)?;
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &contributor)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
 
-        if !is_signer {
-            return Err(Error::Unauthorized);
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
         }
 
-        approver.require_auth();
-
-        let approval_key = DataKey::ReleaseApproval(bounty_id);
-        let mut approval: ReleaseApproval = env
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&approval_key)
-            .unwrap_or(ReleaseApproval {
-                bounty_id,
-                contributor: contributor.clone(),
-                approvals: vec![&env],
-            });
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
 
-        for existing in approval.approvals.iter() {
-            if existing == approver {
-                return Ok(());
-            }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if !Self::lock_duration_met(&env, bounty_id) {
+            return Err(Error::LockDurationNotMet);
         }
 
-        approval.approvals.push_back(approver.clone());
-        env.storage().persistent().set(&approval_key, &approval);
+        // Guard: zero or negative payout makes no sense and would corrupt state
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        events::emit_approval_added(
-            &env,
-            events::ApprovalAdded {
-                bounty_id,
-                contributor: contributor.clone(),
-                approver,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+        // Guard: prevent overpayment — payout cannot exceed what is still owed
+        // outside the amount reserved by unreleased release schedules
+        let unscheduled = escrow.remaining_amount - Self::scheduled_amount(&env, bounty_id);
+        if payout_amount > unscheduled {
+            return Err(Error::InsufficientFunds);
+        }
 
+        let bonus = Self::early_bonus_for(&env, bounty_id, &escrow, payout_amount)
+            .min(unscheduled - payout_amount);
+        Self::pay_partial_release(&env, bounty_id, escrow, contributor, payout_amount, bonus);
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
-    /// Lock funds for a bounty. When `non_transferable_rewards` is true, the escrow is marked
-    /// as using soulbound/non-transferable tokens; the token contract must disallow further
-    /// transfers after the recipient claims. Claim and release still perform a single transfer
-    /// from the contract to the recipient; no further transfers are required.
-    pub fn lock_funds(
-        env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> Result<(), Error> {
-        let res =
-            Self::lock_funds_logic(env.clone(), depositor.clone(), bounty_id, amount, deadline);
-        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
-        res
-    }
-
-    fn lock_funds_logic(
+    /// Reward releases made soon after lock with a bonus from the escrow (admin only).
+    ///
+    /// A `partial_release` of `bounty_id` within `window_seconds` of its lock
+    /// pays `payout_amount * (1 + bonus_bps / BASIS_POINTS)`, the bonus rounded
+    /// down and capped at what remains in the escrow. `release_funds` already
+    /// pays out the whole escrow, so it never carries a bonus. A zero
+    /// `bonus_bps` or `window_seconds` removes the bonus.
+    ///
+    /// # Errors
+    /// * `InvalidFeeRate` - `bonus_bps` is negative or above `BASIS_POINTS`
+    /// * `BountyNotFound` - no escrow with `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    pub fn set_early_bonus(
         env: Env,
-        depositor: Address,
         bounty_id: u64,
-        amount: i128,
-        deadline: u64,
+        bonus_bps: i128,
+        window_seconds: u64,
     ) -> Result<(), Error> {
-        // Validation precedence (deterministic ordering):
-        // 1. Reentrancy guard
-        // 2. Contract initialized
-        // 3. Paused / deprecated (operational state)
-        // 4. Participant filter + rate limiting
-        // 5. Authorization
-        // 6. Input validation (amount policy)
-        // 7. Business logic (bounty uniqueness)
-
-        // 1. GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-
-        // 2. Contract must be initialized before any other check
-        if !env.storage().instance().has(&DataKey::Admin) {
-            reentrancy_guard::release(&env);
-            return Err(Error::NotInitialized);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if !(0..=BASIS_POINTS).contains(&bonus_bps) {
+            return Err(Error::InvalidFeeRate);
         }
-        soroban_sdk::log!(&env, "admin ok");
 
-        // 3. Operational state: paused / deprecated
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            reentrancy_guard::release(&env);
-            return Err(Error::FundsPaused);
-        }
-        if Self::get_deprecation_state(&env).deprecated {
-            reentrancy_guard::release(&env);
-            return Err(Error::ContractDeprecated);
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
-        soroban_sdk::log!(&env, "check paused ok");
 
-        // 4. Participant filtering and rate limiting
-        Self::check_participant_filter(&env, depositor.clone())?;
-        soroban_sdk::log!(&env, "start lock_funds");
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-        soroban_sdk::log!(&env, "rate limit ok");
+        let key = DataKeyExt::EarlyBonus(bounty_id);
+        if bonus_bps == 0 || window_seconds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(
+                &key,
+                &EarlyBonus {
+                    bonus_bps,
+                    window_seconds,
+                },
+            );
+        }
+        Ok(())
+    }
 
-        let _start = env.ledger().timestamp();
-        let _caller = depositor.clone();
+    /// Early-release bonus configured for `bounty_id`, if any.
+    pub fn get_early_bonus(env: Env, bounty_id: u64) -> Option<EarlyBonus> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EarlyBonus(bounty_id))
+    }
 
-        // 5. Authorization
-        depositor.require_auth();
-        soroban_sdk::log!(&env, "auth ok");
+    /// Bonus owed on a partial release of `payout_amount` right now: zero
+    /// outside the bonus window, otherwise capped at what the payout leaves.
+    fn early_bonus_for(env: &Env, bounty_id: u64, escrow: &Escrow, payout_amount: i128) -> i128 {
+        let bonus = match Self::get_early_bonus(env.clone(), bounty_id) {
+            Some(bonus) => bonus,
+            None => return 0,
+        };
+        let window_end =
+            Self::escrow_locked_at(env, bounty_id).saturating_add(bonus.window_seconds);
+        if env.ledger().timestamp() > window_end {
+            return 0;
+        }
+        let amount = payout_amount
+            .checked_mul(bonus.bonus_bps)
+            .map(|v| v / BASIS_POINTS)
+            .unwrap_or(i128::MAX);
+        amount.min(escrow.remaining_amount - payout_amount)
+    }
 
-        // 6. Input validation: amount policy
-        // Enforce min/max amount policy if one has been configured (Issue #62).
-        if let Some((min_amount, max_amount)) = env
+    /// Release an escrow while holding back a share for a review period (admin only).
+    ///
+    /// Pays `remaining_amount` minus `holdback_bps` of it (rounded down) to
+    /// `contributor` now and closes the escrow as `Released`. The held-back
+    /// share stays in the contract as a `Holdback` until `release_holdback`
+    /// pays it to the contributor after `review_seconds`, or
+    /// `revoke_holdback` returns it to the depositor. The release fee (see
+    /// `get_effective_fee_rate`) is taken from each share as it is paid to the
+    /// contributor; a revoked share goes back without a fee.
+    ///
+    /// # Errors
+    /// * `FundsPaused` - releases are paused
+    /// * `InvalidFeeRate` - `holdback_bps` is not strictly between 0 and `BASIS_POINTS`
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    /// * `LockDurationNotMet` - escrow is still inside its minimum lock duration
+    /// * `RecipientApprovalRequired` - recipient's tier needs an approval first
+    /// * `InvalidAmount` - the held-back share rounds down to zero, or the fee
+    ///   leaves nothing of the immediate payout
+    pub fn release_with_holdback(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        holdback_bps: i128,
+        review_seconds: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        let admin: Address = env
             .storage()
             .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountAboveMaximum);
-            }
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_recipient_not_denied(&env, symbol_short!("release"), &contributor)?;
+        if holdback_bps <= 0 || holdback_bps >= BASIS_POINTS {
+            return Err(Error::InvalidFeeRate);
         }
-        soroban_sdk::log!(&env, "amount policy ok");
 
-        // 7. Business logic: bounty must not already exist
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            reentrancy_guard::release(&env);
-            return Err(Error::BountyExists);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
-        soroban_sdk::log!(&env, "bounty exists ok");
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        soroban_sdk::log!(&env, "token client ok");
-
-        // Transfer full gross amount from depositor to contract first.
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
-        soroban_sdk::log!(&env, "transfer ok");
-
-        // Resolve effective fee config (per-token takes precedence over global).
-        let (lock_fee_rate, _release_fee_rate, fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(&env);
-
-        // Deduct lock fee from the escrowed principal.
-        // Ceiling division ensures fee >= 1 stroop whenever rate > 0,
-        // preventing principal drain via dust-amount splitting.
-        let fee_amount = if fee_enabled && lock_fee_rate > 0 {
-            Self::calculate_fee(amount, lock_fee_rate)
-        } else {
-            0
-        };
-
-        // Net amount stored in escrow after fee.
-        // Fee must never exceed the deposit; guard against misconfiguration.
-        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
-        if net_amount <= 0 {
+        if !Self::lock_duration_met(&env, bounty_id) {
+            return Err(Error::LockDurationNotMet);
+        }
+        Self::ensure_payout_gates(&env, bounty_id, &contributor)?;
+        if Self::scheduled_amount(&env, bounty_id) > 0 {
+            return Err(Error::InsufficientFunds);
+        }
+        let held = escrow.remaining_amount * holdback_bps / BASIS_POINTS;
+        if held == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let paid = escrow.remaining_amount - held;
+        let token_addr = Self::escrow_token(&env, bounty_id);
+        let fee_rate = Self::fee_rate_for(
+            &env,
+            &token_addr,
+            &escrow.depositor,
+            escrow.amount,
+            symbol_short!("release"),
+        );
+        let fee = Self::calculate_fee(paid, fee_rate);
+        if paid - fee <= 0 {
             return Err(Error::InvalidAmount);
         }
 
-        // Transfer fee to recipient immediately (separate transfer so it is
-        // visible as a distinct on-chain operation).
-        if fee_amount > 0 {
-            client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Lock,
-                    amount: fee_amount,
-                    fee_rate: lock_fee_rate,
-                    recipient: fee_recipient,
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
-        }
-        soroban_sdk::log!(&env, "fee ok");
+        reentrancy_guard::acquire(&env);
 
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount: net_amount,
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            remaining_amount: net_amount,
+        // EFFECTS: close the escrow and park the held-back share.
+        let now = env.ledger().timestamp();
+        let holdback = Holdback {
+            contributor: contributor.clone(),
+            depositor: escrow.depositor.clone(),
+            token: token_addr.clone(),
+            amount: held,
+            releasable_at: now.saturating_add(review_seconds),
         };
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::Holdback(bounty_id), &holdback);
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
         invariants::assert_escrow(&env, &escrow);
-
-        // Extend the TTL of the storage entry to ensure it lives long enough
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
 
-        // Update indexes
-        let mut index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.push_back(bounty_id);
+        // INTERACTION
+        Self::pay_release_fee(&env, &token_addr, fee, fee_rate);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &(paid - fee));
+        Self::record_contributor_payout(&env, bounty_id, &contributor, paid - fee);
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: paid,
+                recipient: contributor,
+                timestamp: now,
+                bonus: 0,
+            },
+        );
+        events::emit_holdback_recorded(
+            &env,
+            events::HoldbackRecorded {
+                bounty_id,
+                amount: held,
+                releasable_at: holdback.releasable_at,
+                timestamp: now,
+            },
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Pay a held-back share to its contributor once the review period is
+    /// over, less the release fee. Callable by anyone.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no pending holdback or escrow for `bounty_id`
+    /// * `DeadlineNotPassed` - the review period has not ended
+    /// * `FundsPaused` - releases are paused
+    pub fn release_holdback(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let holdback = Self::get_holdback(env.clone(), bounty_id).ok_or(Error::BountyNotFound)?;
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let now = env.ledger().timestamp();
+        if now < holdback.releasable_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_recipient_not_denied(&env, symbol_short!("release"), &holdback.contributor)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
+        let fee_rate = Self::fee_rate_for(
+            &env,
+            &holdback.token,
+            &holdback.depositor,
+            holdback.amount,
+            symbol_short!("release"),
+        );
+        let fee = Self::calculate_fee(holdback.amount, fee_rate);
+        let net = holdback.amount - fee;
+
+        reentrancy_guard::acquire(&env);
         env.storage()
             .persistent()
-            .set(&DataKey::EscrowIndex, &index);
+            .remove(&DataKeyExt::Holdback(bounty_id));
 
-        let mut depositor_index: Vec<u64> = env
+        Self::pay_release_fee(&env, &holdback.token, fee, fee_rate);
+        let client = token::Client::new(&env, &holdback.token);
+        if net > 0 {
+            client.transfer(&env.current_contract_address(), &holdback.contributor, &net);
+        }
+        Self::record_contributor_payout(&env, bounty_id, &holdback.contributor, net);
+
+        events::emit_holdback_settled(
+            &env,
+            events::HoldbackSettled {
+                bounty_id,
+                recipient: holdback.contributor,
+                amount: holdback.amount,
+                revoked: false,
+                timestamp: now,
+            },
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Return a pending held-back share to the depositor instead (admin only).
+    ///
+    /// The escrow moves from `Released` to `PartiallyRefunded` and the share is
+    /// added to its refund history, so it counts toward refund stats.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no pending holdback or escrow for `bounty_id`
+    pub fn revoke_holdback(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let holdback = Self::get_holdback(env.clone(), bounty_id).ok_or(Error::BountyNotFound)?;
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&DataKey::DepositorIndex(depositor.clone()))
-            .unwrap_or(Vec::new(&env));
-        depositor_index.push_back(bounty_id);
-        env.storage().persistent().set(
-            &DataKey::DepositorIndex(depositor.clone()),
-            &depositor_index,
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let now = env.ledger().timestamp();
+
+        reentrancy_guard::acquire(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::Holdback(bounty_id));
+        escrow.status = EscrowStatus::PartiallyRefunded;
+        escrow.refund_history.push_back(RefundRecord {
+            amount: holdback.amount,
+            recipient: holdback.depositor.clone(),
+            timestamp: now,
+            mode: RefundMode::Partial,
+        });
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        let client = token::Client::new(&env, &holdback.token);
+        client.transfer(
+            &env.current_contract_address(),
+            &holdback.depositor,
+            &holdback.amount,
         );
 
-        // Emit value allows for off-chain indexing
-        emit_funds_locked(
+        emit_funds_refunded(
             &env,
-            FundsLocked {
+            FundsRefunded {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount,
-                depositor: depositor.clone(),
-                deadline,
+                amount: holdback.amount,
+                refund_to: holdback.depositor.clone(),
+                timestamp: now,
+                trigger: RefundTrigger::AdminApproval,
+            },
+        );
+        events::emit_holdback_settled(
+            &env,
+            events::HoldbackSettled {
+                bounty_id,
+                recipient: holdback.depositor,
+                amount: holdback.amount,
+                revoked: true,
+                timestamp: now,
             },
         );
 
-        // INV-2: Verify aggregate balance matches token balance after lock
-        multitoken_invariants::assert_after_lock(&env);
-
-        // GUARD: release reentrancy lock
+        multitoken_invariants::assert_after_disbursement(&env);
         reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Simulate lock operation without state changes or token transfers.
+    /// Send an already computed release `fee` of `token_addr` to the fee
+    /// recipient and emit `FeeCollected`; a zero fee does nothing.
+    fn pay_release_fee(env: &Env, token_addr: &Address, fee: i128, fee_rate: i128) {
+        if fee <= 0 {
+            return;
+        }
+        let (_, _, fee_recipient, _) = Self::resolve_fee_config(env, token_addr);
+        token::Client::new(env, token_addr).transfer(
+            &env.current_contract_address(),
+            &fee_recipient,
+            &fee,
+        );
+        events::emit_fee_collected(
+            env,
+            events::FeeCollected {
+                operation_type: events::FeeOperationType::Release,
+                amount: fee,
+                fee_rate,
+                recipient: fee_recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Pending held-back share of `bounty_id`, if any.
+    pub fn get_holdback(env: Env, bounty_id: u64) -> Option<Holdback> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::Holdback(bounty_id))
+    }
+
+    /// Queue a high-risk admin setting change behind a time lock (admin only).
     ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    /// The action takes effect only when `execute_admin_action` is called at
+    /// or after `execute_after`, giving stakeholders time to react; until then
+    /// it can be withdrawn with `cancel_admin_action`. Returns the action id.
     ///
-    /// # Arguments
-    /// * `depositor` - Address that would lock funds
-    /// * `bounty_id` - Bounty identifier
-    /// * `amount` - Amount to lock
-    /// * `deadline` - Deadline timestamp
+    /// After the first queued action, `set_amount_policy`, `set_address_denied`
+    /// and `set_blocklist_entry` return `Unauthorized`, so those settings can
+    /// only change through the queue.
     ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_lock(
+    /// # Errors
+    /// * `NotInitialized` - contract has no admin
+    /// * `InvalidDeadline` - `execute_after` is less than `MIN_ADMIN_ACTION_DELAY` away
+    /// * `InvalidAmount` - a `AmountPolicy` minimum exceeds its maximum
+    pub fn queue_admin_action(
         env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> SimulationResult {
-        fn err_result(e: Error) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
+        action: AdminAction,
+        execute_after: u64,
+    ) -> Result<u64, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let now = env.ledger().timestamp();
+        if execute_after < now.saturating_add(MIN_ADMIN_ACTION_DELAY) {
+            return Err(Error::InvalidDeadline);
+        }
+        if let AdminAction::AmountPolicy(min_amount, max_amount) = action {
+            if min_amount > max_amount {
+                return Err(Error::InvalidAmount);
             }
         }
-        match Self::dry_run_lock_impl(&env, depositor, bounty_id, amount, deadline) {
-            Ok((net_amount,)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount: net_amount,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: net_amount,
+
+        let action_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::AdminActionCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::AdminActionCounter, &action_id);
+        env.storage().persistent().set(
+            &DataKeyExt::PendingAdminAction(action_id),
+            &PendingAdminAction {
+                action: action.clone(),
+                queued_at: now,
+                execute_after,
             },
-            Err(e) => err_result(e),
-        }
+        );
+
+        events::emit_admin_action_queued(
+            &env,
+            events::AdminActionQueued {
+                action_id,
+                action,
+                execute_after,
+                timestamp: now,
+            },
+        );
+        Ok(action_id)
     }
 
-    fn dry_run_lock_impl(
-        env: &Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        _deadline: u64,
-    ) -> Result<(i128,), Error> {
-        // 1. Contract must be initialized
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        // 2. Operational state: paused / deprecated
-        if Self::check_paused(env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
-        }
-        if Self::get_deprecation_state(env).deprecated {
-            return Err(Error::ContractDeprecated);
-        }
-        // 3. Participant filtering (read-only)
-        Self::check_participant_filter(env, depositor.clone())?;
-        // 4. Amount policy
-        if let Some((min_amount, max_amount)) = env
+    /// Whether the admin has started using the action queue. From then on the
+    /// direct setters for queueable settings are disabled.
+    fn admin_timelock_engaged(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKeyExt::AdminActionCounter)
+    }
+
+    /// Apply a queued admin action once its time lock has passed (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - contract has no admin
+    /// * `BountyNotFound` - no queued action with `action_id`
+    /// * `DeadlineNotPassed` - `execute_after` has not been reached
+    pub fn execute_admin_action(env: Env, action_id: u64) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
             .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return Err(Error::AmountBelowMinimum);
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKeyExt::PendingAdminAction(action_id);
+        let pending: PendingAdminAction = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::BountyNotFound)?;
+        let now = env.ledger().timestamp();
+        if now < pending.execute_after {
+            return Err(Error::DeadlineNotPassed);
+        }
+        env.storage().persistent().remove(&key);
+
+        match pending.action.clone() {
+            AdminAction::AmountPolicy(min_amount, max_amount) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
             }
-            if amount > max_amount {
-                return Err(Error::AmountAboveMaximum);
+            AdminAction::AddressDenied(address, denied) => {
+                Self::write_address_denied(&env, address, denied);
+            }
+            AdminAction::BlocklistEntry(address, blocked) => {
+                anti_abuse::set_blocklist(&env, address, blocked);
             }
         }
-        // 5. Bounty must not already exist
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyExists);
-        }
-        // 6. Amount validation
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(env, &token_addr);
-        // 7. Sufficient balance (read-only)
-        let balance = client.balance(&depositor);
-        if balance < amount {
-            return Err(Error::InsufficientFunds);
-        }
-        // 8. Fee computation (pure)
-        let (lock_fee_rate, _release_fee_rate, _fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(env);
-        let fee_amount = if fee_enabled && lock_fee_rate > 0 {
-            Self::calculate_fee(amount, lock_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
-        if net_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        Ok((net_amount,))
+
+        events::emit_admin_action_executed(
+            &env,
+            events::AdminActionExecuted {
+                action_id,
+                action: pending.action,
+                timestamp: now,
+            },
+        );
+        Ok(())
     }
 
-    /// Returns whether the given bounty escrow is marked as using non-transferable (soulbound)
-    /// reward tokens. When true, the token is expected to disallow further transfers after claim.
-    pub fn get_non_transferable_rewards(env: Env, bounty_id: u64) -> Result<bool, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        Ok(env
+    /// Withdraw a queued admin action before it is executed (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - contract has no admin
+    /// * `BountyNotFound` - no queued action with `action_id`
+    pub fn cancel_admin_action(env: Env, action_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKeyExt::PendingAdminAction(action_id);
+        let pending: PendingAdminAction = env
             .storage()
             .persistent()
-            .get(&DataKey::NonTransferableRewards(bounty_id))
-            .unwrap_or(false))
-    }
+            .get(&key)
+            .ok_or(Error::BountyNotFound)?;
+        env.storage().persistent().remove(&key);
 
-    /// Lock funds for a bounty in anonymous mode: only a 32-byte depositor commitment is stored.
-    /// The depositor must authorize and transfer; their address is used only for the transfer
-    /// in this call and is not stored on-chain. Refunds require the configured anonymous
-    /// resolver to call `refund_resolved(bounty_id, recipient)`.
-    pub fn lock_funds_anonymous(
-        env: Env,
-        depositor: Address,
-        depositor_commitment: BytesN<32>,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> Result<(), Error> {
-        // Validation precedence (deterministic ordering):
-        // 1. Reentrancy guard
-        // 2. Contract initialized
-        // 3. Paused (operational state)
-        // 4. Rate limiting
-        // 5. Authorization
-        // 6. Business logic (bounty uniqueness, amount policy)
+        events::emit_admin_action_cancelled(
+            &env,
+            events::AdminActionCancelled {
+                action_id,
+                action: pending.action,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        // 1. Reentrancy guard
-        reentrancy_guard::acquire(&env);
+    /// Queued admin action `action_id`, if it is still pending.
+    pub fn get_admin_action(env: Env, action_id: u64) -> Option<PendingAdminAction> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::PendingAdminAction(action_id))
+    }
 
-        // 2. Contract must be initialized
-        if !env.storage().instance().has(&DataKey::Admin) {
-            reentrancy_guard::release(&env);
-            return Err(Error::NotInitialized);
+    /// Set the dust threshold for partial releases (admin only).
+    ///
+    /// When a partial release leaves `remaining_amount` at or below
+    /// `threshold`, the residual is sent to the fee recipient, the escrow is
+    /// marked `Released` and `DustSwept` is emitted. Zero disables sweeping.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is not the admin
+    /// * `InvalidAmount` - `threshold` is negative
+    pub fn set_dust_threshold(env: Env, caller: Address, threshold: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if caller != admin {
+            return Err(Error::Unauthorized);
         }
-
-        // 3. Operational state: paused
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            reentrancy_guard::release(&env);
-            return Err(Error::FundsPaused);
+        caller.require_auth();
+        if threshold < 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // 4. Rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        env.storage()
+            .instance()
+            .set(&DataKeyExt::DustThreshold, &threshold);
+        Ok(())
+    }
 
-        // 5. Authorization
-        depositor.require_auth();
+    /// Configure the penalty taken from post-deadline refunds (admin only).
+    ///
+    /// Refunds triggered by the deadline send `penalty_bps` of the refunded
+    /// amount (rounded down) to `penalty_recipient` and the rest to the
+    /// depositor. Admin-approved refunds are not affected. Zero removes the
+    /// penalty, which is also the default.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is not the admin
+    /// * `InvalidFeeRate` - `penalty_bps` is negative or above `BASIS_POINTS`
+    pub fn set_refund_penalty(
+        env: Env,
+        caller: Address,
+        penalty_bps: i128,
+        penalty_recipient: Address,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        if !(0..=BASIS_POINTS).contains(&penalty_bps) {
+            return Err(Error::InvalidFeeRate);
+        }
 
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
-            || env
-                .storage()
-                .persistent()
-                .has(&DataKey::EscrowAnon(bounty_id))
-        {
-            reentrancy_guard::release(&env);
-            return Err(Error::BountyExists);
+        if penalty_bps == 0 {
+            env.storage().instance().remove(&DataKeyExt::RefundPenalty);
+        } else {
+            env.storage().instance().set(
+                &DataKeyExt::RefundPenalty,
+                &RefundPenalty {
+                    penalty_bps,
+                    penalty_recipient,
+                },
+            );
         }
+        Ok(())
+    }
 
-        if let Some((min_amount, max_amount)) = env
-            .storage()
+    /// Current refund penalty, if one is configured.
+    pub fn get_refund_penalty(env: Env) -> Option<RefundPenalty> {
+        env.storage().instance().get(&DataKeyExt::RefundPenalty)
+    }
+
+    /// Current dust threshold (zero when unset).
+    pub fn get_dust_threshold(env: Env) -> i128 {
+        env.storage()
             .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                reentrancy_guard::release(&env);
-                return Err(Error::AmountAboveMaximum);
-            }
-        }
+            .get(&DataKeyExt::DustThreshold)
+            .unwrap_or(0)
+    }
 
-        let escrow_anon = AnonymousEscrow {
-            depositor_commitment: depositor_commitment.clone(),
-            amount,
-            remaining_amount: amount,
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
+    /// Effects and interaction of a partial release whose checks have passed:
+    /// decrement `remaining_amount` (marking the escrow `Released` at zero),
+    /// persist, then transfer `payout_amount` plus any early `bonus` to
+    /// `contributor`.
+    fn pay_partial_release(
+        env: &Env,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        contributor: Address,
+        payout_amount: i128,
+        bonus: i128,
+    ) {
+        // Decrement remaining; this is always an exact integer subtraction — no rounding
+        escrow.remaining_amount = escrow
+            .remaining_amount
+            .checked_sub(payout_amount + bonus)
+            .unwrap();
+
+        // A residual at or below the dust threshold is swept to the fee recipient
+        let dust = if escrow.remaining_amount <= Self::get_dust_threshold(env.clone()) {
+            escrow.remaining_amount
+        } else {
+            0
         };
+        escrow.remaining_amount -= dust;
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::EscrowAnon(bounty_id), &escrow_anon);
+        // Automatically transition to Released once fully paid out
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
 
-        let mut index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.push_back(bounty_id);
         env.storage()
             .persistent()
-            .set(&DataKey::EscrowIndex, &index);
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(env, bounty_id, escrow.status.clone());
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
+        // Transfer only the requested partial amount (and bonus) to the contributor
+        let client = token::Client::new(env, &Self::escrow_token(env, bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &(payout_amount + bonus),
+        );
+        Self::record_contributor_payout(env, bounty_id, &contributor, payout_amount + bonus);
+        if dust > 0 {
+            let fee_recipient = Self::get_fee_config_internal(env).fee_recipient;
+            client.transfer(&env.current_contract_address(), &fee_recipient, &dust);
+            events::emit_dust_swept(
+                env,
+                events::DustSwept {
+                    bounty_id,
+                    amount: dust,
+                    recipient: fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
-        emit_funds_locked_anon(
-            &env,
-            FundsLockedAnon {
+        events::emit_funds_released(
+            env,
+            FundsReleased {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount,
-                depositor_commitment,
-                deadline,
+                amount: payout_amount + bonus,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+                bonus,
             },
         );
-
-        multitoken_invariants::assert_after_lock(&env);
-        reentrancy_guard::release(&env);
-        Ok(())
     }
 
-    /// Release funds to the contributor.
-    /// Only the admin (backend) can authorize this.
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let caller = env
+    /// Schedule a milestone payout of part of an escrow (admin only).
+    ///
+    /// Once `release_timestamp` is reached anyone may call
+    /// `release_bounty_schedule` to pay `amount` to `recipient`. Unreleased
+    /// schedules of an escrow may not add up to more than its `remaining_amount`,
+    /// and the amount they reserve cannot be paid out or refunded by any other
+    /// path until the schedule is released or cancelled with
+    /// `cancel_bounty_release_schedule`.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is not positive
+    /// * `InvalidDeadline` - `release_timestamp` is not in the future
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    /// * `InsufficientFunds` - schedules would exceed the remaining amount
+    pub fn create_bounty_release_schedule(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> Result<u64, Error> {
+        let admin: Address = env
             .storage()
             .instance()
-            .get::<DataKey, Address>(&DataKey::Admin)
-            .unwrap_or(contributor.clone());
-        let res = Self::release_funds_logic(env.clone(), bounty_id, contributor);
-        monitoring::track_operation(&env, symbol_short!("release"), caller, res.is_ok());
-        res
-    }
-
-    fn release_funds_logic(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        // Validation precedence (deterministic ordering):
-        // 1. Reentrancy guard
-        // 2. Contract initialized
-        // 3. Paused (operational state)
-        // 4. Authorization
-        // 5. Business logic (bounty exists, funds locked)
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        // 1. Reentrancy guard (manual inline guard used here for release_funds)
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if release_timestamp <= now {
+            return Err(Error::InvalidDeadline);
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
-
-        // 2. Contract must be initialized
-        if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
 
-        // 3. Operational state: paused
-        if Self::check_paused(&env, symbol_short!("release")) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::FundsPaused);
+        let pending_key = DataKeyExt::BountySchedulePending(bounty_id);
+        let pending: i128 = env.storage().persistent().get(&pending_key).unwrap_or(0);
+        let scheduled = pending.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        if scheduled > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
         }
 
-        let _start = env.ledger().timestamp();
+        let count_key = DataKeyExt::BountyScheduleCount(bounty_id);
+        let schedule_id: u64 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+        let schedule = BountyReleaseSchedule {
+            bounty_id,
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient: recipient.clone(),
+            released: false,
+        };
+        env.storage().persistent().set(
+            &DataKeyExt::BountySchedule(bounty_id, schedule_id),
+            &schedule,
+        );
+        env.storage().persistent().set(&count_key, &schedule_id);
+        env.storage().persistent().set(&pending_key, &scheduled);
 
-        // 4. Authorization
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        events::emit_bounty_schedule_created(
+            &env,
+            events::BountyScheduleCreated {
+                bounty_id,
+                schedule_id,
+                amount,
+                release_timestamp,
+                recipient,
+                timestamp: now,
+            },
+        );
+        Ok(schedule_id)
+    }
 
-        // 5. Business logic: bounty must exist and be locked
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+    /// Pay out a due release schedule. Callable by anyone once its timestamp
+    /// has been reached; follows the same checks and CEI order as
+    /// `partial_release`.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no such escrow or schedule
+    /// * `FundsNotLocked` - schedule already released, or escrow not `Locked`
+    /// * `DeadlineNotPassed` - `release_timestamp` not reached yet
+    /// * `FundsPaused` - releases are paused
+    /// * `LockDurationNotMet` - escrow is still inside its minimum lock duration
+    /// * `RecipientDenied` - recipient is on the release denylist
+    /// * `InsufficientFunds` - escrow no longer holds the scheduled amount
+    pub fn release_bounty_schedule(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u64,
+    ) -> Result<(), Error> {
+        let key = DataKeyExt::BountySchedule(bounty_id, schedule_id);
+        let mut schedule: BountyReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::BountyNotFound)?;
+        if schedule.released {
+            return Err(Error::FundsNotLocked);
+        }
+        let now = env.ledger().timestamp();
+        if now < schedule.release_timestamp {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
+        Self::ensure_recipient_not_denied(&env, symbol_short!("sched_rel"), &schedule.recipient)?;
+        Self::ensure_escrow_recipient_allowed(&env, bounty_id, &schedule.recipient)?;
+        Self::ensure_dependency_satisfied(&env, bounty_id)?;
 
-        let mut escrow: Escrow = env
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
+            .ok_or(Error::BountyNotFound)?;
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        if !Self::lock_duration_met(&env, bounty_id) {
+            return Err(Error::LockDurationNotMet);
+        }
+        if schedule.amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        schedule.released = true;
+        env.storage().persistent().set(&key, &schedule);
+        let pending_key = DataKeyExt::BountySchedulePending(bounty_id);
+        let pending: i128 = env.storage().persistent().get(&pending_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&pending_key, &(pending - schedule.amount));
 
-        // Resolve effective fee config for release.
-        let (_lock_fee_rate, release_fee_rate, fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(&env);
+        Self::pay_partial_release(
+            &env,
+            bounty_id,
+            escrow,
+            schedule.recipient.clone(),
+            schedule.amount,
+            0,
+        );
 
-        let release_fee = if fee_enabled && release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, release_fee_rate)
-        } else {
-            0
-        };
+        events::emit_bounty_schedule_released(
+            &env,
+            events::BountyScheduleReleased {
+                bounty_id,
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient,
+                released_at: now,
+            },
+        );
+        Ok(())
+    }
 
-        // Net payout to contributor after release fee.
-        let net_payout = escrow
-            .amount
-            .checked_sub(release_fee)
-            .unwrap_or(escrow.amount);
-        if net_payout <= 0 {
-            return Err(Error::InvalidAmount);
-        }
+    /// Cancel an unreleased release schedule (admin only), returning its
+    /// reserved amount to the escrow's freely releasable balance.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no such schedule
+    /// * `FundsNotLocked` - schedule already released
+    pub fn cancel_bounty_release_schedule(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        if release_fee > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_recipient,
-                &release_fee,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: release_fee,
-                    fee_rate: release_fee_rate,
-                    recipient: fee_recipient,
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+        let key = DataKeyExt::BountySchedule(bounty_id, schedule_id);
+        let schedule: BountyReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::BountyNotFound)?;
+        if schedule.released {
+            return Err(Error::FundsNotLocked);
         }
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_payout);
-
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        invariants::assert_escrow(&env, &escrow);
+        env.storage().persistent().remove(&key);
+        let pending_key = DataKeyExt::BountySchedulePending(bounty_id);
+        let pending = Self::scheduled_amount(&env, bounty_id);
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&pending_key, &(pending - schedule.amount));
 
-        emit_funds_released(
+        events::emit_bounty_schedule_cancelled(
             &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
+            events::BountyScheduleCancelled {
                 bounty_id,
-                amount: escrow.amount,
-                recipient: contributor.clone(),
+                schedule_id,
+                amount: schedule.amount,
                 timestamp: env.ledger().timestamp(),
             },
         );
-
-        // Clear reentrancy guard
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
         Ok(())
     }
 
-    /// Simulate release operation without state changes or token transfers.
-    ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
-    ///
-    /// # Arguments
-    /// * `bounty_id` - Bounty identifier
-    /// * `contributor` - Recipient address
-    ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
-        fn err_result(e: Error) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Released,
-                remaining_amount: 0,
-            }
-        }
-        match Self::dry_run_release_impl(&env, bounty_id, contributor) {
-            Ok((amount,)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount,
-                resulting_status: EscrowStatus::Released,
-                remaining_amount: 0,
-            },
-            Err(e) => err_result(e),
-        }
+    /// Get a bounty release schedule.
+    pub fn get_bounty_release_schedule(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u64,
+    ) -> Result<BountyReleaseSchedule, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::BountySchedule(bounty_id, schedule_id))
+            .ok_or(Error::BountyNotFound)
     }
 
-    fn dry_run_release_impl(
-        env: &Env,
-        bounty_id: u64,
-        _contributor: Address,
-    ) -> Result<(i128,), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        if Self::check_paused(env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        let escrow: Escrow = env
+    /// Allow or revoke an Ed25519 key for signing payout vouchers (admin only).
+    pub fn set_voucher_signer(
+        env: Env,
+        signer_pubkey: BytesN<32>,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-        let (_lock_fee_rate, release_fee_rate, _fee_recipient, fee_enabled) =
-            Self::resolve_fee_config(env);
-        let release_fee = if fee_enabled && release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, release_fee_rate)
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKeyExt::VoucherSigner(signer_pubkey);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
         } else {
-            0
-        };
-        let net_payout = escrow
-            .amount
-            .checked_sub(release_fee)
-            .unwrap_or(escrow.amount);
-        if net_payout <= 0 {
-            return Err(Error::InvalidAmount);
+            env.storage().persistent().remove(&key);
         }
-        Ok((escrow.amount,))
+        Ok(())
     }
 
-    /// Delegated release flow using a capability instead of admin auth.
-    /// The capability amount limit is consumed by `payout_amount`.
-    pub fn release_with_capability(
+    /// Whether `signer_pubkey` is registered to sign payout vouchers.
+    pub fn is_voucher_signer(env: Env, signer_pubkey: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::VoucherSigner(signer_pubkey))
+            .unwrap_or(false)
+    }
+
+    /// Redeem a payout voucher signed off-chain by a registered signer.
+    ///
+    /// The signature must be an Ed25519 signature by `signer_pubkey` over the
+    /// XDR encoding of `(contract, bounty_id, recipient, amount, expiry, nonce)`,
+    /// taken from `voucher`, where `contract` is this contract's address, so a
+    /// voucher cannot be replayed against another deployment. Anyone may
+    /// submit the voucher; funds always go to `voucher.recipient`. Each voucher
+    /// can be redeemed once; the signer picks a fresh `nonce` to issue another
+    /// voucher on the same terms. `amount` is paid like a `partial_release`, so
+    /// the escrow becomes `Released` when it reaches zero.
+    ///
+    /// # Errors
+    /// Expiry and replay share their codes with claim tickets.
+    /// * `FundsPaused` - release operations are paused
+    /// * `DependencyNotSatisfied` - the escrow's release dependency is not completed
+    /// * `Unauthorized` - `signer_pubkey` is not a registered voucher signer
+    /// * `TicketExpired` - the ledger time is past `expiry`
+    /// * `TicketAlreadyUsed` - this voucher has already been redeemed
+    /// * `RecipientDenied` - `recipient` is on the release denylist
+    /// * `BountyNotFound` / `FundsNotLocked` / `LockDurationNotMet` - escrow state
+    /// * `ParticipantNotAllowed` - the escrow's recipient allowlist excludes `recipient`
+    /// * `RecipientApprovalRequired` - `recipient`'s risk tier needs an approval
+    /// * `InvalidAmount` / `InsufficientFunds` - `amount` is not payable
+    ///
+    /// # Panics
+    /// Panics if the signature does not verify.
+    pub fn redeem_voucher(
         env: Env,
-        bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-        holder: Address,
-        capability_id: u64,
+        voucher: Voucher,
+        signature: BytesN<64>,
+        signer_pubkey: BytesN<32>,
     ) -> Result<(), Error> {
+        let Voucher {
+            bounty_id,
+            recipient,
+            amount,
+            expiry,
+            nonce,
+        } = voucher;
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
+        if !Self::is_voucher_signer(env.clone(), signer_pubkey.clone()) {
+            return Err(Error::Unauthorized);
         }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if env.ledger().timestamp() > expiry {
+            return Err(Error::TicketExpired);
+        }
+
+        let payload = (
+            env.current_contract_address(),
+            bounty_id,
+            recipient.clone(),
+            amount,
+            expiry,
+            nonce,
+        )
+            .to_xdr(&env);
+        let redeemed_key = DataKeyExt::VoucherRedeemed(env.crypto().sha256(&payload).into());
+        if env.storage().persistent().has(&redeemed_key) {
+            return Err(Error::TicketAlreadyUsed);
         }
+        env.crypto()
+            .ed25519_verify(&signer_pubkey, &payload, &signature);
+        Self::ensure_recipient_not_denied(&env, symbol_short!("voucher"), &recipient)?;
 
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
-        if payout_amount > escrow.remaining_amount {
+        if !Self::lock_duration_met(&env, bounty_id) {
+            return Err(Error::LockDurationNotMet);
+        }
+        Self::ensure_payout_gates(&env, bounty_id, &recipient)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > escrow.remaining_amount - Self::scheduled_amount(&env, bounty_id) {
             return Err(Error::InsufficientFunds);
         }
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Release,
-            bounty_id,
-            payout_amount,
-        )?;
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
+        reentrancy_guard::acquire(&env);
 
-        escrow.remaining_amount -= payout_amount;
+        // EFFECTS: burn the voucher and debit the escrow before transferring.
+        env.storage().persistent().set(&redeemed_key, &true);
+        escrow.remaining_amount -= amount;
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Released;
         }
+        invariants::assert_escrow(&env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
+
+        // INTERACTION
+        let client = token::Client::new(&env, &Self::escrow_token(&env, bounty_id));
+        client.transfer(&env.current_contract_address(), &recipient, &amount);
+        Self::record_contributor_payout(&env, bounty_id, &recipient, amount);
 
         emit_funds_released(
             &env,
             FundsReleased {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
+                amount,
+                recipient,
                 timestamp: env.ledger().timestamp(),
+                bonus: 0,
             },
         );
 
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Set the claim window duration (admin only).
-    /// claim_window: seconds beneficiary has to claim after release is authorized.
-    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage()
-            .instance()
-            .set(&DataKey::ClaimWindow, &claim_window);
-        Ok(())
-    }
-
-    /// Admin can authorize a release as a pending claim instead of immediate transfer.
-    pub fn authorize_claim(
+    /// Pay one contributor `total_amount` drawn from several escrows (admin only).
+    ///
+    /// Escrows are drained in the order given until `total_amount` is covered;
+    /// the last escrow touched may be partially released and stays `Locked`
+    /// with the rest. Escrows that end at zero become `Released`. All listed
+    /// escrows must be `Locked` and funded in the same token. Nothing moves
+    /// unless their combined remaining amount covers `total_amount`.
+    ///
+    /// Emits one `FundsReleased` per escrow drawn from.
+    ///
+    /// # Errors
+    /// * [`Error::InvalidBatchSize`] — `bounty_ids` is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::InvalidAmount`] — `total_amount` is not positive
+    /// * [`Error::FundsPaused`] — release operations are currently paused
+    /// * [`Error::BountyNotFound`] — a `bounty_id` does not exist in storage
+    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
+    /// * [`Error::FundsNotLocked`] — an escrow's status is not `Locked`
+    /// * [`Error::LockDurationNotMet`] — an escrow is still within the minimum lock duration
+    /// * [`Error::RecipientDenied`] — `contributor` is on the release denylist
+    /// * [`Error::ParticipantNotAllowed`] — an escrow's recipient allowlist excludes `contributor`
+    /// * [`Error::RecipientApprovalRequired`] — `contributor`'s tier needs an approval on an escrow
+    /// * [`Error::DependencyNotSatisfied`] — an escrow's release dependency is not completed
+    /// * [`Error::InvalidAssetId`] — the escrows are funded in different tokens
+    /// * [`Error::InsufficientFunds`] — combined remaining amount is below `total_amount`
+    pub fn release_from_many(
         env: Env,
-        bounty_id: u64,
-        recipient: Address,
-        reason: DisputeReason,
+        bounty_ids: Vec<u64>,
+        contributor: Address,
+        total_amount: i128,
     ) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        Self::ensure_recipient_not_denied(&env, symbol_short!("release"), &contributor)?;
+
+        // Validate every listed escrow before touching any of them.
+        let mut escrows: Vec<Escrow> = Vec::new(&env);
+        let mut available: i128 = 0;
+        for (idx, bounty_id) in bounty_ids.iter().enumerate() {
+            if bounty_ids
+                .iter()
+                .skip(idx + 1)
+                .any(|other| other == bounty_id)
+            {
+                return Err(Error::DuplicateBountyId);
+            }
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .ok_or(Error::BountyNotFound)?;
+            if escrow.status != EscrowStatus::Locked {
+                return Err(Error::FundsNotLocked);
+            }
+            if !Self::lock_duration_met(&env, bounty_id) {
+                return Err(Error::LockDurationNotMet);
+            }
+            Self::ensure_payout_gates(&env, bounty_id, &contributor)?;
+            if Self::escrow_token(&env, bounty_id)
+                != Self::escrow_token(&env, bounty_ids.get(0).unwrap())
+            {
+                return Err(Error::InvalidAssetId);
+            }
+            available = available
+                .checked_add(escrow.remaining_amount - Self::scheduled_amount(&env, bounty_id))
+                .ok_or(Error::InvalidAmount)?;
+            escrows.push_back(escrow);
+        }
+        if available < total_amount {
+            return Err(Error::InsufficientFunds);
         }
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        reentrancy_guard::acquire(&env);
 
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+        // EFFECTS: draw from each escrow in order until the total is covered.
+        let token_addr = Self::escrow_token(&env, bounty_ids.get(0).unwrap());
+        let timestamp = env.ledger().timestamp();
+        let mut outstanding = total_amount;
+        for (idx, bounty_id) in bounty_ids.iter().enumerate() {
+            if outstanding == 0 {
+                break;
+            }
+            let mut escrow = escrows.get(idx as u32).unwrap();
+            let draw =
+                outstanding.min(escrow.remaining_amount - Self::scheduled_amount(&env, bounty_id));
+            if draw == 0 {
+                continue;
+            }
+            escrow.remaining_amount -= draw;
+            if escrow.remaining_amount == 0 {
+                escrow.status = EscrowStatus::Released;
+            }
+            invariants::assert_escrow(&env, &escrow);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+            Self::record_status_change(&env, bounty_id, escrow.status.clone());
+            outstanding -= draw;
+            Self::record_contributor_payout(&env, bounty_id, &contributor, draw);
+
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: draw,
+                    recipient: contributor.clone(),
+                    timestamp,
+                    bonus: 0,
+                },
+            );
         }
 
-        let now = env.ledger().timestamp();
-        let claim_window: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ClaimWindow)
-            .unwrap_or(0);
-        let claim = ClaimRecord {
-            bounty_id,
-            recipient: recipient.clone(),
-            amount: escrow.amount,
-            expires_at: now.saturating_add(claim_window),
-            claimed: false,
-            reason: reason.clone(),
-        };
+        // INTERACTION: a single transfer for the combined amount.
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &total_amount);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        multitoken_invariants::assert_after_disbursement(&env);
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("created")),
-            ClaimCreated {
-                bounty_id,
-                recipient,
-                amount: escrow.amount,
-                expires_at: claim.expires_at,
-            },
-        );
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Beneficiary calls this to claim their authorized funds within the window.
-    pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
+    /// Hand several escrows over to `new_depositor` in one call.
+    ///
+    /// Every listed escrow must belong to the same depositor, who authorizes
+    /// the transfer, and must still hold funds (`Locked`, `PartiallyRefunded`
+    /// or `Pending`). The whole batch is rejected if any escrow fails these
+    /// checks. Each id moves from the old depositor's index to the new one's,
+    /// and future refunds go to `new_depositor`.
+    ///
+    /// Emits one `OwnershipTransferred` per escrow and returns the number of
+    /// escrows transferred (0 when `new_depositor` already owns them).
+    ///
+    /// # Errors
+    /// * [`Error::InvalidBatchSize`] — `bounty_ids` is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
+    /// * [`Error::BountyNotFound`] — a `bounty_id` does not exist in storage
+    /// * [`Error::Unauthorized`] — the escrows do not all share one depositor
+    /// * [`Error::FundsNotLocked`] — an escrow has already been released or refunded
+    pub fn batch_transfer_ownership(
+        env: Env,
+        bounty_ids: Vec<u64>,
+        new_depositor: Address,
+    ) -> Result<u32, Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
         }
-        if !env
+
+        // Validate every listed escrow before touching any of them.
+        let mut escrows: Vec<Escrow> = Vec::new(&env);
+        for (idx, bounty_id) in bounty_ids.iter().enumerate() {
+            if bounty_ids
+                .iter()
+                .skip(idx + 1)
+                .any(|other| other == bounty_id)
+            {
+                return Err(Error::DuplicateBountyId);
+            }
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .ok_or(Error::BountyNotFound)?;
+            if let Some(first) = escrows.first() {
+                if first.depositor != escrow.depositor {
+                    return Err(Error::Unauthorized);
+                }
+            }
+            if escrow.status == EscrowStatus::Released
+                || escrow.status == EscrowStatus::Refunded
+                || escrow.status == EscrowStatus::Merged
+            {
+                return Err(Error::FundsNotLocked);
+            }
+            escrows.push_back(escrow);
+        }
+
+        let previous_depositor = escrows.get(0).unwrap().depositor;
+        previous_depositor.require_auth();
+        if previous_depositor == new_depositor {
+            return Ok(0);
+        }
+
+        let old_key = DataKey::DepositorIndex(previous_depositor.clone());
+        let new_key = DataKey::DepositorIndex(new_depositor.clone());
+        let mut old_index: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
-        }
-        let mut claim: ClaimRecord = env
+            .get(&old_key)
+            .unwrap_or(Vec::new(&env));
+        let mut new_index: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
+            .get(&new_key)
+            .unwrap_or(Vec::new(&env));
 
-        claim.recipient.require_auth();
+        let timestamp = env.ledger().timestamp();
+        for (idx, bounty_id) in bounty_ids.iter().enumerate() {
+            let mut escrow = escrows.get(idx as u32).unwrap();
+            escrow.depositor = new_depositor.clone();
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
+            if let Some(pos) = old_index.first_index_of(bounty_id) {
+                old_index.remove(pos);
+            }
+            new_index.push_back(bounty_id);
+
+            events::emit_ownership_transferred(
+                &env,
+                events::OwnershipTransferred {
+                    bounty_id,
+                    previous_depositor: previous_depositor.clone(),
+                    new_depositor: new_depositor.clone(),
+                    timestamp,
+                },
+            );
         }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+
+        env.storage().persistent().set(&old_key, &old_index);
+        env.storage().persistent().set(&new_key, &new_index);
+        Ok(bounty_ids.len())
+    }
+
+    /// Refunds remaining funds when refund conditions are met.
+    ///
+    /// # Authorization
+    /// Refund execution requires authenticated authorization from the contract admin
+    /// and the escrow depositor.
+    ///
+    /// # Eligibility
+    /// Refund is allowed when either:
+    /// 1. The deadline has passed (standard full refund to depositor), or
+    /// 2. An admin approval exists (early, partial, or custom-recipient refund).
+    ///
+    /// # Errors
+    /// Returns `Error::NotInitialized` if admin is not set,
+    /// `Error::AnonymousRefundRequiresResolution` for an anonymous escrow
+    /// (use `refund_resolved`), and `Error::InsufficientFunds` when the refund
+    /// would take funds reserved by unreleased release schedules.
+    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let caller = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.depositor)
+            .unwrap_or_else(|| env.current_contract_address());
+        let res = Self::refund_logic(env.clone(), bounty_id);
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, res.is_ok());
+        res
+    }
+
+    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock (error returns revert it with the call)
+        reentrancy_guard::acquire(&env);
+
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(bounty_id))
+            {
+                return Err(Error::AnonymousRefundRequiresResolution);
+            }
+            return Err(Error::BountyNotFound);
+        }
 
-        // Update escrow status
-        let mut escrow: Escrow = env
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
-                bounty_id,
-                recipient: claim.recipient.clone(),
-                amount: claim.amount,
-                claimed_at: now,
-            },
-        );
+        // Under the auto-refund policy an expired escrow with no pending
+        // approval can only go back to its depositor, so anyone may trigger it.
+        // Otherwise require authenticated approval from both admin and depositor.
+        let open_to_anyone = Self::is_auto_refund_enabled(env.clone())
+            && env.ledger().timestamp() >= escrow.deadline
+            && Self::active_refund_approval(&env, bounty_id).is_none();
+        if !open_to_anyone {
+            admin.require_auth();
+            escrow.depositor.require_auth();
+        }
+
+        Self::apply_refund(&env, bounty_id, escrow)?;
+
+        // INV-2: Verify the contract balance still covers every escrow after refund
+        multitoken_invariants::assert_after_disbursement(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Delegated claim execution using a capability.
-    /// Funds are still transferred to the pending claim recipient.
-    pub fn claim_with_capability(
-        env: Env,
-        bounty_id: u64,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
+    /// Internal: refund an escrow whose caller authorization has already been checked.
+    ///
+    /// Validates status, pending claims and the deadline / approval rule, then
+    /// updates state before transferring (CEI). Returns an error without touching
+    /// state when the escrow is not eligible.
+    fn apply_refund(env: &Env, bounty_id: u64, mut escrow: Escrow) -> Result<(), Error> {
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
         }
-        if !env
+        if Self::is_non_refundable(env.clone(), bounty_id) {
+            return Err(Error::RefundForbidden);
+        }
+
+        // Block refund if there is a pending claim (Issue #391 fix)
+        if env
             .storage()
             .persistent()
             .has(&DataKey::PendingClaim(bounty_id))
         {
-            return Err(Error::BountyNotFound);
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
         }
 
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
-
         let now = env.ledger().timestamp();
-        if now > claim.expires_at {
+        let approval_key = DataKey::RefundApproval(bounty_id);
+        let approval = Self::active_refund_approval(env, bounty_id);
+
+        // Refund is allowed if:
+        // 1. Deadline has passed (returns full amount to depositor)
+        // 2. An unexpired administrative approval exists (can be early, partial, and to custom recipient)
+        if now < escrow.deadline && approval.is_none() {
             return Err(Error::DeadlineNotPassed);
         }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+
+        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            // Standard refund after deadline
+            (escrow.remaining_amount, escrow.depositor.clone(), true)
+        };
+
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        // Amounts reserved by unreleased release schedules stay in the escrow
+        // until those schedules are released or cancelled.
+        if escrow.remaining_amount - refund_amount < Self::scheduled_amount(env, bounty_id) {
+            return Err(Error::InsufficientFunds);
         }
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Claim,
-            bounty_id,
-            claim.amount,
-        )?;
+        // EFFECTS: update state before external call (CEI)
+        invariants::assert_escrow(env, &escrow);
+        // Update escrow state: subtract the amount exactly refunded
+        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
+        if is_full || escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
+        // Add to refund history
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: if is_full {
+                RefundMode::Full
+            } else {
+                RefundMode::Partial
+            },
+        });
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
+        // Save updated escrow
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::record_status_change(env, bounty_id, escrow.status.clone());
 
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        // Remove approval after successful execution (or a stale one superseded by the deadline rule)
+        if env.storage().persistent().has(&approval_key) {
+            Self::remove_refund_approval(env, bounty_id);
+        }
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
+        // INTERACTION: external token transfer is last
+        let token_addr = Self::escrow_token(env, bounty_id);
+        let client = token::Client::new(env, &token_addr);
+
+        // Early refunds approved by the admin may carry a processing fee;
+        // refunds after the deadline are always fee-free.
+        let fee_config = Self::get_fee_config_internal(env);
+        let refund_fee_rate = Self::fee_rate_for(
+            env,
+            &token_addr,
+            &escrow.depositor,
+            refund_amount,
+            symbol_short!("refund"),
+        );
+        let refund_fee = if approval.is_some() {
+            Self::calculate_fee(refund_amount, refund_fee_rate)
+        } else {
+            0
+        };
+        // Refunds after the deadline may instead give up a configured penalty share.
+        let penalty = if approval.is_none() {
+            Self::get_refund_penalty(env.clone())
+        } else {
+            None
+        };
+        let penalty_amount = penalty
+            .as_ref()
+            .and_then(|p| refund_amount.checked_mul(p.penalty_bps))
+            .map(|x| x / BASIS_POINTS)
+            .unwrap_or(0);
+        if let Some(p) = penalty.filter(|_| penalty_amount > 0) {
+            client.transfer(
+                &env.current_contract_address(),
+                &p.penalty_recipient,
+                &penalty_amount,
+            );
+            events::emit_refund_penalty_paid(
+                env,
+                events::RefundPenaltyPaid {
+                    bounty_id,
+                    amount: penalty_amount,
+                    penalty_bps: p.penalty_bps,
+                    recipient: p.penalty_recipient,
+                    timestamp: now,
+                },
+            );
+        }
+        if refund_fee > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &refund_fee,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Refund,
+                    amount: refund_fee,
+                    fee_rate: refund_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: now,
+                },
+            );
+        }
+        let refund_net = refund_amount - refund_fee - penalty_amount;
+        if refund_net > 0 {
+            client.transfer(&env.current_contract_address(), &refund_to, &refund_net);
+        }
+
+        emit_funds_refunded(
+            env,
+            FundsRefunded {
+                version: EVENT_VERSION_V2,
                 bounty_id,
-                recipient: claim.recipient,
-                amount: claim.amount,
-                claimed_at: now,
+                // The depositor's leg; a penalty leg is reported by `RefundPenaltyPaid`.
+                amount: refund_amount - penalty_amount,
+                refund_to: refund_to.clone(),
+                timestamp: now,
+                trigger: if approval.is_some() {
+                    RefundTrigger::AdminApproval
+                } else {
+                    RefundTrigger::DeadlineExpiry
+                },
             },
         );
+        Self::record_receipt(
+            env,
+            CriticalOperationOutcome::Refunded,
+            bounty_id,
+            refund_amount,
+            refund_to.clone(),
+        );
         Ok(())
     }
 
-    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
-    pub fn cancel_pending_claim(
-        env: Env,
-        bounty_id: u64,
-        outcome: DisputeOutcome,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Simulate refund operation without state changes or token transfers.
+    ///
+    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
+    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - Bounty identifier
+    ///
+    /// # Security
+    /// This function performs only read operations. No storage writes, token transfers,
+    /// or events are emitted.
+    pub fn dry_run_refund(env: Env, bounty_id: u64) -> SimulationResult {
+        fn err_result(e: Error, default_status: EscrowStatus) -> SimulationResult {
+            SimulationResult {
+                success: false,
+                error_code: e as u32,
+                amount: 0,
+                resulting_status: default_status,
+                remaining_amount: 0,
+            }
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        match Self::dry_run_refund_impl(&env, bounty_id) {
+            Ok((refund_amount, resulting_status, remaining_amount)) => SimulationResult {
+                success: true,
+                error_code: 0,
+                amount: refund_amount,
+                resulting_status,
+                remaining_amount,
+            },
+            Err(e) => err_result(e, EscrowStatus::Refunded),
+        }
+    }
 
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
+    fn dry_run_refund_impl(env: &Env, bounty_id: u64) -> Result<(i128, EscrowStatus, i128), Error> {
+        if Self::check_paused(env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let claim: ClaimRecord = env
+        let escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
+            .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-
-        let now = env.ledger().timestamp(); // Added this line
-        let recipient = claim.recipient.clone(); // Added this line
-        let amount = claim.amount; // Added this line
-
-        env.storage()
-            .persistent()
-            .remove(&DataKey::PendingClaim(bounty_id));
-
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("cancel")),
-            ClaimCancelled {
-                bounty_id,
-                recipient,
-                amount,
-                cancelled_at: now,
-                cancelled_by: admin,
-            },
-        );
-        Ok(())
-    }
-
-    /// View: get pending claim for a bounty.
-    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
-        env.storage()
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::is_non_refundable(env.clone(), bounty_id) {
+            return Err(Error::RefundForbidden);
+        }
+        if env
+            .storage()
             .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .ok_or(Error::BountyNotFound)
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
+        }
+        let now = env.ledger().timestamp();
+        let approval = Self::active_refund_approval(env, bounty_id);
+        if now < escrow.deadline && approval.is_none() {
+            return Err(Error::DeadlineNotPassed);
+        }
+        let (refund_amount, _refund_to, is_full) = if let Some(app) = approval {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            (escrow.remaining_amount, escrow.depositor.clone(), true)
+        };
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        // Amounts reserved by unreleased release schedules stay in the escrow
+        // until those schedules are released or cancelled.
+        if escrow.remaining_amount - refund_amount < Self::scheduled_amount(env, bounty_id) {
+            return Err(Error::InsufficientFunds);
+        }
+        let remaining_after = escrow
+            .remaining_amount
+            .checked_sub(refund_amount)
+            .unwrap_or(0);
+        let resulting_status = if is_full || remaining_after == 0 {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::PartiallyRefunded
+        };
+        Ok((refund_amount, resulting_status, remaining_after))
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
+    /// Sets or clears the anonymous resolver address.
+    /// Only the admin can call this. The resolver is the trusted entity that
+    /// resolves anonymous escrow refunds via `refund_resolved`.
+    pub fn set_anonymous_resolver(env: Env, resolver: Option<Address>) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
-
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        match resolver {
+            Some(addr) => env
+                .storage()
+                .instance()
+                .set(&DataKey::AnonymousResolver, &addr),
+            None => env.storage().instance().remove(&DataKey::AnonymousResolver),
         }
-
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
-
         Ok(())
     }
 
-    /// Release a partial amount of the locked funds to the contributor.
-    /// Only the admin (backend) can authorize this.
-    ///
-    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
-    /// - `remaining_amount` is decremented by `payout_amount` after each call.
-    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
-    /// - The bounty stays Locked while any funds remain unreleased.
-    pub fn partial_release(
-        env: Env,
-        bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Refund an anonymous escrow to a resolved recipient.
+    /// Only the configured anonymous resolver can call this; they resolve the depositor
+    /// commitment off-chain and pass the recipient address (signed instruction pattern).
+    pub fn refund_resolved(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
         }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        let resolver: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AnonymousResolver)
+            .ok_or(Error::AnonymousResolverNotSet)?;
+        resolver.require_auth();
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            return Err(Error::NotAnonymousEscrow);
         }
 
-        let mut escrow: Escrow = env
+        reentrancy_guard::acquire(&env);
+
+        let mut anon: AnonymousEscrow = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
+            .get(&DataKey::EscrowAnon(bounty_id))
             .unwrap();
 
-        if escrow.status != EscrowStatus::Locked {
+        if anon.status != EscrowStatus::Locked && anon.status != EscrowStatus::PartiallyRefunded {
             return Err(Error::FundsNotLocked);
         }
 
-        // Guard: zero or negative payout makes no sense and would corrupt state
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
+        // GUARD 1: Block refund if there is a pending claim (Issue #391 fix)
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
         }
 
-        // Guard: prevent overpayment — payout cannot exceed what is still owed
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
+        let now = env.ledger().timestamp();
+        let approval = Self::active_refund_approval(&env, bounty_id);
+
+        // Refund is allowed if:
+        // 1. Deadline has passed (returns full amount to depositor)
+        // 2. An unexpired administrative approval exists (can be early, partial, and to custom recipient)
+        if now < anon.deadline && approval.is_none() {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
+            let full = app.mode == RefundMode::Full || app.amount >= anon.remaining_amount;
+            (app.amount, app.recipient, full)
+        } else {
+            // Standard refund after deadline
+            (anon.remaining_amount, recipient.clone(), true)
+        };
+
+        if refund_amount <= 0 || refund_amount > anon.remaining_amount {
+            return Err(Error::InvalidAmount);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
 
-        // Transfer only the requested partial amount to the contributor
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
-
-        // Decrement remaining; this is always an exact integer subtraction — no rounding
-        escrow.remaining_amount = escrow.remaining_amount.checked_sub(payout_amount).unwrap();
+        // Transfer the calculated refund amount to the designated recipient
+        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
 
-        // Automatically transition to Released once fully paid out
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
+        // Anonymous escrow uses a parallel storage record and invariant model.
+        // Update escrow state: subtract the amount exactly refunded
+        anon.remaining_amount -= refund_amount;
+        if is_full || anon.remaining_amount == 0 {
+            anon.status = EscrowStatus::Refunded;
+        } else {
+            anon.status = EscrowStatus::PartiallyRefunded;
         }
 
+        // Add to refund history
+        anon.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: refund_to.clone(),
+            timestamp: now,
+            mode: if is_full {
+                RefundMode::Full
+            } else {
+                RefundMode::Partial
+            },
+        });
+
+        // Save updated escrow
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&DataKey::EscrowAnon(bounty_id), &anon);
+        Self::record_status_change(&env, bounty_id, anon.status.clone());
 
-        events::emit_funds_released(
+        // Remove approval after successful execution
+        if approval.is_some() {
+            Self::remove_refund_approval(&env, bounty_id);
+        }
+
+        emit_funds_refunded(
             &env,
-            FundsReleased {
+            FundsRefunded {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
-                timestamp: env.ledger().timestamp(),
+                amount: refund_amount,
+                refund_to: refund_to.clone(),
+                timestamp: now,
+                trigger: if approval.is_some() {
+                    RefundTrigger::AdminApproval
+                } else {
+                    RefundTrigger::DeadlineExpiry
+                },
             },
         );
-
         Ok(())
     }
 
-    /// Refunds remaining funds when refund conditions are met.
-    ///
-    /// # Authorization
-    /// Refund execution requires authenticated authorization from the contract admin
-    /// and the escrow depositor.
-    ///
-    /// # Eligibility
-    /// Refund is allowed when either:
-    /// 1. The deadline has passed (standard full refund to depositor), or
-    /// 2. An admin approval exists (early, partial, or custom-recipient refund).
-    ///
-    /// # Errors
-    /// Returns `Error::NotInitialized` if admin is not set.
-    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
-        let caller = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            .map(|escrow| escrow.depositor)
-            .unwrap_or_else(|| env.current_contract_address());
-        let res = Self::refund_logic(env.clone(), bounty_id);
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, res.is_ok());
-        res
-    }
-
-    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
+    /// Delegated refund path using a capability.
+    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
+    pub fn refund_with_capability(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("refund")) {
             return Err(Error::FundsPaused);
         }
-
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
@@ -3215,21 +8254,20 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        // Require authenticated approval from both admin and depositor.
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        escrow.depositor.require_auth();
-
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
         {
             return Err(Error::FundsNotLocked);
         }
+        if Self::is_non_refundable(env.clone(), bounty_id) {
+            return Err(Error::RefundForbidden);
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        if escrow.remaining_amount - amount < Self::scheduled_amount(&env, bounty_id) {
+            return Err(Error::InsufficientFunds);
+        }
 
-        // Block refund if there is a pending claim (Issue #391 fix)
         if env
             .storage()
             .persistent()
@@ -3245,686 +8283,1297 @@ impl BountyEscrowContract {
             }
         }
 
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Refund,
+            bounty_id,
+            amount,
+            if amount == escrow.remaining_amount {
+                Some(EscrowStatus::Refunded)
+            } else {
+                None
+            },
+        )?;
+
+        let client = token::Client::new(&env, &Self::escrow_token(&env, bounty_id));
         let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
-        }
-
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
+        let refund_to = escrow.depositor.clone();
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
+        client.transfer(&env.current_contract_address(), &refund_to, &amount);
 
-        // EFFECTS: update state before external call (CEI)
-        invariants::assert_escrow(&env, &escrow);
-        // Update escrow state: subtract the amount exactly refunded
-        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
-        if is_full || escrow.remaining_amount == 0 {
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Refunded;
         } else {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
 
-        // Add to refund history
         escrow.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
+            amount,
             recipient: refund_to.clone(),
             timestamp: now,
-            mode: if is_full {
+            mode: if escrow.status == EscrowStatus::Refunded {
                 RefundMode::Full
             } else {
                 RefundMode::Partial
             },
         });
 
-        // Save updated escrow
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
-        }
-
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        Self::record_status_change(&env, bounty_id, escrow.status.clone());
 
         emit_funds_refunded(
             &env,
             FundsRefunded {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
+                amount,
+                refund_to,
                 timestamp: now,
+                trigger: RefundTrigger::AdminApproval,
             },
         );
-        Self::record_receipt(
-            &env,
-            CriticalOperationOutcome::Refunded,
-            bounty_id,
-            refund_amount,
-            refund_to.clone(),
-        );
-
-        // INV-2: Verify aggregate balance matches token balance after refund
-        multitoken_invariants::assert_after_disbursement(&env);
 
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Simulate refund operation without state changes or token transfers.
+    /// Append a `(status, timestamp)` entry to the escrow's status history.
     ///
-    /// Returns a `SimulationResult` indicating whether the operation would succeed and the
-    /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    /// Called after every lifecycle write (lock, release, partial release,
+    /// refund, claim). Only the latest `MAX_STATUS_HISTORY` entries are kept.
+    fn record_status_change(env: &Env, bounty_id: u64, status: EscrowStatus) {
+        let key = DataKey::StatusHistory(bounty_id);
+        let mut history: Vec<(EscrowStatus, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        // Move the escrow between aggregate buckets when its status class changes.
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            let previous = history.last().map(|(previous, _)| previous);
+            let unchanged = previous
+                .as_ref()
+                .map(|previous| Self::stats_bucket(previous) == Self::stats_bucket(&status))
+                .unwrap_or(false);
+            if !unchanged {
+                if let Some(previous) = previous {
+                    Self::adjust_stats_cache(env, &previous, -escrow.amount, -1);
+                }
+                Self::adjust_stats_cache(env, &status, escrow.amount, 1);
+            }
+        }
+
+        history.push_back((status, env.ledger().timestamp()));
+        while history.len() > MAX_STATUS_HISTORY {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&key, &history);
+    }
+
+    /// view function to get the status transition trail of an escrow, oldest first
+    pub fn get_status_history(env: Env, bounty_id: u64) -> Vec<(EscrowStatus, u64)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StatusHistory(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// view function to get escrow info
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
+
+    /// Token the escrow was funded in; the default token for escrows locked
+    /// before per-escrow tokens were recorded.
+    pub fn get_escrow_token(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(Self::escrow_token(&env, bounty_id))
+    }
+
+    /// Seconds since the escrow's funds were locked.
     ///
-    /// # Arguments
-    /// * `bounty_id` - Bounty identifier
+    /// Returns 0 for legacy escrows whose lock time is unknown.
+    pub fn get_escrow_age(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let locked_at = Self::get_escrow_locked_at(env.clone(), bounty_id)?;
+        if locked_at == 0 {
+            return Ok(0);
+        }
+        Ok(env.ledger().timestamp().saturating_sub(locked_at))
+    }
+
+    /// Ledger timestamp at which the escrow's funds were locked (its start time
+    /// for scheduled locks). Zero for legacy escrows whose lock time is unknown.
+    pub fn get_escrow_locked_at(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(Self::escrow_locked_at(&env, bounty_id))
+    }
+
+    /// Count one view of an escrow by `viewer` and return the new total.
     ///
-    /// # Security
-    /// This function performs only read operations. No storage writes, token transfers,
-    /// or events are emitted.
-    pub fn dry_run_refund(env: Env, bounty_id: u64) -> SimulationResult {
-        fn err_result(e: Error, default_status: EscrowStatus) -> SimulationResult {
-            SimulationResult {
-                success: false,
-                error_code: e as u32,
-                amount: 0,
-                resulting_status: default_status,
-                remaining_amount: 0,
+    /// Callable by anyone; each call goes through the anti-abuse cooldown and
+    /// window for `viewer`, so a single address cannot inflate the count
+    /// faster than the config allows. Views are throttled separately from
+    /// the viewer's other operations and are not compliance-logged.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    pub fn record_view(env: Env, viewer: Address, bounty_id: u64) -> Result<u64, Error> {
+        viewer.require_auth();
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            return Err(Error::BountyNotFound);
+        }
+        anti_abuse::check_view_rate_limit(&env, viewer);
+
+        let key = DataKeyExt::EscrowViews(bounty_id);
+        let views: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let views = views.saturating_add(1);
+        env.storage().persistent().set(&key, &views);
+        Ok(views)
+    }
+
+    /// Number of views recorded for `bounty_id` with `record_view`.
+    pub fn get_escrow_views(env: Env, bounty_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EscrowViews(bounty_id))
+            .unwrap_or(0)
+    }
+
+    /// view function to resolve several bounty ids in one call.
+    ///
+    /// Ids without an escrow are skipped, so the result may be shorter than
+    /// the input; results keep the input order. Only the first
+    /// `MAX_ESCROW_VIEW_BATCH` ids are examined.
+    pub fn get_escrows_batch(env: Env, bounty_ids: Vec<u64>) -> Vec<EscrowWithId> {
+        let mut results = Vec::new(&env);
+        for bounty_id in bounty_ids.iter().take(MAX_ESCROW_VIEW_BATCH as usize) {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                results.push_back(EscrowWithId { bounty_id, escrow });
             }
         }
-        match Self::dry_run_refund_impl(&env, bounty_id) {
-            Ok((refund_amount, resulting_status, remaining_amount)) => SimulationResult {
-                success: true,
-                error_code: 0,
-                amount: refund_amount,
-                resulting_status,
-                remaining_amount,
-            },
-            Err(e) => err_result(e, EscrowStatus::Refunded),
+        results
+    }
+
+    /// view function to get an escrow together with its metadata, refund
+    /// history, pending claim and lifecycle flags in a single call.
+    ///
+    /// Missing metadata or pending claim records are returned as empty lists.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    pub fn get_escrow_bundle(env: Env, bounty_id: u64) -> Result<EscrowBundle, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        let mut metadata = Vec::new(&env);
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Metadata(bounty_id))
+        {
+            metadata.push_back(record);
+        }
+        let mut pending_claim = Vec::new(&env);
+        if let Some(claim) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+        {
+            pending_claim.push_back(claim);
         }
+        let funds_locked = matches!(
+            escrow.status,
+            EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::Pending
+        );
+        let completed_at = if funds_locked {
+            None
+        } else {
+            Self::get_status_history(env.clone(), bounty_id)
+                .last()
+                .map(|(_, timestamp)| timestamp)
+        };
+
+        Ok(EscrowBundle {
+            bounty_id,
+            refund_history: escrow.refund_history.clone(),
+            escrow,
+            metadata,
+            pending_claim,
+            funds_locked,
+            archived: Self::is_escrow_archived(env.clone(), bounty_id),
+            completed_at,
+        })
     }
 
-    fn dry_run_refund_impl(env: &Env, bounty_id: u64) -> Result<(i128, EscrowStatus, i128), Error> {
-        if Self::check_paused(env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
+    /// view function to get contract balance of the token
+    pub fn get_balance(env: Env) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
         }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// Default-token balance not backing any active escrow.
+    ///
+    /// Returns the contract balance minus the summed `remaining_amount` of
+    /// active escrows in the default token. A positive value means stray
+    /// deposits; a negative value means the escrows are under-funded.
+    pub fn get_surplus(env: Env) -> i128 {
+        multitoken_invariants::default_token_surplus(&env)
+    }
+
+    /// Whether the contract holds enough of the escrow's token to pay it out.
+    ///
+    /// Escrows are served in `EscrowIndex` order: the escrow is solvent when
+    /// the claims of every escrow in the same token up to and including this
+    /// one fit within the contract balance. After a shortfall this pinpoints
+    /// which escrows would fail to release. An escrow with nothing left to pay
+    /// out is always solvent.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow exists for `bounty_id`
+    pub fn is_escrow_solvent(env: Env, bounty_id: u64) -> Result<bool, Error> {
+        let token: Address = if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            Self::escrow_token(&env, bounty_id)
+        } else if env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowAnon(bounty_id))
+        {
+            env.storage()
+                .instance()
+                .get(&DataKey::Token)
+                .ok_or(Error::NotInitialized)?
+        } else {
             return Err(Error::BountyNotFound);
+        };
+
+        let (own_claim, cumulative) =
+            multitoken_invariants::claims_through(&env, &token, bounty_id);
+        if own_claim == 0 {
+            return Ok(true);
+        }
+        Ok(cumulative <= multitoken_invariants::get_contract_token_balance(&env, &token))
+    }
+
+    /// view function to get contract balance of an arbitrary token
+    ///
+    /// Unlike `get_balance`, this works for any token address, e.g. escrow
+    /// tokens other than the default or tokens awaiting rescue.
+    pub fn get_balance_of(env: Env, token_address: Address) -> i128 {
+        let client = token::Client::new(&env, &token_address);
+        client.balance(&env.current_contract_address())
+    }
+
+    /// Query escrows with filtering and pagination
+    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
+    pub fn query_escrows_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Mark a `Released`, `Refunded` or `Merged` escrow as archived (admin only).
+    ///
+    /// Archiving only flags the record; the escrow stays readable. Archiving an
+    /// already archived escrow is a no-op. An escrow whose held-back share was
+    /// revoked (`PartiallyRefunded` with nothing remaining) is terminal too.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow exists for `bounty_id`
+    /// * `EscrowNotTerminal` - The escrow is still active or has a pending holdback
+    pub fn archive_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_escrow_terminal(&escrow) {
+            return Err(Error::EscrowNotTerminal);
         }
-        if env
+        if Self::get_holdback(env.clone(), bounty_id).is_some() {
+            return Err(Error::EscrowNotTerminal);
+        }
+        if Self::is_escrow_archived(env.clone(), bounty_id) {
+            return Ok(());
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Archived(bounty_id), &true);
+        events::emit_escrow_archived(
+            &env,
+            events::EscrowArchived {
+                bounty_id,
+                archived_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether the escrow has been archived.
+    pub fn is_escrow_archived(env: Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Archived(bounty_id))
+            .unwrap_or(false)
+    }
+
+    /// Like `query_escrows_by_status`, but skips archived escrows.
+    pub fn query_unarchived_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for bounty_id in index.iter() {
+            if count >= limit {
+                break;
+            }
+            if Self::is_escrow_archived(env.clone(), bounty_id) {
+                continue;
+            }
+            if let Some(escrow) = env
                 .storage()
                 .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
             }
         }
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
-        }
-        let (refund_amount, _refund_to, is_full) = if let Some(app) = approval {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        results
+    }
+
+    /// Whether `escrow` has finished its lifecycle: released, refunded or
+    /// merged, or partially refunded with nothing left in it.
+    fn is_escrow_terminal(escrow: &Escrow) -> bool {
+        match escrow.status {
+            EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Merged => true,
+            EscrowStatus::PartiallyRefunded => escrow.remaining_amount == 0,
+            EscrowStatus::Locked | EscrowStatus::Pending => false,
         }
-        let remaining_after = escrow
-            .remaining_amount
-            .checked_sub(refund_amount)
-            .unwrap_or(0);
-        let resulting_status = if is_full || remaining_after == 0 {
-            EscrowStatus::Refunded
-        } else {
-            EscrowStatus::PartiallyRefunded
-        };
-        Ok((refund_amount, resulting_status, remaining_after))
     }
 
-    /// Sets or clears the anonymous resolver address.
-    /// Only the admin can call this. The resolver is the trusted entity that
-    /// resolves anonymous escrow refunds via `refund_resolved`.
-    pub fn set_anonymous_resolver(env: Env, resolver: Option<Address>) -> Result<(), Error> {
+    /// Delete an archived escrow's records to reclaim storage (admin only).
+    ///
+    /// Removes the `Escrow`, `Metadata`, memo and status history entries and
+    /// drops the bounty from the global and depositor indexes. A `PurgeRecord`
+    /// is kept in their place so the purge stays auditable and the bounty id
+    /// can never be locked again.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow exists for `bounty_id` (or it was already purged)
+    /// * `EscrowNotTerminal` - The escrow has not reached a terminal state
+    /// * `EscrowNotArchived` - The escrow has not been archived
+    pub fn purge_archived_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        match resolver {
-            Some(addr) => env
-                .storage()
-                .instance()
-                .set(&DataKey::AnonymousResolver, &addr),
-            None => env.storage().instance().remove(&DataKey::AnonymousResolver),
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_escrow_terminal(&escrow) {
+            return Err(Error::EscrowNotTerminal);
+        }
+        if !Self::is_escrow_archived(env.clone(), bounty_id) {
+            return Err(Error::EscrowNotArchived);
         }
-        Ok(())
-    }
 
-    /// Refund an anonymous escrow to a resolved recipient.
-    /// Only the configured anonymous resolver can call this; they resolve the depositor
-    /// commitment off-chain and pass the recipient address (signed instruction pattern).
-    pub fn refund_resolved(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::Purged(bounty_id),
+            &PurgeRecord {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                amount: escrow.amount,
+                final_status: escrow.status.clone(),
+                token: Self::escrow_token(&env, bounty_id),
+                purged_by: admin.clone(),
+                purged_at: now,
+            },
+        );
+
+        Self::adjust_stats_cache(&env, &escrow.status, -escrow.amount, -1);
+        for key in [
+            DataKey::Escrow(bounty_id),
+            DataKey::Metadata(bounty_id),
+            DataKey::EscrowMemo(bounty_id),
+            DataKey::StatusHistory(bounty_id),
+            DataKey::Archived(bounty_id),
+        ] {
+            env.storage().persistent().remove(&key);
         }
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::EscrowToken(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::EscrowLockedAt(bounty_id));
 
-        let resolver: Address = env
+        let mut index: Vec<u64> = env
             .storage()
-            .instance()
-            .get(&DataKey::AnonymousResolver)
-            .ok_or(Error::AnonymousResolverNotSet)?;
-        resolver.require_auth();
-
-        if !env
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        if let Some(pos) = index.first_index_of(bounty_id) {
+            index.remove(pos);
+            env.storage()
+                .persistent()
+                .set(&DataKey::EscrowIndex, &index);
+        }
+        let depositor_key = DataKey::DepositorIndex(escrow.depositor.clone());
+        let mut depositor_index: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::EscrowAnon(bounty_id))
-        {
-            return Err(Error::NotAnonymousEscrow);
+            .get(&depositor_key)
+            .unwrap_or(Vec::new(&env));
+        if let Some(pos) = depositor_index.first_index_of(bounty_id) {
+            depositor_index.remove(pos);
+            env.storage()
+                .persistent()
+                .set(&depositor_key, &depositor_index);
         }
 
-        reentrancy_guard::acquire(&env);
+        events::emit_escrow_purged(
+            &env,
+            events::EscrowPurged {
+                bounty_id,
+                purged_by: admin,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
 
-        let mut anon: AnonymousEscrow = env
+    /// Audit record of a purged escrow, if `bounty_id` was purged.
+    pub fn get_purge_record(env: Env, bounty_id: u64) -> Option<PurgeRecord> {
+        env.storage().persistent().get(&DataKey::Purged(bounty_id))
+    }
+
+    fn is_escrow_purged(env: &Env, bounty_id: u64) -> bool {
+        env.storage().persistent().has(&DataKey::Purged(bounty_id))
+    }
+
+    /// Archived bounty ids in index order, for cold-storage export.
+    pub fn query_archived(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowAnon(bounty_id))
-            .unwrap();
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut skipped = 0u32;
 
-        if anon.status != EscrowStatus::Locked && anon.status != EscrowStatus::PartiallyRefunded {
-            return Err(Error::FundsNotLocked);
+        for bounty_id in index.iter() {
+            if results.len() >= limit {
+                break;
+            }
+            if !Self::is_escrow_archived(env.clone(), bounty_id) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            results.push_back(bounty_id);
         }
+        results
+    }
 
-        // GUARD 1: Block refund if there is a pending claim (Issue #391 fix)
-        if env
+    /// Query escrows with amount range filtering
+    pub fn query_escrows_by_amount(
+        env: Env,
+        min_amount: i128,
+        max_amount: i128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
                 .storage()
                 .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.amount >= min_amount && escrow.amount <= max_amount {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
             }
         }
+        results
+    }
 
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < anon.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
-        }
-
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= anon.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (anon.remaining_amount, recipient.clone(), true)
-        };
-
-        if refund_amount <= 0 || refund_amount > anon.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+    /// Query escrows with deadline range filtering
+    pub fn query_escrows_by_deadline(
+        env: Env,
+        min_deadline: u64,
+        max_deadline: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
 
-        // Transfer the calculated refund amount to the designated recipient
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
 
-        // Anonymous escrow uses a parallel storage record and invariant model.
-        // Update escrow state: subtract the amount exactly refunded
-        anon.remaining_amount -= refund_amount;
-        if is_full || anon.remaining_amount == 0 {
-            anon.status = EscrowStatus::Refunded;
-        } else {
-            anon.status = EscrowStatus::PartiallyRefunded;
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
         }
+        results
+    }
 
-        // Add to refund history
-        anon.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if is_full {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
-        // Save updated escrow
-        env.storage()
+    /// Locked escrows whose deadline falls in `(now, now + window_seconds]`.
+    ///
+    /// Already-expired escrows are left to the expiry sweep and are not returned.
+    pub fn query_escrows_expiring_within(
+        env: Env,
+        window_seconds: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let now = env.ledger().timestamp();
+        let window_end = now.saturating_add(window_seconds);
+        let index: Vec<u64> = env
+            .storage()
             .persistent()
-            .set(&DataKey::EscrowAnon(bounty_id), &anon);
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
-        }
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
 
-        emit_funds_refunded(
-            &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
-                timestamp: now,
-            },
-        );
-        Ok(())
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == EscrowStatus::Locked
+                    && escrow.deadline > now
+                    && escrow.deadline <= window_end
+                {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
     }
 
-    /// Delegated refund path using a capability.
-    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
-    pub fn refund_with_capability(
+    /// Query escrows by depositor
+    pub fn query_escrows_by_depositor(
         env: Env,
-        bounty_id: u64,
-        amount: i128,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
+        depositor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let start = offset.min(index.len());
+        let end = (offset + limit).min(index.len());
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-        if amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                results.push_back(EscrowWithId { bounty_id, escrow });
+            }
         }
+        results
+    }
 
-        if env
+    /// Get aggregate statistics by scanning every escrow.
+    ///
+    /// Cost grows with the number of escrows; prefer
+    /// [`get_aggregate_stats_cached`](Self::get_aggregate_stats_cached) for
+    /// frequent reads and use this to verify the cache.
+    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut stats = AggregateStats {
+            total_locked: 0,
+            total_released: 0,
+            total_refunded: 0,
+            count_locked: 0,
+            count_released: 0,
+            count_refunded: 0,
+        };
+
+        for i in 0..index.len() {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
                 .storage()
                 .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                match escrow.status {
+                    // Pending escrows are already funded and counted as locked.
+                    EscrowStatus::Locked | EscrowStatus::Pending => {
+                        stats.total_locked += escrow.amount;
+                        stats.count_locked += 1;
+                    }
+                    EscrowStatus::Released => {
+                        stats.total_released += escrow.amount;
+                        stats.count_released += 1;
+                    }
+                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
+                        stats.total_refunded += escrow.amount;
+                        stats.count_refunded += 1;
+                    }
+                    // Its funds live on in the merge target.
+                    EscrowStatus::Merged => {}
+                }
             }
         }
+        stats
+    }
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Refund,
-            bounty_id,
-            amount,
-        )?;
+    /// Get aggregate statistics from the incrementally maintained cache (O(1)).
+    ///
+    /// The cache is updated on every status change, top-up and purge. Escrows
+    /// that have not changed status since the cache was introduced are not
+    /// included until `refresh_aggregate_stats_cache` is called.
+    pub fn get_aggregate_stats_cached(env: Env) -> AggregateStats {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::AggregateStatsCache)
+            .unwrap_or(AggregateStats {
+                total_locked: 0,
+                total_released: 0,
+                total_refunded: 0,
+                count_locked: 0,
+                count_released: 0,
+                count_refunded: 0,
+            })
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let now = env.ledger().timestamp();
-        let refund_to = escrow.depositor.clone();
+    /// Rebuild the aggregate stats cache from a full scan (admin only).
+    pub fn refresh_aggregate_stats_cache(env: Env) -> Result<AggregateStats, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        let stats = Self::get_aggregate_stats(env.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::AggregateStatsCache, &stats);
+        Ok(stats)
+    }
 
-        escrow.remaining_amount -= amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
+    /// Aggregate bucket an escrow status counts toward: 0 locked, 1 released,
+    /// 2 refunded, 3 none (merged escrows).
+    fn stats_bucket(status: &EscrowStatus) -> u32 {
+        match status {
+            EscrowStatus::Locked | EscrowStatus::Pending => 0,
+            EscrowStatus::Released => 1,
+            EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => 2,
+            EscrowStatus::Merged => 3,
         }
+    }
 
-        escrow.refund_history.push_back(RefundRecord {
-            amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if escrow.status == EscrowStatus::Refunded {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
+    /// Add `amount` and `count` (either may be negative) to the cached bucket for `status`.
+    fn adjust_stats_cache(env: &Env, status: &EscrowStatus, amount: i128, count: i32) {
+        let mut stats = Self::get_aggregate_stats_cached(env.clone());
+        let (total, counter) = match Self::stats_bucket(status) {
+            0 => (&mut stats.total_locked, &mut stats.count_locked),
+            1 => (&mut stats.total_released, &mut stats.count_released),
+            2 => (&mut stats.total_refunded, &mut stats.count_refunded),
+            _ => return,
+        };
+        *total += amount;
+        *counter = counter.saturating_add_signed(count);
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&DataKeyExt::AggregateStatsCache, &stats);
+    }
 
-        emit_funds_refunded(
-            &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount,
-                refund_to,
-                timestamp: now,
-            },
-        );
+    /// Subscribe `address` to `targeted` copies of the given escrow events.
+    ///
+    /// Whenever one of the listed topics is emitted for an escrow deposited by
+    /// `address`, a `NotificationTargeted` event is also published under
+    /// `("targeted", address)`. Valid topics are listed in
+    /// `events::NOTIFY_TOPICS`; an empty list unsubscribes.
+    ///
+    /// # Errors
+    /// * `InvalidSelectionInput` - a topic is not in the vocabulary or is repeated
+    pub fn set_notify_preference(
+        env: Env,
+        address: Address,
+        events: Vec<Symbol>,
+    ) -> Result<(), Error> {
+        address.require_auth();
+
+        let key = DataKeyExt::NotifyTopics(address);
+        if events.is_empty() {
+            env.storage().persistent().remove(&key);
+            return Ok(());
+        }
+        for (i, topic) in events.iter().enumerate() {
+            if !events::NOTIFY_TOPICS.contains(&topic) {
+                return Err(Error::InvalidSelectionInput);
+            }
+            if events.first_index_of(&topic) != Some(i as u32) {
+                return Err(Error::InvalidSelectionInput);
+            }
+        }
+        env.storage().persistent().set(&key, &events);
+        Ok(())
+    }
 
-        Ok(())
+    /// Topics `address` is subscribed to (empty when not subscribed).
+    pub fn get_notify_preference(env: Env, address: Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::NotifyTopics(address))
+            .unwrap_or(Vec::new(&env))
     }
 
-    /// view function to get escrow info
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        Ok(env
+    /// Get total count of escrows
+    pub fn get_escrow_count(env: Env) -> u32 {
+        let index: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.len()
     }
 
-    /// view function to get contract balance of the token
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
-            return Err(Error::NotInitialized);
+    /// Internal: `(bounty_id, remaining_amount, status)` for a standard or anonymous escrow.
+    fn state_summary_entry(env: &Env, bounty_id: u64) -> Option<(u64, i128, EscrowStatus)> {
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            return Some((bounty_id, escrow.remaining_amount, escrow.status));
         }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
+        env.storage()
+            .persistent()
+            .get::<DataKey, AnonymousEscrow>(&DataKey::EscrowAnon(bounty_id))
+            .map(|anon| (bounty_id, anon.remaining_amount, anon.status))
     }
 
-    /// Query escrows with filtering and pagination
-    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
-    pub fn query_escrows_by_status(
+    /// Export `(bounty_id, remaining_amount, status)` for escrows in index order, paginated.
+    ///
+    /// This is the data hashed by [`Self::export_state_digest`]; auditors can page through it
+    /// and recompute the digest off-chain to confirm they fetched the complete state.
+    pub fn export_state_summary(
         env: Env,
-        status: EscrowStatus,
         offset: u32,
         limit: u32,
-    ) -> Vec<EscrowWithId> {
+    ) -> Vec<(u64, i128, EscrowStatus)> {
         let index: Vec<u64> = env
             .storage()
             .persistent()
             .get(&DataKey::EscrowIndex)
             .unwrap_or(Vec::new(&env));
         let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
-
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        let start = offset.min(index.len());
+        let end = offset.saturating_add(limit).min(index.len());
 
+        for i in start..end {
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.status == status {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
+            if let Some(entry) = Self::state_summary_entry(&env, bounty_id) {
+                results.push_back(entry);
             }
         }
         results
     }
 
-    /// Query escrows with amount range filtering
-    pub fn query_escrows_by_amount(
-        env: Env,
-        min_amount: i128,
-        max_amount: i128,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
+    /// Deterministic SHA-256 digest over every escrow's `(bounty_id, remaining_amount, status)`.
+    ///
+    /// Entries are XDR-encoded and concatenated in `EscrowIndex` order, so the digest is stable
+    /// across calls and changes whenever any escrow balance or status changes.
+    pub fn export_state_digest(env: Env) -> BytesN<32> {
         let index: Vec<u64> = env
             .storage()
             .persistent()
             .get(&DataKey::EscrowIndex)
             .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
-
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
-
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.amount >= min_amount && escrow.amount <= max_amount {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
+        let mut material = Bytes::new(&env);
+        for bounty_id in index.iter() {
+            if let Some(entry) = Self::state_summary_entry(&env, bounty_id) {
+                material.append(&entry.to_xdr(&env));
             }
         }
-        results
+        env.crypto().sha256(&material).into()
     }
 
-    /// Query escrows with deadline range filtering
-    pub fn query_escrows_by_deadline(
+    /// Set the minimum and maximum allowed lock amount (admin only).
+    ///
+    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
+    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
+    /// The policy can be updated at any time by the admin; new limits take effect
+    /// immediately for subsequent lock_funds calls.
+    ///
+    /// Passing min_amount == max_amount restricts locking to a single exact value.
+    /// min_amount must not exceed max_amount — the call panics if this invariant
+    /// is violated.
+    ///
+    /// Once the admin action queue is in use this setter is disabled (returns
+    /// `Unauthorized`) and the change must be queued as
+    /// `AdminAction::AmountPolicy`.
+    pub fn set_amount_policy(
         env: Env,
-        min_deadline: u64,
-        max_deadline: u64,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+        caller: Address,
+        min_amount: i128,
+        max_amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        if min_amount > max_amount {
+            panic!("invalid policy: min_amount cannot exceed max_amount");
+        }
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
-            }
+        if Self::admin_timelock_engaged(&env) {
+            return Err(Error::Unauthorized);
         }
-        results
+
+        // Persist the policy so lock_funds can enforce it on every subsequent call.
+        env.storage()
+            .instance()
+            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+
+        Ok(())
     }
 
-    /// Query escrows by depositor
-    pub fn query_escrows_by_depositor(
+    /// Set the deadline horizon used by `lock_funds_default_deadline` (admin only).
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is not the admin
+    /// * `InvalidDeadline` - `seconds` is zero
+    pub fn set_default_deadline_horizon(
         env: Env,
-        depositor: Address,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if seconds == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultDeadlineHorizon, &seconds);
+        Ok(())
+    }
+
+    /// Get the configured default deadline horizon, if any.
+    pub fn get_default_deadline_horizon(env: Env) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DefaultDeadlineHorizon)
+    }
+
+    /// Set the minimum time an escrow must stay locked before it can be
+    /// released (admin only). Zero disables the check.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is not the admin
+    pub fn set_min_lock_duration(env: Env, caller: Address, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinLockDuration, &seconds);
+        Ok(())
+    }
+
+    /// Get the minimum lock duration in seconds (0 when unset).
+    pub fn get_min_lock_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinLockDuration)
+            .unwrap_or(0)
+    }
+
+    /// Whether `bounty_id` has been locked for at least the minimum lock duration.
+    fn lock_duration_met(env: &Env, bounty_id: u64) -> bool {
+        let min_duration = Self::get_min_lock_duration(env.clone());
+        Self::escrow_locked_at(env, bounty_id).saturating_add(min_duration)
+            <= env.ledger().timestamp()
+    }
+
+    /// Assign a risk tier to a recipient address (admin only). Unassigned
+    /// addresses are tier 0.
+    pub fn set_recipient_tier(env: Env, address: Address, tier: u32) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
             .persistent()
-            .get(&DataKey::DepositorIndex(depositor))
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let start = offset.min(index.len());
-        let end = (offset + limit).min(index.len());
+            .set(&DataKey::RecipientTier(address), &tier);
+        Ok(())
+    }
 
-        for i in start..end {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                results.push_back(EscrowWithId { bounty_id, escrow });
-            }
+    /// Get the risk tier of a recipient (0 when unassigned).
+    pub fn get_recipient_tier(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientTier(address))
+            .unwrap_or(0)
+    }
+
+    /// Require (or stop requiring) an `approve_release_to` confirmation before
+    /// `release_funds` may pay a recipient in `tier` (admin only).
+    pub fn set_tier_approval_threshold(
+        env: Env,
+        tier: u32,
+        requires_approval: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if requires_approval {
+            env.storage()
+                .instance()
+                .set(&DataKey::TierApprovalRequired(tier), &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::TierApprovalRequired(tier));
         }
-        results
+        Ok(())
     }
 
-    /// Get aggregate statistics
-    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
-        let index: Vec<u64> = env
+    /// Whether releases to recipients in `tier` need an approval.
+    pub fn is_tier_approval_required(env: Env, tier: u32) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierApprovalRequired(tier))
+            .unwrap_or(false)
+    }
+
+    /// Confirm that the next `release_funds` of `bounty_id` may pay `recipient`
+    /// (admin only). The confirmation is consumed by that release.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - no escrow for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    pub fn approve_release_to(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut stats = AggregateStats {
-            total_locked: 0,
-            total_released: 0,
-            total_refunded: 0,
-            count_locked: 0,
-            count_released: 0,
-            count_refunded: 0,
-        };
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        for i in 0..index.len() {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                match escrow.status {
-                    EscrowStatus::Locked => {
-                        stats.total_locked += escrow.amount;
-                        stats.count_locked += 1;
-                    }
-                    EscrowStatus::Released => {
-                        stats.total_released += escrow.amount;
-                        stats.count_released += 1;
-                    }
-                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
-                        stats.total_refunded += escrow.amount;
-                        stats.count_refunded += 1;
-                    }
-                }
-            }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
-        stats
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecipientApproval(bounty_id), &recipient);
+        events::emit_recipient_release_approved(
+            &env,
+            events::RecipientReleaseApproved {
+                bounty_id,
+                recipient,
+                approved_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
     }
 
-    /// Get total count of escrows
-    pub fn get_escrow_count(env: Env) -> u32 {
-        let index: Vec<u64> = env
-            .storage()
+    /// Get the recipient currently approved for the next release of `bounty_id`.
+    pub fn get_recipient_approval(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.len()
+            .get(&DataKey::RecipientApproval(bounty_id))
     }
 
-    /// Set the minimum and maximum allowed lock amount (admin only).
+    /// Whether `recipient` may be paid from `bounty_id` under the tier policy:
+    /// either its tier needs no approval or the admin approved this recipient.
+    fn recipient_release_approved(env: &Env, bounty_id: u64, recipient: &Address) -> bool {
+        let tier = Self::get_recipient_tier(env.clone(), recipient.clone());
+        if !Self::is_tier_approval_required(env.clone(), tier) {
+            return true;
+        }
+        Self::get_recipient_approval(env.clone(), bounty_id).as_ref() == Some(recipient)
+    }
+
+    /// Make every payout from `bounty_id` wait on `dependency_id` (admin only).
     ///
-    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
-    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
-    /// The policy can be updated at any time by the admin; new limits take effect
-    /// immediately for subsequent lock_funds calls.
+    /// Releases, partial releases, claims, tickets, vouchers, capabilities,
+    /// schedules and batch releases fail with `DependencyNotSatisfied` until the
+    /// dependency is reported `Completed` through `set_bounty_dependency_status`.
+    /// Several bounties may share a dependency.
     ///
-    /// Passing min_amount == max_amount restricts locking to a single exact value.
-    /// min_amount must not exceed max_amount — the call panics if this invariant
-    /// is violated.
-    pub fn set_amount_policy(
+    /// # Errors
+    /// * `NotInitialized` - contract not initialized
+    /// * `BountyNotFound` - no escrow with `bounty_id`
+    /// * `FundsNotLocked` - escrow is not `Locked`
+    pub fn set_bounty_dependency(
         env: Env,
-        caller: Address,
-        min_amount: i128,
-        max_amount: i128,
+        bounty_id: u64,
+        dependency_id: soroban_sdk::String,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != admin {
-            return Err(Error::Unauthorized);
-        }
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        if min_amount > max_amount {
-            panic!("invalid policy: min_amount cannot exceed max_amount");
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
 
-        // Persist the policy so lock_funds can enforce it on every subsequent call.
         env.storage()
+            .persistent()
+            .set(&DataKeyExt::BountyDependency(bounty_id), &dependency_id);
+        Ok(())
+    }
+
+    /// Report the status of an external dependency (admin only).
+    pub fn set_bounty_dependency_status(
+        env: Env,
+        dependency_id: soroban_sdk::String,
+        status: DependencyStatus,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::DependencyStatus(dependency_id), &status);
+        Ok(())
+    }
+
+    /// Dependency `bounty_id` waits on and its current status (`Pending` until
+    /// reported), or `None` when the release is not gated.
+    pub fn get_bounty_dependency(
+        env: Env,
+        bounty_id: u64,
+    ) -> Option<(soroban_sdk::String, DependencyStatus)> {
+        let dependency_id: soroban_sdk::String = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::BountyDependency(bounty_id))?;
+        let status = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::DependencyStatus(dependency_id.clone()))
+            .unwrap_or(DependencyStatus::Pending);
+        Some((dependency_id, status))
+    }
+
+    /// Whether `bounty_id` has no dependency or its dependency is `Completed`.
+    fn dependency_satisfied(env: &Env, bounty_id: u64) -> bool {
+        match Self::get_bounty_dependency(env.clone(), bounty_id) {
+            Some((_, status)) => status == DependencyStatus::Completed,
+            None => true,
+        }
+    }
 
+    /// Fail with `DependencyNotSatisfied` unless `bounty_id`'s release
+    /// dependency (if any) is `Completed`. Every payout path checks this.
+    fn ensure_dependency_satisfied(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if !Self::dependency_satisfied(env, bounty_id) {
+            return Err(Error::DependencyNotSatisfied);
+        }
         Ok(())
     }
 
@@ -3995,10 +9644,29 @@ impl BountyEscrowContract {
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
-        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
+        anti_abuse::set_whitelist(&env, whitelisted_address.clone(), whitelisted);
+        events::emit_whitelist_changed(
+            &env,
+            events::WhitelistChanged {
+                address: whitelisted_address,
+                whitelisted,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
         Ok(())
     }
 
+    /// Whether `address` is on the anti-abuse whitelist.
+    pub fn get_whitelist_entry(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
+
+    /// List whitelisted addresses in the order they were added.
+    pub fn list_whitelisted(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        anti_abuse::list_whitelisted(&env, offset, limit)
+    }
+
     /// Set participant filter mode (admin only). Mutually exclusive: Disabled, BlocklistOnly, or AllowlistOnly.
     /// Emits ParticipantFilterModeChanged. Transitioning modes does not clear list data; only the active mode is enforced.
     pub fn set_filter_mode(env: Env, new_mode: ParticipantFilterMode) -> Result<(), Error> {
@@ -4030,6 +9698,13 @@ impl BountyEscrowContract {
     }
 
     /// Set blocklist status for an address (admin only). Only enforced when mode is BlocklistOnly.
+    ///
+    /// Once the admin action queue is in use this setter is disabled and the
+    /// change must be queued as `AdminAction::BlocklistEntry`.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - contract has no admin
+    /// * `Unauthorized` - the admin action queue is in use
     pub fn set_blocklist_entry(env: Env, address: Address, blocked: bool) -> Result<(), Error> {
         let admin: Address = env
             .storage()
@@ -4037,6 +9712,9 @@ impl BountyEscrowContract {
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
+        if Self::admin_timelock_engaged(&env) {
+            return Err(Error::Unauthorized);
+        }
         anti_abuse::set_blocklist(&env, address, blocked);
         Ok(())
     }
@@ -4063,6 +9741,22 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Toggle the cooldown exemption for the stored admin address (admin only).
+    ///
+    /// When enabled, rapid admin-initiated operations are not rejected by the
+    /// cooldown check. The per-window operation cap still applies, and all other
+    /// addresses remain subject to the cooldown. Defaults to `false`.
+    pub fn set_admin_cooldown_exempt(env: Env, exempt: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_admin_cooldown_exempt(&env, exempt);
+        Ok(())
+    }
+
     /// Get current anti-abuse config (rate limit and cooldown).
     pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
         let c = anti_abuse::get_config(&env);
@@ -4070,6 +9764,7 @@ impl BountyEscrowContract {
             window_size: c.window_size,
             max_operations: c.max_operations,
             cooldown_period: c.cooldown_period,
+            exempt_admin_cooldown: anti_abuse::is_admin_cooldown_exempt(&env),
         }
     }
 
@@ -4117,7 +9812,7 @@ impl BountyEscrowContract {
     ///   - can_refund: Whether refund is possible
     ///   - deadline_passed: Whether the deadline has passed
     ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
+    ///   - approval: Optional refund approval if one exists and has not expired
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     pub fn get_refund_eligibility(
         env: Env,
@@ -4135,27 +9830,16 @@ impl BountyEscrowContract {
         let now = env.ledger().timestamp();
         let deadline_passed = now >= escrow.deadline;
 
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
-        {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+        let approval = Self::active_refund_approval(&env, bounty_id);
 
         // can_refund is true if:
         // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
+        // 2. (deadline has passed OR there's an approval) AND
+        // 3. The escrow has not been marked non-refundable
         let can_refund = (escrow.status == EscrowStatus::Locked
             || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+            && (deadline_passed || approval.is_some())
+            && !Self::is_non_refundable(env.clone(), bounty_id);
 
         Ok((
             can_refund,
@@ -4250,11 +9934,12 @@ impl BountyEscrowContract {
                 // Participant filtering (blocklist-only / allowlist-only / disabled)
                 Self::check_participant_filter(&env, item.depositor.clone())?;
 
-                // Check if bounty already exists
+                // Check if bounty already exists (or has been purged)
                 if env
                     .storage()
                     .persistent()
                     .has(&DataKey::Escrow(item.bounty_id))
+                    || Self::is_escrow_purged(&env, item.bounty_id)
                 {
                     return Err(Error::BountyExists);
                 }
@@ -4311,6 +9996,14 @@ impl BountyEscrowContract {
                 env.storage()
                     .persistent()
                     .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                env.storage()
+                    .persistent()
+                    .set(&DataKeyExt::EscrowToken(item.bounty_id), &token_addr);
+                env.storage().persistent().set(
+                    &DataKeyExt::EscrowLockedAt(item.bounty_id),
+                    &env.ledger().timestamp(),
+                );
+                Self::record_status_change(&env, item.bounty_id, escrow.status.clone());
 
                 let mut index: Vec<u64> = env
                     .storage()
@@ -4346,6 +10039,7 @@ impl BountyEscrowContract {
                         amount: item.amount,
                         depositor: item.depositor.clone(),
                         deadline: item.deadline,
+                        memo: None,
                     },
                 );
 
@@ -4429,6 +10123,7 @@ impl BountyEscrowContract {
     /// * [`Error::Unauthorized`] — caller is not the admin
     /// * [`Error::BountyNotFound`] — a `bounty_id` does not exist in storage
     /// * [`Error::FundsNotLocked`] — a bounty's status is not `Locked`
+    /// * [`Error::RecipientDenied`] — a contributor is on the release denylist
     /// * [`Error::DuplicateBountyId`] — the same `bounty_id` appears more than once
     ///
     /// # Reentrancy
@@ -4457,8 +10152,6 @@ impl BountyEscrowContract {
             let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
             admin.require_auth();
 
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-            let client = token::Client::new(&env, &token_addr);
             let contract_address = env.current_contract_address();
             let timestamp = env.ledger().timestamp();
 
@@ -4484,6 +10177,16 @@ impl BountyEscrowContract {
                 if escrow.status != EscrowStatus::Locked {
                     return Err(Error::FundsNotLocked);
                 }
+                if Self::scheduled_amount(&env, item.bounty_id) > 0 {
+                    return Err(Error::InsufficientFunds);
+                }
+                Self::ensure_recipient_not_denied(
+                    &env,
+                    symbol_short!("release"),
+                    &item.contributor,
+                )?;
+                Self::ensure_escrow_recipient_allowed(&env, item.bounty_id, &item.contributor)?;
+                Self::ensure_dependency_satisfied(&env, item.bounty_id)?;
 
                 // Check for duplicate bounty_ids in the batch
                 let mut count = 0u32;
@@ -4504,8 +10207,8 @@ impl BountyEscrowContract {
             let ordered_items = Self::order_batch_release_items(&env, &items);
 
             // EFFECTS: update all escrow records before any external calls (CEI)
-            // We collect (contributor, amount) pairs for the transfer pass.
-            let mut release_pairs: Vec<(Address, i128)> = Vec::new(&env);
+            // We collect (contributor, amount, token) triples for the transfer pass.
+            let mut release_pairs: Vec<(Address, i128, Address)> = Vec::new(&env);
             let mut released_count = 0u32;
             for item in ordered_items.iter() {
                 let mut escrow: Escrow = env
@@ -4520,15 +10223,23 @@ impl BountyEscrowContract {
                 env.storage()
                     .persistent()
                     .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                Self::record_status_change(&env, item.bounty_id, escrow.status.clone());
 
-                release_pairs.push_back((item.contributor.clone(), amount));
+                release_pairs.push_back((
+                    item.contributor.clone(),
+                    amount,
+                    Self::escrow_token(&env, item.bounty_id),
+                ));
                 released_count += 1;
             }
 
             // INTERACTION: all external token transfers happen after state is finalized
             for (idx, item) in ordered_items.iter().enumerate() {
-                let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
+                let (ref contributor, amount, ref token_addr) =
+                    release_pairs.get(idx as u32).unwrap();
+                let client = token::Client::new(&env, token_addr);
                 client.transfer(&contract_address, contributor, &amount);
+                Self::record_contributor_payout(&env, item.bounty_id, contributor, amount);
 
                 emit_funds_released(
                     &env,
@@ -4538,27 +10249,160 @@ impl BountyEscrowContract {
                         amount,
                         recipient: contributor.clone(),
                         timestamp,
+                        bonus: 0,
                     },
                 );
             }
 
-            // Emit batch event
-            emit_batch_funds_released(
-                &env,
-                BatchFundsReleased {
-                    count: released_count,
-                    total_amount,
-                    timestamp,
-                },
-            );
+            // Emit batch event
+            emit_batch_funds_released(
+                &env,
+                BatchFundsReleased {
+                    count: released_count,
+                    total_amount,
+                    timestamp,
+                },
+            );
+
+            Ok(released_count)
+        })();
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        result
+    }
+
+    /// Keeper sweep: refund expired escrows, resuming from an `EscrowIndex` cursor.
+    ///
+    /// Walks the escrow index starting at position `start_from` and refunds up to
+    /// `max_to_process` escrows that are `Locked`/`PartiallyRefunded`, past their
+    /// deadline and not blocked by a pending claim. At most `MAX_KEEPER_SCAN`
+    /// entries are examined per call so every sweep is gas-bounded. Anonymous
+    /// escrows are skipped; they must be refunded through `refund_resolved`.
+    ///
+    /// Callable by anyone: funds only ever go to the depositor, or to the
+    /// recipient of an admin-approved refund. Each refund emits `FundsRefunded`
+    /// and updates state before its transfer (CEI).
+    ///
+    /// # Returns
+    /// The cursor to pass as `start_from` on the next call. A value equal to the
+    /// index length means the sweep has reached the end.
+    ///
+    /// # Errors
+    /// * [`Error::NotInitialized`] — `init` has not been called
+    /// * [`Error::FundsPaused`] — refund operations are currently paused
+    pub fn refund_next_expired(
+        env: Env,
+        start_from: u64,
+        max_to_process: u32,
+    ) -> Result<u64, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let len = index.len() as u64;
+        let now = env.ledger().timestamp();
+
+        let mut cursor = start_from.min(len);
+        let mut scanned: u32 = 0;
+        let mut refunded: u32 = 0;
+        while cursor < len && refunded < max_to_process && scanned < MAX_KEEPER_SCAN {
+            let bounty_id = index.get(cursor as u32).unwrap();
+            cursor += 1;
+            scanned += 1;
+
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if now >= escrow.deadline && Self::apply_refund(&env, bounty_id, escrow).is_ok() {
+                    refunded += 1;
+                }
+            }
+        }
+
+        if refunded > 0 {
+            multitoken_invariants::assert_after_disbursement(&env);
+        }
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(cursor)
+    }
+
+    /// Refund several bounties in one call, reporting a result per bounty (admin only).
+    ///
+    /// Unlike the other batch entrypoints this call is **not** all-or-nothing:
+    /// each bounty is refunded independently under the standard refund rules
+    /// (deadline passed or an unexpired approval, no pending claim, status
+    /// `Locked`/`PartiallyRefunded`). Ineligible or unknown bounties are
+    /// reported as `false` and skipped without reverting the batch.
+    ///
+    /// Funds go to the depositor, or to the approved recipient when a refund
+    /// approval exists. Each successful refund emits `FundsRefunded`.
+    ///
+    /// # Returns
+    /// `(bounty_id, refunded)` for every id, in the order given.
+    ///
+    /// # Errors
+    /// * [`Error::FundsPaused`] — refund operations are currently paused
+    /// * [`Error::InvalidBatchSize`] — batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * [`Error::NotInitialized`] — `init` has not been called
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard for the whole batch.
+    pub fn batch_refund(env: Env, bounty_ids: Vec<u64>) -> Result<Vec<(u64, bool)>, Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+        let result: Result<Vec<(u64, bool)>, Error> = (|| {
+            let batch_size = bounty_ids.len();
+            if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+                return Err(Error::InvalidBatchSize);
+            }
+
+            let admin: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(Error::NotInitialized)?;
+            admin.require_auth();
 
-            Ok(released_count)
+            let mut results: Vec<(u64, bool)> = Vec::new(&env);
+            for bounty_id in bounty_ids.iter() {
+                let refunded = match env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+                {
+                    Some(escrow) => Self::apply_refund(&env, bounty_id, escrow).is_ok(),
+                    None => false,
+                };
+                results.push_back((bounty_id, refunded));
+            }
+
+            multitoken_invariants::assert_after_disbursement(&env);
+            Ok(results)
         })();
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         result
     }
+
     /// Update stored metadata for a bounty.
     ///
     /// # Arguments
@@ -4618,6 +10462,136 @@ impl BountyEscrowContract {
             .ok_or(Error::BountyNotFound)
     }
 
+    /// Create or overwrite a named escrow template (admin only).
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `deadline_horizon` is zero
+    /// * `InvalidAmount` - `min_amount` is not positive or exceeds `max_amount`
+    ///
+    /// # Panics
+    /// Panics if `name` or `bounty_type` is empty or exceeds the maximum tag length.
+    pub fn save_escrow_template(
+        env: Env,
+        name: soroban_sdk::String,
+        deadline_horizon: u64,
+        min_amount: i128,
+        max_amount: i128,
+        bounty_type: soroban_sdk::String,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        validation::validate_tag(&env, &name, "template_name");
+        validation::validate_tag(&env, &bounty_type, "bounty_type");
+        if deadline_horizon == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        if min_amount <= 0 || min_amount > max_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let key = DataKeyExt::EscrowTemplate(name.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut index: Vec<soroban_sdk::String> = env
+                .storage()
+                .persistent()
+                .get(&DataKeyExt::EscrowTemplateIndex)
+                .unwrap_or(Vec::new(&env));
+            index.push_back(name.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::EscrowTemplateIndex, &index);
+        }
+        env.storage().persistent().set(
+            &key,
+            &EscrowTemplate {
+                name,
+                deadline_horizon,
+                min_amount,
+                max_amount,
+                bounty_type,
+            },
+        );
+        Ok(())
+    }
+
+    /// Lock funds using a saved template.
+    ///
+    /// The deadline is `now + deadline_horizon` and the escrow's metadata is
+    /// seeded with the template's `bounty_type`. Otherwise behaves exactly like
+    /// [`lock_funds`](Self::lock_funds).
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No template is saved under `template_name`
+    /// * `AmountBelowMinimum` / `AmountAboveMaximum` - `amount` is outside the template's bounds
+    pub fn lock_from_template(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        template_name: soroban_sdk::String,
+    ) -> Result<(), Error> {
+        let template = Self::get_template(env.clone(), template_name)?;
+        if amount < template.min_amount {
+            return Err(Error::AmountBelowMinimum);
+        }
+        if amount > template.max_amount {
+            return Err(Error::AmountAboveMaximum);
+        }
+
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .checked_add(template.deadline_horizon)
+            .ok_or(Error::InvalidDeadline)?;
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        env.storage().persistent().set(
+            &DataKey::Metadata(bounty_id),
+            &EscrowMetadata {
+                repo_id: 0,
+                issue_id: 0,
+                bounty_type: template.bounty_type,
+                risk_flags: 0,
+                notification_prefs: 0,
+                reference_hash: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Saved escrow template by name.
+    pub fn get_template(env: Env, name: soroban_sdk::String) -> Result<EscrowTemplate, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::EscrowTemplate(name))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// All saved escrow templates in first-save order.
+    pub fn list_templates(env: Env) -> Vec<EscrowTemplate> {
+        let index: Vec<soroban_sdk::String> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::EscrowTemplateIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut templates = Vec::new(&env);
+        for name in index.iter() {
+            if let Some(template) = env
+                .storage()
+                .persistent()
+                .get(&DataKeyExt::EscrowTemplate(name))
+            {
+                templates.push_back(template);
+            }
+        }
+        templates
+    }
+
     /// Build the context bytes that feed into the deterministic PRNG.
     ///
     /// The context binds selection to the current contract address, bounty
@@ -4786,7 +10760,7 @@ impl BountyEscrowContract {
             },
         );
 
-        Self::issue_claim_ticket(env, bounty_id, selected, amount, expires_at)
+        Self::issue_claim_ticket(env, bounty_id, selected, amount, expires_at, false, false)
     }
 
     /// Issue a single-use claim ticket to a bounty winner (admin only)
@@ -4800,6 +10774,8 @@ impl BountyEscrowContract {
     /// * `beneficiary` - Address of the winner who will claim the reward
     /// * `amount` - Amount to be claimed (in token units)
     /// * `expires_at` - Unix timestamp when the ticket expires
+    /// * `requires_confirmation` - Require `confirm_claim_ticket` before the ticket can be claimed
+    /// * `redirectable` - Allow the beneficiary to claim to another address via `claim_with_ticket_to`
     ///
     /// # Returns
     /// * `Ok(ticket_id)` - The unique ticket ID for this claim
@@ -4814,6 +10790,8 @@ impl BountyEscrowContract {
         beneficiary: Address,
         amount: i128,
         expires_at: u64,
+        requires_confirmation: bool,
+        redirectable: bool,
     ) -> Result<u64, Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
@@ -4883,6 +10861,15 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+        if requires_confirmation || redirectable {
+            env.storage().persistent().set(
+                &DataKeyExt::TicketFlags(ticket_id),
+                &ClaimTicketFlags {
+                    requires_confirmation,
+                    redirectable,
+                },
+            );
+        }
 
         let mut ticket_index: Vec<u64> = env
             .storage()
@@ -4920,6 +10907,315 @@ impl BountyEscrowContract {
         Ok(ticket_id)
     }
 
+    /// Confirm a claim ticket that was issued with `requires_confirmation` (admin only).
+    ///
+    /// Acts as a second factor for large prizes: the beneficiary cannot claim
+    /// such a ticket until the admin has confirmed it.
+    pub fn confirm_claim_ticket(env: Env, ticket_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let ticket: ClaimTicket = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound)?;
+        if ticket.used {
+            return Err(Error::TicketAlreadyUsed);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TicketConfirmed(ticket_id), &true);
+        Ok(())
+    }
+
+    /// Claim requirements a ticket was issued with; both unset for plain tickets.
+    pub fn get_claim_ticket_flags(env: Env, ticket_id: u64) -> ClaimTicketFlags {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::TicketFlags(ticket_id))
+            .unwrap_or_default()
+    }
+
+    /// Check whether a claim ticket has been confirmed by the admin.
+    pub fn is_claim_ticket_confirmed(env: Env, ticket_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TicketConfirmed(ticket_id))
+            .unwrap_or(false)
+    }
+
+    /// Ids of a beneficiary's outstanding claim tickets, in issue order, paginated.
+    pub fn get_beneficiary_tickets(
+        env: Env,
+        beneficiary: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        Self::beneficiary_ticket_page(&env, beneficiary, offset, limit)
+    }
+
+    /// Full records for the same page `get_beneficiary_tickets` returns.
+    ///
+    /// Ids whose ticket record is missing are skipped, so a page may come back
+    /// shorter than `limit`.
+    pub fn get_beneficiary_tickets_detailed(
+        env: Env,
+        beneficiary: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ClaimTicket> {
+        let mut tickets = Vec::new(&env);
+        for ticket_id in Self::beneficiary_ticket_page(&env, beneficiary, offset, limit).iter() {
+            if let Some(ticket) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, ClaimTicket>(&DataKey::ClaimTicket(ticket_id))
+            {
+                tickets.push_back(ticket);
+            }
+        }
+        tickets
+    }
+
+    fn beneficiary_ticket_page(
+        env: &Env,
+        beneficiary: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(beneficiary))
+            .unwrap_or(Vec::new(env));
+        let mut page = Vec::new(env);
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            page.push_back(index.get(i).unwrap());
+        }
+        page
+    }
+
+    /// Redeem a claim ticket, paying `ticket.amount` to the beneficiary.
+    ///
+    /// Requires beneficiary authorization. Tickets issued with
+    /// `requires_confirmation` must first be confirmed via `confirm_claim_ticket`.
+    /// The escrow's remaining amount is reduced by the ticket amount and the
+    /// escrow is marked `Released` once fully paid out.
+    ///
+    /// # Errors
+    /// * `FundsPaused` - Releases are paused
+    /// * `TicketNotFound` / `TicketExpired` / `TicketAlreadyUsed` - Ticket is not redeemable
+    /// * `TicketNotConfirmed` - Confirmation is required but missing
+    /// * `BountyNotFound` - Ticket's escrow does not exist
+    /// * `FundsNotLocked` - Escrow is not in `Locked` status
+    /// * `InsufficientFunds` - Ticket amount exceeds the escrow's remaining amount
+    /// * `RecipientDenied` - The payout address is on the release denylist
+    pub fn claim_with_ticket(env: Env, ticket_id: u64) -> Result<(), Error> {
+        Self::claim_with_ticket_logic(&env, ticket_id, None)
+    }
+
+    /// Redeem a claim ticket, paying `destination` instead of the beneficiary.
+    ///
+    /// Still requires beneficiary authorization, and the ticket must have been
+    /// issued as `redirectable`. Otherwise identical to `claim_with_ticket`.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Ticket was not issued as redirectable
+    /// * Any error returned by `claim_with_ticket`
+    pub fn claim_with_ticket_to(
+        env: Env,
+        ticket_id: u64,
+        destination: Address,
+    ) -> Result<(), Error> {
+        Self::claim_with_ticket_logic(&env, ticket_id, Some(destination))
+    }
+
+    fn claim_with_ticket_logic(
+        env: &Env,
+        ticket_id: u64,
+        destination: Option<Address>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let mut ticket: ClaimTicket = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicket(ticket_id))
+            .ok_or(Error::TicketNotFound)?;
+        if env.ledger().timestamp() >= ticket.expires_at {
+            return Err(Error::TicketExpired);
+        }
+        if ticket.used {
+            return Err(Error::TicketAlreadyUsed);
+        }
+
+        ticket.beneficiary.require_auth();
+
+        let flags = Self::get_claim_ticket_flags(env.clone(), ticket_id);
+        if flags.requires_confirmation && !Self::is_claim_ticket_confirmed(env.clone(), ticket_id) {
+            return Err(Error::TicketNotConfirmed);
+        }
+        if destination.is_some() && !flags.redirectable {
+            return Err(Error::Unauthorized);
+        }
+        let destination = destination.unwrap_or_else(|| ticket.beneficiary.clone());
+        Self::ensure_recipient_not_denied(env, symbol_short!("tkt_claim"), &destination)?;
+        Self::ensure_escrow_recipient_allowed(env, ticket.bounty_id, &destination)?;
+        Self::ensure_dependency_satisfied(env, ticket.bounty_id)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(ticket.bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if ticket.amount > escrow.remaining_amount - Self::scheduled_amount(env, ticket.bounty_id) {
+            return Err(Error::InsufficientFunds);
+        }
+
+        reentrancy_guard::acquire(env);
+
+        // EFFECTS
+        ticket.used = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+
+        escrow.remaining_amount -= ticket.amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        invariants::assert_escrow(env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(ticket.bounty_id), &escrow);
+        Self::record_status_change(env, ticket.bounty_id, escrow.status.clone());
+
+        // INTERACTION
+        let client = token::Client::new(env, &Self::escrow_token(env, ticket.bounty_id));
+        client.transfer(
+            &env.current_contract_address(),
+            &destination,
+            &ticket.amount,
+        );
+        Self::record_contributor_payout(env, ticket.bounty_id, &destination, ticket.amount);
+
+        emit_ticket_claimed(
+            env,
+            TicketClaimed {
+                ticket_id,
+                bounty_id: ticket.bounty_id,
+                claimer: ticket.beneficiary.clone(),
+                destination,
+                claimed_at: env.ledger().timestamp(),
+            },
+        );
+
+        multitoken_invariants::assert_after_disbursement(env);
+
+        reentrancy_guard::release(env);
+        Ok(())
+    }
+
+    /// Retire expired, unused claim tickets (admin only).
+    ///
+    /// Examines up to `max_to_process` entries of `ClaimTicketIndex`, resuming
+    /// where the previous call stopped and wrapping to the start after the end.
+    /// Expired tickets among them are marked used and dropped from their
+    /// beneficiary's ticket list; used and still-valid tickets are skipped.
+    /// Escrows are not touched, so their funds stay `Locked` and new tickets can
+    /// be issued against them.
+    ///
+    /// Returns the number of tickets reaped.
+    pub fn reap_expired_tickets(env: Env, max_to_process: u32) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let now = env.ledger().timestamp();
+        let ticket_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimTicketIndex)
+            .unwrap_or(Vec::new(&env));
+
+        let len = ticket_index.len();
+        let mut start: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKeyExt::TicketReapCursor)
+            .unwrap_or(0);
+        if start >= len {
+            start = 0;
+        }
+        let end = start.saturating_add(max_to_process).min(len);
+        let next = if end >= len { 0 } else { end };
+        env.storage()
+            .instance()
+            .set(&DataKeyExt::TicketReapCursor, &next);
+
+        let mut reaped: u32 = 0;
+        for i in start..end {
+            let ticket_id = ticket_index.get(i).unwrap();
+            let mut ticket: ClaimTicket = match env
+                .storage()
+                .persistent()
+                .get(&DataKey::ClaimTicket(ticket_id))
+            {
+                Some(ticket) => ticket,
+                None => continue,
+            };
+            if ticket.used || now < ticket.expires_at {
+                continue;
+            }
+
+            ticket.used = true;
+            env.storage()
+                .persistent()
+                .set(&DataKey::ClaimTicket(ticket_id), &ticket);
+
+            let beneficiary_key = DataKey::BeneficiaryTickets(ticket.beneficiary.clone());
+            let beneficiary_tickets: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&beneficiary_key)
+                .unwrap_or(Vec::new(&env));
+            let mut kept = Vec::new(&env);
+            for id in beneficiary_tickets.iter() {
+                if id != ticket_id {
+                    kept.push_back(id);
+                }
+            }
+            env.storage().persistent().set(&beneficiary_key, &kept);
+
+            emit_ticket_reaped(
+                &env,
+                TicketReaped {
+                    ticket_id,
+                    bounty_id: ticket.bounty_id,
+                    beneficiary: ticket.beneficiary,
+                    expires_at: ticket.expires_at,
+                    reaped_at: now,
+                },
+            );
+            reaped += 1;
+        }
+
+        Ok(reaped)
+    }
+
     pub fn set_escrow_risk_flags(
         env: Env,
         bounty_id: u64,
@@ -5138,10 +11434,14 @@ mod test_bounty_escrow;
 #[cfg(test)]
 mod test_capability_tokens;
 #[cfg(test)]
+mod test_claim_ticket_flow;
+#[cfg(test)]
 mod test_deprecation;
 #[cfg(test)]
 mod test_dispute_resolution;
 #[cfg(test)]
+mod test_escrow_topup;
+#[cfg(test)]
 mod test_expiration_and_dispute;
 #[cfg(test)]
 mod test_front_running_ordering;
@@ -16,8 +16,13 @@
 //          - Released => remaining_amount == 0
 //
 // INV-2  (Aggregate-to-Ledger)
-//        Sum of all `remaining_amount` across *active* escrows ==
-//        actual token balance held by the contract.
+//        For every token in use, the sum of `remaining_amount` across
+//        *active* escrows funded in that token <= actual balance of that
+//        token held by the contract.  Anonymous escrows always use the
+//        default token.  Held-back shares of released escrows (see
+//        `release_with_holdback`) still sit in the contract and count too.
+//        A surplus (tokens sent directly to the contract) is informational
+//        and only reported via `SurplusDetected`.
 //
 // INV-3  (Fee Separation)
 //        If a fee was collected, it was transferred out at the time of
@@ -34,8 +39,11 @@
 //
 // ============================================================================
 
-use crate::{AnonymousEscrow, DataKey, Escrow, EscrowStatus};
-use soroban_sdk::{token, Address, Env, Vec};
+use crate::{
+    events, AnonymousEscrow, BountyEscrowContract, DataKey, DataKeyExt, Escrow, EscrowStatus,
+    Holdback,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol, Vec};
 
 /// Full result of a multi-token balance invariant check.
 /// Returned by `check_all_invariants` so callers can inspect what failed.
@@ -44,9 +52,9 @@ use soroban_sdk::{token, Address, Env, Vec};
 pub struct InvariantReport {
     /// True when ALL invariants pass.
     pub healthy: bool,
-    /// Total remaining amount summed across all active escrows.
+    /// Total remaining amount summed across all active escrows, over every token in use.
     pub sum_remaining: i128,
-    /// Actual token balance of the contract.
+    /// Actual token balance of the contract, summed over every token in use.
     pub token_balance: i128,
     /// Number of escrows that failed per-escrow sanity checks (INV-1).
     pub per_escrow_failures: u32,
@@ -140,48 +148,249 @@ pub(crate) fn check_anon_refund_consistency(anon: &AnonymousEscrow) -> bool {
 // INV-2  Aggregate-to-Ledger
 // ---------------------------------------------------------------------------
 
-/// Sum the remaining_amount of all active (Locked or PartiallyRefunded) escrows,
-/// including both normal Escrow and AnonymousEscrow.
-pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
+/// Collect every token the contract currently holds escrows in.
+///
+/// The default token configured at init always comes first, followed by any
+/// other token referenced by an escrow in the index (deduplicated).
+pub(crate) fn tracked_tokens(env: &Env) -> Vec<Address> {
+    let mut tokens: Vec<Address> = Vec::new(env);
+    if let Some(default_token) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::Token)
+    {
+        tokens.push_back(default_token);
+    }
+
     let index: Vec<u64> = env
         .storage()
         .persistent()
         .get(&DataKey::EscrowIndex)
         .unwrap_or(Vec::new(env));
-
-    let mut total: i128 = 0;
     for bounty_id in index.iter() {
-        if let Some(escrow) = env
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let token = BountyEscrowContract::escrow_token(env, bounty_id);
+            if !tokens.contains(&token) {
+                tokens.push_back(token);
+            }
+        }
+    }
+    tokens
+}
+
+/// Amount `bounty_id` still claims against the contract's `token` balance:
+/// its `remaining_amount` while active plus any pending holdback. Anonymous
+/// escrows are counted against the default token.
+fn active_claim(env: &Env, bounty_id: u64, token: &Address, is_default_token: bool) -> i128 {
+    let mut claim: i128 = 0;
+    if let Some(escrow) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+    {
+        if BountyEscrowContract::escrow_token(env, bounty_id) == *token
+            && (escrow.status == EscrowStatus::Locked
+                || escrow.status == EscrowStatus::PartiallyRefunded
+                || escrow.status == EscrowStatus::Pending)
+        {
+            claim += escrow.remaining_amount;
+        }
+        if let Some(holdback) = env
             .storage()
             .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .get::<DataKeyExt, Holdback>(&DataKeyExt::Holdback(bounty_id))
         {
-            if escrow.status == EscrowStatus::Locked
-                || escrow.status == EscrowStatus::PartiallyRefunded
-            {
-                total += escrow.remaining_amount;
+            if holdback.token == *token {
+                claim += holdback.amount;
             }
-        } else if let Some(anon) = env
+        }
+    } else if is_default_token {
+        if let Some(anon) = env
             .storage()
             .persistent()
             .get::<DataKey, AnonymousEscrow>(&DataKey::EscrowAnon(bounty_id))
         {
             if anon.status == EscrowStatus::Locked || anon.status == EscrowStatus::PartiallyRefunded
             {
-                total += anon.remaining_amount;
+                claim += anon.remaining_amount;
             }
         }
     }
+    claim
+}
+
+fn is_default_token(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::Token)
+        .map(|default_token| default_token == *token)
+        .unwrap_or(false)
+}
+
+/// Sum the remaining_amount of all active (Locked or PartiallyRefunded) escrows
+/// funded in `token`, plus pending holdbacks. Anonymous escrows are counted
+/// against the default token.
+pub(crate) fn sum_active_escrow_balances(env: &Env, token: &Address) -> i128 {
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EscrowIndex)
+        .unwrap_or(Vec::new(env));
+    let is_default_token = is_default_token(env, token);
+
+    let mut total: i128 = 0;
+    for bounty_id in index.iter() {
+        total += active_claim(env, bounty_id, token, is_default_token);
+    }
     total
 }
 
-/// Get the actual token balance held by the contract.
-pub(crate) fn get_contract_token_balance(env: &Env) -> i128 {
-    let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-    let client = token::Client::new(env, &token_addr);
+/// Claims on `token` from escrows up to and including `bounty_id` in
+/// `EscrowIndex` order. Returns `(own_claim, cumulative_claims)`.
+pub(crate) fn claims_through(env: &Env, token: &Address, bounty_id: u64) -> (i128, i128) {
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EscrowIndex)
+        .unwrap_or(Vec::new(env));
+    let is_default_token = is_default_token(env, token);
+
+    let mut cumulative: i128 = 0;
+    for id in index.iter() {
+        let claim = active_claim(env, id, token, is_default_token);
+        cumulative += claim;
+        if id == bounty_id {
+            return (claim, cumulative);
+        }
+    }
+    (0, cumulative)
+}
+
+/// Get the actual balance of `token` held by the contract.
+pub(crate) fn get_contract_token_balance(env: &Env, token: &Address) -> i128 {
+    let client = token::Client::new(env, token);
     client.balance(&env.current_contract_address())
 }
 
+/// Find the first token whose escrows claim more than the contract holds.
+/// Returns `(token, token_sum, token_balance)` for the offending token.
+fn first_ledger_shortfall(env: &Env) -> Option<(Address, i128, i128)> {
+    for token in tracked_tokens(env).iter() {
+        let sum = sum_active_escrow_balances(env, &token);
+        let actual = get_contract_token_balance(env, &token);
+        if sum > actual {
+            return Some((token, sum, actual));
+        }
+    }
+    None
+}
+
+/// Find the first token the contract holds more of than its escrows claim.
+/// Returns `(token, token_sum, token_balance)` for that token.
+fn first_ledger_surplus(env: &Env) -> Option<(Address, i128, i128)> {
+    for token in tracked_tokens(env).iter() {
+        let sum = sum_active_escrow_balances(env, &token);
+        let actual = get_contract_token_balance(env, &token);
+        if actual > sum {
+            return Some((token, sum, actual));
+        }
+    }
+    None
+}
+
+/// Default-token balance held by the contract minus the sum of active escrows
+/// in that token. Positive values are stray deposits not backing any escrow.
+pub(crate) fn default_token_surplus(env: &Env) -> i128 {
+    match env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::Token)
+    {
+        Some(token) => {
+            get_contract_token_balance(env, &token) - sum_active_escrow_balances(env, &token)
+        }
+        None => 0,
+    }
+}
+
+/// Emit `SurplusDetected` the first time a check sees more tokens than the
+/// escrows account for. The flag is cleared once the ledger balances again.
+fn note_surplus(env: &Env, surplus: &Option<(Address, i128, i128)>) {
+    let reported: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::SurplusReported)
+        .unwrap_or(false);
+    match surplus {
+        Some((token, sum, actual)) => {
+            if reported {
+                return;
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey::SurplusReported, &true);
+            events::emit_surplus_detected(
+                env,
+                events::SurplusDetected {
+                    version: events::EVENT_VERSION_V2,
+                    token: token.clone(),
+                    surplus: actual - sum,
+                    escrow_sum: *sum,
+                    token_balance: *actual,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        _ => {
+            if reported {
+                env.storage().instance().remove(&DataKey::SurplusReported);
+            }
+        }
+    }
+}
+
+/// When auto-halt is enabled, pause every operation and emit
+/// `InvariantBreachHalt`. Returns `false` if the caller should panic instead.
+fn halt_on_breach(env: &Env, operation: Symbol, sum: i128, actual: i128) -> bool {
+    let enabled: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::InvariantAutoHalt)
+        .unwrap_or(false);
+    if !enabled {
+        return false;
+    }
+
+    let now = env.ledger().timestamp();
+    let mut flags = BountyEscrowContract::get_pause_flags(env);
+    flags.lock_paused = true;
+    flags.release_paused = true;
+    flags.refund_paused = true;
+    flags.pause_reason = Some(soroban_sdk::String::from_str(env, "INV-2 invariant breach"));
+    if flags.paused_at == 0 {
+        flags.paused_at = now;
+    }
+    env.storage().instance().set(&DataKey::PauseFlags, &flags);
+    BountyEscrowContract::record_compliance_rejection(
+        env,
+        operation.clone(),
+        env.current_contract_address(),
+        crate::COMPLIANCE_HALT,
+    );
+
+    events::emit_invariant_breach_halt(
+        env,
+        events::InvariantBreachHalt {
+            version: events::EVENT_VERSION_V2,
+            operation,
+            escrow_sum: sum,
+            token_balance: actual,
+            timestamp: now,
+        },
+    );
+    true
+}
+
 // ---------------------------------------------------------------------------
 // INV-5  Index Completeness
 // ---------------------------------------------------------------------------
@@ -271,15 +480,21 @@ pub(crate) fn check_all_invariants(env: &Env) -> InvariantReport {
         }
     }
 
-    // INV-2: Aggregate-to-Ledger
-    let sum_remaining = sum_active_escrow_balances(env);
-    let token_balance = get_contract_token_balance(env);
+    // INV-2: Aggregate-to-Ledger, checked independently for each token
+    let mut sum_remaining: i128 = 0;
+    let mut token_balance: i128 = 0;
+    for token in tracked_tokens(env).iter() {
+        let token_sum = sum_active_escrow_balances(env, &token);
+        let token_actual = get_contract_token_balance(env, &token);
+        sum_remaining += token_sum;
+        token_balance += token_actual;
 
-    if sum_remaining != token_balance {
-        violations.push_back(soroban_sdk::String::from_str(
-            env,
-            "INV-2: Sum of remaining != contract balance",
-        ));
+        if token_sum > token_actual {
+            violations.push_back(soroban_sdk::String::from_str(
+                env,
+                "INV-2: Sum of remaining > contract balance",
+            ));
+        }
     }
 
     // INV-5: Index Completeness
@@ -321,7 +536,8 @@ pub(crate) fn assert_all_invariants(env: &Env) {
 // most relevant to the specific operation.
 // ---------------------------------------------------------------------------
 
-/// Assert after a lock: aggregate balance must equal token balance.
+/// Assert after a lock: the contract must hold at least the aggregate escrow
+/// balance of every token. A surplus only emits `SurplusDetected`.
 pub(crate) fn assert_after_lock(env: &Env) {
     let key = soroban_sdk::Symbol::new(env, "InvOff");
     let disabled: bool = env.storage().instance().get(&key).unwrap_or(false);
@@ -329,17 +545,20 @@ pub(crate) fn assert_after_lock(env: &Env) {
     if disabled {
         return;
     }
-    let sum = sum_active_escrow_balances(env);
-    let actual = get_contract_token_balance(env);
-    if sum != actual {
+    note_surplus(env, &first_ledger_surplus(env));
+    if let Some((_, sum, actual)) = first_ledger_shortfall(env) {
+        if halt_on_breach(env, symbol_short!("lock"), sum, actual) {
+            return;
+        }
         panic!(
-            "INV-2 violated after lock: escrow sum ({}) != balance ({})",
+            "INV-2 violated after lock: escrow sum ({}) > balance ({})",
             sum, actual
         );
     }
 }
 
-/// Assert after a release/refund: aggregate balance must equal token balance.
+/// Assert after a release/refund: the contract must hold at least the aggregate
+/// escrow balance of every token. A surplus only emits `SurplusDetected`.
 pub(crate) fn assert_after_disbursement(env: &Env) {
     let key = soroban_sdk::Symbol::new(env, "InvOff");
     let disabled: bool = env.storage().instance().get(&key).unwrap_or(false);
@@ -347,11 +566,13 @@ pub(crate) fn assert_after_disbursement(env: &Env) {
     if disabled {
         return;
     }
-    let sum = sum_active_escrow_balances(env);
-    let actual = get_contract_token_balance(env);
-    if sum != actual {
+    note_surplus(env, &first_ledger_surplus(env));
+    if let Some((_, sum, actual)) = first_ledger_shortfall(env) {
+        if halt_on_breach(env, symbol_short!("disburse"), sum, actual) {
+            return;
+        }
         panic!(
-            "INV-2 violated after disbursement: escrow sum ({}) != balance ({})",
+            "INV-2 violated after disbursement: escrow sum ({}) > balance ({})",
             sum, actual
         );
     }
@@ -7,7 +7,7 @@ pub const EXPECTED: &[(&str, &str)] = &[
   ("PauseFlags", "0000001100000001000000050000000f0000000b6c6f636b5f7061757365640000000000000000010000000f0000000c70617573655f726561736f6e0000000e0000000b6d61696e74656e616e6365000000000f000000097061757365645f61740000000000000500000000000003e70000000f0000000d726566756e645f70617573656400000000000000000000010000000f0000000e72656c656173655f70617573656400000000000000000000"),
   ("AggregateStats", "0000001100000001000000060000000f0000000c636f756e745f6c6f636b656400000003000000010000000f0000000e636f756e745f726566756e646564000000000003000000030000000f0000000e636f756e745f72656c6561736564000000000003000000020000000f0000000c746f74616c5f6c6f636b65640000000a0000000000000000000000000000000a0000000f0000000e746f74616c5f726566756e64656400000000000a0000000000000000000000000000001e0000000f0000000e746f74616c5f72656c656173656400000000000a00000000000000000000000000000014"),
   ("PauseStateChanged", "0000001100000001000000050000000f0000000561646d696e000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f000000096f7065726174696f6e0000000000000f000000046c6f636b0000000f00000006706175736564000000000000000000010000000f00000006726561736f6e00000000000e0000000b6d61696e74656e616e6365000000000f0000000974696d657374616d7000000000000005000000000000007b"),
-  ("AntiAbuseConfigView", "0000001100000001000000030000000f0000000f636f6f6c646f776e5f706572696f64000000000500000000000000050000000f0000000e6d61785f6f7065726174696f6e730000000000030000000a0000000f0000000b77696e646f775f73697a650000000005000000000000003c"),
+  ("AntiAbuseConfigView", "0000001100000001000000040000000f0000000f636f6f6c646f776e5f706572696f64000000000500000000000000050000000f000000156578656d70745f61646d696e5f636f6f6c646f776e00000000000000000000000000000f0000000e6d61785f6f7065726174696f6e730000000000030000000a0000000f0000000b77696e646f775f73697a650000000005000000000000003c"),
   ("FeeConfig", "0000001100000001000000040000000f0000000b6665655f656e61626c65640000000000000000010000000f0000000d6665655f726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000d6c6f636b5f6665655f726174650000000000000a000000000000000000000000000000640000000f0000001072656c656173655f6665655f726174650000000a000000000000000000000000000000c8"),
   ("MultisigConfig", "0000001100000001000000030000000f0000001372657175697265645f7369676e6174757265730000000003000000020000000f000000077369676e6572730000000010000000010000000200000012000000010101010101010101010101010101010101010101010101010101010101010101000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f000000107468726573686f6c645f616d6f756e740000000a000000000000000000000000000001f4"),
   ("ReleaseApproval", "0000001100000001000000030000000f00000009617070726f76616c73000000000000100000000100000001000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f720000000012000000010404040404040404040404040404040404040404040404040404040404040404"),
@@ -20,13 +20,13 @@ pub const EXPECTED: &[(&str, &str)] = &[
   ("LockFundsItem", "0000001100000001000000040000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e650000000500000000000001c80000000f000000096465706f7369746f7200000000000012000000010303030303030303030303030303030303030303030303030303030303030303"),
   ("ReleaseFundsItem", "0000001100000001000000020000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f720000000012000000010404040404040404040404040404040404040404040404040404040404040404"),
   ("BountyEscrowInitialized", "0000001100000001000000040000000f0000000561646d696e000000000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f0000000974696d657374616d700000000000000500000000000000010000000f00000005746f6b656e000000000000120000000102020202020202020202020202020202020202020202020202020202020202020000000f0000000776657273696f6e000000000300000002"),
-  ("FundsLocked", "0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000000000000012d6870000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e6500000005000000006553f1000000000f000000096465706f7369746f72000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000776657273696f6e000000000300000002"),
-  ("FundsReleased", "0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726563697069656e74000000000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d700000000000000500000000000001c80000000f0000000776657273696f6e000000000300000002"),
-  ("FundsRefunded", "0000001100000001000000050000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726566756e645f746f000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000974696d657374616d700000000000000500000000000000c80000000f0000000776657273696f6e000000000300000002"),
+  ("FundsLocked", "0000001100000001000000060000000f00000006616d6f756e7400000000000a0000000000000000000000000012d6870000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000008646561646c696e6500000005000000006553f1000000000f000000096465706f7369746f72000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f000000046d656d6f000000010000000f0000000776657273696f6e000000000300000002"),
+  ("FundsReleased", "0000001100000001000000060000000f00000006616d6f756e7400000000000a0000000000000000000000000000007b0000000f00000005626f6e75730000000000000a000000000000000000000000000000000000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726563697069656e74000000000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d700000000000000500000000000001c80000000f0000000776657273696f6e000000000300000002"),
+  ("FundsRefunded", "0000001100000001000000060000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f00000009726566756e645f746f000000000000120000000103030303030303030303030303030303030303030303030303030303030303030000000f0000000974696d657374616d700000000000000500000000000000c80000000f0000000774726967676572000000001000000001000000010000000f0000000e446561646c696e6545787069727900000000000f0000000776657273696f6e000000000300000002"),
   ("FeeOperationType::Lock", "0000001000000001000000010000000f000000044c6f636b"),
   ("FeeCollected", "0000001100000001000000050000000f00000006616d6f756e7400000000000a000000000000000000000000000001c80000000f000000086665655f726174650000000a0000000000000000000000000000007b0000000f0000000e6f7065726174696f6e5f7479706500000000001000000001000000010000000f0000000752656c65617365000000000f00000009726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000974696d657374616d700000000000000500000000000003e7"),
   ("BatchFundsLocked", "0000001100000001000000030000000f00000005636f756e7400000000000003000000020000000f0000000974696d657374616d700000000000000500000000000000010000000f0000000c746f74616c5f616d6f756e740000000a000000000000000000000000000003e7"),
-  ("FeeConfigUpdated", "0000001100000001000000050000000f0000000b6665655f656e61626c65640000000000000000010000000f0000000d6665655f726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000d6c6f636b5f6665655f726174650000000000000a0000000000000000000000000000000a0000000f0000001072656c656173655f6665655f726174650000000a000000000000000000000000000000140000000f0000000974696d657374616d70000000000000050000000000000002"),
+  ("FeeConfigUpdated", "0000001100000001000000060000000f0000000b6665655f656e61626c65640000000000000000010000000f0000000d6665655f726563697069656e74000000000000120000000105050505050505050505050505050505050505050505050505050505050505050000000f0000000d6c6f636b5f6665655f726174650000000000000a0000000000000000000000000000000a0000000f0000000f726566756e645f6665655f72617465000000000a000000000000000000000000000000050000000f0000001072656c656173655f6665655f726174650000000a000000000000000000000000000000140000000f0000000974696d657374616d70000000000000050000000000000002"),
   ("BatchFundsReleased", "0000001100000001000000030000000f00000005636f756e7400000000000003000000010000000f0000000974696d657374616d700000000000000500000000000000030000000f0000000c746f74616c5f616d6f756e740000000a0000000000000000000000000000014d"),
   ("ApprovalAdded", "0000001100000001000000040000000f00000008617070726f766572000000120000000101010101010101010101010101010101010101010101010101010101010101010000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000b636f6e7472696275746f7200000000120000000104040404040404040404040404040404040404040404040404040404040404040000000f0000000974696d657374616d70000000000000050000000000000004"),
   ("ClaimCreated", "0000001100000001000000040000000f00000006616d6f756e7400000000000a000000000000000000000000000000640000000f00000009626f756e74795f696400000000000005000000000000002a0000000f0000000a657870697265735f617400000000000500000000000000c80000000f00000009726563697069656e7400000000000012000000010606060606060606060606060606060606060606060606060606060606060606"),
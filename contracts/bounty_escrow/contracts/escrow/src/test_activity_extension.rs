@@ -0,0 +1,78 @@
+//! Deadline auto-extension tests for `record_activity`.
+//!
+//! Covers:
+//! - Activity is a no-op until a policy is configured
+//! - Each activity pushes the deadline to `now + extend_by`, never backwards
+//! - Total extension per escrow is capped by `max_total_extension`
+//! - Non-`Locked` escrows are rejected
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::Error;
+use soroban_sdk::{symbol_short, testutils::Ledger};
+
+const DEADLINE: u64 = 2_000;
+
+/// Lock 1_000 from the depositor without moving the ledger clock.
+fn lock(s: &Fixture, bounty_id: u64) {
+    s.client
+        .lock_funds(&s.depositor, &bounty_id, &1_000, &DEADLINE);
+}
+
+#[test]
+fn test_activity_without_policy_keeps_deadline() {
+    let s = Fixture::new();
+    lock(&s, 1);
+
+    assert_eq!(s.client.record_activity(&1), DEADLINE);
+    assert_eq!(s.client.get_escrow_info(&1).deadline, DEADLINE);
+    assert_eq!(s.count_topic(symbol_short!("dl_ext")), 0);
+}
+
+#[test]
+fn test_activity_extends_deadline_up_to_cap() {
+    let s = Fixture::new();
+    lock(&s, 1);
+    s.client.set_activity_extension(&1_500, &1_200);
+
+    // now + 1_500 = 2_500 -> +500 of the 1_200 budget.
+    assert_eq!(s.client.record_activity(&1), 2_500);
+    assert_eq!(s.count_topic(symbol_short!("dl_ext")), 1);
+
+    // Deadline already reaches now + extend_by: nothing to do.
+    assert_eq!(s.client.record_activity(&1), 2_500);
+
+    // now + 1_500 = 3_500 would add 1_000, but only 700 budget remains.
+    s.env.ledger().set_timestamp(2_000);
+    assert_eq!(s.client.record_activity(&1), 3_200);
+    assert_eq!(s.client.get_escrow_info(&1).deadline, 3_200);
+
+    s.env.ledger().set_timestamp(3_000);
+    assert_eq!(s.client.record_activity(&1), 3_200);
+}
+
+#[test]
+fn test_activity_budget_is_per_escrow() {
+    let s = Fixture::new();
+    lock(&s, 1);
+    s.env.ledger().set_timestamp(1_100);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &DEADLINE);
+    s.client.set_activity_extension(&2_000, &500);
+
+    assert_eq!(s.client.record_activity(&1), 2_500);
+    assert_eq!(s.client.record_activity(&2), 2_500);
+}
+
+#[test]
+fn test_activity_rejects_non_locked_escrow() {
+    let s = Fixture::new();
+    lock(&s, 1);
+    s.client.set_activity_extension(&1_500, &5_000);
+    s.client.release_funds(&1, &s.contributor);
+
+    let res = s.client.try_record_activity(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+    let res = s.client.try_record_activity(&9);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
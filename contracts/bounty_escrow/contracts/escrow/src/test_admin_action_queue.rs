@@ -0,0 +1,185 @@
+//! Time-locked admin action queue tests for `queue_admin_action`,
+//! `execute_admin_action` and `cancel_admin_action`.
+//!
+//! Covers:
+//! - Queued actions are rejected before `execute_after` and applied after it
+//! - Delays shorter than `MIN_ADMIN_ACTION_DELAY` are rejected
+//! - Cancelled and executed actions leave the queue
+//! - Queue, execute and cancel events
+//! - Direct setters apply immediately until the queue is first used, then
+//!   are rejected with `Unauthorized`
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{AdminAction, Error, MIN_ADMIN_ACTION_DELAY};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const DEADLINE: u64 = 500_000;
+
+#[test]
+fn test_premature_execution_is_rejected() {
+    let s = Fixture::new();
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    let id = s
+        .client
+        .queue_admin_action(&AdminAction::AmountPolicy(500, 10_000), &execute_after);
+
+    assert_eq!(
+        s.client.try_execute_admin_action(&id),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    s.env.ledger().set_timestamp(execute_after - 1);
+    assert_eq!(
+        s.client.try_execute_admin_action(&id),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert!(s.client.get_admin_action(&id).is_some());
+
+    // Policy not yet in force.
+    s.client.lock_funds(&s.depositor, &1, &100, &DEADLINE);
+}
+
+#[test]
+fn test_execute_applies_amount_policy_after_delay() {
+    let s = Fixture::new();
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    let id = s
+        .client
+        .queue_admin_action(&AdminAction::AmountPolicy(500, 10_000), &execute_after);
+
+    s.env.ledger().set_timestamp(execute_after);
+    s.client.execute_admin_action(&id);
+    assert_eq!(s.client.get_admin_action(&id), None);
+    assert_eq!(
+        s.client.try_lock_funds(&s.depositor, &1, &100, &DEADLINE),
+        Err(Ok(Error::AmountBelowMinimum))
+    );
+    assert_eq!(
+        s.client.try_execute_admin_action(&id),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_execute_applies_denylist_change() {
+    let s = Fixture::new();
+    let target = Address::generate(&s.env);
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    let id = s.client.queue_admin_action(
+        &AdminAction::AddressDenied(target.clone(), true),
+        &execute_after,
+    );
+    assert!(!s.client.is_address_denied(&target));
+
+    s.env.ledger().set_timestamp(execute_after + 10);
+    s.client.execute_admin_action(&id);
+    assert!(s.client.is_address_denied(&target));
+}
+
+#[test]
+fn test_queue_rejects_short_delay_and_invalid_policy() {
+    let s = Fixture::new();
+    assert_eq!(
+        s.client.try_queue_admin_action(
+            &AdminAction::AmountPolicy(1, 10),
+            &(s.now() + MIN_ADMIN_ACTION_DELAY - 1)
+        ),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.client.try_queue_admin_action(
+            &AdminAction::AmountPolicy(10, 1),
+            &(s.now() + MIN_ADMIN_ACTION_DELAY)
+        ),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_cancel_removes_action() {
+    let s = Fixture::new();
+    let target = Address::generate(&s.env);
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    let first = s.client.queue_admin_action(
+        &AdminAction::BlocklistEntry(target.clone(), true),
+        &execute_after,
+    );
+    let second = s.client.queue_admin_action(
+        &AdminAction::AddressDenied(target.clone(), true),
+        &execute_after,
+    );
+    assert_eq!(second, first + 1);
+
+    s.client.cancel_admin_action(&second);
+    assert_eq!(s.client.get_admin_action(&second), None);
+    assert!(s.client.get_admin_action(&first).is_some());
+
+    s.env.ledger().set_timestamp(execute_after);
+    assert_eq!(
+        s.client.try_execute_admin_action(&second),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert!(!s.client.is_address_denied(&target));
+    assert_eq!(
+        s.client.try_cancel_admin_action(&second),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_queue_execute_cancel_events() {
+    let s = Fixture::new();
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    let id = s
+        .client
+        .queue_admin_action(&AdminAction::AmountPolicy(1, 10), &execute_after);
+    assert_eq!(s.count_topic(symbol_short!("adm_q")), 1);
+
+    let other = s
+        .client
+        .queue_admin_action(&AdminAction::AmountPolicy(2, 20), &execute_after);
+    s.client.cancel_admin_action(&other);
+    assert_eq!(s.count_topic(symbol_short!("adm_cncl")), 1);
+
+    s.env.ledger().set_timestamp(execute_after);
+    s.client.execute_admin_action(&id);
+    assert_eq!(s.count_topic(symbol_short!("adm_exec")), 1);
+}
+
+#[test]
+fn test_direct_setters_rejected_once_timelock_is_used() {
+    let s = Fixture::new();
+    let target = Address::generate(&s.env);
+
+    // Before the queue is used the setters apply immediately.
+    s.client.set_address_denied(&target, &true);
+    assert!(s.client.is_address_denied(&target));
+    s.client.set_address_denied(&target, &false);
+
+    let execute_after = s.now() + MIN_ADMIN_ACTION_DELAY;
+    s.client.queue_admin_action(
+        &AdminAction::AddressDenied(target.clone(), true),
+        &execute_after,
+    );
+
+    assert_eq!(
+        s.client.try_set_amount_policy(&s.admin, &500, &10_000),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_address_denied(&target, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_blocklist_entry(&target, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!s.client.is_address_denied(&target));
+    s.client.lock_funds(&s.depositor, &1, &100, &DEADLINE);
+    assert_eq!(s.count_topic(symbol_short!("adm_q")), 1);
+}
@@ -8,10 +8,12 @@
 ///
 /// ## Coverage
 /// * `get_aggregate_stats`  – totals update after lock → release → refund lifecycle
+/// * `get_aggregate_stats_cached` – incremental cache matches a full recompute
 /// * `get_escrow_count`     – increments on each lock; never decrements
 /// * `query_escrows_by_status` – returns correct subset filtered by status
 /// * `query_escrows_by_amount` – range filter works for locked, released, and mixed states
 /// * `query_escrows_by_deadline` – deadline range filter returns correct bounties
+/// * `query_escrows_expiring_within` – only Locked escrows due in `(now, now + window]`
 /// * `query_escrows_by_depositor` – per-depositor index is populated on lock
 /// * `get_escrow_ids_by_status` – ID-only view mirrors full-object equivalent
 /// * `get_refund_eligibility` – eligibility flags flip correctly across lifecycle
@@ -21,6 +23,7 @@
 /// * `get_analytics`         – operation_count, error_count, error_rate tracking
 /// * `health_check`          – is_healthy, total_operations verification
 /// * `get_state_snapshot`    – point-in-time metrics capture
+/// * `get_volume_in_window`  – rolling hourly buckets, window sums, bucket eviction
 ///
 /// ## Query Complexity Guarantees (O(n) Bounded)
 ///
@@ -31,10 +34,12 @@
 /// | Function                      | Complexity    | Notes                            |
 /// |-------------------------------|---------------|----------------------------------|
 /// | `get_aggregate_stats`         | O(n)          | Scans all escrows to compute totals |
+/// | `get_aggregate_stats_cached`  | O(1)          | Returns the incrementally kept totals |
 /// | `get_escrow_count`            | O(1)          | Returns a stored counter         |
 /// | `query_escrows_by_status`     | O(n)          | Linear scan with offset/limit    |
 /// | `query_escrows_by_amount`     | O(n)          | Linear scan with range filter    |
 /// | `query_escrows_by_deadline`   | O(n)          | Linear scan with range filter    |
+/// | `query_escrows_expiring_within` | O(n)        | Linear scan with range filter    |
 /// | `query_escrows_by_depositor`  | O(n)          | Linear scan with depositor match |
 /// | `get_escrow_ids_by_status`    | O(n)          | Linear scan, returns IDs only    |
 /// | `get_refund_eligibility`      | O(1)          | Single escrow lookup             |
@@ -42,6 +47,7 @@
 /// | `get_analytics`               | O(1)          | Returns stored counters          |
 /// | `health_check`                | O(1)          | Returns stored metrics           |
 /// | `get_state_snapshot`          | O(1)          | Returns stored metrics           |
+/// | `get_volume_in_window`        | O(b)          | b = retained buckets (max 168)   |
 ///
 /// Pagination via `offset` and `limit` parameters ensures that even O(n) scans
 /// return bounded result sets, preventing excessive gas consumption.
@@ -601,6 +607,66 @@ fn test_query_by_deadline_exact_boundary_included() {
     assert_eq!(results.len(), 2);
 }
 
+#[test]
+fn test_query_expiring_within_skips_expired_and_closed_escrows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+
+    let start = env.ledger().timestamp();
+    escrow.lock_funds(&depositor, &170, &100, &(start + 100));
+    escrow.lock_funds(&depositor, &171, &100, &(start + 600));
+    escrow.lock_funds(&depositor, &172, &100, &(start + 900));
+    escrow.lock_funds(&depositor, &173, &100, &(start + 1_000));
+    escrow.lock_funds(&depositor, &174, &100, &(start + 1_001));
+    escrow.release_funds(&172, &contributor);
+
+    // 170 has expired, 172 is released and 174 is just outside the window.
+    env.ledger().set_timestamp(start + 500);
+    let results = escrow.query_escrows_expiring_within(&500, &0, &10);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().bounty_id, 171);
+    assert_eq!(results.get(1).unwrap().bounty_id, 173);
+
+    // The deadline itself is no longer "expiring".
+    env.ledger().set_timestamp(start + 600);
+    let results = escrow.query_escrows_expiring_within(&500, &0, &10);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().bounty_id, 173);
+    assert_eq!(results.get(1).unwrap().bounty_id, 174);
+}
+
+#[test]
+fn test_query_expiring_within_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+
+    let now = env.ledger().timestamp();
+    for bounty_id in 180..185u64 {
+        escrow.lock_funds(&depositor, &bounty_id, &100, &(now + bounty_id));
+    }
+
+    let first = escrow.query_escrows_expiring_within(&1_000, &0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().bounty_id, 180);
+    let last = escrow.query_escrows_expiring_within(&1_000, &4, &2);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().bounty_id, 184);
+    assert_eq!(escrow.query_escrows_expiring_within(&0, &0, &10).len(), 0);
+}
+
 // ===========================================================================
 // 8. Query by depositor – monitoring view
 // ===========================================================================
@@ -792,7 +858,7 @@ fn test_refund_eligibility_true_with_admin_approval_before_deadline() {
     escrow.lock_funds(&depositor, &183, &1_000, &deadline);
 
     // Admin approves a partial refund before the deadline
-    escrow.approve_refund(&183, &500, &depositor, &RefundMode::Partial);
+    escrow.approve_refund(&183, &500, &depositor, &RefundMode::Partial, &None);
 
     let (can_refund, deadline_passed, remaining, approval) = escrow.get_refund_eligibility(&183);
 
@@ -1505,7 +1571,7 @@ fn test_aggregate_stats_after_partial_refund() {
     escrow.lock_funds(&depositor, &340, &2_000, &deadline);
 
     // Approve and execute a partial refund
-    escrow.approve_refund(&340, &800, &depositor, &RefundMode::Partial);
+    escrow.approve_refund(&340, &800, &depositor, &RefundMode::Partial, &None);
     escrow.refund(&340);
 
     let info = escrow.get_escrow_info(&340);
@@ -1648,3 +1714,154 @@ fn test_query_by_deadline_excludes_no_deadline_from_finite_range() {
     assert_eq!(results.len(), 1);
     assert_eq!(results.get(0).unwrap().bounty_id, 370);
 }
+
+// ===========================================================================
+// 24. Rolling operation volume
+// ===========================================================================
+
+/// Validates that locks are counted in the recent-volume window.
+#[test]
+fn test_volume_in_window_counts_recent_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+
+    assert_eq!(escrow.get_volume_in_window(&3_600), 0);
+
+    let now = env.ledger().timestamp();
+    escrow.lock_funds(&depositor, &380, &100, &(now + 1000));
+    escrow.lock_funds(&depositor, &381, &100, &(now + 1000));
+
+    assert_eq!(escrow.get_volume_in_window(&3_600), 2);
+    assert_eq!(escrow.get_volume_in_window(&86_400), 2);
+}
+
+/// Validates that operations older than the window drop out while lifetime
+/// analytics keep counting them.
+#[test]
+fn test_volume_in_window_excludes_old_buckets() {
+    let env = Env::default();
+    let escrow = create_escrow_contract(&env);
+    let caller = Address::generate(&env);
+
+    env.ledger().set_timestamp(10_000);
+    env.as_contract(&escrow.address, || {
+        for _ in 0..3 {
+            crate::monitoring::track_operation(&env, symbol_short!("test"), caller.clone(), true);
+        }
+    });
+
+    // Two hours later: one more operation in a new bucket.
+    env.ledger().set_timestamp(10_000 + 2 * 3_600);
+    env.as_contract(&escrow.address, || {
+        crate::monitoring::track_operation(&env, symbol_short!("test"), caller.clone(), true);
+    });
+
+    assert_eq!(escrow.get_volume_in_window(&3_600), 1);
+    assert_eq!(escrow.get_volume_in_window(&86_400), 4);
+    assert_eq!(escrow.get_analytics().operation_count, 4);
+}
+
+/// Validates that the number of retained buckets is capped and the oldest
+/// bucket is evicted first.
+#[test]
+fn test_volume_buckets_evict_oldest() {
+    let env = Env::default();
+    let escrow = create_escrow_contract(&env);
+    let caller = Address::generate(&env);
+    let max_buckets = crate::monitoring::MAX_VOLUME_BUCKETS as u64;
+    let bucket = crate::monitoring::VOLUME_BUCKET_SECONDS;
+
+    // One operation per hour for one more hour than the retention cap.
+    for hour in 0..=max_buckets {
+        env.ledger().set_timestamp(hour * bucket);
+        env.as_contract(&escrow.address, || {
+            crate::monitoring::track_operation(&env, symbol_short!("test"), caller.clone(), true);
+        });
+    }
+
+    // A window spanning all history only sees the retained buckets.
+    assert_eq!(escrow.get_volume_in_window(&u64::MAX), max_buckets);
+    assert_eq!(escrow.get_analytics().operation_count, max_buckets + 1);
+}
+
+// ===========================================================================
+// 25. Cached aggregate stats
+// ===========================================================================
+
+#[test]
+fn test_aggregate_stats_cache_matches_full_scan_after_mixed_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+
+    let deadline = 5_000;
+    for id in 1..=6u64 {
+        // Step past the anti-abuse cooldown between locks.
+        env.ledger().set_timestamp(1_000 + id * 100);
+        escrow.lock_funds(&depositor, &id, &(id as i128 * 100), &deadline);
+    }
+    assert_eq!(
+        escrow.get_aggregate_stats_cached(),
+        escrow.get_aggregate_stats()
+    );
+
+    escrow.topup_escrow(&1, &50);
+    escrow.release_funds(&2, &contributor);
+    escrow.partial_release(&3, &contributor, &100);
+    escrow.partial_release(&3, &contributor, &200);
+    escrow.approve_refund(&4, &100, &depositor, &RefundMode::Partial, &None);
+    escrow.refund(&4);
+    escrow.archive_escrow(&2);
+    escrow.purge_archived_escrow(&2);
+
+    env.ledger().set_timestamp(deadline + 1);
+    escrow.refund(&5);
+
+    let cached = escrow.get_aggregate_stats_cached();
+    assert_eq!(cached, escrow.get_aggregate_stats());
+    // 1 and 6 locked, 3 released, 4 partially refunded, 5 refunded, 2 purged.
+    assert_eq!(cached.count_locked, 2);
+    assert_eq!(cached.total_locked, 150 + 600);
+    assert_eq!(cached.count_released, 1);
+    assert_eq!(cached.count_refunded, 2);
+}
+
+#[test]
+fn test_refresh_aggregate_stats_cache_rebuilds_from_scan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &500, &deadline);
+
+    // Drop the cache as if the escrow predated it, then rebuild.
+    env.as_contract(&escrow.address, || {
+        env.storage()
+            .persistent()
+            .remove(&crate::DataKeyExt::AggregateStatsCache);
+    });
+    assert_eq!(escrow.get_aggregate_stats_cached().count_locked, 0);
+
+    let rebuilt = escrow.refresh_aggregate_stats_cache();
+    assert_eq!(rebuilt, escrow.get_aggregate_stats());
+    assert_eq!(escrow.get_aggregate_stats_cached().total_locked, 500);
+}
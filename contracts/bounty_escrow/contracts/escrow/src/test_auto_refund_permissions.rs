@@ -371,3 +371,140 @@ fn test_auto_refund_admin_and_depositor_same_result() {
         initial_balance + (amount * 2)
     );
 }
+
+// ---------------------------------------------------------------------------
+// Keeper sweep: refund_next_expired
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_refund_next_expired_refunds_only_expired_escrows() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 100));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &200, &(now + 5_000));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &300, &(now + 100));
+    let initial_balance = setup.token.balance(&setup.depositor);
+
+    setup.env.ledger().set_timestamp(now + 100);
+    let cursor = setup.escrow.refund_next_expired(&0, &10);
+
+    assert_eq!(cursor, 3);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&3).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), initial_balance + 400);
+}
+
+#[test]
+fn test_refund_next_expired_paginates_with_cursor() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    for bounty_id in 1..=5u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &100, &(now + 10));
+    }
+    setup.env.ledger().set_timestamp(now + 10);
+
+    let cursor = setup.escrow.refund_next_expired(&0, &2);
+    assert_eq!(cursor, 2);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&3).status,
+        EscrowStatus::Locked
+    );
+
+    let cursor = setup.escrow.refund_next_expired(&cursor, &2);
+    assert_eq!(cursor, 4);
+
+    let cursor = setup.escrow.refund_next_expired(&cursor, &2);
+    assert_eq!(cursor, 5);
+    for bounty_id in 1..=5u64 {
+        assert_eq!(
+            setup.escrow.get_escrow_info(&bounty_id).status,
+            EscrowStatus::Refunded
+        );
+    }
+
+    // Cursor past the end is a no-op.
+    assert_eq!(setup.escrow.refund_next_expired(&cursor, &2), 5);
+}
+
+#[test]
+fn test_refund_next_expired_skips_pending_claim() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 10));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &(now + 10));
+    setup
+        .escrow
+        .authorize_claim(&1, &setup.random_user, &DisputeReason::Other);
+
+    setup.env.ledger().set_timestamp(now + 10);
+    setup.escrow.refund_next_expired(&0, &10);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_next_expired_needs_no_caller_auth() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 10));
+
+    setup.env.ledger().set_timestamp(now + 10);
+    setup.env.set_auths(&[]);
+    setup.escrow.refund_next_expired(&0, &10);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_next_expired_respects_refund_pause() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 10));
+    setup.escrow.set_paused(&None, &None, &Some(true), &None);
+
+    setup.env.ledger().set_timestamp(now + 10);
+    assert_eq!(
+        setup.escrow.try_refund_next_expired(&0, &10),
+        Err(Ok(Error::FundsPaused))
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
@@ -53,6 +53,13 @@
 //     - Mixed batch: last item invalid → no partial effect
 //     - Token balances unchanged after failed batch release
 //     - Contributor balances unchanged after failed batch release
+//
+//   BATCH REFUND (best-effort, per-item results — NOT atomic)
+//     - Mixed batch: eligible ids refunded, released / unknown ids reported false
+//     - Approved refund honoured before deadline
+//     - Pending claim blocks only the affected bounty
+//     - Empty / oversized batch rejected (InvalidBatchSize)
+//     - Paused refund operation rejected (FundsPaused)
 // ============================================================
 
 #![cfg(test)]
@@ -63,8 +70,8 @@ use soroban_sdk::{
 };
 
 use crate::{
-    BountyEscrowContract, BountyEscrowContractClient, DataKey, Error, Escrow, EscrowStatus,
-    LockFundsItem, ReleaseFundsItem,
+    BountyEscrowContract, BountyEscrowContractClient, DataKey, DisputeReason, Error, Escrow,
+    EscrowStatus, LockFundsItem, RefundMode, ReleaseFundsItem,
 };
 
 // ---------------------------------------------------------------------------
@@ -1055,4 +1062,93 @@ fn batch_release_reverse_order_input_releases_all_correctly() {
     ctx.assert_escrow_status(10, EscrowStatus::Released);
     ctx.assert_escrow_status(20, EscrowStatus::Released);
     ctx.assert_escrow_status(30, EscrowStatus::Released);
-}
\ No newline at end of file
+}
+
+// ===========================================================================
+// BATCH REFUND — per-item results
+// ===========================================================================
+
+/// Eligible bounties are refunded while ineligible ones are reported and skipped.
+#[test]
+fn batch_refund_reports_per_item_results() {
+    let ctx = TestCtx::new();
+    ctx.lock_n(3);
+    ctx.client.release_funds(&2, &ctx.contributor);
+    let before = ctx.token_balance(&ctx.depositor);
+
+    ctx.env.ledger().set_timestamp(ctx.deadline() + 1);
+    let results = ctx.client.batch_refund(&vec![&ctx.env, 1u64, 2, 99, 3]);
+
+    assert_eq!(
+        results,
+        vec![&ctx.env, (1u64, true), (2, false), (99, false), (3, true)]
+    );
+    ctx.assert_escrow_status(1, EscrowStatus::Refunded);
+    ctx.assert_escrow_status(2, EscrowStatus::Released);
+    ctx.assert_escrow_status(3, EscrowStatus::Refunded);
+    assert_eq!(ctx.token_balance(&ctx.depositor), before + AMOUNT * 2);
+    assert_eq!(ctx.token_balance(&ctx.contract_id), 0);
+}
+
+/// Before the deadline only bounties with an approval are refunded.
+#[test]
+fn batch_refund_honours_approval_before_deadline() {
+    let ctx = TestCtx::new();
+    ctx.lock_n(2);
+    ctx.client
+        .approve_refund(&2, &AMOUNT, &ctx.depositor, &RefundMode::Full, &None);
+
+    let results = ctx.client.batch_refund(&vec![&ctx.env, 1u64, 2]);
+
+    assert_eq!(results, vec![&ctx.env, (1u64, false), (2, true)]);
+    ctx.assert_escrow_status(1, EscrowStatus::Locked);
+    ctx.assert_escrow_status(2, EscrowStatus::Refunded);
+}
+
+/// A pending claim blocks its own bounty without failing the batch.
+#[test]
+fn batch_refund_skips_bounty_with_pending_claim() {
+    let ctx = TestCtx::new();
+    ctx.lock_n(2);
+    ctx.client
+        .authorize_claim(&1, &ctx.contributor, &DisputeReason::Other);
+
+    ctx.env.ledger().set_timestamp(ctx.deadline() + 1);
+    let results = ctx.client.batch_refund(&vec![&ctx.env, 1u64, 2]);
+
+    assert_eq!(results, vec![&ctx.env, (1u64, false), (2, true)]);
+    ctx.assert_escrow_status(1, EscrowStatus::Locked);
+}
+
+#[test]
+fn batch_refund_rejects_invalid_batch_size() {
+    let ctx = TestCtx::new();
+    let empty: Vec<u64> = Vec::new(&ctx.env);
+    assert_eq!(
+        ctx.client.try_batch_refund(&empty),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    let mut oversized: Vec<u64> = Vec::new(&ctx.env);
+    for id in 1..=(MAX_BATCH as u64 + 1) {
+        oversized.push_back(id);
+    }
+    assert_eq!(
+        ctx.client.try_batch_refund(&oversized),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn batch_refund_rejected_when_refund_paused() {
+    let ctx = TestCtx::new();
+    ctx.lock_one(1);
+    ctx.client.set_paused(&None, &None, &Some(true), &None);
+
+    ctx.env.ledger().set_timestamp(ctx.deadline() + 1);
+    assert_eq!(
+        ctx.client.try_batch_refund(&vec![&ctx.env, 1u64]),
+        Err(Ok(Error::FundsPaused))
+    );
+    ctx.assert_escrow_status(1, EscrowStatus::Locked);
+}
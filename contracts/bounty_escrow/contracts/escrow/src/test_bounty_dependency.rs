@@ -0,0 +1,158 @@
+//! Bounty dependency gating tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `release_funds` is held while the dependency is `Pending` or `Failed`
+//! - Every other payout path is held with the same `DependencyNotSatisfied`
+//! - Release goes through once the dependency is `Completed`
+//! - Bounties without a dependency are unaffected
+//! - Dependencies can only be attached to locked escrows
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{CapabilityAction, DependencyStatus, DisputeReason, Error, ReleaseFundsItem};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, String, Vec,
+};
+
+const DEADLINE: u64 = 50_000;
+
+#[test]
+fn test_release_waits_for_completed_dependency() {
+    let s = Fixture::new();
+    let milestone = String::from_str(&s.env, "audit-report");
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_bounty_dependency(&1, &milestone);
+    assert_eq!(
+        s.client.get_bounty_dependency(&1),
+        Some((milestone.clone(), DependencyStatus::Pending))
+    );
+
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DependencyNotSatisfied);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Failed);
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DependencyNotSatisfied);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Completed);
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_every_payout_path_waits_for_dependency() {
+    let s = Fixture::new();
+    let milestone = String::from_str(&s.env, "audit-report");
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client.set_bounty_dependency(&1, &milestone);
+    s.client.set_bounty_dependency(&2, &milestone);
+    let now = s.env.ledger().timestamp();
+    let blocked = Error::DependencyNotSatisfied;
+
+    let res = s.client.try_partial_release(&1, &s.contributor, &400);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+
+    let res = s.client.try_batch_release_funds(&vec![
+        &s.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: s.contributor.clone(),
+        },
+    ]);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+
+    let ticket_id =
+        s.client
+            .issue_claim_ticket(&1, &s.contributor, &300, &(now + 1_000), &false, &false);
+    let res = s.client.try_claim_with_ticket(&ticket_id);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+
+    let delegate = Address::generate(&s.env);
+    let capability_id = s.client.issue_capability(
+        &s.admin,
+        &delegate,
+        &CapabilityAction::Release,
+        &1,
+        &1_000,
+        &(now + 300),
+        &1,
+    );
+    let res =
+        s.client
+            .try_release_with_capability(&1, &s.contributor, &400, &delegate, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+
+    s.client.set_claim_window(&500);
+    s.client
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    let res = s.client.try_claim(&1);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+    let capability_id = s.client.issue_capability(
+        &s.contributor,
+        &delegate,
+        &CapabilityAction::Claim,
+        &1,
+        &1_000,
+        &(now + 300),
+        &1,
+    );
+    let res = s
+        .client
+        .try_claim_with_capability(&1, &delegate, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+
+    let schedule_id =
+        s.client
+            .create_bounty_release_schedule(&2, &400, &(now + 10), &s.contributor);
+    s.env.ledger().set_timestamp(now + 10);
+    let res = s.client.try_release_bounty_schedule(&2, &schedule_id);
+    assert_eq!(res.unwrap_err().unwrap(), blocked);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Completed);
+    s.client.claim(&1);
+    s.client.release_bounty_schedule(&2, &schedule_id);
+    assert_eq!(s.token.balance(&s.contributor), 1_400);
+}
+
+#[test]
+fn test_dependency_shared_across_bounties() {
+    let s = Fixture::new();
+    let milestone = String::from_str(&s.env, "mainnet-launch");
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 2_000, DEADLINE);
+    s.lock(3, 500, DEADLINE);
+    s.client.set_bounty_dependency(&1, &milestone);
+    s.client.set_bounty_dependency(&2, &milestone);
+
+    // Bounty 3 has no dependency and releases as usual.
+    assert_eq!(s.client.get_bounty_dependency(&3), None);
+    s.client.release_funds(&3, &s.contributor);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Completed);
+    s.client.release_funds(&1, &s.contributor);
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 3_500);
+}
+
+#[test]
+fn test_dependency_requires_locked_escrow() {
+    let s = Fixture::new();
+    let milestone = String::from_str(&s.env, "audit-report");
+
+    let res = s.client.try_set_bounty_dependency(&99, &milestone);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.lock(1, 1_000, DEADLINE);
+    s.client.release_funds(&1, &s.contributor);
+    let res = s.client.try_set_bounty_dependency(&1, &milestone);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
@@ -277,6 +277,31 @@ fn test_refund_allows_exact_deadline_boundary() {
     assert_eq!(token_client.balance(&depositor), amount);
 }
 
+#[test]
+fn test_refund_of_anonymous_escrow_requires_resolution() {
+    let (env, client, _contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let bounty_id = 104;
+    let deadline = env.ledger().timestamp() + 500;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &700);
+    let commitment = soroban_sdk::BytesN::from_array(&env, &[7; 32]);
+    client.lock_funds_anonymous(&depositor, &commitment, &bounty_id, &700, &deadline);
+
+    env.ledger().set_timestamp(deadline);
+    let res = client.try_refund(&bounty_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AnonymousRefundRequiresResolution
+    );
+}
+
 #[test]
 fn test_maximum_lock_and_release_path() {
     let (env, client, _contract_id) = create_test_env();
@@ -1419,6 +1444,99 @@ fn test_anti_abuse_whitelisted_address_bypasses_checks() {
     assert_eq!(client.get_escrow_count(), 5);
 }
 
+/// With the admin cooldown exemption enabled, rapid admin locks succeed while a
+/// non-admin depositor is still held to the cooldown.
+#[test]
+fn test_anti_abuse_admin_cooldown_exemption() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &10, &100);
+    client.set_admin_cooldown_exempt(&true);
+    assert!(client.get_anti_abuse_config().exempt_admin_cooldown);
+
+    token_admin_client.mint(&admin, &10_000);
+    token_admin_client.mint(&depositor, &10_000);
+
+    // Admin operations in the same ledger second are not cooled down.
+    for i in 1..=3 {
+        client.lock_funds(&admin, &i, &100, &deadline);
+    }
+    assert_eq!(client.get_escrow_count(), 3);
+
+    // A non-admin is still subject to the cooldown.
+    client.lock_funds(&depositor, &10, &100, &deadline);
+    let second = client.try_lock_funds(&depositor, &11, &100, &deadline);
+    assert!(second.is_err());
+}
+
+/// The exemption lives outside the stored config: a config record in the
+/// original three-field layout still decodes, and updating it keeps the flag.
+#[test]
+fn test_anti_abuse_admin_cooldown_exemption_survives_config_update() {
+    let (env, client, contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    env.as_contract(&contract_id, || {
+        crate::anti_abuse::set_config(
+            &env,
+            crate::anti_abuse::AntiAbuseConfig {
+                window_size: 600,
+                max_operations: 5,
+                cooldown_period: 30,
+            },
+        );
+    });
+    let view = client.get_anti_abuse_config();
+    assert_eq!(view.window_size, 600);
+    assert!(!view.exempt_admin_cooldown);
+
+    client.set_admin_cooldown_exempt(&true);
+    client.update_anti_abuse_config(&3600, &10, &100);
+    let view = client.get_anti_abuse_config();
+    assert_eq!(view.max_operations, 10);
+    assert!(view.exempt_admin_cooldown);
+}
+
+/// The exemption only skips the cooldown; the per-window cap still applies to the admin.
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_anti_abuse_admin_cooldown_exemption_keeps_window_cap() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &2, &60);
+    client.set_admin_cooldown_exempt(&true);
+
+    token_admin_client.mint(&admin, &10_000);
+
+    client.lock_funds(&admin, &1, &100, &deadline);
+    client.lock_funds(&admin, &2, &100, &deadline);
+    client.lock_funds(&admin, &3, &100, &deadline);
+}
+
 // =============================================================================
 // Admin and config updates (Issue #465)
 // =============================================================================
@@ -1528,3 +1646,237 @@ fn test_emergency_withdraw() {
     // Verify pause state still true
     assert_eq!(is_paused(&client), true);
 }
+
+// ========================================================================
+// Foreign Token Rescue Tests
+// ========================================================================
+
+#[test]
+fn test_rescue_foreign_token_transfers_full_balance() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    let (stray_address, stray_client, stray_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    token_admin.mint(&depositor, &1_000);
+    client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 1_000));
+
+    // Someone sends an unrelated token to the contract by mistake.
+    stray_admin.mint(&contract_id, &750);
+
+    let rescued = client.rescue_foreign_token(&stray_address, &recipient);
+    assert_eq!(rescued, 750);
+    assert_eq!(stray_client.balance(&recipient), 750);
+    assert_eq!(stray_client.balance(&contract_id), 0);
+
+    // Escrowed funds are untouched.
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_rescue_foreign_token_rejects_escrow_token() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    token_admin.mint(&depositor, &1_000);
+    client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 1_000));
+
+    let result = client.try_rescue_foreign_token(&token_address, &recipient);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAssetId)));
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+}
+
+#[test]
+fn test_rescue_foreign_token_with_zero_balance_returns_zero() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+    let (stray_address, _stray_client, _stray_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    assert_eq!(client.rescue_foreign_token(&stray_address, &recipient), 0);
+}
+
+#[test]
+fn test_lock_funds_memo_stores_and_emits_memo() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, _token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+
+    let memo = soroban_sdk::String::from_str(&env, "Q1 frontend bounty");
+    client.lock_funds_memo(&depositor, &1, &1_000, &1_000, &Some(memo.clone()));
+
+    assert_eq!(client.get_escrow_memo(&1), Some(memo.clone()));
+    assert_eq!(client.get_escrow_info(&1).amount, 1_000);
+
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            Symbol::try_from_val(&env, &topics.get(0).unwrap())
+                .map(|s| s == Symbol::new(&env, "f_lock"))
+                .unwrap_or(false)
+        })
+        .unwrap();
+    let payload: Map<Symbol, Val> = Map::try_from_val(&env, &data).unwrap();
+    let emitted: Option<soroban_sdk::String> = payload
+        .get(Symbol::new(&env, "memo"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(emitted, Some(memo));
+}
+
+#[test]
+fn test_lock_funds_without_memo_has_none() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, _token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &2_000);
+
+    client.lock_funds(&depositor, &1, &1_000, &1_000);
+    client.lock_funds_memo(&depositor, &2, &1_000, &1_000, &None);
+
+    assert_eq!(client.get_escrow_memo(&1), None);
+    assert_eq!(client.get_escrow_memo(&2), None);
+}
+
+#[test]
+fn test_lock_funds_memo_rejects_overlong_memo() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+
+    let long = soroban_sdk::String::from_str(&env, &"x".repeat(129));
+    let result = client.try_lock_funds_memo(&depositor, &1, &1_000, &1_000, &Some(long));
+    assert_eq!(result, Err(Ok(ContractError::MemoTooLong)));
+    assert_eq!(token_client.balance(&depositor), 1_000);
+    assert!(client.try_get_escrow_info(&1).is_err());
+}
+
+fn refund_triggers(env: &Env) -> soroban_sdk::Vec<crate::events::RefundTrigger> {
+    let mut triggers = soroban_sdk::Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let is_refund = Symbol::try_from_val(env, &topics.get(0).unwrap())
+            .map(|s| s == Symbol::new(env, "f_ref"))
+            .unwrap_or(false);
+        if is_refund {
+            let payload: Map<Symbol, Val> = Map::try_from_val(env, &data).unwrap();
+            triggers.push_back(
+                payload
+                    .get(Symbol::new(env, "trigger"))
+                    .unwrap()
+                    .into_val(env),
+            );
+        }
+    }
+    triggers
+}
+
+#[test]
+fn test_refund_after_deadline_reports_deadline_trigger() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, _token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+
+    client.lock_funds(&depositor, &1, &1_000, &1_000);
+    env.ledger().set_timestamp(1_001);
+    client.refund(&1);
+
+    assert_eq!(
+        refund_triggers(&env),
+        soroban_sdk::vec![&env, crate::events::RefundTrigger::DeadlineExpiry]
+    );
+}
+
+#[test]
+fn test_approved_refund_reports_admin_approval_trigger() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+
+    client.lock_funds(&depositor, &1, &1_000, &1_000);
+    client.approve_refund(&1, &400, &depositor, &crate::RefundMode::Partial, &None);
+    client.refund(&1);
+
+    assert_eq!(token_client.balance(&depositor), 400);
+    assert_eq!(
+        refund_triggers(&env),
+        soroban_sdk::vec![&env, crate::events::RefundTrigger::AdminApproval]
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_reports_emergency_trigger_per_escrow() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let target = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &3_500);
+
+    client.lock_funds(&depositor, &1, &1_000, &1_000);
+    client.lock_funds(&depositor, &2, &2_000, &1_000);
+    client.lock_funds(&depositor, &3, &500, &1_000);
+    client.release_funds(&3, &contributor);
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&target);
+
+    assert_eq!(token_client.balance(&target), 3_000);
+    // Released escrow #3 has nothing left to sweep, so only #1 and #2 report.
+    assert_eq!(
+        refund_triggers(&env),
+        soroban_sdk::vec![
+            &env,
+            crate::events::RefundTrigger::EmergencyWithdraw,
+            crate::events::RefundTrigger::EmergencyWithdraw,
+        ]
+    );
+}
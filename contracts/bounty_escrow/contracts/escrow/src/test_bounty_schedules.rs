@@ -0,0 +1,259 @@
+//! Bounty release schedule tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - Schedules cannot add up to more than the escrow's remaining amount
+//! - A schedule pays out only once, and only after its timestamp
+//! - Releasing the last scheduled amount marks the escrow `Released`
+//! - Scheduled amounts are held back from every other payout and refund path,
+//!   which all report `InsufficientFunds`
+//! - Cancelling a schedule frees its reservation
+//! - Input validation on creation
+//! - An emergency withdrawal clears an escrow's schedules and reservation
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{DisputeReason, Error, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const DEADLINE: u64 = 50_000;
+
+struct Suite {
+    fx: Fixture,
+    alice: Address,
+    bob: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        Self {
+            alice: Address::generate(&fx.env),
+            bob: Address::generate(&fx.env),
+            fx,
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        self.client
+            .lock_funds(&self.depositor, &bounty_id, &amount, &DEADLINE);
+    }
+}
+
+#[test]
+fn test_schedules_release_in_milestones() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+
+    let first = s
+        .client
+        .create_bounty_release_schedule(&1, &400, &2_000, &s.alice);
+    let second = s
+        .client
+        .create_bounty_release_schedule(&1, &600, &3_000, &s.bob);
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(s.count_topic(symbol_short!("bs_new")), 2);
+
+    let res = s.client.try_release_bounty_schedule(&1, &first);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+
+    s.env.ledger().set_timestamp(2_000);
+    s.client.release_bounty_schedule(&1, &first);
+    assert_eq!(s.token.balance(&s.alice), 400);
+    assert!(s.client.get_bounty_release_schedule(&1, &first).released);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+
+    let res = s.client.try_release_bounty_schedule(&1, &first);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(s.token.balance(&s.alice), 400);
+
+    s.env.ledger().set_timestamp(3_000);
+    s.client.release_bounty_schedule(&1, &second);
+    assert_eq!(s.token.balance(&s.bob), 600);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(s.count_topic(symbol_short!("bs_rel")), 2);
+}
+
+#[test]
+fn test_schedules_cannot_exceed_remaining_amount() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    s.client
+        .create_bounty_release_schedule(&1, &700, &2_000, &s.alice);
+
+    let res = s
+        .client
+        .try_create_bounty_release_schedule(&1, &301, &2_000, &s.bob);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    s.client
+        .create_bounty_release_schedule(&1, &300, &2_000, &s.bob);
+
+    // Releasing a schedule frees its share of the budget only by paying it out.
+    s.env.ledger().set_timestamp(2_000);
+    s.client.release_bounty_schedule(&1, &1);
+    let res = s
+        .client
+        .try_create_bounty_release_schedule(&1, &1, &5_000, &s.bob);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+}
+
+#[test]
+fn test_create_schedule_validates_input() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+
+    let res = s
+        .client
+        .try_create_bounty_release_schedule(&1, &0, &2_000, &s.alice);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    let res = s
+        .client
+        .try_create_bounty_release_schedule(&1, &100, &1_000, &s.alice);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidDeadline);
+    let res = s
+        .client
+        .try_create_bounty_release_schedule(&9, &100, &2_000, &s.alice);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s.client.try_release_bounty_schedule(&1, &1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_scheduled_amount_is_held_back_from_other_payouts() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    s.client
+        .create_bounty_release_schedule(&1, &600, &2_000, &s.alice);
+
+    let res = s.client.try_release_funds(&1, &s.bob);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    let res = s.client.try_partial_release(&1, &s.bob, &401);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    s.client.partial_release(&1, &s.bob, &400);
+    assert_eq!(s.token.balance(&s.bob), 400);
+
+    // The schedule still pays out in full.
+    s.env.ledger().set_timestamp(2_000);
+    s.client.release_bounty_schedule(&1, &1);
+    assert_eq!(s.token.balance(&s.alice), 600);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_scheduled_amount_blocks_refund_and_claim() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    s.client
+        .create_bounty_release_schedule(&1, &600, &60_000, &s.alice);
+
+    s.client
+        .approve_refund(&1, &401, &s.depositor, &RefundMode::Partial, &None);
+    let res = s.client.try_refund(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    let preview = s.client.dry_run_refund(&1);
+    assert_eq!(preview.error_code, Error::InsufficientFunds as u32);
+
+    s.client.authorize_claim(&1, &s.bob, &DisputeReason::Other);
+    let res = s.client.try_claim(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    assert_eq!(s.token.balance(&s.bob), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_scheduled_amount_blocks_merge_split_and_holdback() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    s.advance(100);
+    s.lock(2, 1_000);
+    s.client
+        .create_bounty_release_schedule(&1, &600, &60_000, &s.alice);
+
+    let res = s.client.try_merge_escrows(&1, &2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    let res = s.client.try_split_escrow(&s.depositor, &1, &3, &401);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    let res = s
+        .client
+        .try_release_with_holdback(&1, &s.bob, &2_000, &3_600);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_cancel_schedule_frees_reservation() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    let id = s
+        .client
+        .create_bounty_release_schedule(&1, &600, &2_000, &s.alice);
+
+    s.client.cancel_bounty_release_schedule(&1, &id);
+    assert_eq!(s.count_topic(symbol_short!("bs_cncl")), 1);
+    let res = s.client.try_get_bounty_release_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s.client.try_cancel_bounty_release_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.env.ledger().set_timestamp(2_000);
+    let res = s.client.try_release_bounty_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    s.client.release_funds(&1, &s.bob);
+    assert_eq!(s.token.balance(&s.bob), 1_000);
+}
+
+#[test]
+fn test_cancel_released_schedule_fails() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    let id = s
+        .client
+        .create_bounty_release_schedule(&1, &400, &2_000, &s.alice);
+    s.env.ledger().set_timestamp(2_000);
+    s.client.release_bounty_schedule(&1, &id);
+
+    let res = s.client.try_cancel_bounty_release_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 600);
+}
+
+#[test]
+fn test_emergency_withdraw_clears_schedules() {
+    let s = Suite::new();
+    s.lock(1, 1_000);
+    let id = s
+        .client
+        .create_bounty_release_schedule(&1, &600, &2_000, &s.alice);
+    let target = Address::generate(&s.env);
+    s.client.set_paused(&Some(true), &None, &None, &None);
+    s.client.emergency_withdraw(&target);
+    let res = s.client.try_get_bounty_release_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    // A new escrow under the same id starts without the old reservation.
+    s.client.set_paused(&Some(false), &None, &None, &None);
+    s.advance(100);
+    s.lock(1, 1_000);
+    s.client.release_funds(&1, &s.bob);
+    assert_eq!(s.token.balance(&s.bob), 1_000);
+    s.env.ledger().set_timestamp(2_000);
+    let res = s.client.try_release_bounty_schedule(&1, &id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
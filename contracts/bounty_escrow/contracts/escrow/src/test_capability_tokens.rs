@@ -6,7 +6,7 @@ use crate::{
 };
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger},
-    token, Address, Env, Symbol, TryFromVal,
+    token, vec, Address, Env, Symbol, TryFromVal, Vec,
 };
 
 struct CapabilitySetup {
@@ -255,3 +255,443 @@ fn test_capability_cannot_exceed_owner_authority() {
     );
     assert_eq!(wrong_claim_owner.unwrap_err().unwrap(), Error::Unauthorized);
 }
+
+#[test]
+fn test_simulate_capability_use_matches_consumption() {
+    let setup = CapabilitySetup::new();
+    setup.lock(5, 1_000);
+
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &5,
+        &600,
+        &expiry,
+        &1,
+    );
+
+    let ok = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &5,
+        &400,
+        &CapabilityAction::Release,
+    );
+    assert!(ok.success);
+    assert_eq!(ok.error_code, 0);
+    assert_eq!(ok.amount, 400);
+    assert_eq!(ok.remaining_amount, 200);
+
+    // Simulation leaves the capability untouched.
+    let unchanged = setup.client.get_capability(&capability_id);
+    assert_eq!(unchanged.remaining_amount, 600);
+    assert_eq!(unchanged.remaining_uses, 1);
+
+    let over = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &5,
+        &601,
+        &CapabilityAction::Release,
+    );
+    assert!(!over.success);
+    assert_eq!(over.error_code, Error::CapabilityAmountExceeded as u32);
+
+    let wrong_action = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &5,
+        &100,
+        &CapabilityAction::Refund,
+    );
+    assert_eq!(
+        wrong_action.error_code,
+        Error::CapabilityActionMismatch as u32
+    );
+
+    let wrong_holder = setup.client.simulate_capability_use(
+        &setup.recipient,
+        &capability_id,
+        &5,
+        &100,
+        &CapabilityAction::Release,
+    );
+    assert_eq!(wrong_holder.error_code, Error::Unauthorized as u32);
+
+    setup.client.release_with_capability(
+        &5,
+        &setup.contributor,
+        &100,
+        &setup.delegate,
+        &capability_id,
+    );
+    let exhausted = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &5,
+        &100,
+        &CapabilityAction::Release,
+    );
+    assert_eq!(exhausted.error_code, Error::CapabilityUsesExhausted as u32);
+
+    setup.env.ledger().set_timestamp(expiry + 1);
+    let missing = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &999,
+        &5,
+        &100,
+        &CapabilityAction::Release,
+    );
+    assert!(!missing.success);
+}
+
+#[test]
+fn test_simulate_capability_use_rechecks_owner_authority() {
+    let setup = CapabilitySetup::new();
+    setup.lock(6, 1_000);
+
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &6,
+        &800,
+        &expiry,
+        &2,
+    );
+
+    // Owner's authority shrinks once part of the escrow is paid out.
+    setup.client.partial_release(&6, &setup.contributor, &500);
+
+    let res = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &6,
+        &600,
+        &CapabilityAction::Release,
+    );
+    assert!(!res.success);
+    assert_eq!(res.error_code, Error::CapabilityExceedsAuthority as u32);
+}
+
+#[test]
+fn test_capability_usage_log_records_each_use() {
+    let setup = CapabilitySetup::new();
+    setup.lock(1, 1_000);
+
+    let expiry = setup.env.ledger().timestamp() + 1_000;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &1,
+        &600,
+        &expiry,
+        &3,
+    );
+    assert_eq!(setup.client.get_capability_usage(&capability_id).len(), 0);
+
+    let first_at = setup.env.ledger().timestamp();
+    setup.client.release_with_capability(
+        &1,
+        &setup.contributor,
+        &150,
+        &setup.delegate,
+        &capability_id,
+    );
+    setup.env.ledger().set_timestamp(first_at + 60);
+    setup.client.release_with_capability(
+        &1,
+        &setup.contributor,
+        &250,
+        &setup.delegate,
+        &capability_id,
+    );
+
+    let usage = setup.client.get_capability_usage(&capability_id);
+    assert_eq!(usage.len(), 2);
+    let first = usage.get(0).unwrap();
+    assert_eq!(
+        (first.bounty_id, first.amount, first.timestamp),
+        (1, 150, first_at)
+    );
+    let second = usage.get(1).unwrap();
+    assert_eq!(
+        (second.bounty_id, second.amount, second.timestamp),
+        (1, 250, first_at + 60)
+    );
+}
+
+#[test]
+fn test_capability_usage_log_is_bounded() {
+    let setup = CapabilitySetup::new();
+    setup.lock(1, 2_000);
+
+    let expiry = setup.env.ledger().timestamp() + 1_000;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &1,
+        &2_000,
+        &expiry,
+        &60,
+    );
+    for i in 1..=55i128 {
+        setup.client.release_with_capability(
+            &1,
+            &setup.contributor,
+            &i,
+            &setup.delegate,
+            &capability_id,
+        );
+    }
+
+    let usage = setup.client.get_capability_usage(&capability_id);
+    assert_eq!(usage.len(), 50);
+    // The five oldest uses were dropped.
+    assert_eq!(usage.get(0).unwrap().amount, 6);
+    assert_eq!(usage.get(49).unwrap().amount, 55);
+}
+
+#[test]
+fn test_capability_auto_revoked_after_full_release() {
+    let setup = CapabilitySetup::new();
+    setup.lock(6, 1_000);
+
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &6,
+        &1_000,
+        &expiry,
+        &3,
+    );
+
+    setup.client.release_with_capability(
+        &6,
+        &setup.contributor,
+        &400,
+        &setup.delegate,
+        &capability_id,
+    );
+    assert!(!setup.client.get_capability(&capability_id).revoked);
+    assert!(!has_event_topic(&setup.env, "cap_arev"));
+
+    setup.client.release_with_capability(
+        &6,
+        &setup.contributor,
+        &600,
+        &setup.delegate,
+        &capability_id,
+    );
+    assert!(has_event_topic(&setup.env, "cap_arev"));
+    assert_eq!(
+        setup.client.get_escrow_info(&6).status,
+        EscrowStatus::Released
+    );
+
+    let capability = setup.client.get_capability(&capability_id);
+    assert!(capability.revoked);
+    assert_eq!(capability.remaining_uses, 1);
+
+    let next = setup.client.simulate_capability_use(
+        &setup.delegate,
+        &capability_id,
+        &6,
+        &1,
+        &CapabilityAction::Release,
+    );
+    assert!(!next.success);
+    assert_eq!(next.error_code, Error::CapabilityRevoked as u32);
+}
+
+#[test]
+fn test_capability_auto_revoked_after_full_refund() {
+    let setup = CapabilitySetup::new();
+    setup.lock(7, 500);
+
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Refund,
+        &7,
+        &500,
+        &expiry,
+        &2,
+    );
+
+    setup
+        .client
+        .refund_with_capability(&7, &500, &setup.delegate, &capability_id);
+    assert!(has_event_topic(&setup.env, "cap_arev"));
+    assert_eq!(
+        setup.client.get_escrow_info(&7).status,
+        EscrowStatus::Refunded
+    );
+    assert!(setup.client.get_capability(&capability_id).revoked);
+}
+
+#[test]
+fn test_batch_extend_capabilities_skips_foreign_revoked_and_later() {
+    let setup = CapabilitySetup::new();
+    setup.lock(8, 1_000);
+
+    let now = setup.env.ledger().timestamp();
+    let issue = |owner: &Address, expiry: u64| {
+        setup.client.issue_capability(
+            owner,
+            &setup.delegate,
+            &CapabilityAction::Refund,
+            &8,
+            &100,
+            &expiry,
+            &1,
+        )
+    };
+    let short_id = issue(&setup.admin, now + 10);
+    let revoked_id = issue(&setup.admin, now + 10);
+    let later_id = issue(&setup.admin, now + 5_000);
+    setup.client.revoke_capability(&setup.admin, &revoked_id);
+
+    let ids = vec![&setup.env, short_id, revoked_id, later_id, 999];
+    let extended = setup
+        .client
+        .batch_extend_capabilities(&setup.admin, &ids, &(now + 1_000));
+    assert_eq!(extended, 1);
+    assert!(has_event_topic(&setup.env, "cap_ext"));
+
+    assert_eq!(setup.client.get_capability(&short_id).expiry, now + 1_000);
+    assert_eq!(setup.client.get_capability(&revoked_id).expiry, now + 10);
+    assert_eq!(setup.client.get_capability(&later_id).expiry, now + 5_000);
+
+    // A caller that does not own the capabilities extends nothing.
+    let foreign = setup.client.batch_extend_capabilities(
+        &setup.depositor,
+        &vec![&setup.env, later_id],
+        &(now + 9_000),
+    );
+    assert_eq!(foreign, 0);
+    assert_eq!(setup.client.get_capability(&later_id).expiry, now + 5_000);
+
+    // The extended capability is usable past its original expiry.
+    setup.env.ledger().set_timestamp(now + 500);
+    setup
+        .client
+        .refund_with_capability(&8, &100, &setup.delegate, &short_id);
+}
+
+#[test]
+fn test_batch_extend_capabilities_rejects_past_expiry_and_bad_batch() {
+    let setup = CapabilitySetup::new();
+    setup.lock(9, 1_000);
+    setup.env.ledger().set_timestamp(1_000);
+
+    let cap_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Refund,
+        &9,
+        &100,
+        &1_100,
+        &1,
+    );
+    let ids = vec![&setup.env, cap_id];
+
+    let res = setup
+        .client
+        .try_batch_extend_capabilities(&setup.admin, &ids, &1_000);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidDeadline);
+
+    let res =
+        setup
+            .client
+            .try_batch_extend_capabilities(&setup.admin, &Vec::new(&setup.env), &2_000);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    assert_eq!(setup.client.get_capability(&cap_id).expiry, 1_100);
+}
+
+#[test]
+fn test_release_capability_restricted_to_allowed_recipients() {
+    let setup = CapabilitySetup::new();
+    setup.lock(10, 1_000);
+
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &setup.delegate,
+        &CapabilityAction::Release,
+        &10,
+        &600,
+        &expiry,
+        &2,
+    );
+    assert!(setup
+        .client
+        .get_capability_recipients(&capability_id)
+        .is_empty());
+
+    // Only the issuing owner may restrict the capability.
+    let not_owner = setup.client.try_set_capability_recipients(
+        &setup.delegate,
+        &capability_id,
+        &vec![&setup.env, setup.recipient.clone()],
+    );
+    assert_eq!(not_owner.unwrap_err().unwrap(), Error::Unauthorized);
+
+    setup.client.set_capability_recipients(
+        &setup.admin,
+        &capability_id,
+        &vec![&setup.env, setup.contributor.clone()],
+    );
+    assert_eq!(
+        setup.client.get_capability_recipients(&capability_id),
+        vec![&setup.env, setup.contributor.clone()]
+    );
+
+    let denied = setup.client.try_release_with_capability(
+        &10,
+        &setup.recipient,
+        &100,
+        &setup.delegate,
+        &capability_id,
+    );
+    assert_eq!(denied.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+    // The rejected attempt consumed nothing.
+    let unused = setup.client.get_capability(&capability_id);
+    assert_eq!(unused.remaining_uses, 2);
+    assert_eq!(unused.remaining_amount, 600);
+    assert_eq!(setup.token_client.balance(&setup.recipient), 0);
+
+    setup.client.release_with_capability(
+        &10,
+        &setup.contributor,
+        &100,
+        &setup.delegate,
+        &capability_id,
+    );
+    assert_eq!(setup.token_client.balance(&setup.contributor), 100);
+    assert_eq!(
+        setup.client.get_capability(&capability_id).remaining_uses,
+        1
+    );
+
+    // Clearing the list lifts the restriction.
+    setup
+        .client
+        .set_capability_recipients(&setup.admin, &capability_id, &Vec::new(&setup.env));
+    setup.client.release_with_capability(
+        &10,
+        &setup.recipient,
+        &100,
+        &setup.delegate,
+        &capability_id,
+    );
+    assert_eq!(setup.token_client.balance(&setup.recipient), 100);
+}
@@ -0,0 +1,152 @@
+//! Error-rate circuit breaker tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - The breaker pauses lock, release and refund once the window's error rate
+//!   exceeds the limit after `min_samples` operations
+//! - No trip below `min_samples` or at/under the limit
+//! - Paused operations stay paused until the admin resumes them
+//! - Config validation and disabling
+//! - Admin-reported failures count toward the window; failed calls keep
+//!   their error and leave no sample
+
+#![cfg(test)]
+
+use crate::events::CircuitBreakerTripped;
+use crate::test_support::Fixture;
+use crate::{monitoring, Error};
+use soroban_sdk::symbol_short;
+
+/// Feed `track_operation` directly, as the entrypoints do.
+fn track(s: &Fixture, outcomes: &[bool]) {
+    s.env.as_contract(&s.client.address, || {
+        for success in outcomes {
+            monitoring::track_operation(
+                &s.env,
+                symbol_short!("release"),
+                s.depositor.clone(),
+                *success,
+            );
+        }
+    });
+}
+
+fn trips(s: &Fixture) -> u32 {
+    s.count_topic(symbol_short!("cb_trip"))
+}
+
+fn last_trip(s: &Fixture) -> CircuitBreakerTripped {
+    s.last_event(symbol_short!("cb_trip")).unwrap()
+}
+
+#[test]
+fn test_breaker_trips_after_min_samples() {
+    let s = Fixture::new();
+    s.client.set_error_rate_breaker(&5_000, &4);
+
+    // Three straight failures, but the window is still below min_samples.
+    track(&s, &[false, false, false]);
+    assert_eq!(trips(&s), 0);
+    assert!(!s.client.get_pause_flags().lock_paused);
+
+    track(&s, &[true]);
+    assert_eq!(trips(&s), 1);
+    let flags = s.client.get_pause_flags();
+    assert!(flags.lock_paused && flags.release_paused && flags.refund_paused);
+    assert!(flags.pause_reason.is_some());
+
+    let event = last_trip(&s);
+    assert_eq!(event.operation, symbol_short!("release"));
+    assert_eq!(event.samples, 4);
+    assert_eq!(event.errors, 3);
+    assert_eq!(event.error_rate, 7_500);
+    assert_eq!(event.max_rate_bps, 5_000);
+
+    let breaker = s.client.get_error_rate_breaker().unwrap();
+    assert_eq!(breaker.samples, 0);
+    assert_eq!(breaker.errors, 0);
+}
+
+#[test]
+fn test_breaker_holds_at_or_under_limit() {
+    let s = Fixture::new();
+    s.client.set_error_rate_breaker(&5_000, &4);
+
+    track(&s, &[false, true, false, true, true, true]);
+    assert_eq!(trips(&s), 0);
+    assert!(!s.client.get_pause_flags().release_paused);
+    assert_eq!(s.client.get_error_rate_breaker().unwrap().samples, 6);
+}
+
+#[test]
+fn test_breaker_requires_manual_resume() {
+    let s = Fixture::new();
+    s.client.set_error_rate_breaker(&1_000, &2);
+    track(&s, &[false, false]);
+    assert_eq!(trips(&s), 1);
+
+    // Successes while paused do not lift the pause.
+    track(&s, &[true, true, true]);
+    let res = s.client.try_lock_funds(&s.depositor, &1, &1_000, &10_000);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsPaused);
+
+    s.client
+        .set_paused(&Some(false), &Some(false), &Some(false), &None);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &10_000);
+    assert!(!s.client.get_pause_flags().lock_paused);
+}
+
+#[test]
+fn test_breaker_config_validation_and_disable() {
+    let s = Fixture::new();
+    let res = s.client.try_set_error_rate_breaker(&10_001, &1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert!(s.client.get_error_rate_breaker().is_none());
+
+    s.client.set_error_rate_breaker(&1_000, &1);
+    s.client.set_error_rate_breaker(&0, &1);
+    assert!(s.client.get_error_rate_breaker().is_none());
+
+    track(&s, &[false, false]);
+    assert_eq!(trips(&s), 0);
+}
+
+#[test]
+fn test_reported_failures_trip_the_breaker() {
+    let s = Fixture::new();
+    s.client.set_error_rate_breaker(&5_000, &4);
+    track(&s, &[true]);
+
+    s.client
+        .report_operation_failures(&symbol_short!("lock"), &2);
+    assert_eq!(trips(&s), 0);
+    let breaker = s.client.get_error_rate_breaker().unwrap();
+    assert_eq!((breaker.samples, breaker.errors), (3, 2));
+
+    s.client
+        .report_operation_failures(&symbol_short!("lock"), &1);
+    assert_eq!(trips(&s), 1);
+    let event = last_trip(&s);
+    assert_eq!(event.operation, symbol_short!("lock"));
+    assert_eq!((event.samples, event.errors), (4, 3));
+    assert!(s.client.get_pause_flags().lock_paused);
+
+    let res = s
+        .client
+        .try_report_operation_failures(&symbol_short!("lock"), &0);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+}
+
+#[test]
+fn test_failed_calls_keep_their_error_with_breaker() {
+    let s = Fixture::new();
+    s.client.set_error_rate_breaker(&1_000, &1);
+
+    let res = s.client.try_release_funds(&7, &s.depositor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s.client.try_lock_funds(&s.depositor, &1, &0, &10_000);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+
+    // The failed calls rolled back their samples, so nothing tripped.
+    assert_eq!(s.client.get_error_rate_breaker().unwrap().samples, 0);
+    assert!(!s.client.get_pause_flags().lock_paused);
+}
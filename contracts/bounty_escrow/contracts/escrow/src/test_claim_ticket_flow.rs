@@ -0,0 +1,383 @@
+//! Claim ticket redemption tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `claim_with_ticket` pays the beneficiary and marks the ticket used
+//! - Expired, reused and over-budget tickets are rejected
+//! - Tickets issued with `requires_confirmation` need `confirm_claim_ticket` first
+//! - `claim_with_ticket_to` redirects payouts only for `redirectable` tickets
+//! - `reap_expired_tickets` retires expired tickets without touching escrows
+//! - `get_beneficiary_tickets` / `get_beneficiary_tickets_detailed` page through
+//!   a beneficiary's tickets
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{
+    events::{TicketClaimed, TicketReaped},
+    DataKey, Error, EscrowStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, IntoVal, Symbol, Vec,
+};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+fn issue(s: &Fixture, bounty_id: u64, amount: i128, requires_confirmation: bool) -> u64 {
+    issue_with(s, bounty_id, amount, requires_confirmation, false)
+}
+
+fn issue_with(
+    s: &Fixture,
+    bounty_id: u64,
+    amount: i128,
+    requires_confirmation: bool,
+    redirectable: bool,
+) -> u64 {
+    let expires_at = s.env.ledger().timestamp() + 1_000;
+    s.client.issue_claim_ticket(
+        &bounty_id,
+        &s.contributor,
+        &amount,
+        &expires_at,
+        &requires_confirmation,
+        &redirectable,
+    )
+}
+
+fn beneficiary_tickets(s: &Fixture) -> Vec<u64> {
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryTickets(s.contributor.clone()))
+            .unwrap_or(Vec::new(&s.env))
+    })
+}
+
+fn reaped_events(s: &Fixture) -> Vec<TicketReaped> {
+    let mut reaped = Vec::new(&s.env);
+    for data in s.events_named(Symbol::new(&s.env, "ticket_r")) {
+        reaped.push_back(data.into_val(&s.env));
+    }
+    reaped
+}
+
+// ── redemption ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_claim_with_ticket_pays_beneficiary() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 400, false);
+
+    s.client.claim_with_ticket(&ticket_id);
+
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+
+    let again = s.client.try_claim_with_ticket(&ticket_id);
+    assert_eq!(again, Err(Ok(Error::TicketAlreadyUsed)));
+}
+
+#[test]
+fn test_claim_with_ticket_full_amount_releases_escrow() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 1_000, false);
+
+    s.client.claim_with_ticket(&ticket_id);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_claim_with_ticket_rejects_expired_ticket() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 400, false);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_000);
+    let res = s.client.try_claim_with_ticket(&ticket_id);
+    assert_eq!(res, Err(Ok(Error::TicketExpired)));
+}
+
+#[test]
+fn test_claim_with_ticket_rejects_amount_above_remaining() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let first = issue(&s, 1, 700, false);
+    let second = issue(&s, 1, 700, false);
+
+    s.client.claim_with_ticket(&first);
+    let res = s.client.try_claim_with_ticket(&second);
+    assert_eq!(res, Err(Ok(Error::InsufficientFunds)));
+}
+
+#[test]
+fn test_claim_with_unknown_ticket_fails() {
+    let s = Fixture::new();
+    let res = s.client.try_claim_with_ticket(&99);
+    assert_eq!(res, Err(Ok(Error::TicketNotFound)));
+}
+
+// ── confirmation ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_confirmation_required_ticket_blocks_until_confirmed() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 500, true);
+
+    let res = s.client.try_claim_with_ticket(&ticket_id);
+    assert_eq!(res, Err(Ok(Error::TicketNotConfirmed)));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.client.confirm_claim_ticket(&ticket_id);
+    assert!(s.client.is_claim_ticket_confirmed(&ticket_id));
+
+    s.client.claim_with_ticket(&ticket_id);
+    assert_eq!(s.token.balance(&s.contributor), 500);
+}
+
+#[test]
+fn test_confirm_claim_ticket_rejects_unknown_and_used_tickets() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+
+    let res = s.client.try_confirm_claim_ticket(&99);
+    assert_eq!(res, Err(Ok(Error::TicketNotFound)));
+
+    let ticket_id = issue(&s, 1, 500, false);
+    s.client.claim_with_ticket(&ticket_id);
+    let res = s.client.try_confirm_claim_ticket(&ticket_id);
+    assert_eq!(res, Err(Ok(Error::TicketAlreadyUsed)));
+}
+
+// ── redirection ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_claim_with_ticket_to_pays_destination() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue_with(&s, 1, 300, false, true);
+    let destination = Address::generate(&s.env);
+
+    s.client.claim_with_ticket_to(&ticket_id, &destination);
+
+    assert_eq!(s.token.balance(&destination), 300);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 700);
+
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event: TicketClaimed = data.into_val(&s.env);
+    assert_eq!(event.claimer, s.contributor);
+    assert_eq!(event.destination, destination);
+}
+
+#[test]
+fn test_claim_with_ticket_to_rejects_non_redirectable_ticket() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 300, false);
+    let destination = Address::generate(&s.env);
+
+    let res = s.client.try_claim_with_ticket_to(&ticket_id, &destination);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.token.balance(&destination), 0);
+
+    // The registered address can still claim normally.
+    s.client.claim_with_ticket(&ticket_id);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+}
+
+#[test]
+fn test_claim_with_ticket_records_beneficiary_as_destination() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let ticket_id = issue(&s, 1, 300, false);
+
+    s.client.claim_with_ticket(&ticket_id);
+
+    let (_, _, data) = s.env.events().all().last().unwrap();
+    let event: TicketClaimed = data.into_val(&s.env);
+    assert_eq!(event.destination, s.contributor);
+}
+
+// ── reaping ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_reap_expired_tickets_skips_used_and_valid_tickets() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let claimed = issue(&s, 1, 100, false);
+    let expired = issue(&s, 1, 200, false);
+    s.client.claim_with_ticket(&claimed);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_000);
+    let valid = issue(&s, 1, 300, false);
+
+    assert_eq!(s.client.reap_expired_tickets(&10), 1);
+
+    let events = reaped_events(&s);
+    assert_eq!(events.len(), 1);
+    let event = events.get(0).unwrap();
+    assert_eq!(event.ticket_id, expired);
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.beneficiary, s.contributor);
+
+    assert_eq!(
+        beneficiary_tickets(&s),
+        soroban_sdk::vec![&s.env, claimed, valid]
+    );
+    assert_eq!(
+        s.client.try_claim_with_ticket(&expired),
+        Err(Ok(Error::TicketExpired))
+    );
+
+    // The escrow keeps its funds for re-issuance and the valid ticket still works.
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 900);
+    s.client.claim_with_ticket(&valid);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+
+    assert_eq!(s.client.reap_expired_tickets(&10), 0);
+}
+
+#[test]
+fn test_reap_expired_tickets_respects_max_to_process() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let first = issue(&s, 1, 100, false);
+    let second = issue(&s, 1, 100, false);
+    let third = issue(&s, 1, 100, false);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_000);
+
+    assert_eq!(s.client.reap_expired_tickets(&2), 2);
+    assert_eq!(beneficiary_tickets(&s), soroban_sdk::vec![&s.env, third]);
+    let events = reaped_events(&s);
+    assert_eq!(events.get(0).unwrap().ticket_id, first);
+    assert_eq!(events.get(1).unwrap().ticket_id, second);
+
+    assert_eq!(s.client.reap_expired_tickets(&2), 1);
+    assert_eq!(beneficiary_tickets(&s).len(), 0);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_reap_expired_tickets_resumes_from_cursor() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let now = s.env.ledger().timestamp();
+    let long_lived =
+        s.client
+            .issue_claim_ticket(&1, &s.contributor, &100, &(now + 5_000), &false, &false);
+    let expiring = issue(&s, 1, 100, false);
+
+    s.env.ledger().set_timestamp(now + 1_000);
+
+    // The first call only examines the still-valid ticket.
+    assert_eq!(s.client.reap_expired_tickets(&1), 0);
+    // The next call picks up after it instead of rescanning from the start.
+    assert_eq!(s.client.reap_expired_tickets(&1), 1);
+    assert_eq!(reaped_events(&s).get(0).unwrap().ticket_id, expiring);
+    assert_eq!(
+        beneficiary_tickets(&s),
+        soroban_sdk::vec![&s.env, long_lived]
+    );
+
+    // The cursor wrapped, so the index is walked again from the start.
+    assert_eq!(s.client.reap_expired_tickets(&2), 0);
+}
+
+// ── beneficiary queries ──────────────────────────────────────────────────────
+
+#[test]
+fn test_beneficiary_tickets_detailed_matches_id_pages() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    s.lock(2, 1_000, s.now() + 10_000);
+    let first = issue(&s, 1, 100, false);
+    let second = issue(&s, 2, 200, true);
+    let third = issue(&s, 1, 300, false);
+
+    let ids = s.client.get_beneficiary_tickets(&s.contributor, &0, &2);
+    assert_eq!(ids, soroban_sdk::vec![&s.env, first, second]);
+    let page = s
+        .client
+        .get_beneficiary_tickets_detailed(&s.contributor, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().ticket_id, first);
+    assert_eq!(page.get(0).unwrap().amount, 100);
+    let second_ticket = page.get(1).unwrap();
+    assert_eq!(second_ticket.ticket_id, second);
+    assert_eq!(second_ticket.bounty_id, 2);
+    assert!(
+        s.client
+            .get_claim_ticket_flags(&second)
+            .requires_confirmation
+    );
+    assert!(
+        !s.client
+            .get_claim_ticket_flags(&first)
+            .requires_confirmation
+    );
+    assert_eq!(second_ticket.beneficiary, s.contributor);
+
+    let rest = s
+        .client
+        .get_beneficiary_tickets_detailed(&s.contributor, &2, &2);
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.get(0).unwrap().ticket_id, third);
+    assert_eq!(
+        s.client
+            .get_beneficiary_tickets_detailed(&s.contributor, &3, &2)
+            .len(),
+        0
+    );
+    assert_eq!(
+        s.client
+            .get_beneficiary_tickets_detailed(&Address::generate(&s.env), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_beneficiary_tickets_detailed_skips_missing_records() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, s.now() + 10_000);
+    let first = issue(&s, 1, 100, false);
+    let second = issue(&s, 1, 100, false);
+    let third = issue(&s, 1, 100, false);
+
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .remove(&DataKey::ClaimTicket(second));
+    });
+
+    assert_eq!(
+        s.client.get_beneficiary_tickets(&s.contributor, &0, &3),
+        soroban_sdk::vec![&s.env, first, second, third]
+    );
+    let page = s
+        .client
+        .get_beneficiary_tickets_detailed(&s.contributor, &0, &3);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().ticket_id, first);
+    assert_eq!(page.get(1).unwrap().ticket_id, third);
+}
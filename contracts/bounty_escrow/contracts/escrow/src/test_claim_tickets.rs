@@ -134,6 +134,8 @@ mod test_claim_tickets {
             beneficiary.clone(),
             ticket_amount,
             ticket_expiry,
+            false,
+            false,
         );
 
         assert!(result.is_ok(), "Failed to issue claim ticket");
@@ -170,6 +172,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 1000i128,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -182,6 +186,8 @@ mod test_claim_tickets {
                 beneficiary2,
                 2000i128,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -200,7 +206,7 @@ mod test_claim_tickets {
         let ticket_expiry = env.ledger().timestamp() + 3600;
 
         let result =
-            contract.issue_claim_ticket(env, bounty_id, beneficiary, amount, ticket_expiry);
+            contract.issue_claim_ticket(env, bounty_id, beneficiary, amount, ticket_expiry, false, false);
 
         assert!(
             matches!(result, Err(Error::BountyNotFound)),
@@ -226,6 +232,8 @@ mod test_claim_tickets {
             beneficiary,
             0i128, // Invalid: zero amount
             ticket_expiry,
+            false,
+            false,
         );
 
         assert!(
@@ -252,6 +260,8 @@ mod test_claim_tickets {
             beneficiary,
             5000i128, // Exceed escrow amount
             ticket_expiry,
+            false,
+            false,
         );
 
         assert!(
@@ -278,7 +288,7 @@ mod test_claim_tickets {
             beneficiary,
             500i128,
             current_time, // Expiry is now (in the past)
-        );
+        , false);
 
         assert!(
             matches!(result, Err(Error::InvalidDeadline)),
@@ -301,7 +311,7 @@ mod test_claim_tickets {
         // Try to issue ticket as non-admin user
         let attacker = Address::random(&env);
         let result =
-            contract.issue_claim_ticket(env, bounty_id, beneficiary, 500i128, ticket_expiry);
+            contract.issue_claim_ticket(env, bounty_id, beneficiary, 500i128, ticket_expiry, false, false);
 
         // Note: With mock_all_auths(), we can't easily test Unauthorized.
         // The test just ensures the function works correctly when admin calls it.
@@ -331,6 +341,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -388,6 +400,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -422,6 +436,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -453,6 +469,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -491,6 +509,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -524,6 +544,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -557,6 +579,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -594,6 +618,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 ticket_amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -632,7 +658,7 @@ mod test_claim_tickets {
         create_locked_bounty(&env, bounty_id, admin, amount, deadline);
 
         let ticket_id = contract
-            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry)
+            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry, false, false)
             .unwrap();
 
         let (is_valid, is_expired, already_used) = verify_claim_ticket(env, ticket_id);
@@ -659,7 +685,7 @@ mod test_claim_tickets {
         create_locked_bounty(&env, bounty_id, admin, amount, deadline);
 
         let ticket_id = contract
-            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry)
+            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry, false, false)
             .unwrap();
 
         // Advance past expiry
@@ -688,7 +714,7 @@ mod test_claim_tickets {
         create_locked_bounty(&env, bounty_id, admin, amount, deadline);
 
         let ticket_id = contract
-            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry)
+            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry, false, false)
             .unwrap();
 
         // Claim the ticket
@@ -735,6 +761,8 @@ mod test_claim_tickets {
                     beneficiary.clone(),
                     amount,
                     ticket_expiry,
+                    false,
+                    false,
                 )
                 .unwrap();
         }
@@ -781,6 +809,8 @@ mod test_claim_tickets {
                     beneficiary.clone(),
                     amount,
                     ticket_expiry,
+                    false,
+                    false,
                 )
                 .unwrap();
         }
@@ -813,7 +843,7 @@ mod test_claim_tickets {
         create_locked_bounty(&env, bounty_id, admin, amount, deadline);
 
         let ticket_id = contract
-            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry)
+            .issue_claim_ticket(env.clone(), bounty_id, beneficiary, amount, ticket_expiry, false, false)
             .unwrap();
 
         // Try to claim as different address (in test env with mock_all_auths,
@@ -857,6 +887,8 @@ mod test_claim_tickets {
                 beneficiary.clone(),
                 amount,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -917,6 +949,8 @@ mod test_claim_tickets {
                 beneficiary1.clone(),
                 share1,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -927,6 +961,8 @@ mod test_claim_tickets {
                 beneficiary2.clone(),
                 share2,
                 ticket_expiry,
+                false,
+                false,
             )
             .unwrap();
 
@@ -0,0 +1,107 @@
+//! Compliance log tests for `BountyEscrowContract::get_compliance_log`.
+//!
+//! Covers:
+//! - An invariant auto-halt is recorded with `COMPLIANCE_HALT`
+//! - Denylist hits are recorded with the rejected operation and recipient
+//! - A rejection that fails the invocation is rolled back with its entry
+//! - The log is capped and evicts its oldest entries; pagination
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{
+    BountyEscrowContract, Error, COMPLIANCE_DENYLIST, COMPLIANCE_HALT, COMPLIANCE_RATE_LIMIT,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+fn record(s: &Fixture, caller: &Address, reason_code: u32) {
+    s.env.as_contract(&s.client.address, || {
+        BountyEscrowContract::record_compliance_rejection(
+            &s.env,
+            symbol_short!("lock"),
+            caller.clone(),
+            reason_code,
+        );
+    });
+}
+
+#[test]
+fn test_auto_halt_is_logged() {
+    let s = Fixture::new();
+    s.client.set_auto_halt_on_breach(&true);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &10_000);
+    // Tokens leave the contract without going through an escrow.
+    s.token
+        .transfer(&s.client.address, &Address::generate(&s.env), &5);
+
+    s.env.ledger().set_timestamp(1_100);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &10_000);
+
+    let log = s.client.get_compliance_log(&0, &10);
+    assert_eq!(log.len(), 1);
+    let entry = log.get(0).unwrap();
+    assert_eq!(entry.operation, symbol_short!("lock"));
+    assert_eq!(entry.caller, s.client.address);
+    assert_eq!(entry.reason_code, COMPLIANCE_HALT);
+    assert_eq!(entry.timestamp, 1_100);
+}
+
+#[test]
+fn test_denylist_hit_is_logged() {
+    let s = Fixture::new();
+    let denied = Address::generate(&s.env);
+    s.client.set_address_denied(&denied, &true);
+
+    s.env.as_contract(&s.client.address, || {
+        let res = BountyEscrowContract::ensure_recipient_not_denied(
+            &s.env,
+            symbol_short!("release"),
+            &denied,
+        );
+        assert_eq!(res, Err(Error::RecipientDenied));
+    });
+
+    let entry = s.client.get_compliance_log(&0, &10).get(0).unwrap();
+    assert_eq!(entry.operation, symbol_short!("release"));
+    assert_eq!(entry.caller, denied);
+    assert_eq!(entry.reason_code, COMPLIANCE_DENYLIST);
+}
+
+#[test]
+fn test_failed_invocation_rolls_back_its_entry() {
+    let s = Fixture::new();
+    let denied = Address::generate(&s.env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &10_000);
+    s.client.set_address_denied(&denied, &true);
+
+    let res = s.client.try_release_funds(&1, &denied);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    assert_eq!(s.client.get_compliance_log(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_log_is_capped_and_paginated() {
+    let s = Fixture::new();
+    s.env.budget().reset_unlimited();
+    let callers: [Address; 3] = [
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    for i in 0..203u32 {
+        record(&s, &callers[(i % 3) as usize], COMPLIANCE_RATE_LIMIT);
+    }
+
+    assert_eq!(s.client.get_compliance_log(&0, &500).len(), 200);
+    // The three oldest entries were evicted.
+    let first = s.client.get_compliance_log(&0, &1).get(0).unwrap();
+    assert_eq!(first.caller, callers[0]);
+    let page = s.client.get_compliance_log(&198, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().caller, callers[202 % 3]);
+    assert_eq!(s.client.get_compliance_log(&200, &5).len(), 0);
+}
@@ -0,0 +1,66 @@
+//! Contributor reputation tests for `BountyEscrowContract::get_contributor_reputation`.
+//!
+//! Covers:
+//! - `release_funds`, `claim` and `claim_with_ticket` each count one payout
+//!   and add the amount received
+//! - Dry runs and rejected releases leave the counter untouched
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::DisputeReason;
+
+const DEADLINE: u64 = 50_000;
+
+#[test]
+fn test_reputation_counts_every_payout_path() {
+    let s = Fixture::new();
+    assert_eq!(s.client.get_contributor_reputation(&s.contributor), (0, 0));
+
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 2_000, DEADLINE);
+    s.lock(3, 3_000, DEADLINE);
+    s.lock(4, 4_000, DEADLINE);
+
+    s.client.release_funds(&1, &s.contributor);
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(
+        s.client.get_contributor_reputation(&s.contributor),
+        (2, 3_000)
+    );
+
+    s.client
+        .authorize_claim(&3, &s.contributor, &DisputeReason::Other);
+    s.client.claim(&3);
+
+    let ticket_id = s.client.issue_claim_ticket(
+        &4,
+        &s.contributor,
+        &1_500,
+        &(s.env.ledger().timestamp() + 1_000),
+        &false,
+        &false,
+    );
+    s.client.claim_with_ticket(&ticket_id);
+
+    assert_eq!(
+        s.client.get_contributor_reputation(&s.contributor),
+        (4, 7_500)
+    );
+}
+
+#[test]
+fn test_dry_runs_and_failures_do_not_count() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+
+    s.client.dry_run_release(&1, &s.contributor);
+    assert_eq!(s.client.get_contributor_reputation(&s.contributor), (0, 0));
+
+    s.client.release_funds(&1, &s.contributor);
+    assert!(s.client.try_release_funds(&1, &s.contributor).is_err());
+    assert_eq!(
+        s.client.get_contributor_reputation(&s.contributor),
+        (1, 1_000)
+    );
+}
@@ -555,6 +555,34 @@ mod cross_contract_interface_tests {
         assert_eq!(updated_version, 2);
     }
 
+    #[test]
+    fn test_get_capabilities_reports_version_and_features() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = crate::BountyEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let (token, _) = create_token_contract(&env, &token_admin);
+        client.init(&admin, &token.address);
+
+        let caps = client.get_capabilities();
+        assert_eq!(caps.version, 1);
+        assert_eq!(
+            caps.semver,
+            soroban_sdk::String::from_str(&env, crate::CONTRACT_SEMVER)
+        );
+        assert!(caps.claim_tickets);
+        assert!(caps.capability_tokens);
+        assert!(caps.multi_token);
+        assert!(caps.dry_run);
+
+        client.set_version(&3);
+        assert_eq!(client.get_capabilities().version, 3);
+    }
+
     #[test]
     fn test_pause_interface_maps_to_pause_entrypoints() {
         let env = Env::default();
@@ -40,7 +40,9 @@
 //! - Partial refunds via `approve_refund` with `RefundMode::Partial` correctly
 //!   preserve the remaining balance and transition to `PartiallyRefunded` status.
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, RefundMode};
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, DataKeyExt, Error, EscrowStatus, RefundMode,
+};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
     token, Address, Env,
@@ -223,7 +225,7 @@ fn test_future_deadline_early_refund_with_admin_approval() {
     s.escrow.lock_funds(&s.depositor, &13, &2_000, &deadline);
 
     s.escrow
-        .approve_refund(&13, &2_000, &s.depositor, &RefundMode::Full);
+        .approve_refund(&13, &2_000, &s.depositor, &RefundMode::Full, &None);
 
     let before = s.token.balance(&s.depositor);
     s.escrow.refund(&13);
@@ -302,7 +304,7 @@ fn test_no_deadline_refund_succeeds_with_admin_approval() {
     s.escrow.lock_funds(&s.depositor, &23, &1_500, &NO_DEADLINE);
 
     s.escrow
-        .approve_refund(&23, &1_500, &s.depositor, &RefundMode::Full);
+        .approve_refund(&23, &1_500, &s.depositor, &RefundMode::Full, &None);
 
     let before = s.token.balance(&s.depositor);
     s.escrow.refund(&23);
@@ -319,7 +321,7 @@ fn test_no_deadline_partial_refund_with_admin_approval() {
     s.escrow.lock_funds(&s.depositor, &24, &2_000, &NO_DEADLINE);
 
     s.escrow
-        .approve_refund(&24, &800, &s.depositor, &RefundMode::Partial);
+        .approve_refund(&24, &800, &s.depositor, &RefundMode::Partial, &None);
 
     s.escrow.refund(&24);
 
@@ -441,7 +443,7 @@ fn test_zero_deadline_partial_refund() {
     s.escrow.lock_funds(&s.depositor, &50, &2_000, &0);
 
     s.escrow
-        .approve_refund(&50, &600, &s.depositor, &RefundMode::Partial);
+        .approve_refund(&50, &600, &s.depositor, &RefundMode::Partial, &None);
     s.escrow.refund(&50);
 
     let info = s.escrow.get_escrow_info(&50);
@@ -458,7 +460,7 @@ fn test_future_deadline_partial_refund_with_approval() {
 
     // Before deadline: partial refund via admin approval
     s.escrow
-        .approve_refund(&51, &1_000, &s.depositor, &RefundMode::Partial);
+        .approve_refund(&51, &1_000, &s.depositor, &RefundMode::Partial, &None);
 
     let before = s.token.balance(&s.depositor);
     s.escrow.refund(&51);
@@ -599,7 +601,7 @@ fn test_no_deadline_full_refund_workflow() {
 
     // Admin approves full refund
     s.escrow
-        .approve_refund(&90, &5_000, &s.depositor, &RefundMode::Full);
+        .approve_refund(&90, &5_000, &s.depositor, &RefundMode::Full, &None);
 
     // Now refund succeeds
     let before = s.token.balance(&s.depositor);
@@ -628,3 +630,207 @@ fn test_no_deadline_escrow_released_after_refund_blocked() {
     assert_eq!(s.escrow.get_escrow_info(&91).status, EscrowStatus::Released);
     assert_eq!(s.token.balance(&s.contributor), 2_000);
 }
+
+// =============================================================================
+// Refund approval expiry
+//
+// Approvals carry an `expires_at` timestamp. Once it passes, the approval no
+// longer overrides the deadline and the standard deadline rule applies again.
+// =============================================================================
+
+#[test]
+fn test_refund_approval_defaults_to_configured_window() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds(&s.depositor, &100, &1_000, &NO_DEADLINE);
+    s.escrow.set_refund_approval_window(&3_600);
+
+    s.env.ledger().set_timestamp(500);
+    s.escrow
+        .approve_refund(&100, &1_000, &s.depositor, &RefundMode::Full, &None);
+
+    let approval = s.escrow.get_refund_approval(&100).unwrap();
+    assert_eq!(approval.approved_at, 500);
+    assert_eq!(s.escrow.get_refund_approval_expiry(&100), Some(500 + 3_600));
+}
+
+#[test]
+fn test_stale_refund_approval_does_not_permit_early_refund() {
+    let s = Setup::new();
+    let deadline = 100_000;
+    s.escrow.lock_funds(&s.depositor, &101, &1_000, &deadline);
+
+    s.escrow
+        .approve_refund(&101, &1_000, &s.depositor, &RefundMode::Full, &Some(1_000));
+
+    // Past the approval's expiry but before the escrow deadline.
+    s.env.ledger().set_timestamp(1_001);
+    assert_eq!(
+        s.escrow.try_refund(&101).unwrap_err().unwrap(),
+        Error::DeadlineNotPassed
+    );
+    let (can_refund, _, _, approval) = s.escrow.get_refund_eligibility(&101);
+    assert!(!can_refund);
+    assert!(approval.is_none());
+
+    // Once the deadline passes the standard refund goes through.
+    s.env.ledger().set_timestamp(deadline);
+    s.escrow.refund(&101);
+    assert_eq!(
+        s.escrow.get_escrow_info(&101).status,
+        EscrowStatus::Refunded
+    );
+    assert!(s.escrow.get_refund_approval(&101).is_none());
+}
+
+#[test]
+fn test_refund_approval_without_expiry_never_expires() {
+    let s = Setup::new();
+    let deadline = 100_000;
+    s.escrow.lock_funds(&s.depositor, &103, &1_000, &deadline);
+    s.escrow
+        .approve_refund(&103, &400, &s.depositor, &RefundMode::Partial, &Some(1_000));
+    // Simulate an approval granted before approvals carried an expiry.
+    s.env.as_contract(&s.escrow.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .remove(&DataKeyExt::RefundApprovalExpiry(103));
+    });
+    assert_eq!(s.escrow.get_refund_approval_expiry(&103), None);
+
+    s.env.ledger().set_timestamp(50_000);
+    s.escrow.refund(&103);
+    assert_eq!(s.escrow.get_escrow_info(&103).remaining_amount, 600);
+    assert!(s.escrow.get_refund_approval(&103).is_none());
+}
+
+#[test]
+fn test_refund_approval_rejects_past_expiry() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds(&s.depositor, &102, &1_000, &NO_DEADLINE);
+    s.env.ledger().set_timestamp(2_000);
+
+    let result =
+        s.escrow
+            .try_approve_refund(&102, &1_000, &s.depositor, &RefundMode::Full, &Some(2_000));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDeadline);
+}
+
+// =============================================================================
+// Default deadline horizon
+//
+// `lock_funds_default_deadline` derives `deadline = now + horizon` from the
+// admin-configured horizon and refuses to lock when none is configured.
+// =============================================================================
+
+#[test]
+fn test_lock_with_default_deadline_uses_horizon() {
+    let s = Setup::new();
+    s.escrow.set_default_deadline_horizon(&s._admin, &7_200);
+    assert_eq!(s.escrow.get_default_deadline_horizon(), Some(7_200));
+
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow
+        .lock_funds_default_deadline(&s.depositor, &200, &1_000);
+
+    let escrow = s.escrow.get_escrow_info(&200);
+    assert_eq!(escrow.deadline, 1_000 + 7_200);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_lock_with_default_deadline_requires_horizon() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_default_deadline_horizon(), None);
+
+    let result = s
+        .escrow
+        .try_lock_funds_default_deadline(&s.depositor, &201, &1_000);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDeadline);
+    assert_eq!(s.token.balance(&s.depositor), 10_000_000);
+}
+
+#[test]
+fn test_default_deadline_horizon_admin_only_and_nonzero() {
+    let s = Setup::new();
+    let result = s
+        .escrow
+        .try_set_default_deadline_horizon(&s.depositor, &3_600);
+    assert_eq!(result.unwrap_err().unwrap(), Error::Unauthorized);
+
+    let result = s.escrow.try_set_default_deadline_horizon(&s._admin, &0);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDeadline);
+}
+
+// =============================================================================
+// Auto-refund policy
+//
+// With `set_auto_refund_policy(true)`, anyone may refund an escrow past its
+// deadline back to the depositor. Without it, `refund` still needs both the
+// admin and the depositor.
+// =============================================================================
+
+#[test]
+fn test_auto_refund_policy_lets_anyone_refund_expired_escrow() {
+    let s = Setup::new();
+    s.escrow.set_auto_refund_policy(&s._admin, &true);
+    assert!(s.escrow.is_auto_refund_enabled());
+
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.lock_funds(&s.depositor, &300, &5_000, &2_000);
+    s.env.ledger().set_timestamp(2_000);
+
+    // No authorizations at all: a keeper can trigger the refund.
+    s.env.set_auths(&[]);
+    s.escrow.refund(&300);
+
+    let escrow = s.escrow.get_escrow_info(&300);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&s.depositor), 10_000_000);
+}
+
+#[test]
+fn test_auto_refund_policy_disabled_requires_authorization() {
+    let s = Setup::new();
+    assert!(!s.escrow.is_auto_refund_enabled());
+
+    s.escrow.lock_funds(&s.depositor, &301, &5_000, &0);
+    s.env.set_auths(&[]);
+    assert!(s.escrow.try_refund(&301).is_err());
+    assert_eq!(s.escrow.get_escrow_info(&301).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_auto_refund_policy_does_not_open_early_refunds() {
+    let s = Setup::new();
+    s.escrow.set_auto_refund_policy(&s._admin, &true);
+
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.lock_funds(&s.depositor, &302, &5_000, &2_000);
+    s.env.ledger().set_timestamp(1_999);
+
+    s.env.set_auths(&[]);
+    assert!(s.escrow.try_refund(&302).is_err());
+    assert_eq!(s.escrow.get_escrow_info(&302).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_auto_refund_policy_respects_refund_pause() {
+    let s = Setup::new();
+    s.escrow.set_auto_refund_policy(&s._admin, &true);
+    s.escrow.lock_funds(&s.depositor, &303, &5_000, &0);
+    s.escrow.set_paused(&None, &None, &Some(true), &None);
+
+    let result = s.escrow.try_refund(&303);
+    assert_eq!(result.unwrap_err().unwrap(), Error::FundsPaused);
+}
+
+#[test]
+fn test_set_auto_refund_policy_admin_only() {
+    let s = Setup::new();
+    let result = s.escrow.try_set_auto_refund_policy(&s.depositor, &true);
+    assert_eq!(result.unwrap_err().unwrap(), Error::Unauthorized);
+    assert!(!s.escrow.is_auto_refund_enabled());
+}
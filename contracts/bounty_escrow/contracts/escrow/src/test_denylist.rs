@@ -0,0 +1,256 @@
+//! Release denylist tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - Only the admin can add or remove denylist entries
+//! - `release_funds`, `partial_release`, `claim`, `claim_with_ticket`, the
+//!   capability paths and `batch_release_funds` reject a denied recipient with
+//!   `RecipientDenied`
+//! - Holdbacks and bounty schedules reject a denied recipient the same way
+//! - A rejected payout leaves the escrow, balances, tickets and holdbacks
+//!   untouched
+//! - Removing an entry restores normal payouts
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{CapabilityAction, DisputeReason, Error, Escrow, ReleaseFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, Vec,
+};
+
+const DEADLINE: u64 = 50_000;
+
+struct Suite {
+    fx: Fixture,
+    denied: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        let denied = Address::generate(&fx.env);
+        fx.client.set_address_denied(&denied, &true);
+        Self { fx, denied }
+    }
+
+    fn assert_untouched(&self, bounty_id: u64, before: &Escrow) {
+        assert_eq!(&self.client.get_escrow_info(&bounty_id), before);
+        assert_eq!(self.token.balance(&self.denied), 0);
+    }
+}
+
+#[test]
+fn test_only_admin_can_update_denylist() {
+    let s = Suite::new();
+    let outsider = Address::generate(&s.env);
+    let target = Address::generate(&s.env);
+
+    s.env.mock_auths(&[MockAuth {
+        address: &outsider,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "set_address_denied",
+            args: (target.clone(), true).into_val(&s.env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(s.client.try_set_address_denied(&target, &true).is_err());
+
+    s.env.mock_all_auths();
+    assert!(s.client.is_address_denied(&s.denied));
+    assert!(!s.client.is_address_denied(&target));
+    s.client.set_address_denied(&target, &true);
+    assert!(s.client.is_address_denied(&target));
+    s.client.set_address_denied(&target, &false);
+    assert!(!s.client.is_address_denied(&target));
+}
+
+#[test]
+fn test_release_and_partial_release_reject_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s.client.try_release_funds(&1, &s.denied);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+
+    let res = s.client.try_partial_release(&1, &s.denied, &400);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+}
+
+#[test]
+fn test_claim_rejects_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_claim_window(&500);
+    s.client
+        .authorize_claim(&1, &s.denied, &DisputeReason::Other);
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s.client.try_claim(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+}
+
+#[test]
+fn test_claim_with_ticket_rejects_denied_beneficiary() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    let expires_at = s.env.ledger().timestamp() + 1_000;
+    let ticket_id = s
+        .client
+        .issue_claim_ticket(&1, &s.denied, &300, &expires_at, &false, &false);
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s.client.try_claim_with_ticket(&ticket_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+
+    // The ticket was not consumed and pays out once the entry is lifted.
+    s.client.set_address_denied(&s.denied, &false);
+    s.client.claim_with_ticket(&ticket_id);
+    assert_eq!(s.token.balance(&s.denied), 300);
+}
+
+#[test]
+fn test_release_with_capability_rejects_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    let delegate = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let capability_id = s.client.issue_capability(
+        &s.admin,
+        &delegate,
+        &CapabilityAction::Release,
+        &1,
+        &1_000,
+        &expiry,
+        &1,
+    );
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s
+        .client
+        .try_release_with_capability(&1, &s.denied, &400, &delegate, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+    assert_eq!(s.client.get_capability(&capability_id).remaining_uses, 1);
+}
+
+#[test]
+fn test_claim_with_capability_rejects_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_claim_window(&500);
+    s.client
+        .authorize_claim(&1, &s.denied, &DisputeReason::Other);
+    let delegate = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let capability_id = s.client.issue_capability(
+        &s.denied,
+        &delegate,
+        &CapabilityAction::Claim,
+        &1,
+        &1_000,
+        &expiry,
+        &1,
+    );
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s
+        .client
+        .try_claim_with_capability(&1, &delegate, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+}
+
+#[test]
+fn test_batch_release_rejects_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 500, DEADLINE);
+    let allowed = Address::generate(&s.env);
+    let before = s.client.get_escrow_info(&2);
+
+    let res = s.client.try_batch_release_funds(&vec![
+        &s.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: allowed.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: s.denied.clone(),
+        },
+    ]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(2, &before);
+    assert_eq!(s.token.balance(&allowed), 0);
+}
+
+#[test]
+fn test_holdback_paths_reject_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s
+        .client
+        .try_release_with_holdback(&1, &s.denied, &2_000, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+
+    // A recipient denied during the review period cannot collect the holdback.
+    s.client.set_address_denied(&s.denied, &false);
+    s.client.release_with_holdback(&2, &s.denied, &2_000, &100);
+    s.client.set_address_denied(&s.denied, &true);
+    s.advance(100);
+    let res = s.client.try_release_holdback(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    assert_eq!(s.client.get_holdback(&2).unwrap().amount, 200);
+    assert_eq!(s.token.balance(&s.denied), 800);
+}
+
+#[test]
+fn test_bounty_schedule_rejects_denied_recipient() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    let release_at = s.now() + 1_000;
+    let schedule_id = s
+        .client
+        .create_bounty_release_schedule(&1, &400, &release_at, &s.denied);
+    s.env.ledger().set_timestamp(release_at);
+    let before = s.client.get_escrow_info(&1);
+
+    let res = s.client.try_release_bounty_schedule(&1, &schedule_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientDenied);
+    s.assert_untouched(1, &before);
+    assert!(
+        !s.client
+            .get_bounty_release_schedule(&1, &schedule_id)
+            .released
+    );
+}
+
+#[test]
+fn test_removing_entry_restores_release() {
+    let s = Suite::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_address_denied(&s.denied, &false);
+
+    s.client.release_funds(&1, &s.denied);
+    assert_eq!(s.token.balance(&s.denied), 1_000);
+}
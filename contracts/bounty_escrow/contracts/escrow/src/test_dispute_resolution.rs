@@ -2,8 +2,8 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env,
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, Symbol, TryFromVal,
 };
 
 fn create_token(
@@ -99,3 +99,108 @@ fn test_open_dispute_blocks_refund_before_resolution() {
     let info = s.escrow.get_escrow_info(&bounty_id);
     assert_eq!(info.status, EscrowStatus::Refunded);
 }
+
+#[test]
+fn test_dispute_stats_count_reasons_and_outcomes() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 3600;
+    s.escrow.set_claim_window(&600);
+    for bounty_id in 1..=3u64 {
+        s.escrow
+            .lock_funds(&s.depositor, &bounty_id, &1000, &deadline);
+    }
+
+    assert_eq!(s.escrow.get_dispute_stats(), DisputeStats::default());
+
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::QualityIssue);
+    s.escrow
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Fraud);
+    s.escrow
+        .authorize_claim(&3, &s.contributor, &DisputeReason::Fraud);
+
+    s.escrow.claim(&1);
+    s.escrow
+        .cancel_pending_claim(&2, &DisputeOutcome::ResolvedInFavorOfDepositor);
+    s.escrow
+        .cancel_pending_claim(&3, &DisputeOutcome::CancelledByAdmin);
+
+    let stats = s.escrow.get_dispute_stats();
+    assert_eq!(stats.reason_quality_issue, 1);
+    assert_eq!(stats.reason_fraud, 2);
+    assert_eq!(stats.reason_expired, 0);
+    assert_eq!(stats.outcome_contributor, 1);
+    assert_eq!(stats.outcome_depositor, 1);
+    assert_eq!(stats.outcome_cancelled_by_admin, 1);
+    assert_eq!(stats.outcome_refunded, 0);
+}
+
+fn late_claim_events(env: &Env) -> u32 {
+    let mut count = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        if topics.len() < 2 {
+            continue;
+        }
+        let second = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if second == Ok(symbol_short!("late")) {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[test]
+fn test_claim_within_grace_succeeds_and_flags_late_claim() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 3600;
+    s.escrow.set_claim_window(&600);
+    s.escrow.set_claim_grace(&120);
+    s.escrow.lock_funds(&s.depositor, &1, &1000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &2, &1000, &deadline);
+
+    let start = s.env.ledger().timestamp();
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    s.escrow
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Other);
+    assert_eq!(s.escrow.get_claim_grace_period(&1), 120);
+
+    // The grace is fixed per claim; later config changes do not touch it.
+    s.escrow.set_claim_grace(&0);
+    assert_eq!(s.escrow.get_claim_grace_period(&2), 120);
+
+    // Inside the primary window: no late flag.
+    s.escrow.claim(&1);
+    assert_eq!(late_claim_events(&s.env), 0);
+
+    // Past the window but inside the grace period.
+    s.env.ledger().set_timestamp(start + 600 + 120);
+    s.escrow.claim(&2);
+    assert_eq!(late_claim_events(&s.env), 1);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_claim_after_grace_or_without_grace_is_rejected() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 3600;
+    s.escrow.set_claim_window(&600);
+    s.escrow.lock_funds(&s.depositor, &1, &1000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &2, &1000, &deadline);
+
+    // Default zero grace keeps the window strict.
+    let start = s.env.ledger().timestamp();
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    s.env.ledger().set_timestamp(start + 601);
+    let res = s.escrow.try_claim(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+
+    s.escrow.set_claim_grace(&100);
+    let start = s.env.ledger().timestamp();
+    s.escrow
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Other);
+    s.env.ledger().set_timestamp(start + 701);
+    let res = s.escrow.try_claim(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+}
@@ -0,0 +1,105 @@
+//! Early-release bonus tests for `BountyEscrowContract::set_early_bonus`.
+//!
+//! Covers:
+//! - Partial releases inside the window pay the bonus and report it in `FundsReleased`
+//! - Releases after the window pay exactly the requested amount
+//! - The bonus is capped at what remains in the escrow
+//! - Full releases never carry a bonus
+//! - Config validation and removal
+
+#![cfg(test)]
+
+use crate::events::FundsReleased;
+use crate::test_support::Fixture;
+use crate::{EarlyBonus, Error, EscrowStatus};
+use soroban_sdk::symbol_short;
+
+const DEADLINE: u64 = 50_000;
+const WINDOW: u64 = 3_600;
+
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.client.lock_funds(&s.depositor, &1, &10_000, &DEADLINE);
+    s
+}
+
+fn last_release(s: &Fixture) -> FundsReleased {
+    s.last_event(symbol_short!("f_rel")).unwrap()
+}
+
+#[test]
+fn test_partial_release_in_window_pays_bonus() {
+    let s = setup();
+    s.client.set_early_bonus(&1, &1_000, &WINDOW);
+    assert_eq!(
+        s.client.get_early_bonus(&1),
+        Some(EarlyBonus {
+            bonus_bps: 1_000,
+            window_seconds: WINDOW,
+        })
+    );
+
+    s.advance(WINDOW);
+    s.client.partial_release(&1, &s.contributor, &2_000);
+    assert_eq!(s.token.balance(&s.contributor), 2_200);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 7_800);
+
+    let event = last_release(&s);
+    assert_eq!(event.amount, 2_200);
+    assert_eq!(event.bonus, 200);
+}
+
+#[test]
+fn test_partial_release_after_window_pays_no_bonus() {
+    let s = setup();
+    s.client.set_early_bonus(&1, &1_000, &WINDOW);
+
+    s.advance(WINDOW + 1);
+    s.client.partial_release(&1, &s.contributor, &2_000);
+    assert_eq!(s.token.balance(&s.contributor), 2_000);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 8_000);
+    assert_eq!(last_release(&s).bonus, 0);
+}
+
+#[test]
+fn test_bonus_capped_at_remaining_amount() {
+    let s = setup();
+    s.client.set_early_bonus(&1, &5_000, &WINDOW);
+
+    // 8_000 + 50% would be 12_000; only 10_000 is in the escrow.
+    s.client.partial_release(&1, &s.contributor, &8_000);
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(last_release(&s).bonus, 2_000);
+}
+
+#[test]
+fn test_full_release_carries_no_bonus() {
+    let s = setup();
+    s.client.set_early_bonus(&1, &1_000, &WINDOW);
+
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+    let event = last_release(&s);
+    assert_eq!(event.amount, 10_000);
+    assert_eq!(event.bonus, 0);
+}
+
+#[test]
+fn test_early_bonus_validation_and_removal() {
+    let s = setup();
+    let res = s.client.try_set_early_bonus(&1, &10_001, &WINDOW);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    let res = s.client.try_set_early_bonus(&99, &1_000, &WINDOW);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.client.set_early_bonus(&1, &1_000, &WINDOW);
+    s.client.set_early_bonus(&1, &0, &WINDOW);
+    assert_eq!(s.client.get_early_bonus(&1), None);
+
+    s.client.partial_release(&1, &s.contributor, &2_000);
+    assert_eq!(s.token.balance(&s.contributor), 2_000);
+}
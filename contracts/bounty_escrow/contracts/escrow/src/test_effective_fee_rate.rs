@@ -0,0 +1,117 @@
+//! Effective fee rate view tests for `BountyEscrowContract::get_effective_fee_rate`.
+//!
+//! Covers:
+//! - The reported lock, release and refund rates match what those paths deduct
+//! - Volume discounts, per-token overrides, exemptions and disabled fees
+//! - Unknown operations report no fee
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::RefundMode;
+use core::ops::Deref;
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address};
+
+const DEADLINE: u64 = 50_000;
+
+struct Suite {
+    fx: Fixture,
+    treasury: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        let treasury = Address::generate(&fx.env);
+        fx.client.update_fee_config(
+            &Some(1_000),
+            &Some(500),
+            &Some(treasury.clone()),
+            &Some(true),
+        );
+        Self { fx, treasury }
+    }
+
+    fn rate(&self, amount: i128, operation: soroban_sdk::Symbol) -> i128 {
+        self.client
+            .get_effective_fee_rate(&self.depositor, &amount, &operation)
+    }
+
+    /// Lock `amount` and return the fee the treasury received.
+    fn lock_fee(&self, bounty_id: u64, amount: i128) -> i128 {
+        let before = self.token.balance(&self.treasury);
+        self.lock(bounty_id, amount, DEADLINE);
+        self.token.balance(&self.treasury) - before
+    }
+}
+
+#[test]
+fn test_reported_rates_match_deducted_fees() {
+    let s = Suite::new();
+    s.client
+        .set_fee_discount_tiers(&vec![&s.env, (50_000, 2_000)]);
+
+    assert_eq!(s.rate(10_000, symbol_short!("lock")), 1_000);
+    assert_eq!(s.lock_fee(1, 10_000), 1_000);
+
+    // 20% off the 10% lock rate from 50_000 up.
+    assert_eq!(s.rate(50_000, symbol_short!("lock")), 800);
+    assert_eq!(s.lock_fee(2, 50_000), 4_000);
+
+    // Discounts only apply to the lock fee.
+    assert_eq!(s.rate(50_000, symbol_short!("release")), 500);
+    let escrow_amount = s.client.get_escrow_info(&2).amount;
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(
+        s.token.balance(&s.contributor),
+        escrow_amount - escrow_amount * 500 / 10_000
+    );
+}
+
+#[test]
+fn test_refund_rate_matches_approved_refund_fee() {
+    let s = Suite::new();
+    s.client.set_refund_fee_rate(&200);
+    assert_eq!(s.rate(9_000, symbol_short!("refund")), 200);
+
+    s.lock_fee(1, 10_000);
+    s.client
+        .approve_refund(&1, &9_000, &s.depositor, &RefundMode::Full, &None);
+    let before = s.token.balance(&s.treasury);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&s.treasury) - before, 180);
+}
+
+#[test]
+fn test_token_override_exemption_and_disabled_fees() {
+    let s = Suite::new();
+    s.client
+        .set_token_fee_config(&s.token.address, &300, &100, &s.treasury, &true);
+    assert_eq!(s.rate(10_000, symbol_short!("lock")), 300);
+    assert_eq!(s.rate(10_000, symbol_short!("release")), 100);
+    assert_eq!(s.lock_fee(1, 10_000), 300);
+
+    s.client.set_fee_exempt(&s.depositor, &true);
+    assert_eq!(s.rate(10_000, symbol_short!("lock")), 0);
+    assert_eq!(s.lock_fee(2, 10_000), 0);
+    s.client.set_fee_exempt(&s.depositor, &false);
+
+    s.client
+        .set_token_fee_config(&s.token.address, &300, &100, &s.treasury, &false);
+    assert_eq!(s.rate(10_000, symbol_short!("lock")), 0);
+    assert_eq!(s.lock_fee(3, 10_000), 0);
+}
+
+#[test]
+fn test_unknown_operation_has_no_fee() {
+    let s = Suite::new();
+    assert_eq!(s.rate(10_000, symbol_short!("topup")), 0);
+}
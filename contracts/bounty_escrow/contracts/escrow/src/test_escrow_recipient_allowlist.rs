@@ -0,0 +1,140 @@
+//! Per-escrow recipient allowlist tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `release_funds` and `partial_release` reject recipients not on the list
+//! - Other release paths (here `release_from_many`, `claim` and
+//!   `claim_with_capability`) honour the list too
+//! - Admin or depositor may set the list; anyone else is rejected
+//! - An empty list lifts the restriction
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{CapabilityAction, DisputeReason, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+const DEADLINE: u64 = 50_000;
+
+struct Suite {
+    fx: Fixture,
+    vendor: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        fx.client.lock_funds(&fx.depositor, &1, &1_000, &DEADLINE);
+        fx.lock(2, 1_000, DEADLINE);
+        Self {
+            vendor: Address::generate(&fx.env),
+            fx,
+        }
+    }
+}
+
+#[test]
+fn test_release_restricted_to_listed_recipients() {
+    let s = Suite::new();
+    let stranger = Address::generate(&s.env);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &vec![&s.env, s.vendor.clone()]);
+    assert_eq!(
+        s.client.get_escrow_recipient_allowlist(&1),
+        vec![&s.env, s.vendor.clone()]
+    );
+
+    let res = s.client.try_release_funds(&1, &stranger);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+    let res = s.client.try_partial_release(&1, &stranger, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+
+    s.client.partial_release(&1, &s.vendor, &400);
+    s.client.release_funds(&1, &s.vendor);
+    assert_eq!(s.token.balance(&stranger), 0);
+    assert!(s.token.balance(&s.vendor) > 0);
+}
+
+#[test]
+fn test_allowlist_applies_to_every_release_path() {
+    let s = Suite::new();
+    let stranger = Address::generate(&s.env);
+    s.client
+        .set_escrow_recipient_allowlist(&2, &s.admin, &vec![&s.env, s.vendor.clone()]);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &stranger, &1_500);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+
+    // Escrow 1 is unrestricted.
+    assert!(s.client.get_escrow_recipient_allowlist(&1).is_empty());
+    s.client.release_funds(&1, &stranger);
+    assert_eq!(s.token.balance(&stranger), 1_000);
+}
+
+#[test]
+fn test_allowlist_applies_to_pending_claims() {
+    let s = Suite::new();
+    let stranger = Address::generate(&s.env);
+    s.client.set_claim_window(&500);
+    s.client
+        .authorize_claim(&1, &stranger, &DisputeReason::Other);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &vec![&s.env, s.vendor.clone()]);
+
+    let delegate = Address::generate(&s.env);
+    let capability_id = s.client.issue_capability(
+        &stranger,
+        &delegate,
+        &CapabilityAction::Claim,
+        &1,
+        &1_000,
+        &(s.env.ledger().timestamp() + 300),
+        &1,
+    );
+    let res = s
+        .client
+        .try_claim_with_capability(&1, &delegate, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+    let res = s.client.try_claim(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+    assert_eq!(s.token.balance(&stranger), 0);
+}
+
+#[test]
+fn test_only_admin_or_depositor_may_set_allowlist() {
+    let s = Suite::new();
+    let outsider = Address::generate(&s.env);
+    let res =
+        s.client
+            .try_set_escrow_recipient_allowlist(&1, &outsider, &vec![&s.env, outsider.clone()]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    let res = s
+        .client
+        .try_set_escrow_recipient_allowlist(&99, &s.admin, &Vec::new(&s.env));
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_empty_allowlist_lifts_restriction() {
+    let s = Suite::new();
+    let stranger = Address::generate(&s.env);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &vec![&s.env, s.vendor.clone()]);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &Vec::new(&s.env));
+    assert!(s.client.get_escrow_recipient_allowlist(&1).is_empty());
+
+    s.client.release_funds(&1, &stranger);
+    assert_eq!(s.token.balance(&stranger), 1_000);
+}
@@ -0,0 +1,122 @@
+//! Named escrow template tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `lock_from_template` derives the deadline from the template horizon and
+//!   seeds the escrow metadata with the template's bounty type
+//! - Amounts outside the template bounds are rejected
+//! - Saving validates the template and re-saving overwrites in place
+//! - `get_template` / `list_templates` and unknown template names
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus};
+use soroban_sdk::String;
+
+fn name(s: &Fixture, value: &str) -> String {
+    String::from_str(&s.env, value)
+}
+
+fn save(s: &Fixture, template: &str, horizon: u64, min: i128, max: i128, bounty_type: &str) {
+    s.client.save_escrow_template(
+        &name(s, template),
+        &horizon,
+        &min,
+        &max,
+        &name(s, bounty_type),
+    );
+}
+
+#[test]
+fn test_lock_from_template_applies_horizon_and_metadata() {
+    let s = Fixture::new();
+    save(&s, "bugfix", 7 * 86_400, 100, 5_000, "bug");
+
+    s.client
+        .lock_from_template(&s.depositor, &1, &1_000, &name(&s, "bugfix"));
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(escrow.deadline, 1_000 + 7 * 86_400);
+
+    let metadata = s.client.get_metadata(&1);
+    assert_eq!(metadata.bounty_type, name(&s, "bug"));
+    assert_eq!(metadata.repo_id, 0);
+    assert_eq!(metadata.risk_flags, 0);
+}
+
+#[test]
+fn test_lock_from_template_enforces_amount_bounds() {
+    let s = Fixture::new();
+    save(&s, "bugfix", 86_400, 100, 5_000, "bug");
+
+    let res = s
+        .client
+        .try_lock_from_template(&s.depositor, &1, &99, &name(&s, "bugfix"));
+    assert_eq!(res.unwrap_err().unwrap(), Error::AmountBelowMinimum);
+    let res = s
+        .client
+        .try_lock_from_template(&s.depositor, &1, &5_001, &name(&s, "bugfix"));
+    assert_eq!(res.unwrap_err().unwrap(), Error::AmountAboveMaximum);
+    assert!(s.client.try_get_escrow_info(&1).is_err());
+
+    s.client
+        .lock_from_template(&s.depositor, &1, &5_000, &name(&s, "bugfix"));
+    assert_eq!(s.client.get_escrow_info(&1).amount, 5_000);
+}
+
+#[test]
+fn test_unknown_template_rejected() {
+    let s = Fixture::new();
+    let res = s
+        .client
+        .try_lock_from_template(&s.depositor, &1, &1_000, &name(&s, "missing"));
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s.client.try_get_template(&name(&s, "missing"));
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_save_template_validation() {
+    let s = Fixture::new();
+    let res =
+        s.client
+            .try_save_escrow_template(&name(&s, "bugfix"), &0, &100, &5_000, &name(&s, "bug"));
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidDeadline);
+    let res = s.client.try_save_escrow_template(
+        &name(&s, "bugfix"),
+        &86_400,
+        &0,
+        &5_000,
+        &name(&s, "bug"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    let res = s.client.try_save_escrow_template(
+        &name(&s, "bugfix"),
+        &86_400,
+        &6_000,
+        &5_000,
+        &name(&s, "bug"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(s.client.list_templates().len(), 0);
+}
+
+#[test]
+fn test_list_templates_keeps_first_save_order() {
+    let s = Fixture::new();
+    save(&s, "bugfix", 86_400, 100, 5_000, "bug");
+    save(&s, "feature", 30 * 86_400, 1_000, 50_000, "feature");
+    save(&s, "bugfix", 2 * 86_400, 200, 6_000, "bug");
+
+    let templates = s.client.list_templates();
+    assert_eq!(templates.len(), 2);
+    assert_eq!(templates.get(0).unwrap().name, name(&s, "bugfix"));
+    assert_eq!(templates.get(1).unwrap().name, name(&s, "feature"));
+
+    let bugfix = s.client.get_template(&name(&s, "bugfix"));
+    assert_eq!(bugfix.deadline_horizon, 2 * 86_400);
+    assert_eq!(bugfix.min_amount, 200);
+    assert_eq!(bugfix.max_amount, 6_000);
+}
@@ -0,0 +1,168 @@
+//! Escrow top-up tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `topup_escrow` adds funds without touching the deadline
+//! - `topup_and_extend` adds funds and moves the deadline forward atomically
+//! - Shorter deadlines, non-positive amounts and non-locked escrows are rejected
+//! - The amount policy is enforced against the new escrow total
+//! - Top-ups are pulled in the escrow's own token
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus};
+use soroban_sdk::{symbol_short, testutils::Ledger};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+/// These tests use absolute deadlines counted from ledger time zero.
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.env.ledger().set_timestamp(0);
+    s
+}
+
+// ── topup_and_extend ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_topup_and_extend_updates_amount_and_deadline() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    s.client.topup_and_extend(&1, &500, &2_000);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_500);
+    assert_eq!(escrow.remaining_amount, 1_500);
+    assert_eq!(escrow.deadline, 2_000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.client.address), 1_500);
+    assert_eq!(s.token.balance(&s.depositor), 998_500);
+    assert!(s.count_topic(symbol_short!("f_topup")) > 0);
+    assert!(s.count_topic(symbol_short!("dl_ext")) > 0);
+}
+
+#[test]
+fn test_topup_and_extend_after_partial_release() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+    s.client.partial_release(&1, &s.contributor, &400);
+
+    s.client.topup_and_extend(&1, &200, &1_000);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_200);
+    assert_eq!(escrow.remaining_amount, 800);
+    assert_eq!(s.token.balance(&s.client.address), 800);
+}
+
+#[test]
+fn test_topup_and_extend_rejects_shorter_deadline() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    let res = s.client.try_topup_and_extend(&1, &500, &999);
+    assert_eq!(res, Err(Ok(Error::InvalidDeadline)));
+    assert_eq!(s.client.get_escrow_info(&1).amount, 1_000);
+}
+
+#[test]
+fn test_topup_and_extend_rejects_non_positive_amount() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    let res = s.client.try_topup_and_extend(&1, &0, &2_000);
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_topup_and_extend_rejects_released_escrow() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+    s.client.release_funds(&1, &s.contributor);
+
+    let res = s.client.try_topup_and_extend(&1, &500, &2_000);
+    assert_eq!(res, Err(Ok(Error::FundsNotLocked)));
+}
+
+#[test]
+fn test_topup_and_extend_rejects_unknown_bounty() {
+    let s = setup();
+    let res = s.client.try_topup_and_extend(&42, &500, &2_000);
+    assert_eq!(res, Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_topup_and_extend_enforces_policy_on_new_total() {
+    let s = setup();
+    s.client.set_amount_policy(&s.admin, &100, &1_200);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    let res = s.client.try_topup_and_extend(&1, &300, &2_000);
+    assert_eq!(res, Err(Ok(Error::AmountAboveMaximum)));
+
+    s.client.topup_and_extend(&1, &200, &2_000);
+    assert_eq!(s.client.get_escrow_info(&1).amount, 1_200);
+}
+
+#[test]
+fn test_topup_and_extend_pulls_escrow_token() {
+    let s = setup();
+    let other = s.new_token(10_000);
+    s.client
+        .lock_funds_token(&s.depositor, &1, &1_000, &1_000, &other);
+
+    s.client.topup_and_extend(&1, &500, &1_500);
+
+    assert_eq!(s.balance(&other, &s.client.address), 1_500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+// ── topup_escrow ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_topup_escrow_keeps_deadline() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    s.client.topup_escrow(&1, &250);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_250);
+    assert_eq!(escrow.remaining_amount, 1_250);
+    assert_eq!(escrow.deadline, 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 1_250);
+    assert!(s.count_topic(symbol_short!("f_topup")) > 0);
+    assert!(!s.count_topic(symbol_short!("dl_ext")) > 0);
+}
+
+#[test]
+fn test_topup_escrow_rejects_refunded_escrow() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &0);
+    s.client.refund(&1);
+
+    let res = s.client.try_topup_escrow(&1, &250);
+    assert_eq!(res, Err(Ok(Error::FundsNotLocked)));
+}
+
+#[test]
+fn test_topup_escrow_enforces_policy_on_new_total() {
+    let s = setup();
+    s.client.set_amount_policy(&s.admin, &100, &1_100);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+
+    let res = s.client.try_topup_escrow(&1, &101);
+    assert_eq!(res, Err(Ok(Error::AmountAboveMaximum)));
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+}
+
+#[test]
+fn test_topup_escrow_rejected_while_lock_paused() {
+    let s = setup();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &1_000);
+    s.client.set_paused(&Some(true), &None, &None, &None);
+
+    let res = s.client.try_topup_escrow(&1, &250);
+    assert_eq!(res, Err(Ok(Error::FundsPaused)));
+}
@@ -0,0 +1,95 @@
+//! Escrow view counter tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `record_view` increments the per-bounty counter read by `get_escrow_views`
+//! - Counters are independent per bounty
+//! - A single viewer is held to the anti-abuse cooldown and window limits
+//! - Viewing does not put the viewer's own operations in cooldown
+//! - Unknown bounties are rejected
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::Error;
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DEADLINE: u64 = 50_000;
+
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s
+}
+
+#[test]
+fn test_views_counted_per_bounty() {
+    let s = setup();
+    assert_eq!(s.client.get_escrow_views(&1), 0);
+
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    assert_eq!(s.client.record_view(&alice, &1), 1);
+    assert_eq!(s.client.record_view(&bob, &1), 2);
+    s.advance(60);
+    assert_eq!(s.client.record_view(&bob, &2), 1);
+
+    assert_eq!(s.client.get_escrow_views(&1), 2);
+    assert_eq!(s.client.get_escrow_views(&2), 1);
+}
+
+#[test]
+#[should_panic(expected = "Operation in cooldown period")]
+fn test_repeat_view_within_cooldown_rejected() {
+    let s = setup();
+    let viewer = Address::generate(&s.env);
+    s.client.record_view(&viewer, &1);
+    s.client.record_view(&viewer, &2);
+}
+
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_views_capped_per_window() {
+    let s = setup();
+    s.client.update_anti_abuse_config(&3_600, &2, &10);
+    let viewer = Address::generate(&s.env);
+
+    s.client.record_view(&viewer, &1);
+    s.advance(10);
+    s.client.record_view(&viewer, &1);
+    assert_eq!(s.client.get_escrow_views(&1), 2);
+    s.advance(10);
+    s.client.record_view(&viewer, &1);
+}
+
+#[test]
+fn test_view_after_cooldown_counts() {
+    let s = setup();
+    let viewer = Address::generate(&s.env);
+    s.client.record_view(&viewer, &1);
+    s.advance(60);
+    assert_eq!(s.client.record_view(&viewer, &1), 2);
+}
+
+#[test]
+fn test_view_does_not_throttle_viewer_operations() {
+    let s = setup();
+    s.advance(100);
+    s.client.record_view(&s.depositor, &1);
+    // A lock right after a view is not in cooldown.
+    s.client.lock_funds(&s.depositor, &3, &1_000, &DEADLINE);
+    // Nor does the lock put the next view in cooldown.
+    s.advance(60);
+    s.client.record_view(&s.depositor, &1);
+    s.client.lock_funds(&s.depositor, &4, &1_000, &DEADLINE);
+    assert_eq!(s.client.get_escrow_views(&1), 2);
+}
+
+#[test]
+fn test_view_of_unknown_bounty_rejected() {
+    let s = setup();
+    let viewer = Address::generate(&s.env);
+    let res = s.client.try_record_view(&viewer, &99);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert_eq!(s.client.get_escrow_views(&99), 0);
+}
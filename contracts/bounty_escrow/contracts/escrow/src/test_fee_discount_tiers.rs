@@ -0,0 +1,107 @@
+//! Lock-fee volume discount tests for `BountyEscrowContract::set_fee_discount_tiers`.
+//!
+//! Covers:
+//! - Locks below, at and above each tier boundary pay the matching rate
+//! - Without tiers the base lock fee rate applies
+//! - Tier validation (ordering, monotonic discounts, 100% cap) and clearing
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::Error;
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+const DEADLINE: u64 = 50_000;
+
+struct Suite {
+    fx: Fixture,
+    treasury: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        let treasury = Address::generate(&fx.env);
+        // 10% base lock fee.
+        fx.client
+            .update_fee_config(&Some(1_000), &Some(0), &Some(treasury.clone()), &Some(true));
+        Self { fx, treasury }
+    }
+
+    /// Lock `amount` and return the lock fee charged for it.
+    fn lock_fee(&self, bounty_id: u64, amount: i128) -> i128 {
+        let before = self.token.balance(&self.treasury);
+        self.lock(bounty_id, amount, DEADLINE);
+        let fee = self.token.balance(&self.treasury) - before;
+        assert_eq!(self.client.get_escrow_info(&bounty_id).amount, amount - fee);
+        fee
+    }
+}
+
+#[test]
+fn test_discount_applied_across_tier_boundaries() {
+    let s = Suite::new();
+    // 10% off the rate from 10_000, 50% off from 50_000.
+    s.client
+        .set_fee_discount_tiers(&vec![&s.env, (10_000, 1_000), (50_000, 5_000)]);
+
+    assert_eq!(s.lock_fee(1, 9_999), 1_000);
+    assert_eq!(s.lock_fee(2, 10_000), 900);
+    assert_eq!(s.lock_fee(3, 49_999), 4_500);
+    assert_eq!(s.lock_fee(4, 50_000), 2_500);
+    assert_eq!(s.lock_fee(5, 100_000), 5_000);
+}
+
+#[test]
+fn test_no_tiers_falls_back_to_base_rate() {
+    let s = Suite::new();
+    assert!(s.client.get_fee_discount_tiers().is_empty());
+    assert_eq!(s.lock_fee(1, 100_000), 10_000);
+}
+
+#[test]
+fn test_full_discount_waives_lock_fee() {
+    let s = Suite::new();
+    s.client
+        .set_fee_discount_tiers(&vec![&s.env, (20_000, 10_000)]);
+    assert_eq!(s.lock_fee(1, 20_000), 0);
+    assert_eq!(s.lock_fee(2, 19_999), 2_000);
+}
+
+#[test]
+fn test_tier_validation_and_clearing() {
+    let s = Suite::new();
+    let res = s
+        .client
+        .try_set_fee_discount_tiers(&vec![&s.env, (50_000, 1_000), (10_000, 2_000)]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    let res = s
+        .client
+        .try_set_fee_discount_tiers(&vec![&s.env, (10_000, 2_000), (50_000, 1_000)]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    let res = s
+        .client
+        .try_set_fee_discount_tiers(&vec![&s.env, (10_000, 10_001)]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    let res = s
+        .client
+        .try_set_fee_discount_tiers(&vec![&s.env, (0, 1_000)]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+
+    let tiers = vec![&s.env, (10_000, 1_000), (50_000, 5_000)];
+    s.client.set_fee_discount_tiers(&tiers);
+    assert_eq!(s.client.get_fee_discount_tiers(), tiers);
+
+    s.client.set_fee_discount_tiers(&Vec::new(&s.env));
+    assert!(s.client.get_fee_discount_tiers().is_empty());
+    assert_eq!(s.lock_fee(1, 50_000), 5_000);
+}
@@ -0,0 +1,132 @@
+//! Forced finalization tests for `BountyEscrowContract::finalize_escrow`.
+//!
+//! Covers:
+//! - An escrow the contract can no longer cover is closed as `Refunded`
+//! - The completion time is recorded and `ForceFinalized` is emitted
+//! - A solvent escrow cannot be force-finalized
+//! - Solvency follows `is_escrow_solvent` when several escrows share a shortfall
+//! - Already closed or unknown escrows are rejected
+
+#![cfg(test)]
+
+use crate::events::ForceFinalized;
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address};
+
+const DEADLINE: u64 = 5_000;
+
+/// Move `amount` out of the contract behind the escrow records' back.
+fn drain(s: &Fixture, amount: i128) {
+    let sink = Address::generate(&s.env);
+    s.env.as_contract(&s.client.address, || {
+        s.token.transfer(&s.client.address, &sink, &amount);
+    });
+}
+
+fn force_finalized(s: &Fixture) -> Option<ForceFinalized> {
+    s.last_event(symbol_short!("f_final"))
+}
+
+#[test]
+fn test_insolvent_escrow_is_force_finalized() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    drain(&s, 600);
+
+    s.client.finalize_escrow(&1);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    let bundle = s.client.get_escrow_bundle(&1);
+    assert!(!bundle.funds_locked);
+    assert_eq!(bundle.completed_at, Some(s.env.ledger().timestamp()));
+
+    let event = force_finalized(&s).unwrap();
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.written_off, 1_000);
+    assert_eq!(event.contract_balance, 400);
+    assert_eq!(event.timestamp, s.env.ledger().timestamp());
+
+    // The finalized escrow is closed for good.
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
+
+#[test]
+fn test_partially_released_escrow_checks_remaining_amount() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.partial_release(&1, &s.contributor, &700);
+
+    // 300 left on record and in the contract: still solvent.
+    let res = s.client.try_finalize_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+
+    drain(&s, 1);
+    s.client.finalize_escrow(&1);
+    let event = force_finalized(&s).unwrap();
+    assert_eq!(event.written_off, 300);
+    assert_eq!(event.contract_balance, 299);
+}
+
+#[test]
+fn test_solvent_escrow_is_refused() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+
+    let res = s.client.try_finalize_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert!(force_finalized(&s).is_none());
+}
+
+#[test]
+fn test_shared_shortfall_finalizes_only_uncovered_escrow() {
+    let s = Fixture::new();
+    s.lock(1, 800, DEADLINE);
+    s.lock(2, 800, DEADLINE);
+    drain(&s, 1_500);
+    // Escrows of 800 and 800 against a balance of 100: neither is covered.
+    assert!(!s.client.is_escrow_solvent(&1));
+    assert!(!s.client.is_escrow_solvent(&2));
+
+    s.client.finalize_escrow(&2);
+    assert!(!s.client.is_escrow_solvent(&1));
+    s.client.finalize_escrow(&1);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_finalize_agrees_with_is_escrow_solvent() {
+    let s = Fixture::new();
+    s.lock(1, 80, DEADLINE);
+    s.lock(2, 80, DEADLINE);
+    drain(&s, 60);
+
+    // A balance of 100 covers the first escrow but not the second.
+    assert!(s.client.is_escrow_solvent(&1));
+    assert!(!s.client.is_escrow_solvent(&2));
+    let res = s.client.try_finalize_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+
+    s.client.finalize_escrow(&2);
+    assert_eq!(force_finalized(&s).unwrap().written_off, 80);
+    assert!(s.client.is_escrow_solvent(&1));
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_closed_or_missing_escrow_rejected() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.release_funds(&1, &s.contributor);
+
+    let res = s.client.try_finalize_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+
+    let res = s.client.try_finalize_escrow(&99);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
@@ -299,7 +299,7 @@ mod gas_profile {
         s.mint(&s.depositor.clone(), 1_000);
         s.lock(1, 1_000);
         s.client
-            .approve_refund(&1, &1_000, &s.depositor.clone(), &RefundMode::Full);
+            .approve_refund(&1, &1_000, &s.depositor.clone(), &RefundMode::Full, &None);
         s.env.budget().reset_unlimited();
         print_header();
         let d = s.refund(1);
@@ -313,7 +313,7 @@ mod gas_profile {
         s.mint(&s.depositor.clone(), 1_000);
         s.lock(1, 1_000);
         s.client
-            .approve_refund(&1, &400, &s.depositor.clone(), &RefundMode::Partial);
+            .approve_refund(&1, &400, &s.depositor.clone(), &RefundMode::Partial, &None);
         s.env.budget().reset_unlimited();
         print_header();
         let d = s.refund(1);
@@ -869,7 +869,7 @@ mod gas_profile {
             s.env.budget().reset_unlimited();
             let d = measure(&s.env, || {
                 s.client
-                    .approve_refund(&1, &1_000, &s.depositor.clone(), &RefundMode::Full);
+                    .approve_refund(&1, &1_000, &s.depositor.clone(), &RefundMode::Full, &None);
             });
             row!("approve_refund", d.cpu, d.mem);
         }
@@ -1109,7 +1109,7 @@ fn test_approved_refund_blocked_when_refund_paused() {
     lock_bounty(&client, &env, &depositor, 1, 500);
 
     // Admin approves an early refund
-    client.approve_refund(&1, &250, &depositor, &RefundMode::Partial);
+    client.approve_refund(&1, &250, &depositor, &RefundMode::Partial, &None);
 
     // Pause refund — even approved refunds should be blocked
     client.set_paused(&None, &None, &Some(true), &None);
@@ -1123,7 +1123,7 @@ fn test_approved_refund_succeeds_when_only_lock_paused() {
     let (client, _, depositor, token) = setup(&env, 1_000);
 
     lock_bounty(&client, &env, &depositor, 1, 500);
-    client.approve_refund(&1, &200, &depositor, &RefundMode::Partial);
+    client.approve_refund(&1, &200, &depositor, &RefundMode::Partial, &None);
 
     // Only lock is paused — refund should still work
     client.set_paused(&Some(true), &None, &None, &None);
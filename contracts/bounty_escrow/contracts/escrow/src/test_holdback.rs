@@ -0,0 +1,165 @@
+//! Review holdback tests for `BountyEscrowContract::release_with_holdback`.
+//!
+//! Covers:
+//! - The immediate leg pays everything but the held-back share
+//! - `release_holdback` pays the rest only after the review period
+//! - The release fee is taken from each share as it is paid out
+//! - `revoke_holdback` returns the held-back share to the depositor and
+//!   records it as a partial refund
+//! - Balance invariants hold while a holdback is pending
+//! - Parameter validation and archive protection
+//! - An emergency withdrawal clears pending holdbacks with their escrows
+
+#![cfg(test)]
+
+use crate::events::HoldbackSettled;
+use crate::test_support::Fixture;
+use crate::{multitoken_invariants, Error, EscrowStatus};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address};
+
+const DEADLINE: u64 = 50_000;
+const REVIEW: u64 = 7_200;
+
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.client.lock_funds(&s.depositor, &1, &10_000, &DEADLINE);
+    s
+}
+
+fn settled(s: &Fixture) -> Option<HoldbackSettled> {
+    s.last_event(symbol_short!("hb_set"))
+}
+
+#[test]
+fn test_holdback_paid_after_review_period() {
+    let s = setup();
+    s.client
+        .release_with_holdback(&1, &s.contributor, &2_000, &REVIEW);
+
+    assert_eq!(s.token.balance(&s.contributor), 8_000);
+    assert_eq!(s.token.balance(&s.client.address), 2_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    let holdback = s.client.get_holdback(&1).unwrap();
+    assert_eq!(holdback.amount, 2_000);
+    assert_eq!(holdback.releasable_at, 1_000 + REVIEW);
+    s.env.as_contract(&s.client.address, || {
+        assert!(multitoken_invariants::check_all_invariants(&s.env).healthy);
+    });
+
+    s.advance(REVIEW - 1);
+    let res = s.client.try_release_holdback(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+
+    s.advance(1);
+    s.client.release_holdback(&1);
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert!(s.client.get_holdback(&1).is_none());
+
+    let event = settled(&s).unwrap();
+    assert_eq!(event.recipient, s.contributor);
+    assert_eq!(event.amount, 2_000);
+    assert!(!event.revoked);
+
+    let res = s.client.try_release_holdback(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_revoked_holdback_returns_to_depositor() {
+    let s = setup();
+    let before = s.token.balance(&s.depositor);
+    s.client
+        .release_with_holdback(&1, &s.contributor, &2_500, &REVIEW);
+
+    s.client.revoke_holdback(&1);
+    assert_eq!(s.token.balance(&s.contributor), 7_500);
+    assert_eq!(s.token.balance(&s.depositor) - before, 2_500);
+    assert!(settled(&s).unwrap().revoked);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    let refund = escrow.refund_history.last().unwrap();
+    assert_eq!(refund.amount, 2_500);
+    assert_eq!(refund.recipient, s.depositor);
+    let stats = s.client.get_aggregate_stats();
+    assert_eq!((stats.count_released, stats.count_refunded), (0, 1));
+    assert_eq!(s.client.get_aggregate_stats_cached(), stats);
+    s.client.archive_escrow(&1);
+
+    s.advance(REVIEW);
+    let res = s.client.try_release_holdback(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_holdback_charges_release_fee_per_share() {
+    let s = setup();
+    let fee_recipient = Address::generate(&s.env);
+    s.client
+        .update_fee_config(&None, &Some(500), &Some(fee_recipient.clone()), &Some(true));
+
+    s.client
+        .release_with_holdback(&1, &s.contributor, &2_000, &REVIEW);
+    assert_eq!(s.token.balance(&s.contributor), 7_600);
+    assert_eq!(s.token.balance(&fee_recipient), 400);
+    assert_eq!(s.client.get_holdback(&1).unwrap().amount, 2_000);
+
+    s.advance(REVIEW);
+    s.client.release_holdback(&1);
+    assert_eq!(s.token.balance(&s.contributor), 9_500);
+    assert_eq!(s.token.balance(&fee_recipient), 500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_pending_holdback_blocks_archiving() {
+    let s = setup();
+    s.client
+        .release_with_holdback(&1, &s.contributor, &2_000, &REVIEW);
+    let res = s.client.try_archive_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::EscrowNotTerminal);
+
+    s.advance(REVIEW);
+    s.client.release_holdback(&1);
+    s.client.archive_escrow(&1);
+}
+
+#[test]
+fn test_holdback_validation() {
+    let s = setup();
+    for bps in [0, 10_000] {
+        let res = s
+            .client
+            .try_release_with_holdback(&1, &s.contributor, &bps, &REVIEW);
+        assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    }
+    let res = s
+        .client
+        .try_release_with_holdback(&99, &s.contributor, &2_000, &REVIEW);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.client.release_funds(&1, &s.contributor);
+    let res = s
+        .client
+        .try_release_with_holdback(&1, &s.contributor, &2_000, &REVIEW);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
+
+#[test]
+fn test_emergency_withdraw_clears_holdback() {
+    let s = setup();
+    s.client
+        .release_with_holdback(&1, &s.contributor, &2_000, &REVIEW);
+    let target = Address::generate(&s.env);
+    s.client.set_paused(&Some(true), &None, &None, &None);
+    s.client.emergency_withdraw(&target);
+    assert_eq!(s.token.balance(&target), 2_000);
+    assert!(s.client.get_holdback(&1).is_none());
+
+    s.advance(REVIEW);
+    let res = s.client.try_release_holdback(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s.client.try_revoke_holdback(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
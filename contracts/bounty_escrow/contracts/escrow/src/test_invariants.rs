@@ -160,7 +160,7 @@ fn test_invariant_checker_healthy_refunded_state() {
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
     // Approve refund and execute - should pass invariants
-    client.approve_refund(&bounty_id, &amount, &depositor, &RefundMode::Full);
+    client.approve_refund(&bounty_id, &amount, &depositor, &RefundMode::Full, &None);
     client.refund(&bounty_id);
 
     // Verify invariants pass for refunded state
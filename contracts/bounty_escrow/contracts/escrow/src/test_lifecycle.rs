@@ -124,14 +124,27 @@ fn test_full_bounty_lifecycle_with_refund() {
         invoke: &MockAuthInvoke {
             contract: &escrow_client.address,
             fn_name: "approve_refund",
-            args: (bounty_id, 2000i128, depositor.clone(), RefundMode::Partial).into_val(&env),
+            args: (
+                bounty_id,
+                2000i128,
+                depositor.clone(),
+                RefundMode::Partial,
+                None::<u64>,
+            )
+                .into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
     // Approve a partial refund
     let refund_amount = 2000;
-    escrow_client.approve_refund(&bounty_id, &refund_amount, &depositor, &RefundMode::Partial);
+    escrow_client.approve_refund(
+        &bounty_id,
+        &refund_amount,
+        &depositor,
+        &RefundMode::Partial,
+        &None,
+    );
 
     // Verify eligibility
     let (can_refund, deadline_passed, remaining, approval) =
@@ -196,12 +209,25 @@ fn test_full_bounty_lifecycle_with_refund() {
         invoke: &MockAuthInvoke {
             contract: &escrow_client.address,
             fn_name: "approve_refund",
-            args: (bounty_id, final_amount, depositor.clone(), RefundMode::Full).into_val(&env),
+            args: (
+                bounty_id,
+                final_amount,
+                depositor.clone(),
+                RefundMode::Full,
+                None::<u64>,
+            )
+                .into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
-    escrow_client.approve_refund(&bounty_id, &final_amount, &depositor, &RefundMode::Full);
+    escrow_client.approve_refund(
+        &bounty_id,
+        &final_amount,
+        &depositor,
+        &RefundMode::Full,
+        &None,
+    );
 
     // Set auth for final refund with nested token transfer
     env.mock_auths(&[
@@ -0,0 +1,189 @@
+//! Minimum lock duration tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - Escrows record their lock time (`get_escrow_locked_at`), and `get_escrow_age`
+//!   reports the time since then (0 for legacy escrows without a timestamp)
+//! - `release_funds` and `partial_release` reject escrows locked for less than
+//!   the configured minimum duration, and succeed exactly at the boundary
+//! - Only the admin can configure the duration; zero (the default) disables it
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{DataKey, DataKeyExt, Error, EscrowStatus, LockFundsItem, RefundRecord};
+use soroban_sdk::{contracttype, Address, Vec};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+/// `Escrow` as stored before lock times and per-escrow tokens were recorded.
+#[contracttype]
+#[derive(Clone, Debug)]
+struct LegacyEscrow {
+    depositor: Address,
+    amount: i128,
+    remaining_amount: i128,
+    status: EscrowStatus,
+    deadline: u64,
+    refund_history: Vec<RefundRecord>,
+}
+
+/// Lock funds at the current ledger time, without stepping the clock.
+fn lock(s: &Fixture, bounty_id: u64, amount: i128) {
+    let deadline = s.now() + 10_000;
+    s.client
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+}
+
+// ── locked_at ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_lock_records_locked_at() {
+    let s = Fixture::new();
+    lock(&s, 1, 1_000);
+    assert_eq!(s.client.get_escrow_locked_at(&1), 1_000);
+}
+
+#[test]
+fn test_batch_lock_records_locked_at() {
+    let s = Fixture::new();
+    let deadline = s.now() + 10_000;
+    s.client.batch_lock_funds(&soroban_sdk::vec![
+        &s.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: s.depositor.clone(),
+            amount: 500,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: s.depositor.clone(),
+            amount: 700,
+            deadline,
+        },
+    ]);
+
+    assert_eq!(s.client.get_escrow_locked_at(&1), 1_000);
+    assert_eq!(s.client.get_escrow_locked_at(&2), 1_000);
+    assert_eq!(
+        s.client.try_get_escrow_locked_at(&3),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_get_escrow_age_tracks_time_since_lock() {
+    let s = Fixture::new();
+    lock(&s, 1, 1_000);
+    assert_eq!(s.client.get_escrow_age(&1), 0);
+
+    s.advance(250);
+    assert_eq!(s.client.get_escrow_age(&1), 250);
+
+    assert_eq!(
+        s.client.try_get_escrow_age(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_get_escrow_age_is_zero_for_legacy_escrow() {
+    let s = Fixture::new();
+    s.client.set_min_lock_duration(&s.admin, &100);
+    lock(&s, 1, 1_000);
+
+    // Rewrite the record in the pre-upgrade layout, with no lock time or
+    // per-escrow token stored beside it.
+    s.env.as_contract(&s.client.address, || {
+        let storage = s.env.storage().persistent();
+        storage.set(
+            &DataKey::Escrow(1),
+            &LegacyEscrow {
+                depositor: s.depositor.clone(),
+                amount: 1_000,
+                remaining_amount: 1_000,
+                status: EscrowStatus::Locked,
+                deadline: 11_000,
+                refund_history: Vec::new(&s.env),
+            },
+        );
+        storage.remove(&DataKeyExt::EscrowLockedAt(1));
+        storage.remove(&DataKeyExt::EscrowToken(1));
+    });
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.client.get_escrow_locked_at(&1), 0);
+
+    s.advance(10);
+    assert_eq!(s.client.get_escrow_age(&1), 0);
+    // Unknown lock time never holds a legacy escrow back.
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+// ── minimum duration ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_min_lock_duration_defaults_to_zero() {
+    let s = Fixture::new();
+    assert_eq!(s.client.get_min_lock_duration(), 0);
+
+    lock(&s, 1, 1_000);
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_release_funds_respects_min_lock_duration_boundary() {
+    let s = Fixture::new();
+    s.client.set_min_lock_duration(&s.admin, &100);
+    lock(&s, 1, 1_000);
+
+    s.advance(99);
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res, Err(Ok(Error::LockDurationNotMet)));
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    s.advance(1);
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_partial_release_respects_min_lock_duration_boundary() {
+    let s = Fixture::new();
+    s.client.set_min_lock_duration(&s.admin, &100);
+    lock(&s, 1, 1_000);
+
+    s.advance(99);
+    let res = s.client.try_partial_release(&1, &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::LockDurationNotMet)));
+
+    s.advance(1);
+    s.client.partial_release(&1, &s.contributor, &400);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 600);
+}
+
+#[test]
+fn test_min_lock_duration_can_be_disabled() {
+    let s = Fixture::new();
+    s.client.set_min_lock_duration(&s.admin, &100);
+    lock(&s, 1, 1_000);
+    assert_eq!(
+        s.client.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::LockDurationNotMet))
+    );
+
+    s.client.set_min_lock_duration(&s.admin, &0);
+    s.client.release_funds(&1, &s.contributor);
+}
+
+#[test]
+fn test_set_min_lock_duration_rejects_non_admin() {
+    let s = Fixture::new();
+    let res = s.client.try_set_min_lock_duration(&s.depositor, &100);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.client.get_min_lock_duration(), 0);
+}
@@ -0,0 +1,163 @@
+//! Escrow consolidation tests for `BountyEscrowContract::merge_escrows`.
+//!
+//! Covers:
+//! - The source's remaining amount moves into the target and the source closes as `Merged`
+//! - The merged target pays out the combined amount
+//! - `EscrowsMerged` is emitted and the aggregate stats count nothing as refunded
+//! - The target takes on the source's non-refundable flag, dependency and allowlist
+//! - Mismatched depositors, closed escrows and pending claims are rejected
+//! - Conflicting dependencies and disjoint allowlists are rejected
+
+#![cfg(test)]
+
+use crate::events::EscrowsMerged;
+use crate::test_support::Fixture;
+use crate::{DependencyStatus, DisputeReason, Error, EscrowStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token, vec, Address, String,
+};
+
+const DEADLINE: u64 = 50_000;
+
+fn merged(s: &Fixture) -> Option<EscrowsMerged> {
+    s.last_event(symbol_short!("merged"))
+}
+
+#[test]
+fn test_merge_moves_remaining_into_target() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 2_500, DEADLINE);
+    s.client.partial_release(&1, &s.contributor, &400);
+
+    s.client.merge_escrows(&1, &2);
+
+    let source = s.client.get_escrow_info(&1);
+    assert_eq!(source.status, EscrowStatus::Merged);
+    assert_eq!(source.remaining_amount, 0);
+    let target = s.client.get_escrow_info(&2);
+    assert_eq!(target.status, EscrowStatus::Locked);
+    assert_eq!(target.amount, 3_100);
+    assert_eq!(target.remaining_amount, 3_100);
+
+    let event = merged(&s).unwrap();
+    assert_eq!(event.source_bounty_id, 1);
+    assert_eq!(event.target_bounty_id, 2);
+    assert_eq!(event.amount, 600);
+    assert_eq!(event.target_remaining, 3_100);
+
+    let stats = s.client.get_aggregate_stats();
+    assert_eq!(s.client.get_aggregate_stats_cached(), stats);
+    assert_eq!((stats.total_refunded, stats.count_refunded), (0, 0));
+    assert_eq!((stats.total_locked, stats.count_locked), (3_100, 1));
+
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 3_500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_merge_requires_same_depositor() {
+    let s = Fixture::new();
+    let other = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&other, &10_000);
+    s.lock(1, 1_000, DEADLINE);
+    s.lock_from(&other, 2, 1_000, DEADLINE);
+
+    let res = s.client.try_merge_escrows(&1, &2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+    let res = s.client.try_merge_escrows(&1, &1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DuplicateBountyId);
+    let res = s.client.try_merge_escrows(&1, &99);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert!(merged(&s).is_none());
+}
+
+#[test]
+fn test_merge_rejects_closed_escrows() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client.release_funds(&1, &s.contributor);
+
+    let res = s.client.try_merge_escrows(&1, &2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+    let res = s.client.try_merge_escrows(&2, &1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
+
+#[test]
+fn test_merge_rejects_pending_claims() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Other);
+
+    let res = s.client.try_merge_escrows(&1, &2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ClaimPending);
+    let res = s.client.try_merge_escrows(&2, &1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ClaimPending);
+    assert_eq!(s.client.get_escrow_info(&1).amount, 1_000);
+}
+
+#[test]
+fn test_merge_carries_source_policies() {
+    let s = Fixture::new();
+    let milestone = String::from_str(&s.env, "audit-report");
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client.set_non_refundable(&1, &s.depositor);
+    s.client.set_bounty_dependency(&1, &milestone);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &vec![&s.env, s.contributor.clone()]);
+
+    s.client.merge_escrows(&1, &2);
+
+    assert!(s.client.is_non_refundable(&2));
+    assert_eq!(
+        s.client.get_bounty_dependency(&2),
+        Some((milestone.clone(), DependencyStatus::Pending))
+    );
+    assert_eq!(
+        s.client.get_escrow_recipient_allowlist(&2),
+        vec![&s.env, s.contributor.clone()]
+    );
+    let res = s.client.try_release_funds(&2, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DependencyNotSatisfied);
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    let res = s.client.try_refund(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RefundForbidden);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Completed);
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 2_000);
+}
+
+#[test]
+fn test_merge_rejects_conflicting_policies() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client
+        .set_bounty_dependency(&1, &String::from_str(&s.env, "audit-report"));
+    s.client
+        .set_bounty_dependency(&2, &String::from_str(&s.env, "mainnet-launch"));
+    let res = s.client.try_merge_escrows(&1, &2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DependencyNotSatisfied);
+
+    s.lock(3, 1_000, DEADLINE);
+    s.lock(4, 1_000, DEADLINE);
+    let other = Address::generate(&s.env);
+    s.client
+        .set_escrow_recipient_allowlist(&3, &s.depositor, &vec![&s.env, s.contributor.clone()]);
+    s.client
+        .set_escrow_recipient_allowlist(&4, &s.depositor, &vec![&s.env, other]);
+    let res = s.client.try_merge_escrows(&3, &4);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ParticipantNotAllowed);
+    assert_eq!(s.client.get_escrow_info(&3).status, EscrowStatus::Locked);
+    assert!(merged(&s).is_none());
+}
@@ -0,0 +1,392 @@
+//! Multi-token escrow tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `lock_funds` records the default token on the escrow
+//! - Escrows stored before per-escrow tokens fall back to the default token
+//! - `lock_funds_token` pulls funds in the requested token
+//! - Release, partial release and refund pay out in the escrow's own token
+//! - Balance invariants hold independently for each token
+//! - Opt-in auto-halt pauses the contract on an INV-2 breach instead of panicking
+//! - Per-token fee config applies to escrows locked in that token
+//! - Tokens backing an escrow cannot be rescued
+//! - `emergency_withdraw` drains every escrow token
+//! - `get_balance_of` reports the contract balance of any token
+//! - `get_surplus` reports stray deposits, and `SurplusDetected` fires once
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{multitoken_invariants, DataKeyExt, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol,
+};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+struct Suite {
+    fx: Fixture,
+    other_token: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        // Lock repeatedly from one depositor without stepping past the
+        // anti-abuse cooldown, which is skipped at ledger time zero.
+        fx.env.ledger().set_timestamp(0);
+        let other_token = fx.new_token(1_000_000);
+        Self { fx, other_token }
+    }
+
+    /// Simulate an incident moving `amount` of `token_id` out of the contract.
+    fn drain(&self, token_id: &Address, amount: i128) {
+        token::Client::new(&self.env, token_id).transfer(
+            &self.client.address,
+            &Address::generate(&self.env),
+            &amount,
+        );
+    }
+
+    fn deadline(&self) -> u64 {
+        self.env.ledger().timestamp() + 1000
+    }
+}
+
+// ── locking ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_lock_funds_records_default_token() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+
+    assert_eq!(s.client.get_escrow_token(&1), s.token.address);
+}
+
+#[test]
+fn test_escrow_without_recorded_token_uses_default() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    // Simulate an escrow stored before per-escrow tokens were recorded.
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .remove(&DataKeyExt::EscrowToken(1));
+    });
+
+    assert_eq!(s.client.get_escrow_token(&1), s.token.address);
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.balance(&s.token.address, &s.contributor), 1_000);
+}
+
+#[test]
+fn test_lock_funds_token_uses_requested_token() {
+    let s = Suite::new();
+    s.client
+        .lock_funds_token(&s.depositor, &1, &2_500, &s.deadline(), &s.other_token);
+
+    assert_eq!(s.client.get_escrow_token(&1), s.other_token);
+    assert_eq!(s.client.get_escrow_info(&1).amount, 2_500);
+    assert_eq!(s.balance(&s.other_token, &s.client.address), 2_500);
+    assert_eq!(s.balance(&s.token.address, &s.client.address), 0);
+    assert_eq!(s.balance(&s.other_token, &s.depositor), 997_500);
+}
+
+#[test]
+fn test_lock_funds_token_rejects_duplicate_bounty_across_tokens() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+
+    let res =
+        s.client
+            .try_lock_funds_token(&s.depositor, &1, &1_000, &s.deadline(), &s.other_token);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+}
+
+// ── payouts ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_release_pays_in_escrow_token() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client
+        .lock_funds_token(&s.depositor, &2, &3_000, &s.deadline(), &s.other_token);
+
+    s.client.release_funds(&2, &s.contributor);
+
+    assert_eq!(s.balance(&s.other_token, &s.contributor), 3_000);
+    assert_eq!(s.balance(&s.token.address, &s.contributor), 0);
+    assert_eq!(s.balance(&s.token.address, &s.client.address), 1_000);
+    assert_eq!(s.client.get_escrow_info(&2).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_partial_release_pays_in_escrow_token() {
+    let s = Suite::new();
+    s.client
+        .lock_funds_token(&s.depositor, &1, &1_000, &s.deadline(), &s.other_token);
+
+    s.client.partial_release(&1, &s.contributor, &400);
+
+    assert_eq!(s.balance(&s.other_token, &s.contributor), 400);
+    assert_eq!(s.balance(&s.other_token, &s.client.address), 600);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 600);
+}
+
+#[test]
+fn test_refund_returns_escrow_token() {
+    let s = Suite::new();
+    let deadline = s.deadline();
+    s.client
+        .lock_funds_token(&s.depositor, &1, &1_000, &deadline, &s.other_token);
+
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.client.refund(&1);
+
+    assert_eq!(s.balance(&s.other_token, &s.depositor), 1_000_000);
+    assert_eq!(s.balance(&s.other_token, &s.client.address), 0);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+// ── invariants ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_invariants_hold_per_token_with_mixed_escrows() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client
+        .lock_funds_token(&s.depositor, &2, &2_000, &s.deadline(), &s.other_token);
+    s.client
+        .lock_funds_token(&s.depositor, &3, &3_000, &s.deadline(), &s.other_token);
+    s.client.release_funds(&3, &s.contributor);
+
+    s.env.as_contract(&s.client.address, || {
+        let tokens = multitoken_invariants::tracked_tokens(&s.env);
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens.get(0).unwrap(), s.token.address);
+
+        assert_eq!(
+            multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address),
+            1_000
+        );
+        assert_eq!(
+            multitoken_invariants::sum_active_escrow_balances(&s.env, &s.other_token),
+            2_000
+        );
+
+        let report = multitoken_invariants::check_all_invariants(&s.env);
+        assert!(report.healthy);
+        assert_eq!(report.sum_remaining, 3_000);
+        assert_eq!(report.token_balance, 3_000);
+    });
+}
+
+#[test]
+fn test_invariants_tolerate_stray_balance_in_escrow_token() {
+    let s = Suite::new();
+    s.client
+        .lock_funds_token(&s.depositor, &1, &1_000, &s.deadline(), &s.other_token);
+
+    // Tokens sent directly to the contract are not backed by any escrow, but
+    // every escrow is still covered.
+    token::StellarAssetClient::new(&s.env, &s.other_token).mint(&s.client.address, &5);
+    s.env.as_contract(&s.client.address, || {
+        let report = multitoken_invariants::check_all_invariants(&s.env);
+        assert!(report.healthy);
+        assert_eq!(report.sum_remaining, 1_000);
+        assert_eq!(report.token_balance, 1_005);
+    });
+
+    // Losing more than the surplus leaves the escrow short.
+    s.drain(&s.other_token, 10);
+    s.env.as_contract(&s.client.address, || {
+        let report = multitoken_invariants::check_all_invariants(&s.env);
+        assert!(!report.healthy);
+    });
+}
+
+#[test]
+#[should_panic(expected = "INV-2 violated after lock")]
+fn test_invariant_breach_panics_by_default() {
+    let s = Suite::new();
+    assert!(!s.client.is_auto_halt_on_breach());
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.drain(&s.token.address, 5);
+
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline());
+}
+
+#[test]
+fn test_invariant_breach_auto_halts_when_enabled() {
+    let s = Suite::new();
+    s.client.set_auto_halt_on_breach(&true);
+    assert!(s.client.is_auto_halt_on_breach());
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.drain(&s.token.address, 5);
+
+    s.client.lock_funds(&s.depositor, &3, &1_000, &s.deadline());
+
+    let has_halt_event = s.env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .map(|t| {
+                let sym: Symbol = t.into_val(&s.env);
+                sym == Symbol::new(&s.env, "inv_halt")
+            })
+            .unwrap_or(false)
+    });
+    assert!(has_halt_event);
+
+    let flags = s.client.get_pause_flags();
+    assert!(flags.lock_paused && flags.release_paused && flags.refund_paused);
+    assert!(flags.pause_reason.is_some());
+
+    let res = s
+        .client
+        .try_lock_funds(&s.depositor, &2, &1_000, &s.deadline());
+    assert_eq!(res, Err(Ok(Error::FundsPaused)));
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res, Err(Ok(Error::FundsPaused)));
+}
+
+fn count_topic(env: &Env, name: &str) -> usize {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map(|t| {
+                    let sym: Symbol = t.into_val(env);
+                    sym == Symbol::new(env, name)
+                })
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+#[test]
+fn test_get_surplus_reports_stray_default_token_deposits() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    assert_eq!(s.client.get_surplus(), 0);
+
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&s.client.address, &7);
+    assert_eq!(s.client.get_surplus(), 7);
+
+    // Deposits in other tokens do not count towards the default-token surplus.
+    token::StellarAssetClient::new(&s.env, &s.other_token).mint(&s.client.address, &3);
+    assert_eq!(s.client.get_surplus(), 7);
+}
+
+#[test]
+fn test_surplus_detected_emitted_once() {
+    let s = Suite::new();
+    s.client.set_auto_halt_on_breach(&true);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&s.client.address, &5);
+
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    assert_eq!(count_topic(&s.env, "surplus"), 1);
+
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline());
+    assert_eq!(count_topic(&s.env, "surplus"), 1);
+    assert_eq!(count_topic(&s.env, "inv_halt"), 0);
+}
+
+#[test]
+fn test_surplus_does_not_block_disbursements() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client.lock_funds(&s.depositor, &2, &1_000, &s.deadline());
+    // A one-stroop donation leaves the contract over-collateralized.
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&s.client.address, &1);
+
+    s.client.release_funds(&1, &s.contributor);
+    s.client.partial_release(&2, &s.contributor, &400);
+    assert_eq!(s.balance(&s.token.address, &s.contributor), 1_400);
+    assert!(!s.client.get_pause_flags().release_paused);
+    assert_eq!(s.client.get_surplus(), 1);
+}
+
+// ── fees and rescue ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_token_fee_config_applies_to_locked_token() {
+    let s = Suite::new();
+    let fee_recipient = Address::generate(&s.env);
+    // 10% lock fee on the secondary token only.
+    s.client
+        .set_token_fee_config(&s.other_token, &1_000, &0, &fee_recipient, &true);
+
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client
+        .lock_funds_token(&s.depositor, &2, &1_000, &s.deadline(), &s.other_token);
+
+    assert_eq!(s.client.get_escrow_info(&1).amount, 1_000);
+    assert_eq!(s.client.get_escrow_info(&2).amount, 900);
+    assert_eq!(s.balance(&s.other_token, &fee_recipient), 100);
+    assert_eq!(s.balance(&s.token.address, &fee_recipient), 0);
+}
+
+#[test]
+fn test_rescue_rejects_token_backing_an_escrow() {
+    let s = Suite::new();
+    s.client
+        .lock_funds_token(&s.depositor, &1, &1_000, &s.deadline(), &s.other_token);
+
+    let res = s.client.try_rescue_foreign_token(&s.other_token, &s.admin);
+    assert_eq!(res, Err(Ok(Error::InvalidAssetId)));
+    assert_eq!(s.balance(&s.other_token, &s.client.address), 1_000);
+}
+
+#[test]
+fn test_emergency_withdraw_drains_every_escrow_token() {
+    let s = Suite::new();
+    let target = Address::generate(&s.env);
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client
+        .lock_funds_token(&s.depositor, &2, &2_500, &s.deadline(), &s.other_token);
+
+    s.client
+        .set_paused(&Some(true), &None::<bool>, &None::<bool>, &None);
+    s.client.emergency_withdraw(&target);
+
+    assert_eq!(s.balance(&s.token.address, &target), 1_000);
+    assert_eq!(s.balance(&s.other_token, &target), 2_500);
+    assert_eq!(s.balance(&s.other_token, &s.client.address), 0);
+    assert_eq!(
+        s.client.try_get_escrow_info(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(count_topic(&s.env, "em_tok"), 1);
+}
+
+// ── views ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_balance_of_reports_each_token() {
+    let s = Suite::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.client
+        .lock_funds_token(&s.depositor, &2, &2_500, &s.deadline(), &s.other_token);
+
+    assert_eq!(s.client.get_balance_of(&s.token.address), 1_000);
+    assert_eq!(s.client.get_balance_of(&s.other_token), 2_500);
+    assert_eq!(s.client.get_balance(), 1_000);
+
+    let unrelated = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    assert_eq!(s.client.get_balance_of(&unrelated), 0);
+}
@@ -9,13 +9,18 @@
 //! - Max fee rate (50%) is enforced
 //! - Invalid fee rates are rejected
 //! - Fee events are emitted
+//! - Refund fee applies to admin-approved refunds only, never post-deadline ones
 
 #![cfg(test)]
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient, DataKey, Error, TokenFeeConfig};
+use crate::{
+    events::{FeeCollected, FeeOperationType},
+    BountyEscrowContract, BountyEscrowContractClient, DataKey, Error, RefundMode, TokenFeeConfig,
+};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env,
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol,
 };
 
 // ── helpers ──────────────────────────────────────────────────────────────────
@@ -378,3 +383,167 @@ fn test_get_token_fee_config_returns_some_when_set() {
     assert_eq!(cfg.lock_fee_rate, 150);
     assert_eq!(cfg.release_fee_rate, 75);
 }
+
+// ── fee-exempt allowlist ──────────────────────────────────────────────────────
+
+#[test]
+fn test_fee_exempt_defaults_to_false() {
+    let s = Suite::new();
+    assert!(!s.client.is_fee_exempt(&s.depositor));
+}
+
+#[test]
+fn test_set_fee_exempt_toggles_flag() {
+    let s = Suite::new();
+    s.client.set_fee_exempt(&s.depositor, &true);
+    assert!(s.client.is_fee_exempt(&s.depositor));
+
+    s.client.set_fee_exempt(&s.depositor, &false);
+    assert!(!s.client.is_fee_exempt(&s.depositor));
+}
+
+/// An exempt depositor pays nothing on lock or release while a normal
+/// depositor pays the configured rates.
+#[test]
+fn test_fee_exempt_depositor_pays_no_fees() {
+    let s = Suite::new();
+    // 1% lock fee, 1% release fee
+    s.client
+        .set_token_fee_config(&s.token_id, &100, &100, &s.fee_recipient, &true);
+
+    let partner = Address::generate(&s.env);
+    s.client.set_fee_exempt(&partner, &true);
+
+    let amount = 100_000i128;
+    s.token_admin.mint(&partner, &amount);
+    s.fund_depositor(amount);
+
+    s.client.lock_funds(&partner, &1, &amount, &s.deadline());
+    assert_eq!(s.client.get_escrow_info(&1).amount, amount);
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.balance(&s.contributor), amount);
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+
+    // Normal depositor: lock fee 1_000, release fee ceil(99_000 * 1%) = 990.
+    s.client
+        .lock_funds(&s.depositor, &2, &amount, &s.deadline());
+    assert_eq!(s.client.get_escrow_info(&2).amount, 99_000);
+    assert_eq!(s.balance(&s.fee_recipient), 1_000);
+
+    let other = Address::generate(&s.env);
+    s.client.release_funds(&2, &other);
+    assert_eq!(s.balance(&other), 98_010);
+    assert_eq!(s.balance(&s.fee_recipient), 1_990);
+}
+
+#[test]
+fn test_fee_exempt_dry_run_lock_reports_full_amount() {
+    let s = Suite::new();
+    s.client
+        .set_token_fee_config(&s.token_id, &100, &0, &s.fee_recipient, &true);
+    s.client.set_fee_exempt(&s.depositor, &true);
+
+    let amount = 10_000i128;
+    s.fund_depositor(amount);
+    let sim = s
+        .client
+        .dry_run_lock(&s.depositor, &1, &amount, &s.deadline());
+    assert!(sim.success);
+    assert_eq!(sim.amount, amount);
+}
+
+// ── refund fee ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_set_refund_fee_rate_validates_range() {
+    let s = Suite::new();
+    assert_eq!(s.client.get_refund_fee_rate(), 0);
+
+    s.client.set_refund_fee_rate(&5_000);
+    assert_eq!(s.client.get_refund_fee_rate(), 5_000);
+
+    let res = s.client.try_set_refund_fee_rate(&5_001);
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+    let res = s.client.try_set_refund_fee_rate(&-1);
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+}
+
+#[test]
+fn test_refund_fee_charged_on_admin_approved_refund() {
+    let s = Suite::new();
+    s.client
+        .update_fee_config(&None, &None, &Some(s.fee_recipient.clone()), &Some(true));
+    // 2% refund fee
+    s.client.set_refund_fee_rate(&200);
+
+    let amount = 10_000i128;
+    s.fund_depositor(amount);
+    s.client
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+    s.client
+        .approve_refund(&1, &amount, &s.depositor, &RefundMode::Full, &None);
+    s.client.refund(&1);
+
+    assert_eq!(s.balance(&s.fee_recipient), 200);
+    assert_eq!(s.balance(&s.depositor), 9_800);
+    assert_eq!(s.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 0);
+
+    let fee_event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find_map(|(_, topics, data)| {
+            let topic: Symbol = topics.get(0).unwrap().into_val(&s.env);
+            let event: Option<FeeCollected> = if topic == symbol_short!("fee") {
+                Some(data.into_val(&s.env))
+            } else {
+                None
+            };
+            event.filter(|e| e.operation_type == FeeOperationType::Refund)
+        })
+        .unwrap();
+    assert_eq!(fee_event.amount, 200);
+    assert_eq!(fee_event.fee_rate, 200);
+}
+
+#[test]
+fn test_refund_fee_not_charged_after_deadline() {
+    let s = Suite::new();
+    s.client
+        .update_fee_config(&None, &None, &Some(s.fee_recipient.clone()), &Some(true));
+    s.client.set_refund_fee_rate(&200);
+
+    let amount = 10_000i128;
+    s.fund_depositor(amount);
+    let deadline = s.deadline();
+    s.client.lock_funds(&s.depositor, &1, &amount, &deadline);
+
+    s.env.ledger().set_timestamp(deadline);
+    s.client.refund(&1);
+
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+    assert_eq!(s.balance(&s.depositor), amount);
+}
+
+#[test]
+fn test_refund_fee_not_charged_when_fees_disabled() {
+    let s = Suite::new();
+    s.client
+        .update_fee_config(&None, &None, &Some(s.fee_recipient.clone()), &Some(false));
+    s.client.set_refund_fee_rate(&200);
+
+    let amount = 10_000i128;
+    s.fund_depositor(amount);
+    s.client
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+    s.client
+        .approve_refund(&1, &amount, &s.depositor, &RefundMode::Full, &None);
+    s.client.refund(&1);
+
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+    assert_eq!(s.balance(&s.depositor), amount);
+}
@@ -2,7 +2,7 @@
 //!
 //! These tests verify that:
 //! - INV-1: Per-escrow sanity checks catch inconsistent state
-//! - INV-2: Sum of active escrow balances always equals contract token balance
+//! - INV-2: Sum of active escrow balances never exceeds contract token balance
 //! - INV-4: Refund history is consistent with consumed amounts
 //! - INV-5: Index completeness (no orphaned entries)
 //!
@@ -146,7 +146,7 @@ fn test_inv1_refunded_with_nonzero_remaining_fails() {
 }
 
 // ===========================================================================
-// INV-2: Aggregate-to-Ledger (sum remaining == contract balance)
+// INV-2: Aggregate-to-Ledger (sum remaining <= contract balance)
 // ===========================================================================
 
 #[test]
@@ -161,8 +161,8 @@ fn test_inv2_single_lock_invariant_holds() {
 
     // Double-check manually
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         assert_eq!(sum, balance);
         assert_eq!(sum, 1_000);
     });
@@ -180,8 +180,8 @@ fn test_inv2_multiple_locks_invariant_holds() {
     assert!(s.escrow.verify_all_invariants());
 
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         assert_eq!(sum, 6_000);
         assert_eq!(sum, balance);
     });
@@ -201,8 +201,8 @@ fn test_inv2_lock_then_release_invariant_holds() {
     assert!(s.escrow.verify_all_invariants());
 
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         // Only bounty 2 is still active
         assert_eq!(sum, 2_000);
         assert_eq!(sum, balance);
@@ -226,8 +226,8 @@ fn test_inv2_lock_then_refund_invariant_holds() {
     assert!(s.escrow.verify_all_invariants());
 
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         assert_eq!(sum, 2_000);
         assert_eq!(sum, balance);
     });
@@ -247,8 +247,8 @@ fn test_inv2_all_released_contract_empty() {
     assert!(s.escrow.verify_all_invariants());
 
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         assert_eq!(sum, 0);
         assert_eq!(balance, 0);
     });
@@ -268,8 +268,8 @@ fn test_inv2_partial_release_invariant_holds() {
     assert!(s.escrow.verify_all_invariants());
 
     s.env.as_contract(&s.escrow.address, || {
-        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env);
-        let balance = multitoken_invariants::get_contract_token_balance(&s.env);
+        let sum = multitoken_invariants::sum_active_escrow_balances(&s.env, &s.token.address);
+        let balance = multitoken_invariants::get_contract_token_balance(&s.env, &s.token.address);
         assert_eq!(sum, 7_000);
         assert_eq!(sum, balance);
     });
@@ -496,14 +496,14 @@ fn test_tampered_balance_detected_by_invariant() {
         assert!(!report.healthy);
         // INV-1 should fail (remaining > amount)
         assert!(report.per_escrow_failures > 0);
-        // INV-2 should fail (sum != balance)
-        assert_ne!(report.sum_remaining, report.token_balance);
+        // INV-2 should fail (sum > balance)
+        assert!(report.sum_remaining > report.token_balance);
     });
 
     let public_report = s.escrow.check_invariants();
     assert!(!public_report.healthy);
     assert!(public_report.per_escrow_failures > 0);
-    assert_ne!(public_report.sum_remaining, public_report.token_balance);
+    assert!(public_report.sum_remaining > public_report.token_balance);
 }
 
 // ===========================================================================
@@ -0,0 +1,117 @@
+//! Non-refundable escrow tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - Only the admin or the depositor can mark an open escrow non-refundable
+//! - Every refund path rejects (or skips) a non-refundable escrow, including
+//!   the eligibility view and the refund dry run
+//! - A non-refundable escrow can still be released
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{CapabilityAction, Error, EscrowStatus, RefundMode};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Vec,
+};
+
+const DEADLINE: u64 = 5_000;
+
+#[test]
+fn test_only_admin_or_depositor_can_set_flag() {
+    let s = Fixture::new();
+    let outsider = Address::generate(&s.env);
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+
+    let res = s.client.try_set_non_refundable(&1, &outsider);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+    assert!(!s.client.is_non_refundable(&1));
+
+    s.client.set_non_refundable(&1, &s.depositor);
+    s.client.set_non_refundable(&2, &s.admin);
+    assert!(s.client.is_non_refundable(&1));
+    assert!(s.client.is_non_refundable(&2));
+
+    let res = s.client.try_set_non_refundable(&9, &s.admin);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_cannot_flag_finished_escrow() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.release_funds(&1, &s.contributor);
+
+    let res = s.client.try_set_non_refundable(&1, &s.depositor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
+
+#[test]
+fn test_refund_paths_rejected_for_non_refundable_escrow() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_non_refundable(&1, &s.depositor);
+
+    let res = s
+        .client
+        .try_approve_refund(&1, &500, &s.depositor, &RefundMode::Partial, &None);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RefundForbidden);
+
+    let expiry = s.env.ledger().timestamp() + 300;
+    let capability_id = s.client.issue_capability(
+        &s.admin,
+        &s.contributor,
+        &CapabilityAction::Refund,
+        &1,
+        &1_000,
+        &expiry,
+        &1,
+    );
+    let res = s
+        .client
+        .try_refund_with_capability(&1, &1_000, &s.contributor, &capability_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RefundForbidden);
+
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    let res = s.client.try_refund(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RefundForbidden);
+
+    let (can_refund, deadline_passed, remaining, _) = s.client.get_refund_eligibility(&1);
+    assert!(!can_refund);
+    assert!(deadline_passed);
+    assert_eq!(remaining, 1_000);
+
+    let sim = s.client.dry_run_refund(&1);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::RefundForbidden as u32);
+}
+
+#[test]
+fn test_keeper_and_batch_refunds_skip_non_refundable_escrow() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 1_000, DEADLINE);
+    s.client.set_non_refundable(&1, &s.depositor);
+
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    let results = s.client.batch_refund(&vec![&s.env, 1u64]);
+    assert_eq!(results.get(0).unwrap(), (1u64, false));
+
+    s.client.refund_next_expired(&0, &10);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.client.get_escrow_info(&2).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_non_refundable_escrow_can_only_be_released() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.client.set_non_refundable(&1, &s.depositor);
+
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    assert!(s.client.try_refund(&1).is_err());
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
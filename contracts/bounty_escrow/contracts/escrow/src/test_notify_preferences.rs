@@ -0,0 +1,132 @@
+//! Per-depositor notification subscription tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - A subscriber receives `targeted` events only for the topics they chose
+//! - Other depositors' escrows never produce `targeted` events for them
+//! - Unsubscribing with an empty list stops delivery
+//! - Unknown or repeated topics are rejected
+
+#![cfg(test)]
+
+use crate::events::NotificationTargeted;
+use crate::test_support::Fixture;
+use crate::Error;
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, IntoVal, Symbol, TryFromVal, Vec,
+};
+
+const DEADLINE: u64 = 5_000;
+
+struct Suite {
+    fx: Fixture,
+    other: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        let other = Address::generate(&fx.env);
+        fx.mint(&other, 1_000_000);
+        Self { fx, other }
+    }
+
+    /// `targeted` notifications published for `address`, in emission order.
+    fn targeted(&self, address: &Address) -> Vec<NotificationTargeted> {
+        let mut out = Vec::new(&self.env);
+        for (_, topics, data) in self.env.events().all().iter() {
+            if topics.len() != 2 {
+                continue;
+            }
+            let name = Symbol::try_from_val(&self.env, &topics.get(0).unwrap());
+            let target = Address::try_from_val(&self.env, &topics.get(1).unwrap());
+            if name == Ok(symbol_short!("targeted")) && target.as_ref() == Ok(address) {
+                out.push_back(data.into_val(&self.env));
+            }
+        }
+        out
+    }
+}
+
+#[test]
+fn test_subscriber_receives_only_chosen_topics() {
+    let s = Suite::new();
+    s.client
+        .set_notify_preference(&s.depositor, &vec![&s.env, symbol_short!("f_lock")]);
+    assert_eq!(
+        s.client.get_notify_preference(&s.depositor),
+        vec![&s.env, symbol_short!("f_lock")]
+    );
+
+    s.lock(1, 1_000, DEADLINE);
+    let notes = s.targeted(&s.depositor);
+    assert_eq!(notes.len(), 1);
+    let note = notes.get(0).unwrap();
+    assert_eq!(note.topic, symbol_short!("f_lock"));
+    assert_eq!(note.bounty_id, 1);
+    assert_eq!(note.timestamp, s.now());
+
+    // Release is not in the subscription, so no further targeted copy is published.
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.targeted(&s.depositor).len(), 1);
+}
+
+#[test]
+fn test_other_depositors_escrows_are_not_delivered() {
+    let s = Suite::new();
+    s.client.set_notify_preference(
+        &s.depositor,
+        &vec![&s.env, symbol_short!("f_lock"), symbol_short!("f_rel")],
+    );
+
+    s.lock_from(&s.other, 7, 1_000, DEADLINE);
+    assert!(s.targeted(&s.depositor).is_empty());
+    assert!(s.targeted(&s.other).is_empty());
+
+    s.lock(8, 1_000, DEADLINE);
+    s.client.release_funds(&8, &s.contributor);
+    let notes = s.targeted(&s.depositor);
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes.get(0).unwrap().topic, symbol_short!("f_lock"));
+    assert_eq!(notes.get(1).unwrap().topic, symbol_short!("f_rel"));
+    assert!(notes.iter().all(|n| n.bounty_id == 8));
+}
+
+#[test]
+fn test_empty_list_unsubscribes() {
+    let s = Suite::new();
+    s.client
+        .set_notify_preference(&s.depositor, &vec![&s.env, symbol_short!("f_lock")]);
+    s.client
+        .set_notify_preference(&s.depositor, &Vec::new(&s.env));
+    assert!(s.client.get_notify_preference(&s.depositor).is_empty());
+
+    s.lock(1, 1_000, DEADLINE);
+    assert!(s.targeted(&s.depositor).is_empty());
+}
+
+#[test]
+fn test_unknown_or_repeated_topics_rejected() {
+    let s = Suite::new();
+    let res = s
+        .client
+        .try_set_notify_preference(&s.depositor, &vec![&s.env, symbol_short!("f_nope")]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidSelectionInput);
+
+    let res = s.client.try_set_notify_preference(
+        &s.depositor,
+        &vec![&s.env, symbol_short!("f_ref"), symbol_short!("f_ref")],
+    );
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidSelectionInput);
+    assert!(s.client.get_notify_preference(&s.depositor).is_empty());
+}
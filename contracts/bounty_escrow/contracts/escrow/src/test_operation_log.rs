@@ -0,0 +1,93 @@
+//! Operation log tests for `BountyEscrowContract::export_operations`.
+//!
+//! Covers:
+//! - Locks, releases, refunds and claims are appended with monotonic `seq`
+//! - Resuming from `since_seq` and paging with `limit`
+//! - Only the latest `MAX_OPERATION_LOG` entries are retained
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{events, DisputeReason, MAX_OPERATION_LOG};
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Symbol};
+
+const DEADLINE: u64 = 50_000;
+
+#[test]
+fn test_operations_recorded_in_order() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 2_000, DEADLINE);
+    s.lock(3, 3_000, DEADLINE);
+
+    s.client.release_funds(&1, &s.contributor);
+    s.client
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Other);
+    s.client.claim(&2);
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    s.client.refund(&3);
+
+    let ops = s.client.export_operations(&0, &10);
+    assert_eq!(ops.len(), 6);
+    let expected: [(Symbol, u64, i128, &Address); 6] = [
+        (symbol_short!("lock"), 1, 1_000, &s.depositor),
+        (symbol_short!("lock"), 2, 2_000, &s.depositor),
+        (symbol_short!("lock"), 3, 3_000, &s.depositor),
+        (symbol_short!("release"), 1, 1_000, &s.contributor),
+        (symbol_short!("claim"), 2, 2_000, &s.contributor),
+        (symbol_short!("refund"), 3, 3_000, &s.depositor),
+    ];
+    for (i, (op_type, bounty_id, amount, actor)) in expected.iter().enumerate() {
+        let entry = ops.get(i as u32).unwrap();
+        assert_eq!(entry.seq, i as u64 + 1);
+        assert_eq!(entry.op_type, *op_type);
+        assert_eq!(entry.bounty_id, *bounty_id);
+        assert_eq!(entry.amount, *amount);
+        assert_eq!(entry.actor, **actor);
+    }
+    assert_eq!(ops.get(5).unwrap().timestamp, DEADLINE + 1);
+}
+
+#[test]
+fn test_export_resumes_from_since_seq() {
+    let s = Fixture::new();
+    for bounty_id in 1..=5u64 {
+        s.lock(bounty_id, 100, DEADLINE);
+    }
+
+    let first = s.client.export_operations(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(1).unwrap().seq, 2);
+
+    let rest = s.client.export_operations(&2, &10);
+    assert_eq!(rest.len(), 3);
+    assert_eq!(rest.get(0).unwrap().seq, 3);
+    assert_eq!(rest.get(2).unwrap().seq, 5);
+
+    assert_eq!(s.client.export_operations(&5, &10).len(), 0);
+    assert_eq!(s.client.export_operations(&0, &0).len(), 0);
+}
+
+#[test]
+fn test_log_keeps_latest_entries() {
+    let s = Fixture::new();
+    let total = MAX_OPERATION_LOG + 5;
+    s.env.budget().reset_unlimited();
+    s.env.as_contract(&s.client.address, || {
+        for bounty_id in 1..=total {
+            events::record_operation(
+                &s.env,
+                symbol_short!("lock"),
+                bounty_id,
+                1,
+                s.depositor.clone(),
+            );
+        }
+    });
+
+    // Entries 1..=5 were dropped; an indexer resuming from 0 sees the gap.
+    let ops = s.client.export_operations(&0, &1_000);
+    assert_eq!(ops.len() as u64, MAX_OPERATION_LOG);
+    assert_eq!(ops.get(0).unwrap().seq, 6);
+    assert_eq!(ops.get(ops.len() - 1).unwrap().seq, total);
+}
@@ -0,0 +1,116 @@
+//! Batch escrow ownership transfer tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `batch_transfer_ownership` moves every listed escrow to the new
+//!   depositor, updates both depositor indexes and emits one event per escrow
+//! - Refunds after the transfer pay the new depositor
+//! - The whole batch is rejected when escrows have mixed depositors, repeat
+//!   an id, are missing or have already been paid out
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::Error;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+const DEADLINE: u64 = 10_000;
+
+#[test]
+fn test_batch_transfer_moves_escrows_and_indexes() {
+    let s = Fixture::new();
+    let new_owner = Address::generate(&s.env);
+    s.lock(1, 100, DEADLINE);
+    s.lock(2, 200, DEADLINE);
+    s.lock(3, 300, DEADLINE);
+
+    let moved = s
+        .client
+        .batch_transfer_ownership(&vec![&s.env, 1u64, 3u64], &new_owner);
+    assert_eq!(moved, 2);
+    assert_eq!(s.count_topic(symbol_short!("own_xfer")), 2);
+
+    assert_eq!(s.client.get_escrow_info(&1).depositor, new_owner);
+    assert_eq!(s.client.get_escrow_info(&2).depositor, s.depositor);
+    assert_eq!(s.client.get_escrow_info(&3).depositor, new_owner);
+
+    let old = s.client.query_escrows_by_depositor(&s.depositor, &0, &10);
+    assert_eq!(old.len(), 1);
+    assert_eq!(old.get(0).unwrap().bounty_id, 2);
+    let new = s.client.query_escrows_by_depositor(&new_owner, &0, &10);
+    assert_eq!(new.len(), 2);
+}
+
+#[test]
+fn test_refund_after_transfer_pays_new_depositor() {
+    let s = Fixture::new();
+    let new_owner = Address::generate(&s.env);
+    s.lock(1, 100, DEADLINE);
+    s.client
+        .batch_transfer_ownership(&vec![&s.env, 1u64], &new_owner);
+
+    s.env.ledger().set_timestamp(20_000);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&new_owner), 100);
+}
+
+#[test]
+fn test_batch_rejected_when_depositors_differ() {
+    let s = Fixture::new();
+    let other = Address::generate(&s.env);
+    let new_owner = Address::generate(&s.env);
+    s.mint(&other, 1_000);
+    s.lock(1, 100, DEADLINE);
+    s.lock_from(&other, 2, 100, DEADLINE);
+
+    let res = s
+        .client
+        .try_batch_transfer_ownership(&vec![&s.env, 1u64, 2u64], &new_owner);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+    // Nothing moved, including the escrow that was valid on its own.
+    assert_eq!(s.client.get_escrow_info(&1).depositor, s.depositor);
+    assert_eq!(s.count_topic(symbol_short!("own_xfer")), 0);
+}
+
+#[test]
+fn test_batch_rejects_invalid_input() {
+    let s = Fixture::new();
+    let new_owner = Address::generate(&s.env);
+    let contributor = Address::generate(&s.env);
+    s.lock(1, 100, DEADLINE);
+    s.lock(2, 100, DEADLINE);
+    s.client.release_funds(&2, &contributor);
+
+    let res = s
+        .client
+        .try_batch_transfer_ownership(&vec![&s.env], &new_owner);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidBatchSize);
+    let res = s
+        .client
+        .try_batch_transfer_ownership(&vec![&s.env, 1u64, 1u64], &new_owner);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DuplicateBountyId);
+    let res = s
+        .client
+        .try_batch_transfer_ownership(&vec![&s.env, 1u64, 9u64], &new_owner);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    let res = s
+        .client
+        .try_batch_transfer_ownership(&vec![&s.env, 1u64, 2u64], &new_owner);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
+
+#[test]
+fn test_transfer_to_current_depositor_is_noop() {
+    let s = Fixture::new();
+    s.lock(1, 100, DEADLINE);
+
+    let moved = s
+        .client
+        .batch_transfer_ownership(&vec![&s.env, 1u64], &s.depositor);
+    assert_eq!(moved, 0);
+    let owned = s.client.query_escrows_by_depositor(&s.depositor, &0, &10);
+    assert_eq!(owned.len(), 1);
+}
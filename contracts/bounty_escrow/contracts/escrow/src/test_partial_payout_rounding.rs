@@ -194,6 +194,8 @@ fn test_hundred_single_unit_payouts() {
     let s = Setup::new();
     let amount = 100_i128;
     s.lock(5, amount);
+    // The test budget accumulates across the 200 calls below.
+    s.env.budget().reset_unlimited();
 
     for step in 1..=100_i128 {
         s.escrow.partial_release(&5, &s.contributor, &1_i128);
@@ -653,7 +655,7 @@ fn test_partial_release_then_approved_early_refund() {
 
     // Admin approves refund for the remaining 200 (early, before deadline)
     s.escrow
-        .approve_refund(&24, &200_i128, &s.depositor, &RefundMode::Full);
+        .approve_refund(&24, &200_i128, &s.depositor, &RefundMode::Full, &None);
 
     let depositor_before = s.token.balance(&s.depositor);
     s.escrow.refund(&24);
@@ -711,3 +713,50 @@ fn test_remaining_amounts_sum_equals_contract_balance() {
     );
     assert_eq!(info_a.status, EscrowStatus::Released); // A fully drained
 }
+
+// ===========================================================================
+// Dust threshold sweep
+// ===========================================================================
+
+#[test]
+fn test_dust_residual_swept_to_fee_recipient() {
+    let s = Setup::new();
+    s.escrow.set_dust_threshold(&s.admin, &5);
+    s.lock(30, 1_000);
+
+    // Leaves 6: above the threshold, nothing swept.
+    s.escrow.partial_release(&30, &s.contributor, &994);
+    assert_eq!(s.escrow.get_escrow_info(&30).remaining_amount, 6);
+    assert_eq!(s.token.balance(&s.admin), 0);
+
+    // Leaves 5: swept to the fee recipient (the admin by default).
+    s.escrow.partial_release(&30, &s.contributor, &1);
+    let info = s.escrow.get_escrow_info(&30);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), 995);
+    assert_eq!(s.token.balance(&s.admin), 5);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_zero_dust_threshold_keeps_tiny_remainder() {
+    let s = Setup::new();
+    s.lock(31, 1_000);
+
+    s.escrow.partial_release(&31, &s.contributor, &999);
+    let info = s.escrow.get_escrow_info(&31);
+    assert_eq!(info.remaining_amount, 1);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_dust_threshold(), 0);
+}
+
+#[test]
+fn test_set_dust_threshold_validation() {
+    let s = Setup::new();
+    let res = s.escrow.try_set_dust_threshold(&s.depositor, &5);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+    let res = s.escrow.try_set_dust_threshold(&s.admin, &-1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert_eq!(s.escrow.get_dust_threshold(), 0);
+}
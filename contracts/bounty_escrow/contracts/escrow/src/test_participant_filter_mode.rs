@@ -5,8 +5,8 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, Env,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    token, Address, Env, IntoVal,
 };
 
 fn create_env() -> Env {
@@ -230,3 +230,54 @@ fn test_set_filter_mode_emits_event_and_persists() {
         ParticipantFilterMode::AllowlistOnly
     );
 }
+
+#[test]
+fn test_whitelist_entry_emits_audit_event() {
+    let env = create_env();
+    let (client, depositor, _other, _token) = setup(&env);
+
+    client.set_whitelist_entry(&depositor, &true);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("wl_chg"), depositor.clone()).into_val(&env)
+    );
+    let event: events::WhitelistChanged = data.into_val(&env);
+    assert_eq!(event.address, depositor);
+    assert!(event.whitelisted);
+    assert_eq!(event.timestamp, 1_000_000);
+    assert!(client.get_whitelist_entry(&depositor));
+
+    client.set_whitelist_entry(&depositor, &false);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: events::WhitelistChanged = data.into_val(&env);
+    assert!(!event.whitelisted);
+    assert!(!client.get_whitelist_entry(&depositor));
+}
+
+#[test]
+fn test_list_whitelisted_tracks_adds_and_removals() {
+    let env = create_env();
+    let (client, depositor, other, _token) = setup(&env);
+    let third = Address::generate(&env);
+
+    client.set_whitelist_entry(&depositor, &true);
+    client.set_whitelist_entry(&other, &true);
+    client.set_whitelist_entry(&third, &true);
+    // Re-adding an existing entry does not duplicate it.
+    client.set_whitelist_entry(&other, &true);
+
+    assert_eq!(
+        client.list_whitelisted(&0, &10),
+        vec![&env, depositor.clone(), other.clone(), third.clone()]
+    );
+    assert_eq!(client.list_whitelisted(&1, &1), vec![&env, other.clone()]);
+    assert_eq!(client.list_whitelisted(&5, &10).len(), 0);
+
+    client.set_whitelist_entry(&other, &false);
+    assert_eq!(
+        client.list_whitelisted(&0, &10),
+        vec![&env, depositor, third]
+    );
+}
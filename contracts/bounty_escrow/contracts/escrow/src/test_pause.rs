@@ -637,3 +637,176 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     escrow_client.lock_funds(&new_depositor, &99u64, &200i128, &deadline);
     assert_eq!(token_client.balance(&escrow_client.address), 200);
 }
+
+// =========================================================================
+// MULTISIG-GATED EMERGENCY WITHDRAW
+// =========================================================================
+
+/// Above the multisig threshold the drain needs `required_signatures` approvals.
+#[test]
+fn test_emergency_withdraw_above_threshold_requires_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, token_client, escrow_client) = setup_rbac_env(&env);
+    let target = Address::generate(&env);
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    let signer_c = Address::generate(&env);
+    escrow_client.update_multisig_config(
+        &100i128,
+        &vec![&env, signer_a.clone(), signer_b.clone(), signer_c.clone()],
+        &2u32,
+    );
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+
+    let res = escrow_client.try_emergency_withdraw(&target);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    // A repeated approval from the same signer does not count twice.
+    escrow_client.approve_emergency_withdraw(&target, &signer_a);
+    escrow_client.approve_emergency_withdraw(&target, &signer_a);
+    let res = escrow_client.try_emergency_withdraw(&target);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+    assert_eq!(token_client.balance(&escrow_client.address), 500);
+
+    escrow_client.approve_emergency_withdraw(&target, &signer_b);
+    escrow_client.emergency_withdraw(&target);
+    assert_eq!(token_client.balance(&target), 500);
+    assert!(escrow_client
+        .get_emergency_withdraw_approvals(&target)
+        .is_empty());
+}
+
+/// Approvals only apply to the target they were given for.
+#[test]
+fn test_emergency_withdraw_approvals_are_per_target() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, token_client, escrow_client) = setup_rbac_env(&env);
+    let approved_target = Address::generate(&env);
+    let other_target = Address::generate(&env);
+    let signer = Address::generate(&env);
+    escrow_client.update_multisig_config(&100i128, &vec![&env, signer.clone()], &1u32);
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+
+    escrow_client.approve_emergency_withdraw(&approved_target, &signer);
+    let res = escrow_client.try_emergency_withdraw(&other_target);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    escrow_client.emergency_withdraw(&approved_target);
+    assert_eq!(token_client.balance(&approved_target), 500);
+}
+
+/// At or below the threshold the admin alone can still drain.
+#[test]
+fn test_emergency_withdraw_below_threshold_needs_no_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, token_client, escrow_client) = setup_rbac_env(&env);
+    let target = Address::generate(&env);
+    let signer = Address::generate(&env);
+    escrow_client.update_multisig_config(&500i128, &vec![&env, signer], &1u32);
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+
+    escrow_client.emergency_withdraw(&target);
+    assert_eq!(token_client.balance(&target), 500);
+}
+
+/// Only configured signers can approve, and signers removed later stop counting.
+#[test]
+fn test_emergency_withdraw_approval_requires_current_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, _, escrow_client) = setup_rbac_env(&env);
+    let target = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    escrow_client.update_multisig_config(&100i128, &vec![&env, signer.clone()], &1u32);
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+
+    let res = escrow_client.try_approve_emergency_withdraw(&target, &outsider);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    escrow_client.approve_emergency_withdraw(&target, &signer);
+    escrow_client.update_multisig_config(&100i128, &vec![&env, outsider.clone()], &1u32);
+    let res = escrow_client.try_emergency_withdraw(&target);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+}
+
+// =========================================================================
+// EMERGENCY WITHDRAW PREVIEW
+// =========================================================================
+
+/// The preview reports every token's balance and every escrow the withdraw
+/// would wipe, without needing a pause.
+#[test]
+fn test_simulate_emergency_withdraw_reports_scope() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, token_client, escrow_client) = setup_rbac_env(&env);
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_client.address);
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let target = Address::generate(&env);
+    token_admin_client.mint(&depositor, &1000);
+    token::StellarAssetClient::new(&env, &other_token).mint(&depositor, &50);
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow_client.lock_funds(&depositor, &2u64, &300i128, &deadline);
+    escrow_client.lock_funds(&depositor, &3u64, &200i128, &deadline);
+    escrow_client.lock_funds_token(&depositor, &4u64, &50i128, &deadline, &other_token);
+    escrow_client.partial_release(&2u64, &contributor, &100i128);
+    escrow_client.release_funds(&3u64, &contributor);
+
+    let preview = escrow_client.simulate_emergency_withdraw(&target);
+    assert_eq!(preview.target, target);
+    assert!(preview.initialized);
+    assert!(!preview.lock_paused);
+    assert_eq!(preview.escrows, 4);
+    assert_eq!(preview.active_escrows, 3);
+    assert_eq!(preview.tokens.len(), 2);
+    let default = preview.tokens.get(0).unwrap();
+    assert_eq!(default.token, token_client.address);
+    assert_eq!(default.balance, 700);
+    assert_eq!(default.total_remaining, 700);
+    let other = preview.tokens.get(1).unwrap();
+    assert_eq!(other.token, other_token);
+    assert_eq!(other.balance, 50);
+    assert_eq!(other.total_remaining, 50);
+
+    // Nothing moved and the escrows are untouched.
+    assert_eq!(token_client.balance(&escrow_client.address), 700);
+    assert_eq!(escrow_client.get_escrow_info(&2u64).remaining_amount, 200);
+
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+    let preview = escrow_client.simulate_emergency_withdraw(&target);
+    assert!(preview.lock_paused);
+    escrow_client.emergency_withdraw(&target);
+    assert_eq!(token_client.balance(&target), 700);
+    assert_eq!(token::Client::new(&env, &other_token).balance(&target), 50);
+    assert_eq!(
+        escrow_client.simulate_emergency_withdraw(&target).escrows,
+        0
+    );
+}
+
+/// An uninitialized contract yields an empty preview instead of failing.
+#[test]
+fn test_simulate_emergency_withdraw_uninitialized() {
+    let env = Env::default();
+    let (escrow_client, _) = create_escrow_contract(&env);
+    let target = Address::generate(&env);
+
+    let preview = escrow_client.simulate_emergency_withdraw(&target);
+    assert!(!preview.initialized);
+    assert_eq!(preview.tokens.len(), 0);
+    assert_eq!(preview.escrows, 0);
+    assert_eq!(preview.active_escrows, 0);
+}
@@ -502,3 +502,213 @@ fn test_aggregate_stats_amounts_invariant_sum_equals_total_locked() {
     let total = stats.total_locked + stats.total_released + stats.total_refunded;
     assert_eq!(total, 1000);
 }
+
+//  archival filter tests
+
+#[test]
+fn test_archive_escrow_requires_terminal_status() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+
+    let res = s.escrow.try_archive_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::EscrowNotTerminal);
+    let res = s.escrow.try_archive_escrow(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_escrow(&1);
+    assert!(s.escrow.is_escrow_archived(&1));
+}
+
+#[test]
+fn test_query_unarchived_by_status_skips_archived() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    for id in 1..=4u64 {
+        s.escrow.lock_funds(&s.depositor, &id, &100, &dl);
+    }
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.release_funds(&2, &s.contributor);
+    s.escrow.release_funds(&3, &s.contributor);
+    s.escrow.archive_escrow(&2);
+
+    let results = s
+        .escrow
+        .query_unarchived_by_status(&EscrowStatus::Released, &0, &10);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().bounty_id, 1);
+    assert_eq!(results.get(1).unwrap().bounty_id, 3);
+
+    let page = s
+        .escrow
+        .query_unarchived_by_status(&EscrowStatus::Released, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().bounty_id, 3);
+
+    // The unfiltered query still sees the archived escrow.
+    let all = s
+        .escrow
+        .query_escrows_by_status(&EscrowStatus::Released, &0, &10);
+    assert_eq!(all.len(), 3);
+}
+
+#[test]
+fn test_query_archived_lists_archived_ids_with_pagination() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    for id in 1..=4u64 {
+        s.escrow.lock_funds(&s.depositor, &id, &100, &dl);
+        s.escrow.release_funds(&id, &s.contributor);
+    }
+    assert_eq!(s.escrow.query_archived(&0, &10).len(), 0);
+
+    s.escrow.archive_escrow(&1);
+    s.escrow.archive_escrow(&3);
+    s.escrow.archive_escrow(&4);
+
+    let all = s.escrow.query_archived(&0, &10);
+    assert_eq!(all, soroban_sdk::vec![&s.env, 1, 3, 4]);
+    let page = s.escrow.query_archived(&1, &1);
+    assert_eq!(page, soroban_sdk::vec![&s.env, 3]);
+}
+
+//  purge tests
+
+#[test]
+fn test_purge_archived_escrow_removes_records_and_reserves_id() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    let res = s.escrow.try_purge_archived_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::EscrowNotArchived);
+    let res = s.escrow.try_purge_archived_escrow(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::EscrowNotTerminal);
+
+    s.escrow.archive_escrow(&1);
+    s.escrow.purge_archived_escrow(&1);
+
+    let res = s.escrow.try_get_escrow_info(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+    assert!(!s.escrow.is_escrow_archived(&1));
+    assert_eq!(s.escrow.get_status_history(&1).len(), 0);
+    assert_eq!(s.escrow.query_archived(&0, &10).len(), 0);
+    let ids = s
+        .escrow
+        .get_escrow_ids_by_status(&EscrowStatus::Released, &0, &10);
+    assert_eq!(ids.len(), 0);
+    let by_depositor = s.escrow.query_escrows_by_depositor(&s.depositor, &0, &10);
+    assert_eq!(by_depositor.len(), 1);
+    assert_eq!(by_depositor.get(0).unwrap().bounty_id, 2);
+
+    let record = s.escrow.get_purge_record(&1).unwrap();
+    assert_eq!(record.amount, 100);
+    assert_eq!(record.final_status, EscrowStatus::Released);
+    assert_eq!(record.depositor, s.depositor);
+    assert_eq!(s.escrow.get_purge_record(&2), None);
+
+    // The purged bounty id cannot be reused, and the purge cannot be repeated.
+    let res = s.escrow.try_lock_funds(&s.depositor, &1, &100, &dl);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyExists);
+    let res = s.escrow.try_purge_archived_escrow(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+//  escrow bundle tests
+
+#[test]
+fn test_escrow_bundle_open_escrow_with_optional_records() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+
+    // Bounty 1 has no metadata or pending claim yet.
+    let bundle = s.escrow.get_escrow_bundle(&1);
+    assert_eq!(bundle.bounty_id, 1);
+    assert_eq!(bundle.escrow.amount, 100);
+    assert_eq!(bundle.metadata.len(), 0);
+    assert_eq!(bundle.pending_claim.len(), 0);
+    assert_eq!(bundle.refund_history.len(), 0);
+    assert!(bundle.funds_locked);
+    assert!(!bundle.archived);
+    assert_eq!(bundle.completed_at, None);
+
+    let bounty_type = soroban_sdk::String::from_str(&s.env, "bug");
+    s.escrow
+        .update_metadata(&s.depositor, &2, &10, &20, &bounty_type, &None);
+    s.escrow
+        .authorize_claim(&2, &s.contributor, &DisputeReason::Other);
+
+    let bundle = s.escrow.get_escrow_bundle(&2);
+    assert_eq!(bundle.metadata.get(0).unwrap().repo_id, 10);
+    assert_eq!(
+        bundle.pending_claim.get(0).unwrap().recipient,
+        s.contributor
+    );
+    assert!(bundle.funds_locked);
+}
+
+#[test]
+fn test_escrow_bundle_completed_and_archived_escrow() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+
+    s.env.ledger().set_timestamp(500);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_escrow(&1);
+
+    let bundle = s.escrow.get_escrow_bundle(&1);
+    assert_eq!(bundle.escrow.status, EscrowStatus::Released);
+    assert!(!bundle.funds_locked);
+    assert!(bundle.archived);
+    assert_eq!(bundle.completed_at, Some(500));
+}
+
+#[test]
+fn test_escrow_bundle_missing_escrow() {
+    let s = Setup::new();
+    let res = s.escrow.try_get_escrow_bundle(&42);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
+
+#[test]
+fn test_escrows_batch_skips_missing_ids_and_keeps_order() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+    s.escrow.lock_funds(&s.depositor, &3, &300, &dl);
+
+    let ids = soroban_sdk::vec![&s.env, 3u64, 42, 1];
+    let results = s.escrow.get_escrows_batch(&ids);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().bounty_id, 3);
+    assert_eq!(results.get(0).unwrap().escrow.amount, 300);
+    assert_eq!(results.get(1).unwrap().bounty_id, 1);
+
+    let empty = s.escrow.get_escrows_batch(&Vec::new(&s.env));
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+fn test_escrows_batch_caps_input_length() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+
+    // The only existing id sits past the cap, so it is never examined.
+    let mut ids = Vec::new(&s.env);
+    for i in 0..MAX_ESCROW_VIEW_BATCH {
+        ids.push_back(1_000 + i as u64);
+    }
+    ids.push_back(1);
+    assert_eq!(s.escrow.get_escrows_batch(&ids).len(), 0);
+
+    ids.pop_front();
+    assert_eq!(s.escrow.get_escrows_batch(&ids).len(), 1);
+}
@@ -184,7 +184,7 @@ fn test_admin_can_approve_refund() {
     let s = Setup::new();
     s.lock_bounty(1, 1000);
     s.client
-        .approve_refund(&1u64, &500i128, &s.depositor, &RefundMode::Partial);
+        .approve_refund(&1u64, &500i128, &s.depositor, &RefundMode::Partial, &None);
 }
 
 // ─── Admin-only: partial_release ────────────────────────────────────────────
@@ -302,7 +302,7 @@ fn test_refund_requires_both_admin_and_depositor() {
     s.lock_bounty(1, 1000);
     // Approve first (admin-only step)
     s.client
-        .approve_refund(&1u64, &1000i128, &s.depositor, &RefundMode::Full);
+        .approve_refund(&1u64, &1000i128, &s.depositor, &RefundMode::Full, &None);
     // refund itself requires admin.require_auth() + depositor.require_auth()
     // mock_all_auths covers both — this must succeed
     s.client.refund(&1u64);
@@ -370,6 +370,7 @@ fn test_participant_cannot_approve_refund() {
         &100i128,
         &Address::generate(&env),
         &RefundMode::Full,
+        &None,
     );
 }
 
@@ -0,0 +1,136 @@
+//! Recipient payout history tests for `BountyEscrowContract::get_recipient_history`.
+//!
+//! Covers:
+//! - Releases and claims are appended as `(bounty_id, amount, timestamp)`
+//! - Partial, capability, multi-escrow, batch and scheduled releases are
+//!   recorded as well
+//! - Pagination over the history
+//! - Only the latest `MAX_RECIPIENT_HISTORY` entries are retained
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{BountyEscrowContract, CapabilityAction, DisputeReason, ReleaseFundsItem};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+const DEADLINE: u64 = 50_000;
+
+#[test]
+fn test_history_records_releases_and_claims() {
+    let s = Fixture::new();
+    s.lock(1, 1_000, DEADLINE);
+    s.lock(2, 2_000, DEADLINE);
+    s.lock(3, 3_000, DEADLINE);
+
+    s.env.ledger().set_timestamp(2_000);
+    s.client.release_funds(&2, &s.contributor);
+    s.env.ledger().set_timestamp(2_500);
+    s.client
+        .authorize_claim(&3, &s.contributor, &DisputeReason::Other);
+    s.client.claim(&3);
+    s.env.ledger().set_timestamp(3_000);
+    s.client.release_funds(&1, &s.contributor);
+
+    assert_eq!(
+        s.client.get_recipient_history(&s.contributor, &0, &10),
+        vec![
+            &s.env,
+            (2, 2_000, 2_000),
+            (3, 3_000, 2_500),
+            (1, 1_000, 3_000)
+        ]
+    );
+    assert_eq!(
+        s.client.get_recipient_history(&s.contributor, &1, &1),
+        vec![&s.env, (3, 3_000, 2_500)]
+    );
+    assert!(s
+        .client
+        .get_recipient_history(&s.contributor, &3, &10)
+        .is_empty());
+    assert!(s
+        .client
+        .get_recipient_history(&s.depositor, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_history_records_every_release_path() {
+    let s = Fixture::new();
+    for bounty_id in 1..=6 {
+        s.lock(bounty_id, 1_000, DEADLINE);
+    }
+    s.env.ledger().set_timestamp(5_000);
+
+    s.client.partial_release(&1, &s.contributor, &300);
+
+    let holder = Address::generate(&s.env);
+    let capability_id = s.client.issue_capability(
+        &s.admin,
+        &holder,
+        &CapabilityAction::Release,
+        &2,
+        &400,
+        &6_000,
+        &1,
+    );
+    s.client
+        .release_with_capability(&2, &s.contributor, &400, &holder, &capability_id);
+
+    s.client
+        .release_from_many(&vec![&s.env, 3, 4], &s.contributor, &1_500);
+
+    s.client.batch_release_funds(&vec![
+        &s.env,
+        ReleaseFundsItem {
+            bounty_id: 5,
+            contributor: s.contributor.clone(),
+        },
+    ]);
+
+    let schedule_id = s
+        .client
+        .create_bounty_release_schedule(&6, &250, &5_500, &s.contributor);
+    s.env.ledger().set_timestamp(5_500);
+    s.client.release_bounty_schedule(&6, &schedule_id);
+
+    assert_eq!(
+        s.client.get_recipient_history(&s.contributor, &0, &10),
+        vec![
+            &s.env,
+            (1, 300, 5_000),
+            (2, 400, 5_000),
+            (3, 1_000, 5_000),
+            (4, 500, 5_000),
+            (5, 1_000, 5_000),
+            (6, 250, 5_500)
+        ]
+    );
+    assert_eq!(
+        s.client.get_contributor_reputation(&s.contributor),
+        (6, 3_450)
+    );
+}
+
+#[test]
+fn test_history_keeps_latest_entries() {
+    let s = Fixture::new();
+    s.env.as_contract(&s.client.address, || {
+        for bounty_id in 0..105u64 {
+            BountyEscrowContract::record_contributor_payout(&s.env, bounty_id, &s.contributor, 1);
+        }
+    });
+
+    let history = s.client.get_recipient_history(&s.contributor, &0, &200);
+    assert_eq!(history.len(), 100);
+    assert_eq!(history.get(0).unwrap().0, 5);
+    assert_eq!(history.get(99).unwrap().0, 104);
+    // The reputation counter is not truncated.
+    assert_eq!(
+        s.client.get_contributor_reputation(&s.contributor),
+        (105, 105)
+    );
+}
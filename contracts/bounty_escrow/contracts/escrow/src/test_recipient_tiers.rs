@@ -0,0 +1,115 @@
+//! Recipient risk-tier release approval tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - Releases to recipients whose tier needs approval are rejected with
+//!   `RecipientApprovalRequired` until `approve_release_to` names them
+//! - An approval for a different recipient does not unlock the release
+//! - Approvals are consumed by the release they authorize
+//! - Tiers without the requirement (including the default tier 0) flow through
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const RISKY_TIER: u32 = 2;
+
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.client.set_tier_approval_threshold(&RISKY_TIER, &true);
+    s
+}
+
+#[test]
+fn test_tier_configuration_getters() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    assert_eq!(s.client.get_recipient_tier(&recipient), 0);
+    assert!(!s.client.is_tier_approval_required(&0));
+    assert!(s.client.is_tier_approval_required(&RISKY_TIER));
+
+    s.client.set_recipient_tier(&recipient, &RISKY_TIER);
+    assert_eq!(s.client.get_recipient_tier(&recipient), RISKY_TIER);
+
+    s.client.set_tier_approval_threshold(&RISKY_TIER, &false);
+    assert!(!s.client.is_tier_approval_required(&RISKY_TIER));
+}
+
+#[test]
+fn test_tiered_recipient_blocked_until_approved() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    s.client.set_recipient_tier(&recipient, &RISKY_TIER);
+    s.lock(1, 1_000, s.now() + 10_000);
+
+    let res = s.client.try_release_funds(&1, &recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientApprovalRequired);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    s.client.approve_release_to(&1, &recipient);
+    assert_eq!(s.client.get_recipient_approval(&1), Some(recipient.clone()));
+
+    s.client.release_funds(&1, &recipient);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+    assert_eq!(s.client.get_recipient_approval(&1), None);
+}
+
+#[test]
+fn test_approval_for_other_recipient_does_not_unlock_release() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let other = Address::generate(&s.env);
+    s.client.set_recipient_tier(&recipient, &RISKY_TIER);
+    s.lock(1, 1_000, s.now() + 10_000);
+
+    s.client.approve_release_to(&1, &other);
+    let res = s.client.try_release_funds(&1, &recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientApprovalRequired);
+}
+
+#[test]
+fn test_approval_is_consumed_by_release() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    s.client.set_recipient_tier(&recipient, &RISKY_TIER);
+    s.lock(1, 1_000, s.now() + 10_000);
+    s.lock(2, 1_000, s.now() + 10_000);
+
+    s.client.approve_release_to(&1, &recipient);
+    s.client.release_funds(&1, &recipient);
+
+    // The approval was scoped to bounty 1 and is gone after use.
+    let res = s.client.try_release_funds(&2, &recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RecipientApprovalRequired);
+}
+
+#[test]
+fn test_untiered_and_unrestricted_recipients_flow_through() {
+    let s = setup();
+    let untiered = Address::generate(&s.env);
+    let trusted = Address::generate(&s.env);
+    s.client.set_recipient_tier(&trusted, &1);
+    s.lock(1, 1_000, s.now() + 10_000);
+    s.lock(2, 1_000, s.now() + 10_000);
+
+    s.client.release_funds(&1, &untiered);
+    s.client.release_funds(&2, &trusted);
+    assert_eq!(s.token.balance(&untiered), 1_000);
+    assert_eq!(s.token.balance(&trusted), 1_000);
+}
+
+#[test]
+fn test_approve_release_to_requires_locked_escrow() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    let res = s.client.try_approve_release_to(&7, &recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+
+    s.lock(1, 1_000, s.now() + 10_000);
+    s.client.release_funds(&1, &recipient);
+    let res = s.client.try_approve_release_to(&1, &recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+}
@@ -0,0 +1,147 @@
+//! Refund penalty tests for `BountyEscrowContract::set_refund_penalty`.
+//!
+//! Covers:
+//! - Post-deadline refunds at 0%, 50% and 100% penalty
+//! - Each leg is reported by its own event
+//! - Admin-approved refunds are not penalized
+//! - Config validation and admin-only access
+
+#![cfg(test)]
+
+use crate::events::{FundsRefunded, RefundPenaltyPaid};
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const DEADLINE: u64 = 5_000;
+
+struct Suite {
+    fx: Fixture,
+    pool: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        fx.client.lock_funds(&fx.depositor, &1, &1_000, &DEADLINE);
+        Self {
+            pool: Address::generate(&fx.env),
+            fx,
+        }
+    }
+
+    fn refund_after_deadline(&self) {
+        self.env.ledger().set_timestamp(DEADLINE + 1);
+        self.client.refund(&1);
+    }
+
+    fn penalty_event(&self) -> Option<RefundPenaltyPaid> {
+        self.last_event(symbol_short!("ref_pen"))
+    }
+
+    fn refund_event(&self) -> FundsRefunded {
+        self.last_event(symbol_short!("f_ref")).unwrap()
+    }
+}
+
+#[test]
+fn test_zero_penalty_refunds_depositor_in_full() {
+    let s = Suite::new();
+    assert!(s.client.get_refund_penalty().is_none());
+    let before = s.token.balance(&s.depositor);
+
+    s.refund_after_deadline();
+
+    assert_eq!(s.token.balance(&s.depositor) - before, 1_000);
+    assert_eq!(s.token.balance(&s.pool), 0);
+    let refunded = s.refund_event();
+    assert_eq!(refunded.amount, 1_000);
+    assert!(s.penalty_event().is_none());
+}
+
+#[test]
+fn test_half_penalty_splits_refund() {
+    let s = Suite::new();
+    s.client.set_refund_penalty(&s.admin, &5_000, &s.pool);
+    let before = s.token.balance(&s.depositor);
+
+    s.refund_after_deadline();
+
+    assert_eq!(s.token.balance(&s.depositor) - before, 500);
+    assert_eq!(s.token.balance(&s.pool), 500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Refunded);
+
+    let penalty = s.penalty_event().unwrap();
+    assert_eq!(penalty.bounty_id, 1);
+    assert_eq!(penalty.amount, 500);
+    assert_eq!(penalty.penalty_bps, 5_000);
+    assert_eq!(penalty.recipient, s.pool);
+    let refunded = s.refund_event();
+    assert_eq!(refunded.amount, 500);
+    assert_eq!(refunded.refund_to, s.depositor);
+}
+
+#[test]
+fn test_full_penalty_sends_everything_to_recipient() {
+    let s = Suite::new();
+    s.client.set_refund_penalty(&s.admin, &10_000, &s.pool);
+    let before = s.token.balance(&s.depositor);
+
+    s.refund_after_deadline();
+
+    assert_eq!(s.token.balance(&s.depositor), before);
+    assert_eq!(s.token.balance(&s.pool), 1_000);
+    let penalty = s.penalty_event().unwrap();
+    assert_eq!(penalty.amount, 1_000);
+    let refunded = s.refund_event();
+    assert_eq!(refunded.amount, 0);
+}
+
+#[test]
+fn test_approved_refund_is_not_penalized() {
+    let s = Suite::new();
+    s.client.set_refund_penalty(&s.admin, &5_000, &s.pool);
+    s.client
+        .approve_refund(&1, &400, &s.depositor, &RefundMode::Partial, &None);
+    let before = s.token.balance(&s.depositor);
+
+    s.client.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor) - before, 400);
+    assert_eq!(s.token.balance(&s.pool), 0);
+}
+
+#[test]
+fn test_refund_penalty_config_validation() {
+    let s = Suite::new();
+    let res = s.client.try_set_refund_penalty(&s.admin, &10_001, &s.pool);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    let res = s.client.try_set_refund_penalty(&s.admin, &-1, &s.pool);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidFeeRate);
+    let res = s
+        .client
+        .try_set_refund_penalty(&s.depositor, &5_000, &s.pool);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    s.client.set_refund_penalty(&s.admin, &2_500, &s.pool);
+    let penalty = s.client.get_refund_penalty().unwrap();
+    assert_eq!(penalty.penalty_bps, 2_500);
+    assert_eq!(penalty.penalty_recipient, s.pool);
+
+    s.client.set_refund_penalty(&s.admin, &0, &s.pool);
+    assert!(s.client.get_refund_penalty().is_none());
+}
@@ -0,0 +1,144 @@
+//! Large-release approval lifecycle tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - The first `approve_large_release` sets the approval's expiry; later
+//!   signers extend the same record within the window
+//! - Approvals cannot be added once the window has closed
+//! - Releasing the escrow marks the approval consumed, after which it cannot
+//!   be extended or reused
+//! - Approvals recorded without an expiry never expire
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{DataKeyExt, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+const WINDOW: u64 = 7 * 24 * 60 * 60;
+
+struct Suite {
+    fx: Fixture,
+    signer_a: Address,
+    signer_b: Address,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        let signer_a = Address::generate(&fx.env);
+        let signer_b = Address::generate(&fx.env);
+        fx.client.update_multisig_config(
+            &500,
+            &vec![&fx.env, signer_a.clone(), signer_b.clone()],
+            &2,
+        );
+        fx.client.lock_funds(&fx.depositor, &1, &1_000, &1_000_000);
+
+        Self {
+            fx,
+            signer_a,
+            signer_b,
+        }
+    }
+}
+
+#[test]
+fn test_first_approval_sets_expiry() {
+    let s = Suite::new();
+    assert_eq!(s.client.get_release_approval(&1), None);
+
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_a);
+    let status = s.client.get_release_approval_status(&1);
+    assert_eq!(status.expires_at, 1_000 + WINDOW);
+    assert!(!status.consumed);
+
+    // A later signer joins the same record without moving the expiry.
+    s.env.ledger().set_timestamp(5_000);
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_b);
+    let approval = s.client.get_release_approval(&1).unwrap();
+    assert_eq!(approval.approvals.len(), 2);
+    assert_eq!(
+        s.client.get_release_approval_status(&1).expires_at,
+        1_000 + WINDOW
+    );
+}
+
+#[test]
+fn test_approvals_rejected_after_expiry() {
+    let s = Suite::new();
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_a);
+
+    s.env.ledger().set_timestamp(1_000 + WINDOW);
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_b);
+
+    s.env.ledger().set_timestamp(1_000 + WINDOW + 1);
+    let res = s
+        .client
+        .try_approve_large_release(&1, &s.contributor, &s.signer_b);
+    assert_eq!(res.unwrap_err().unwrap(), Error::TicketExpired);
+}
+
+#[test]
+fn test_release_consumes_approval() {
+    let s = Suite::new();
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_a);
+
+    s.client.release_funds(&1, &s.contributor);
+    assert!(s.client.get_release_approval_status(&1).consumed);
+
+    let res = s
+        .client
+        .try_approve_large_release(&1, &s.contributor, &s.signer_b);
+    assert_eq!(res.unwrap_err().unwrap(), Error::TicketAlreadyUsed);
+    assert_eq!(
+        s.client.get_release_approval(&1).unwrap().approvals.len(),
+        1
+    );
+}
+
+#[test]
+fn test_approval_without_recorded_expiry_never_expires() {
+    let s = Suite::new();
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_a);
+
+    // Simulate an approval recorded before expiry was tracked.
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .remove(&DataKeyExt::ReleaseApprovalStatus(1));
+    });
+    assert_eq!(
+        s.client.get_release_approval_status(&1).expires_at,
+        u64::MAX
+    );
+
+    s.env.ledger().set_timestamp(1_000 + 10 * WINDOW);
+    s.client
+        .approve_large_release(&1, &s.contributor, &s.signer_b);
+    assert_eq!(
+        s.client.get_release_approval(&1).unwrap().approvals.len(),
+        2
+    );
+
+    s.client.release_funds(&1, &s.contributor);
+    assert!(s.client.get_release_approval_status(&1).consumed);
+}
@@ -0,0 +1,208 @@
+//! Multi-escrow release tests for `BountyEscrowContract::release_from_many`.
+//!
+//! Covers:
+//! - Escrows are drained in order, with the last one partially released
+//! - Fully drained escrows become `Released`; untouched escrows are unchanged
+//! - One `FundsReleased` event per escrow drawn from
+//! - Insufficient combined funds, duplicates, unlocked escrows and mixed
+//!   tokens are rejected without moving any funds
+//! - The recipient denylist, escrow allowlists, tier approvals and release
+//!   dependencies apply as they do to `release_funds`
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{events::FundsReleased, DependencyStatus, Error, EscrowStatus};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, IntoVal};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+fn released_events(s: &Fixture) -> soroban_sdk::Vec<FundsReleased> {
+    let mut released = soroban_sdk::Vec::new(&s.env);
+    for data in s.events_named(symbol_short!("f_rel")) {
+        released.push_back(data.into_val(&s.env));
+    }
+    released
+}
+
+// ── draws ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_release_from_many_drains_in_order() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    s.lock(3, 400, s.now() + 10_000);
+
+    s.client
+        .release_from_many(&vec![&s.env, 1, 2, 3], &s.contributor, &700);
+
+    assert_eq!(s.token.balance(&s.contributor), 700);
+    assert_eq!(s.token.balance(&s.client.address), 500);
+
+    let first = s.client.get_escrow_info(&1);
+    assert_eq!(first.status, EscrowStatus::Released);
+    assert_eq!(first.remaining_amount, 0);
+    let second = s.client.get_escrow_info(&2);
+    assert_eq!(second.status, EscrowStatus::Locked);
+    assert_eq!(second.remaining_amount, 100);
+    let third = s.client.get_escrow_info(&3);
+    assert_eq!(third.status, EscrowStatus::Locked);
+    assert_eq!(third.remaining_amount, 400);
+
+    let events = released_events(&s);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().bounty_id, 1);
+    assert_eq!(events.get(0).unwrap().amount, 300);
+    assert_eq!(events.get(1).unwrap().bounty_id, 2);
+    assert_eq!(events.get(1).unwrap().amount, 400);
+    assert_eq!(events.get(1).unwrap().recipient, s.contributor);
+}
+
+#[test]
+fn test_release_from_many_exact_total_releases_all() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+
+    s.client
+        .release_from_many(&vec![&s.env, 2, 1], &s.contributor, &800);
+
+    assert_eq!(s.token.balance(&s.contributor), 800);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.client.get_escrow_info(&2).status, EscrowStatus::Released);
+}
+
+// ── rejections ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_release_from_many_rejects_insufficient_combined_funds() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &801);
+    assert_eq!(res, Err(Ok(Error::InsufficientFunds)));
+
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 300);
+    assert_eq!(s.client.get_escrow_info(&2).remaining_amount, 500);
+}
+
+#[test]
+fn test_release_from_many_rejects_invalid_inputs() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    s.client.release_funds(&2, &s.contributor);
+
+    let empty = s
+        .client
+        .try_release_from_many(&vec![&s.env], &s.contributor, &100);
+    assert_eq!(empty, Err(Ok(Error::InvalidBatchSize)));
+
+    let zero = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1], &s.contributor, &0);
+    assert_eq!(zero, Err(Ok(Error::InvalidAmount)));
+
+    let duplicate = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 1], &s.contributor, &100);
+    assert_eq!(duplicate, Err(Ok(Error::DuplicateBountyId)));
+
+    let released = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &100);
+    assert_eq!(released, Err(Ok(Error::FundsNotLocked)));
+
+    let missing = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 9], &s.contributor, &100);
+    assert_eq!(missing, Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_release_from_many_rejects_mixed_tokens() {
+    let s = Fixture::new();
+    let other = s.new_token(1_000);
+    s.lock(1, 300, s.now() + 10_000);
+    s.advance(100);
+    let deadline = s.now() + 10_000;
+    s.client
+        .lock_funds_token(&s.depositor, &2, &500, &deadline, &other);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::InvalidAssetId)));
+}
+
+// ── release gates ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_release_from_many_rejects_denied_recipient() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    s.client.set_address_denied(&s.contributor, &true);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::RecipientDenied)));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_release_from_many_checks_every_escrow_allowlist() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    let other = Address::generate(&s.env);
+    s.client
+        .set_escrow_recipient_allowlist(&2, &s.depositor, &vec![&s.env, other]);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::ParticipantNotAllowed)));
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 300);
+}
+
+#[test]
+fn test_release_from_many_requires_tier_approval() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    s.client.set_recipient_tier(&s.contributor, &2);
+    s.client.set_tier_approval_threshold(&2, &true);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::RecipientApprovalRequired)));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_release_from_many_waits_on_dependency() {
+    let s = Fixture::new();
+    s.lock(1, 300, s.now() + 10_000);
+    s.lock(2, 500, s.now() + 10_000);
+    let dependency = soroban_sdk::String::from_str(&s.env, "audit");
+    s.client.set_bounty_dependency(&2, &dependency);
+
+    let res = s
+        .client
+        .try_release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(res, Err(Ok(Error::DependencyNotSatisfied)));
+
+    s.client
+        .set_bounty_dependency_status(&dependency, &DependencyStatus::Completed);
+    s.client
+        .release_from_many(&vec![&s.env, 1, 2], &s.contributor, &400);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+}
@@ -0,0 +1,114 @@
+//! Scheduled future lock tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `schedule_future_lock` pulls funds in immediately and creates a `Pending`
+//!   escrow; start/deadline ordering is validated
+//! - Releases and refunds are rejected while the escrow is `Pending`
+//! - `activate_lock` fails before `start_time`, flips the escrow to `Locked`
+//!   at or after it, and cannot be repeated
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{Error, EscrowStatus};
+use soroban_sdk::{symbol_short, testutils::Ledger};
+
+const START: u64 = 2_000;
+const DEADLINE: u64 = 5_000;
+
+fn schedule(s: &Fixture, bounty_id: u64, amount: i128) {
+    s.client
+        .schedule_future_lock(&s.depositor, &bounty_id, &amount, &START, &DEADLINE);
+}
+
+#[test]
+fn test_schedule_creates_funded_pending_escrow() {
+    let s = Fixture::new();
+    schedule(&s, 1, 1_000);
+
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Pending);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(s.client.get_escrow_locked_at(&1), START);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 999_000);
+    assert_eq!(s.count_topic(symbol_short!("f_sched")), 1);
+    assert_eq!(s.count_topic(symbol_short!("f_lock")), 0);
+}
+
+#[test]
+fn test_schedule_rejects_bad_times_and_duplicate_ids() {
+    let s = Fixture::new();
+
+    let res = s
+        .client
+        .try_schedule_future_lock(&s.depositor, &1, &1_000, &1_000, &DEADLINE);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidDeadline);
+    let res = s
+        .client
+        .try_schedule_future_lock(&s.depositor, &1, &1_000, &START, &START);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidDeadline);
+
+    schedule(&s, 1, 1_000);
+    // Step past the anti-abuse cooldown so the duplicate id is what fails.
+    s.env.ledger().set_timestamp(1_100);
+    let res = s
+        .client
+        .try_schedule_future_lock(&s.depositor, &1, &1_000, &START, &DEADLINE);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyExists);
+}
+
+#[test]
+fn test_release_and_refund_blocked_while_pending() {
+    let s = Fixture::new();
+    schedule(&s, 1, 1_000);
+
+    let res = s.client.try_release_funds(&1, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    let res = s.client.try_refund(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+}
+
+#[test]
+fn test_activate_before_start_time_fails() {
+    let s = Fixture::new();
+    schedule(&s, 1, 1_000);
+
+    s.env.ledger().set_timestamp(START - 1);
+    let res = s.client.try_activate_lock(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::LockNotStarted);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Pending);
+}
+
+#[test]
+fn test_activate_at_start_time_enables_release() {
+    let s = Fixture::new();
+    schedule(&s, 1, 1_000);
+
+    s.env.ledger().set_timestamp(START);
+    s.client.activate_lock(&1);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.count_topic(symbol_short!("f_active")), 1);
+
+    let res = s.client.try_activate_lock(&1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::FundsNotLocked);
+
+    s.client.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_activate_after_deadline_allows_refund() {
+    let s = Fixture::new();
+    schedule(&s, 1, 1_000);
+
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    s.client.activate_lock(&1);
+    s.client.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 1_000_000);
+    let res = s.client.try_activate_lock(&99);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
@@ -152,6 +152,7 @@ fn serialization_compatibility_public_types_and_events() {
                 window_size: 60,
                 max_operations: 10,
                 cooldown_period: 5,
+                exempt_admin_cooldown: false,
             }
             .into_val(&env),
         ),
@@ -265,6 +266,7 @@ fn serialization_compatibility_public_types_and_events() {
                 amount,
                 depositor: depositor.clone(),
                 deadline,
+                memo: None,
             }
             .into_val(&env),
         ),
@@ -276,6 +278,7 @@ fn serialization_compatibility_public_types_and_events() {
                 amount: 123,
                 recipient: contributor.clone(),
                 timestamp: 456,
+                bonus: 0,
             }
             .into_val(&env),
         ),
@@ -287,6 +290,7 @@ fn serialization_compatibility_public_types_and_events() {
                 amount: 100,
                 refund_to: depositor.clone(),
                 timestamp: 200,
+                trigger: RefundTrigger::DeadlineExpiry,
             }
             .into_val(&env),
         ),
@@ -321,6 +325,7 @@ fn serialization_compatibility_public_types_and_events() {
                 release_fee_rate: 20,
                 fee_recipient: fee_recipient.clone(),
                 fee_enabled: true,
+                refund_fee_rate: 5,
                 timestamp: 2,
             }
             .into_val(&env),
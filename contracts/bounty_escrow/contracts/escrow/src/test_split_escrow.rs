@@ -0,0 +1,161 @@
+//! Escrow splitting tests for `BountyEscrowContract::split_escrow`.
+//!
+//! Covers:
+//! - The split amount moves into a new escrow with the source's terms
+//! - The new escrow inherits the source's per-escrow policies
+//! - Both halves can be released independently and the balance stays covered
+//! - `EscrowSplit` is emitted and the new escrow is indexed
+//! - The source must keep a remainder; taken ids and outsiders are rejected
+
+#![cfg(test)]
+
+use crate::events::EscrowSplit;
+use crate::test_support::Fixture;
+use crate::{DependencyStatus, EarlyBonus, Error, EscrowStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, String,
+};
+
+const DEADLINE: u64 = 50_000;
+
+fn setup() -> Fixture {
+    let s = Fixture::new();
+    s.client.lock_funds(&s.depositor, &1, &1_000, &DEADLINE);
+    s
+}
+
+fn split_event(s: &Fixture) -> Option<EscrowSplit> {
+    s.last_event(symbol_short!("split"))
+}
+
+#[test]
+fn test_split_creates_escrow_with_source_terms() {
+    let s = setup();
+    s.client.split_escrow(&s.depositor, &1, &2, &300);
+
+    let source = s.client.get_escrow_info(&1);
+    assert_eq!(source.amount, 700);
+    assert_eq!(source.remaining_amount, 700);
+
+    let split = s.client.get_escrow_info(&2);
+    assert_eq!(split.status, EscrowStatus::Locked);
+    assert_eq!(split.amount, 300);
+    assert_eq!(split.remaining_amount, 300);
+    assert_eq!(split.depositor, source.depositor);
+    assert_eq!(split.deadline, source.deadline);
+    assert_eq!(s.client.get_escrow_token(&2), s.client.get_escrow_token(&1));
+    assert_eq!(
+        s.client.get_escrow_locked_at(&2),
+        s.client.get_escrow_locked_at(&1)
+    );
+
+    let event = split_event(&s).unwrap();
+    assert_eq!(event.source_bounty_id, 1);
+    assert_eq!(event.new_bounty_id, 2);
+    assert_eq!(event.amount, 300);
+    assert_eq!(event.source_remaining, 700);
+
+    assert_eq!(
+        s.client
+            .get_escrow_ids_by_status(&EscrowStatus::Locked, &0, &10),
+        soroban_sdk::vec![&s.env, 1, 2]
+    );
+    assert_eq!(
+        s.client.get_aggregate_stats_cached(),
+        s.client.get_aggregate_stats()
+    );
+}
+
+#[test]
+fn test_split_copies_source_policies() {
+    let s = setup();
+    let milestone = String::from_str(&s.env, "audit-report");
+    s.client.set_non_refundable(&1, &s.depositor);
+    s.client.set_bounty_dependency(&1, &milestone);
+    s.client
+        .set_escrow_recipient_allowlist(&1, &s.depositor, &vec![&s.env, s.contributor.clone()]);
+    s.client.set_recipient_tier(&s.contributor, &2);
+    s.client.set_tier_approval_threshold(&2, &true);
+    s.client.approve_release_to(&1, &s.contributor);
+    s.client.set_early_bonus(&1, &500, &3_600);
+
+    s.client.split_escrow(&s.depositor, &1, &2, &300);
+
+    assert!(s.client.is_non_refundable(&2));
+    assert_eq!(
+        s.client.get_bounty_dependency(&2),
+        Some((milestone.clone(), DependencyStatus::Pending))
+    );
+    assert_eq!(
+        s.client.get_escrow_recipient_allowlist(&2),
+        vec![&s.env, s.contributor.clone()]
+    );
+    assert_eq!(
+        s.client.get_recipient_approval(&2),
+        Some(s.contributor.clone())
+    );
+    assert_eq!(
+        s.client.get_early_bonus(&2),
+        Some(EarlyBonus {
+            bonus_bps: 500,
+            window_seconds: 3_600,
+        })
+    );
+
+    let res = s.client.try_release_funds(&2, &s.contributor);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DependencyNotSatisfied);
+    s.env.ledger().set_timestamp(DEADLINE + 1);
+    let res = s.client.try_refund(&2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::RefundForbidden);
+
+    s.client
+        .set_bounty_dependency_status(&milestone, &DependencyStatus::Completed);
+    s.client.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+}
+
+#[test]
+fn test_split_halves_release_independently() {
+    let s = setup();
+    s.client.partial_release(&1, &s.contributor, &200);
+    s.client.split_escrow(&s.depositor, &1, &2, &500);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 300);
+
+    s.client.partial_release(&2, &s.contributor, &500);
+    assert_eq!(s.client.get_escrow_info(&2).status, EscrowStatus::Released);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    s.client.partial_release(&1, &s.contributor, &300);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_split_must_leave_remainder_in_source() {
+    let s = setup();
+    let res = s.client.try_split_escrow(&s.depositor, &1, &2, &1_000);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientFunds);
+    let res = s.client.try_split_escrow(&s.depositor, &1, &2, &0);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InvalidAmount);
+    assert!(split_event(&s).is_none());
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_split_rejects_taken_ids_and_outsiders() {
+    let s = setup();
+    s.env.ledger().set_timestamp(1_100);
+    s.client.lock_funds(&s.depositor, &2, &1_000, &DEADLINE);
+
+    let res = s.client.try_split_escrow(&s.depositor, &1, &2, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyExists);
+
+    let outsider = Address::generate(&s.env);
+    let res = s.client.try_split_escrow(&outsider, &1, &3, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    let res = s.client.try_split_escrow(&s.depositor, &99, &3, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::BountyNotFound);
+}
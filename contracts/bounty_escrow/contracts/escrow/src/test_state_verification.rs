@@ -325,6 +325,7 @@ mod test {
             &partial_amount,
             &depositor,
             &RefundMode::Partial,
+            &None,
         );
         client.refund(&bounty_id);
 
@@ -611,4 +612,63 @@ mod test {
         assert!(client.verify_state(&81));
         assert!(client.verify_state(&82));
     }
+
+    // =========================================================================
+    // 6. State export for auditors
+    //
+    // The digest must be deterministic and must change whenever any escrow's
+    // remaining amount or status changes.
+    // =========================================================================
+
+    /// Summary follows index order and the digest is stable across calls.
+    #[test]
+    fn test_export_state_summary_and_digest_are_deterministic() {
+        let env = Env::default();
+        let (client, _contract_id, _admin, depositor, _token_id, _token) = setup_bounty(&env);
+
+        let now = env.ledger().timestamp();
+        client.lock_funds(&depositor, &92, &700, &(now + 5000));
+        client.lock_funds(&depositor, &90, &500, &(now + 5000));
+        client.lock_funds(&depositor, &91, &600, &(now + 5000));
+
+        let summary = client.export_state_summary(&0, &10);
+        assert_eq!(summary.len(), 3);
+        assert_eq!(summary.get(0).unwrap(), (92, 700, EscrowStatus::Locked));
+        assert_eq!(summary.get(1).unwrap(), (90, 500, EscrowStatus::Locked));
+        assert_eq!(summary.get(2).unwrap(), (91, 600, EscrowStatus::Locked));
+
+        let page = client.export_state_summary(&1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().0, 90);
+        assert_eq!(client.export_state_summary(&5, &10).len(), 0);
+
+        assert_eq!(client.export_state_digest(), client.export_state_digest());
+    }
+
+    /// Any balance or status change produces a different digest.
+    #[test]
+    fn test_export_state_digest_changes_with_state() {
+        let env = Env::default();
+        let (client, _contract_id, _admin, depositor, _token_id, _token) = setup_bounty(&env);
+        let contributor = Address::generate(&env);
+
+        let empty_digest = client.export_state_digest();
+
+        let now = env.ledger().timestamp();
+        client.lock_funds(&depositor, &93, &1000, &(now + 5000));
+        let locked_digest = client.export_state_digest();
+        assert_ne!(empty_digest, locked_digest);
+
+        client.partial_release(&93, &contributor, &400);
+        let partial_digest = client.export_state_digest();
+        assert_ne!(locked_digest, partial_digest);
+
+        client.partial_release(&93, &contributor, &600);
+        let released_digest = client.export_state_digest();
+        assert_ne!(partial_digest, released_digest);
+        assert_eq!(
+            client.export_state_summary(&0, &1).get(0).unwrap(),
+            (93, 0, EscrowStatus::Released)
+        );
+    }
 }
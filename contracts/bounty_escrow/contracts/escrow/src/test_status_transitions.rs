@@ -126,9 +126,13 @@ fn test_locked_to_partially_refunded() {
     );
 
     // Approve partial refund before deadline
-    setup
-        .escrow
-        .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.approve_refund(
+        &bounty_id,
+        &500,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None,
+    );
     setup.escrow.refund(&bounty_id);
     assert_eq!(
         setup.escrow.get_escrow_info(&bounty_id).status,
@@ -149,9 +153,13 @@ fn test_partially_refunded_to_refunded() {
         .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
 
     // First partial refund
-    setup
-        .escrow
-        .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.approve_refund(
+        &bounty_id,
+        &500,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None,
+    );
     setup.escrow.refund(&bounty_id);
     assert_eq!(
         setup.escrow.get_escrow_info(&bounty_id).status,
@@ -365,9 +373,13 @@ fn test_partially_refunded_to_locked_fails() {
     setup
         .escrow
         .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
-    setup
-        .escrow
-        .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.approve_refund(
+        &bounty_id,
+        &500,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None,
+    );
     setup.escrow.refund(&bounty_id);
 
     setup
@@ -387,10 +399,72 @@ fn test_partially_refunded_to_released_fails() {
     setup
         .escrow
         .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.approve_refund(
+        &bounty_id,
+        &500,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None,
+    );
+    setup.escrow.refund(&bounty_id);
+
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+}
+
+// Status history: every lifecycle write is recorded with its timestamp
+#[test]
+fn test_status_history_records_transitions() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = 500;
+
+    setup.env.ledger().set_timestamp(100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(200);
     setup
         .escrow
-        .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
+        .partial_release(&bounty_id, &setup.contributor, &400);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
 
-    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    let history = setup.escrow.get_status_history(&bounty_id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap(), (EscrowStatus::Locked, 100));
+    assert_eq!(history.get(1).unwrap(), (EscrowStatus::Locked, 200));
+    assert_eq!(
+        history.get(2).unwrap(),
+        (EscrowStatus::Refunded, deadline + 1)
+    );
+}
+
+#[test]
+fn test_status_history_empty_for_unknown_bounty() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_status_history(&42).len(), 0);
+}
+
+#[test]
+fn test_status_history_is_bounded() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+    for i in 0..MAX_STATUS_HISTORY {
+        setup.env.ledger().set_timestamp(u64::from(i) + 1);
+        setup
+            .escrow
+            .partial_release(&bounty_id, &setup.contributor, &1);
+    }
+
+    let history = setup.escrow.get_status_history(&bounty_id);
+    assert_eq!(history.len(), MAX_STATUS_HISTORY);
+    // The initial lock entry has been dropped; the oldest retained is the first partial.
+    assert_eq!(history.get(0).unwrap(), (EscrowStatus::Locked, 1));
 }
@@ -0,0 +1,129 @@
+//! Shared fixture for the contract's test modules.
+//!
+//! `Fixture::new()` registers and initializes `BountyEscrowContract` with all
+//! auths mocked, the ledger at `START_TIME` and a depositor holding
+//! `DEPOSITOR_BALANCE` of the contract's default token. Test modules that need
+//! more state wrap it in their own `Suite` that derefs to the fixture.
+
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+
+/// Ledger timestamp a fresh fixture starts at.
+pub const START_TIME: u64 = 1_000;
+/// Default-token balance minted to the fixture's depositor.
+pub const DEPOSITOR_BALANCE: i128 = 1_000_000;
+
+pub struct Fixture {
+    pub env: Env,
+    pub client: BountyEscrowContractClient<'static>,
+    pub token: token::Client<'static>,
+    pub admin: Address,
+    pub depositor: Address,
+    pub contributor: Address,
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(START_TIME);
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        token::StellarAssetClient::new(&env, &token_id).mint(&depositor, &DEPOSITOR_BALANCE);
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = BountyEscrowContractClient::new(&env, &contract_id);
+        client.init(&admin, &token_id);
+
+        Self {
+            token: token::Client::new(&env, &token_id),
+            env,
+            client,
+            admin,
+            depositor,
+            contributor,
+        }
+    }
+
+    pub fn now(&self) -> u64 {
+        self.env.ledger().timestamp()
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.env.ledger().set_timestamp(self.now() + seconds);
+    }
+
+    /// Mint `amount` of the default token to `to`.
+    pub fn mint(&self, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &self.token.address).mint(to, &amount);
+    }
+
+    /// Register another Stellar asset and mint `amount` of it to the depositor.
+    pub fn new_token(&self, amount: i128) -> Address {
+        let token_id = self
+            .env
+            .register_stellar_asset_contract_v2(self.admin.clone())
+            .address();
+        token::StellarAssetClient::new(&self.env, &token_id).mint(&self.depositor, &amount);
+        token_id
+    }
+
+    pub fn balance(&self, token_id: &Address, addr: &Address) -> i128 {
+        token::Client::new(&self.env, token_id).balance(addr)
+    }
+
+    /// Lock funds from the depositor, first stepping past the per-address
+    /// anti-abuse cooldown.
+    pub fn lock(&self, bounty_id: u64, amount: i128, deadline: u64) {
+        self.lock_from(&self.depositor, bounty_id, amount, deadline);
+    }
+
+    /// Lock funds from `depositor`, first stepping past the per-address
+    /// anti-abuse cooldown.
+    pub fn lock_from(&self, depositor: &Address, bounty_id: u64, amount: i128, deadline: u64) {
+        self.advance(100);
+        self.client
+            .lock_funds(depositor, &bounty_id, &amount, &deadline);
+    }
+
+    /// Data of every event so far whose first topic is `topic`, oldest first.
+    pub fn events_named(&self, topic: Symbol) -> impl Iterator<Item = Val> + '_ {
+        self.env
+            .events()
+            .all()
+            .into_iter()
+            .filter(move |(_, topics, _)| {
+                topics
+                    .get(0)
+                    .and_then(|t| Symbol::try_from_val(&self.env, &t).ok())
+                    == Some(topic.clone())
+            })
+            .map(|(_, _, data)| data)
+    }
+
+    /// Number of events so far whose first topic is `topic`.
+    pub fn count_topic(&self, topic: Symbol) -> u32 {
+        self.events_named(topic).count() as u32
+    }
+
+    /// Decoded data of the latest event whose first topic is `topic`.
+    pub fn last_event<T>(&self, topic: Symbol) -> Option<T>
+    where
+        Val: IntoVal<Env, T>,
+    {
+        self.events_named(topic)
+            .last()
+            .map(|data| data.into_val(&self.env))
+    }
+}
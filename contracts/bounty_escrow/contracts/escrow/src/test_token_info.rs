@@ -0,0 +1,83 @@
+//! Token metadata getter tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - `init` caches the token's decimals and symbol for `get_token_info`
+//! - `refresh_token_info` re-reads the token contract and is admin-only
+//! - Initializing with an address that is not a token contract still works
+
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, DataKeyExt, Error};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn register(env: &Env) -> BountyEscrowContractClient<'static> {
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    BountyEscrowContractClient::new(env, &contract_id)
+}
+
+#[test]
+fn test_token_info_cached_at_init() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_id);
+    let client = register(&env);
+    client.init(&admin, &token_id);
+
+    let (address, decimals, symbol) = client.get_token_info();
+    assert_eq!(address, token_id);
+    assert_eq!(decimals, token.decimals());
+    assert_eq!(symbol, token.symbol());
+
+    // The getter answers from the cache, not from the token contract.
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(
+            &DataKeyExt::TokenInfo,
+            &(2u32, String::from_str(&env, "USDC")),
+        );
+    });
+    let (_, decimals, symbol) = client.get_token_info();
+    assert_eq!(decimals, 2);
+    assert_eq!(symbol, String::from_str(&env, "USDC"));
+
+    env.mock_all_auths();
+    let (_, decimals, symbol) = client.refresh_token_info();
+    assert_eq!(decimals, token.decimals());
+    assert_eq!(symbol, token.symbol());
+    assert_eq!(client.get_token_info(), (token_id, decimals, symbol));
+}
+
+#[test]
+fn test_init_with_non_token_address_leaves_cache_empty() {
+    let env = Env::default();
+    let client = register(&env);
+    client.init(&Address::generate(&env), &Address::generate(&env));
+
+    assert!(client.try_get_token_info().is_err());
+    env.mock_all_auths();
+    assert!(client.try_refresh_token_info().is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_refresh_token_info_requires_admin_auth() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = register(&env);
+    client.init(&admin, &token_id);
+
+    client.refresh_token_info();
+}
+
+#[test]
+fn test_token_info_before_init() {
+    let env = Env::default();
+    let client = register(&env);
+    let res = client.try_get_token_info();
+    assert_eq!(res.unwrap_err().unwrap(), Error::NotInitialized);
+}
@@ -0,0 +1,237 @@
+//! Signed payout voucher tests for `BountyEscrowContract`.
+//!
+//! Covers:
+//! - A voucher signed by a registered key pays the recipient without admin
+//!   involvement, and draining the escrow marks it `Released`
+//! - Replay: the same voucher cannot be redeemed twice
+//! - Expired vouchers, unregistered or revoked signers and tampered payloads
+//!   are rejected without moving funds
+//! - The payload is bound to this contract and a nonce, so the same terms can
+//!   be reissued but not replayed against another deployment
+//! - The recipient denylist and release dependencies apply
+
+#![cfg(test)]
+
+use crate::test_support::Fixture;
+use crate::{DependencyStatus, Error, EscrowStatus, Voucher};
+use core::ops::Deref;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    xdr::ToXdr,
+    Address, BytesN,
+};
+
+const EXPIRY: u64 = 2_000;
+
+struct Suite {
+    fx: Fixture,
+    signer: SigningKey,
+}
+
+impl Deref for Suite {
+    type Target = Fixture;
+
+    fn deref(&self) -> &Fixture {
+        &self.fx
+    }
+}
+
+impl Suite {
+    fn new() -> Self {
+        let fx = Fixture::new();
+        fx.client.lock_funds(&fx.depositor, &1, &1_000, &10_000);
+
+        let suite = Self {
+            fx,
+            signer: SigningKey::from_bytes(&[7u8; 32]),
+        };
+        suite
+            .client
+            .set_voucher_signer(&suite.pubkey(&suite.signer), &true);
+        suite
+    }
+
+    fn pubkey(&self, key: &SigningKey) -> BytesN<32> {
+        BytesN::from_array(&self.env, &key.verifying_key().to_bytes())
+    }
+
+    fn sign(&self, key: &SigningKey, bounty_id: u64, amount: i128, expiry: u64) -> BytesN<64> {
+        self.sign_for(key, &self.client.address, bounty_id, amount, expiry, 0)
+    }
+
+    fn sign_for(
+        &self,
+        key: &SigningKey,
+        contract: &Address,
+        bounty_id: u64,
+        amount: i128,
+        expiry: u64,
+        nonce: u64,
+    ) -> BytesN<64> {
+        let payload = (
+            contract.clone(),
+            bounty_id,
+            self.contributor.clone(),
+            amount,
+            expiry,
+            nonce,
+        )
+            .to_xdr(&self.env);
+        let mut buf = [0u8; 256];
+        let len = payload.len() as usize;
+        payload.copy_into_slice(&mut buf[..len]);
+        BytesN::from_array(&self.env, &key.sign(&buf[..len]).to_bytes())
+    }
+
+    fn voucher(&self, amount: i128, nonce: u64) -> Voucher {
+        Voucher {
+            bounty_id: 1,
+            recipient: self.contributor.clone(),
+            amount,
+            expiry: EXPIRY,
+            nonce,
+        }
+    }
+
+    fn redeem(&self, amount: i128) -> Result<(), Error> {
+        self.redeem_with_nonce(amount, 0)
+    }
+
+    fn redeem_with_nonce(&self, amount: i128, nonce: u64) -> Result<(), Error> {
+        let signature = self.sign_for(&self.signer, &self.client.address, 1, amount, EXPIRY, nonce);
+        match self.client.try_redeem_voucher(
+            &self.voucher(amount, nonce),
+            &signature,
+            &self.pubkey(&self.signer),
+        ) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.unwrap()),
+        }
+    }
+}
+
+#[test]
+fn test_voucher_pays_recipient_and_finishes_escrow() {
+    let s = Suite::new();
+
+    s.redeem(400).unwrap();
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    assert_eq!(
+        s.client.get_recipient_history(&s.contributor, &0, &10),
+        soroban_sdk::vec![&s.env, (1, 400, 1_000)]
+    );
+    let escrow = s.client.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+
+    s.redeem(600).unwrap();
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.client.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_voucher_cannot_be_replayed() {
+    let s = Suite::new();
+
+    s.redeem(300).unwrap();
+    assert_eq!(s.redeem(300), Err(Error::TicketAlreadyUsed));
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 700);
+}
+
+#[test]
+fn test_expired_voucher_rejected() {
+    let s = Suite::new();
+
+    s.env.ledger().set_timestamp(EXPIRY + 1);
+    assert_eq!(s.redeem(300), Err(Error::TicketExpired));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_unregistered_and_revoked_signers_rejected() {
+    let s = Suite::new();
+    let rogue = SigningKey::from_bytes(&[9u8; 32]);
+
+    let signature = s.sign(&rogue, 1, 300, EXPIRY);
+    let res = s
+        .client
+        .try_redeem_voucher(&s.voucher(300, 0), &signature, &s.pubkey(&rogue));
+    assert_eq!(res.unwrap_err().unwrap(), Error::Unauthorized);
+
+    s.client.set_voucher_signer(&s.pubkey(&s.signer), &false);
+    assert!(!s.client.is_voucher_signer(&s.pubkey(&s.signer)));
+    assert_eq!(s.redeem(300), Err(Error::Unauthorized));
+}
+
+#[test]
+fn test_tampered_amount_fails_signature_check() {
+    let s = Suite::new();
+
+    // Signed for 100 but submitted for 900.
+    let signature = s.sign(&s.signer, 1, 100, EXPIRY);
+    let res = s
+        .client
+        .try_redeem_voucher(&s.voucher(900, 0), &signature, &s.pubkey(&s.signer));
+    assert!(res.is_err());
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_voucher_above_remaining_rejected() {
+    let s = Suite::new();
+    assert_eq!(s.redeem(1_001), Err(Error::InsufficientFunds));
+}
+
+#[test]
+fn test_fresh_nonce_reissues_same_terms() {
+    let s = Suite::new();
+
+    s.redeem_with_nonce(300, 1).unwrap();
+    assert_eq!(s.redeem_with_nonce(300, 1), Err(Error::TicketAlreadyUsed));
+    s.redeem_with_nonce(300, 2).unwrap();
+    assert_eq!(s.token.balance(&s.contributor), 600);
+}
+
+#[test]
+fn test_voucher_signed_for_another_contract_rejected() {
+    let s = Suite::new();
+
+    let other_contract = Address::generate(&s.env);
+    let signature = s.sign_for(&s.signer, &other_contract, 1, 300, EXPIRY, 0);
+    let res = s
+        .client
+        .try_redeem_voucher(&s.voucher(300, 0), &signature, &s.pubkey(&s.signer));
+    assert!(res.is_err());
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_voucher_to_denied_recipient_rejected() {
+    let s = Suite::new();
+
+    s.client.set_address_denied(&s.contributor, &true);
+    assert_eq!(s.redeem(300), Err(Error::RecipientDenied));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    // The voucher was not consumed and pays out once the entry is lifted.
+    s.client.set_address_denied(&s.contributor, &false);
+    s.redeem(300).unwrap();
+    assert_eq!(s.token.balance(&s.contributor), 300);
+}
+
+#[test]
+fn test_voucher_waits_on_dependency() {
+    let s = Suite::new();
+    let dependency = soroban_sdk::String::from_str(&s.env, "audit");
+    s.client.set_bounty_dependency(&1, &dependency);
+
+    assert_eq!(s.redeem(300), Err(Error::DependencyNotSatisfied));
+
+    s.client
+        .set_bounty_dependency_status(&dependency, &DependencyStatus::Completed);
+    s.redeem(300).unwrap();
+    assert_eq!(s.token.balance(&s.contributor), 300);
+}
@@ -142,7 +142,7 @@
 #![no_std]
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    IntoVal, String, Symbol, Vec,
 };
 
 // Event types
@@ -158,6 +158,13 @@ const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgRgd");
 const RELEASE_SCHEDULED: Symbol = symbol_short!("RelSched");
 const SCHEDULE_RELEASED: Symbol = symbol_short!("SchRel");
+const SCHEDULE_MODIFIED: Symbol = symbol_short!("SchMod");
+const BALANCE_SYNCED: Symbol = symbol_short!("BalSync");
+const WHITELIST_CHANGED: Symbol = symbol_short!("wl_chg");
+const PROGRAM_CLOSED: Symbol = symbol_short!("PrgClose");
+const FUNDING_TARGET_REACHED: Symbol = symbol_short!("FundTgt");
+const SUB_POOL_CREATED: Symbol = symbol_short!("SubPool");
+const PROGRAM_CANCELLED: Symbol = symbol_short!("PrgCncl");
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
@@ -304,6 +311,65 @@ pub struct FundsLockedEvent {
     pub remaining_balance: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceSyncedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub credited: i128,
+    pub total_funds: i128,
+    pub remaining_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramClosedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub closed_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramCancelledEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub refund_recipient: Address,
+    pub refunded: i128,
+    pub cancelled_schedules: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingTargetReachedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub target: i128,
+    pub total_funds: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubPoolCreatedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub track_id: String,
+    pub budget: i128,
+}
+
+/// Audit record for an anti-abuse whitelist change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistChanged {
+    pub address: Address,
+    pub whitelisted: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BatchPayoutEvent {
@@ -335,6 +401,18 @@ pub struct ReleaseScheduledEvent {
     pub release_timestamp: u64,
 }
 
+/// Emitted when a pending schedule is revised; only the changed fields are set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleModifiedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub new_amount: Option<i128>,
+    pub new_recipient: Option<Address>,
+    pub new_release_timestamp: Option<u64>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ScheduleReleasedEvent {
@@ -472,6 +550,33 @@ pub enum DataKey {
     SplitConfig(String),             // program_id -> SplitConfig (payout splits)
     Dispute,                         // DisputeRecord (single active dispute per contract)
     SplitConfig(String),             // program_id -> SplitConfig
+    EventSchema,                     // Vec<(Symbol, u32)> topic -> payload version
+    FundCap(String),                 // program_id -> i128 max total_funds
+    PayoutVelocity(String),          // program_id -> PayoutVelocityLimit
+    ProgramClosed(String),           // program_id -> bool (closed to new funds/schedules)
+    FeeRecipientForToken(Address),   // token_address -> Address receiving that token's fees
+    FundingTarget(String),           // program_id -> i128 total_funds required before payouts
+    TrackBudget(String, String),     // (program_id, track_id) -> i128 unspent sub-pool budget
+    TrackReserved(String),           // program_id -> i128 sum of unspent sub-pool budgets
+    TrackIds(String),                // program_id -> Vec<String> track ids with a sub-pool
+    SchedulerKey(String),            // program_id -> Address allowed to create schedules only
+    MaxPayoutRecipients(String),     // program_id -> u32 max recipients per batch_payout
+    ScheduleMinDelay(String),        // program_id -> u64 min seconds from scheduling to release
+    ScheduleCreatedAt(u64),          // schedule_id -> u64 creation timestamp
+    RequireReferenceHash,            // bool (global config)
+}
+
+/// Rate limit on payouts per time window for a program.
+///
+/// `spent_in_window` resets once `window_seconds` have elapsed since
+/// `window_start`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutVelocityLimit {
+    pub max_amount_per_window: i128,
+    pub window_seconds: u64,
+    pub window_start: u64,
+    pub spent_in_window: i128,
 }
 
 #[contracttype]
@@ -606,9 +711,20 @@ pub enum BatchError {
     InvalidBatchSize = 1,
     ProgramAlreadyExists = 2,
     DuplicateProgramId = 3,
+    ReferenceHashRequired = 4,
 }
 
 pub const MAX_BATCH_SIZE: u32 = 100;
+/// Recipients allowed in one `batch_payout` when the program has not set its own limit.
+pub const DEFAULT_MAX_PAYOUT_RECIPIENTS: u32 = 100;
+
+/// Issue codes reported by `validate_batch_payout`.
+pub const BATCH_ISSUE_INVALID_AMOUNT: u32 = 1;
+pub const BATCH_ISSUE_OVERFLOW: u32 = 2;
+pub const BATCH_ISSUE_INSUFFICIENT_BALANCE: u32 = 3;
+pub const BATCH_ISSUE_LENGTH_MISMATCH: u32 = 4;
+pub const BATCH_ISSUE_EMPTY: u32 = 5;
+pub const BATCH_ISSUE_TOO_MANY_RECIPIENTS: u32 = 6;
 
 fn vec_contains(values: &Vec<String>, target: &String) -> bool {
     for value in values.iter() {
@@ -660,14 +776,69 @@ fn path_exists_to_target(
 }
 
 mod anti_abuse {
-    use soroban_sdk::{symbol_short, Address, Env, Symbol};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
 
     const RATE_LIMIT: Symbol = symbol_short!("RateLim");
 
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum AntiAbuseKey {
+        Whitelist(Address),
+        WhitelistIndex,
+    }
+
     pub fn check_rate_limit(env: &Env, _caller: Address) {
         let count: u32 = env.storage().instance().get(&RATE_LIMIT).unwrap_or(0);
         env.storage().instance().set(&RATE_LIMIT, &(count + 1));
     }
+
+    pub fn is_whitelisted(env: &Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&AntiAbuseKey::Whitelist(address))
+    }
+
+    pub fn set_whitelist(env: &Env, address: Address, whitelisted: bool) {
+        let mut index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(Vec::new(env));
+        let position = index.first_index_of(&address);
+        if whitelisted {
+            env.storage()
+                .persistent()
+                .set(&AntiAbuseKey::Whitelist(address.clone()), &true);
+            if position.is_none() {
+                index.push_back(address);
+            }
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&AntiAbuseKey::Whitelist(address));
+            if let Some(i) = position {
+                index.remove(i);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&AntiAbuseKey::WhitelistIndex, &index);
+    }
+
+    /// Whitelisted addresses in insertion order, paginated.
+    pub fn list_whitelisted(env: &Env, offset: u32, limit: u32) -> Vec<Address> {
+        let index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(Vec::new(env));
+        let mut results = Vec::new(env);
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            results.push_back(index.get(i).unwrap());
+        }
+        results
+    }
 }
 
 mod claim_period;
@@ -719,8 +890,12 @@ mod test_risk_flags;
 #[cfg(test)]
 mod test_serialization_compatibility;
 
+#[cfg(test)]
+mod test_payout_callback;
 #[cfg(test)]
 mod test_payout_splits;
+#[cfg(test)]
+mod test_sub_pools;
 
 // ========================================================================
 // Contract Implementation
@@ -773,6 +948,54 @@ impl ProgramEscrowContract {
         )
     }
 
+    /// Idempotent variant of `init_program` for deploy scripts that may re-run.
+    ///
+    /// If `program_id` is already registered with the same token and
+    /// authorized payout key, the existing `ProgramData` is returned unchanged
+    /// and no funds move. Otherwise the program is initialized as usual.
+    ///
+    /// # Panics
+    /// * If `program_id` exists with a different token or payout key
+    pub fn init_program_idempotent(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        reference_hash: Option<soroban_sdk::Bytes>,
+    ) -> ProgramData {
+        let existing = env
+            .storage()
+            .instance()
+            .get::<Symbol, ProgramData>(&PROGRAM_DATA)
+            .filter(|data| data.program_id == program_id)
+            .or_else(|| {
+                env.storage()
+                    .instance()
+                    .get(&DataKey::Program(program_id.clone()))
+            });
+
+        if let Some(program_data) = existing {
+            if program_data.token_address != token_address
+                || program_data.authorized_payout_key != authorized_payout_key
+            {
+                panic!("Conflicting program config");
+            }
+            return program_data;
+        }
+
+        Self::initialize_program(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            reference_hash,
+        )
+    }
+
     pub fn initialize_program(
         env: Env,
         program_id: String,
@@ -786,6 +1009,9 @@ impl ProgramEscrowContract {
         if env.storage().instance().has(&PROGRAM_DATA) {
             panic!("Program already initialized");
         }
+        if reference_hash.is_none() && Self::get_require_reference_hash(env.clone()) {
+            panic!("Reference hash required");
+        }
 
         let mut total_funds = 0i128;
         let mut remaining_balance = 0i128;
@@ -865,6 +1091,7 @@ impl ProgramEscrowContract {
             .instance()
             .set(&RELEASE_HISTORY, &Vec::<ProgramReleaseHistory>::new(&env));
         env.storage().instance().set(&NEXT_SCHEDULE_ID, &1_u64);
+        Self::seed_event_schema(&env);
 
         // Emit ProgramInitialized event
         env.events().publish(
@@ -926,6 +1153,8 @@ impl ProgramEscrowContract {
     /// * `BatchError::InvalidBatchSize` - empty or len > MAX_BATCH_SIZE
     /// * `BatchError::DuplicateProgramId` - duplicate program_id in items
     /// * `BatchError::ProgramAlreadyExists` - a program_id already registered
+    /// * `BatchError::ReferenceHashRequired` - an item has no reference_hash
+    ///   while `set_require_reference_hash(true)` is in effect
     pub fn batch_initialize_programs(
         env: Env,
         items: Vec<ProgramInitItem>,
@@ -947,6 +1176,13 @@ impl ProgramEscrowContract {
                 return Err(BatchError::ProgramAlreadyExists);
             }
         }
+        if Self::get_require_reference_hash(env.clone()) {
+            for item in items.iter() {
+                if item.reference_hash.is_none() {
+                    return Err(BatchError::ReferenceHashRequired);
+                }
+            }
+        }
 
         // Update registry
         let mut registry: Vec<String> = env
@@ -1010,6 +1246,33 @@ impl ProgramEscrowContract {
         Ok(batch_size as u32)
     }
 
+    /// Require every new program to carry a `reference_hash` (admin-only).
+    ///
+    /// When enabled, `init_program` and the other single-program initializers
+    /// panic on a `None` hash and `batch_initialize_programs` returns
+    /// `BatchError::ReferenceHashRequired`. Programs that already exist are
+    /// unaffected. Off by default.
+    pub fn set_require_reference_hash(env: Env, required: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RequireReferenceHash, &required);
+    }
+
+    /// Whether new programs must be initialized with a `reference_hash`.
+    pub fn get_require_reference_hash(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequireReferenceHash)
+            .unwrap_or(false)
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -1094,6 +1357,43 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&FEE_CONFIG, &config);
     }
 
+    /// Route fees collected in `token_address` to `recipient` (admin-only).
+    ///
+    /// Fee transfers in a token without an entry fall back to the global
+    /// `fee_recipient`.
+    ///
+    /// # Arguments
+    /// * `token_address` - Token whose fees are routed
+    /// * `recipient` - Treasury receiving fees in that token
+    pub fn set_fee_recipient_for_token(env: Env, token_address: Address, recipient: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeRecipientForToken(token_address), &recipient);
+    }
+
+    /// Get the per-token fee recipient for `token_address`, if one is set.
+    pub fn get_fee_recipient_for_token(env: Env, token_address: Address) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeRecipientForToken(token_address))
+    }
+
+    /// Resolve where fees in `token_address` are sent: the per-token recipient
+    /// when set, otherwise the global `fee_recipient`.
+    fn fee_recipient_for(env: &Env, fee_config: &FeeConfig, token_address: &Address) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeRecipientForToken(token_address.clone()))
+            .unwrap_or_else(|| fee_config.fee_recipient.clone())
+    }
+
     /// Enable or disable fee collection (admin-only).
     ///
     /// # Arguments
@@ -1116,6 +1416,19 @@ impl ProgramEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Rescale `amount` between decimal bases, rounding down.
+    ///
+    /// Pure helper for reasoning about payouts across tokens with different
+    /// decimals; see `token_math::scale_amount`. Returns `None` on overflow.
+    pub fn normalize_to_decimals(
+        _env: Env,
+        amount: i128,
+        from_decimals: u32,
+        to_decimals: u32,
+    ) -> Option<i128> {
+        token_math::scale_amount(amount, from_decimals, to_decimals)
+    }
+
     /// Check if a program exists (legacy single-program check)
     ///
     /// # Returns
@@ -1138,7 +1451,8 @@ impl ProgramEscrowContract {
     ///
     /// When fees are enabled, the lock fee is deducted from `amount`. Only the net
     /// amount is added to `total_funds` and `remaining_balance`. The fee is transferred
-    /// to the configured fee recipient.
+    /// to the program token's fee recipient (see `set_fee_recipient_for_token`), or the
+    /// global fee recipient when none is set.
     ///
     /// # Arguments
     /// * `amount` - Gross amount to lock (in native token units)
@@ -1151,8 +1465,9 @@ impl ProgramEscrowContract {
     pub fn lock_program_funds(env: Env, amount: i128) -> ProgramData {
         // Validation precedence (deterministic ordering):
         // 1. Contract initialized
-        // 2. Paused (operational state)
+        // 2. Paused / closed (operational state)
         // 3. Input validation (amount)
+        // 4. Fund cap
 
         // 1. Contract must be initialized
         if !env.storage().instance().has(&PROGRAM_DATA) {
@@ -1164,12 +1479,32 @@ impl ProgramEscrowContract {
             panic!("Funds Paused");
         }
 
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        // 2b. Operational state: closed
+        if Self::program_closed(&env, &program_data.program_id) {
+            panic!("Program closed");
+        }
+
         // 3. Input validation
         if amount <= 0 {
             panic!("Amount must be greater than zero");
         }
 
-        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        // 4. Fund cap, if configured
+        if let Some(cap) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::FundCap(program_data.program_id.clone()))
+        {
+            let new_total = program_data
+                .total_funds
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Total funds overflow"));
+            if new_total > cap {
+                panic!("Program fund cap exceeded");
+            }
+        }
 
         // Get fee configuration
         let fee_config = Self::get_fee_config_internal(&env);
@@ -1186,7 +1521,9 @@ impl ProgramEscrowContract {
         if fee_amount > 0 {
             let contract_address = env.current_contract_address();
             let token_client = token::Client::new(&env, &program_data.token_address);
-            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            let fee_recipient =
+                Self::fee_recipient_for(&env, &fee_config, &program_data.token_address);
+            token_client.transfer(&contract_address, &fee_recipient, &fee_amount);
         }
 
         // Update balances with overflow safety
@@ -1214,77 +1551,569 @@ impl ProgramEscrowContract {
             },
         );
 
+        // Announce the lock that first brings the program up to its funding target
+        if let Some(target) = Self::funding_target(&env, &program_data.program_id) {
+            let previous_total = program_data.total_funds - amount;
+            if previous_total < target && program_data.total_funds >= target {
+                env.events().publish(
+                    (FUNDING_TARGET_REACHED,),
+                    FundingTargetReachedEvent {
+                        version: EVENT_VERSION_V2,
+                        program_id: program_data.program_id.clone(),
+                        target,
+                        total_funds: program_data.total_funds,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+        }
+
         program_data
     }
 
-    // ========================================================================
-    // Initialization & Admin
-    // ========================================================================
+    /// Close a concluded program to new funding and new schedules.
+    ///
+    /// After closing, `lock_program_funds` and `create_program_release_schedule`
+    /// panic with "Program closed". Payouts and releases of existing schedules
+    /// keep working so outstanding commitments can still be paid. Closing is
+    /// permanent and callable by the program's authorized payout key.
+    pub fn close_program(env: Env, program_id: String) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
 
-    /// Initialize the contract with an admin.
-    /// This must be called before any admin protected functions (like pause) can be used.
-    pub fn initialize_contract(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+        program_data.authorized_payout_key.require_auth();
+
+        if Self::program_closed(&env, &program_id) {
+            panic!("Program closed");
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+
         env.storage()
-            .instance()
-            .set(&DataKey::MaintenanceMode, &false);
-        env.storage().instance().set(
-            &DataKey::PauseFlags,
-            &PauseFlags {
-                lock_paused: false,
-                release_paused: false,
-                refund_paused: false,
-                pause_reason: None,
-                paused_at: 0,
+            .persistent()
+            .set(&DataKey::ProgramClosed(program_id.clone()), &true);
+
+        env.events().publish(
+            (PROGRAM_CLOSED,),
+            ProgramClosedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                closed_by: program_data.authorized_payout_key,
+                timestamp: env.ledger().timestamp(),
             },
         );
     }
 
-    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
-    pub fn set_admin(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-            current.require_auth();
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+    /// Whether the program has been closed with `close_program`.
+    pub fn is_program_closed(env: Env, program_id: String) -> bool {
+        Self::program_closed(&env, &program_id)
     }
 
-    /// Returns the current admin address, if set.
-    pub fn get_admin(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::Admin)
-    }
+    /// Cancel a program outright and refund everything still held for it.
+    ///
+    /// Requires lock and release to be paused so nothing moves while the
+    /// program is unwound. Drops every unreleased schedule, transfers the whole
+    /// `remaining_balance` to `refund_recipient`, drops every track sub-pool
+    /// and closes the program. Released schedules and payout
+    /// history are kept as they are. Callable by the program's authorized
+    /// payout key.
+    ///
+    /// # Returns
+    /// The amount refunded.
+    pub fn cancel_program(env: Env, program_id: String, refund_recipient: Address) -> i128 {
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
 
-    fn require_admin(env: &Env) -> Address {
-        let admin: Address = env
+        let mut program_data: ProgramData = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("Not initialized"));
-        admin.require_auth();
-        admin
-    }
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Program not initialized")
+            });
+        if program_data.program_id != program_id {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not found");
+        }
 
-    fn get_program_data_by_id(env: &Env, program_id: &String) -> ProgramData {
-        let program_key = DataKey::Program(program_id.clone());
-        if env.storage().instance().has(&program_key) {
-            return env
-                .storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| panic!("Program not found"));
+        program_data.authorized_payout_key.require_auth();
+
+        if !Self::check_paused(&env, symbol_short!("lock"))
+            || !Self::check_paused(&env, symbol_short!("release"))
+        {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not paused");
         }
 
-        if env.storage().instance().has(&PROGRAM_DATA) {
-            let program_data: ProgramData = env
-                .storage()
-                .instance()
-                .get(&PROGRAM_DATA)
-                .unwrap_or_else(|| panic!("Program not initialized"));
-            if &program_data.program_id == program_id {
-                return program_data;
+        let schedules: Vec<ProgramReleaseSchedule> = env
+            .storage()
+            .instance()
+            .get(&SCHEDULES)
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut kept = Vec::new(&env);
+        let mut cancelled_schedules: u32 = 0;
+        for schedule in schedules.iter() {
+            if schedule.released {
+                kept.push_back(schedule);
+            } else {
+                cancelled_schedules += 1;
+            }
+        }
+
+        let refunded = program_data.remaining_balance;
+        if refunded > 0 {
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &refund_recipient,
+                &refunded,
+            );
+        }
+
+        program_data.remaining_balance = 0;
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        env.storage().instance().set(&SCHEDULES, &kept);
+        Self::clear_tracks(&env, &program_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramClosed(program_id.clone()), &true);
+
+        env.events().publish(
+            (PROGRAM_CANCELLED,),
+            ProgramCancelledEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                refund_recipient,
+                refunded,
+                cancelled_schedules,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        reentrancy_guard::clear_entered(&env);
+        refunded
+    }
+
+    fn program_closed(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramClosed(program_id.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Cap the total funds a program can ever accumulate.
+    ///
+    /// Once set, `lock_program_funds` rejects any lock that would push
+    /// `total_funds` above `cap`. Callable by the program's authorized payout key.
+    ///
+    /// # Panics
+    /// * If `cap` is not positive
+    pub fn set_program_fund_cap(env: Env, program_id: String, cap: i128) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if cap <= 0 {
+            panic!("Fund cap must be greater than zero");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundCap(program_id), &cap);
+    }
+
+    /// Get the program's fund cap, or `None` if no cap applies.
+    pub fn get_program_fund_cap(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FundCap(program_id))
+    }
+
+    /// Require the program to raise `target` in total before paying out.
+    ///
+    /// While `total_funds < target`, `single_payout` and `batch_payout` panic
+    /// with "Funding target not met". The lock that first brings `total_funds`
+    /// to or past the target emits `FundingTargetReached`. Callable by the
+    /// program's authorized payout key.
+    ///
+    /// # Panics
+    /// * If `target` is not positive
+    pub fn set_funding_target(env: Env, program_id: String, target: i128) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if target <= 0 {
+            panic!("Funding target must be greater than zero");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundingTarget(program_id), &target);
+    }
+
+    /// Funding progress as `(total_funds, target)`; `target` is 0 when unset.
+    pub fn get_funding_progress(env: Env, program_id: String) -> (i128, i128) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let target = Self::funding_target(&env, &program_id).unwrap_or(0);
+        (program_data.total_funds, target)
+    }
+
+    /// Cap the number of recipients a single `batch_payout` may carry.
+    ///
+    /// Larger batches panic with "Batch exceeds max payout recipients" before
+    /// any transfer is made. Defaults to `DEFAULT_MAX_PAYOUT_RECIPIENTS`.
+    /// Callable by the program's authorized payout key.
+    ///
+    /// # Panics
+    /// * If `max` is zero
+    pub fn set_max_payout_recipients(env: Env, program_id: String, max: u32) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if max == 0 {
+            panic!("Max payout recipients must be greater than zero");
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPayoutRecipients(program_id), &max);
+    }
+
+    /// Maximum recipients accepted by one `batch_payout` for the program.
+    pub fn get_max_payout_recipients(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxPayoutRecipients(program_id))
+            .unwrap_or(DEFAULT_MAX_PAYOUT_RECIPIENTS)
+    }
+
+    fn funding_target(env: &Env, program_id: &String) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FundingTarget(program_id.clone()))
+    }
+
+    fn funding_target_met(env: &Env, program_data: &ProgramData) -> bool {
+        match Self::funding_target(env, &program_data.program_id) {
+            Some(target) => program_data.total_funds >= target,
+            None => true,
+        }
+    }
+
+    /// Limit how much the authorized payout key can distribute per time window.
+    ///
+    /// `single_payout` and `batch_payout` reject any distribution that would
+    /// take the current window's total above `max_amount_per_window`. The
+    /// window restarts whenever `window_seconds` have elapsed since it began.
+    /// Changing an existing limit keeps the current window and what was
+    /// already paid out in it, so re-setting the limit cannot reset the allowance.
+    ///
+    /// # Panics
+    /// * If `max_amount_per_window` or `window_seconds` is not positive
+    pub fn set_payout_velocity_limit(
+        env: Env,
+        program_id: String,
+        max_amount_per_window: i128,
+        window_seconds: u64,
+    ) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if max_amount_per_window <= 0 || window_seconds == 0 {
+            panic!("Invalid velocity limit");
+        }
+
+        let key = DataKey::PayoutVelocity(program_id);
+        let (window_start, spent_in_window) = match env
+            .storage()
+            .persistent()
+            .get::<_, PayoutVelocityLimit>(&key)
+        {
+            Some(current) => {
+                let current = Self::roll_velocity_window(&env, current);
+                (current.window_start, current.spent_in_window)
+            }
+            None => (env.ledger().timestamp(), 0),
+        };
+        env.storage().persistent().set(
+            &key,
+            &PayoutVelocityLimit {
+                max_amount_per_window,
+                window_seconds,
+                window_start,
+                spent_in_window,
+            },
+        );
+    }
+
+    /// Get how much can still be paid out in the current window, or `None`
+    /// if no velocity limit is configured.
+    pub fn get_payout_velocity_remaining(env: Env, program_id: String) -> Option<i128> {
+        let limit: PayoutVelocityLimit = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutVelocity(program_id))?;
+        let limit = Self::roll_velocity_window(&env, limit);
+        Some(limit.max_amount_per_window - limit.spent_in_window)
+    }
+
+    fn roll_velocity_window(env: &Env, mut limit: PayoutVelocityLimit) -> PayoutVelocityLimit {
+        let now = env.ledger().timestamp();
+        if now >= limit.window_start.saturating_add(limit.window_seconds) {
+            limit.window_start = now;
+            limit.spent_in_window = 0;
+        }
+        limit
+    }
+
+    /// Count `amount` against the program's velocity limit. Returns `false`
+    /// (without recording anything) if it would exceed the window allowance.
+    fn charge_payout_velocity(env: &Env, program_id: &String, amount: i128) -> bool {
+        let key = DataKey::PayoutVelocity(program_id.clone());
+        let limit: PayoutVelocityLimit = match env.storage().persistent().get(&key) {
+            Some(limit) => limit,
+            None => return true,
+        };
+        let mut limit = Self::roll_velocity_window(env, limit);
+        let spent = match limit.spent_in_window.checked_add(amount) {
+            Some(spent) if spent <= limit.max_amount_per_window => spent,
+            _ => return false,
+        };
+        limit.spent_in_window = spent;
+        env.storage().persistent().set(&key, &limit);
+        true
+    }
+
+    /// Credit tokens sent directly to the contract into the program's balance.
+    ///
+    /// `lock_program_funds` only records amounts, so a transfer that is never
+    /// followed by a lock call leaves the real token balance above
+    /// `remaining_balance`. This credits that surplus into `total_funds` and
+    /// `remaining_balance`. Reconciliation is upward only: if the real balance
+    /// is not above `remaining_balance`, nothing changes.
+    ///
+    /// # Arguments
+    /// * `program_id` - Program to reconcile
+    ///
+    /// # Returns
+    /// The amount credited (0 if there was no surplus)
+    pub fn sync_balance_up(env: Env, program_id: String) -> i128 {
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let balance = token_client.balance(&env.current_contract_address());
+        let surplus = balance - program_data.remaining_balance;
+        if surplus <= 0 {
+            return 0;
+        }
+
+        program_data.total_funds = program_data
+            .total_funds
+            .checked_add(surplus)
+            .unwrap_or_else(|| panic!("Total funds overflow"));
+        program_data.remaining_balance = balance;
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        env.events().publish(
+            (BALANCE_SYNCED,),
+            BalanceSyncedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                credited: surplus,
+                total_funds: program_data.total_funds,
+                remaining_balance: program_data.remaining_balance,
+            },
+        );
+
+        surplus
+    }
+
+    // ========================================================================
+    // Initialization & Admin
+    // ========================================================================
+
+    /// Initialize the contract with an admin.
+    /// This must be called before any admin protected functions (like pause) can be used.
+    pub fn initialize_contract(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("Already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaintenanceMode, &false);
+        env.storage().instance().set(
+            &DataKey::PauseFlags,
+            &PauseFlags {
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                pause_reason: None,
+                paused_at: 0,
+            },
+        );
+        Self::seed_event_schema(&env);
+    }
+
+    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
+    pub fn set_admin(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            current.require_auth();
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Returns the current admin address, if set.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Returns each event topic paired with its current payload version.
+    ///
+    /// Indexers can validate their decoders against this instead of
+    /// `EVENT_SCHEMA.md`. Falls back to the built-in schema if none is stored.
+    pub fn get_event_schema(env: Env) -> Vec<(Symbol, u32)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventSchema)
+            .unwrap_or_else(|| Self::default_event_schema(&env))
+    }
+
+    /// Set the payload version for an event topic (admin only).
+    /// Intended to be called after an upgrade that changes an event's shape.
+    pub fn set_event_schema_version(env: Env, topic: Symbol, version: u32) {
+        Self::require_admin(&env);
+        if version == 0 {
+            panic!("Event schema version must be positive");
+        }
+
+        let mut schema = Self::get_event_schema(env.clone());
+        let mut updated = false;
+        for i in 0..schema.len() {
+            let (existing, _) = schema.get(i).unwrap();
+            if existing == topic {
+                schema.set(i, (topic.clone(), version));
+                updated = true;
+                break;
+            }
+        }
+        if !updated {
+            schema.push_back((topic, version));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::EventSchema, &schema);
+    }
+
+    fn default_event_schema(env: &Env) -> Vec<(Symbol, u32)> {
+        vec![
+            env,
+            (PROGRAM_INITIALIZED, EVENT_VERSION_V2),
+            (FUNDS_LOCKED, EVENT_VERSION_V2),
+            (BATCH_PAYOUT, EVENT_VERSION_V2),
+            (PAYOUT, EVENT_VERSION_V2),
+            (RELEASE_SCHEDULED, EVENT_VERSION_V2),
+            (SCHEDULE_RELEASED, EVENT_VERSION_V2),
+            (SCHEDULE_MODIFIED, EVENT_VERSION_V2),
+            (BALANCE_SYNCED, EVENT_VERSION_V2),
+            (PROGRAM_CLOSED, EVENT_VERSION_V2),
+            (PROGRAM_RISK_FLAGS_UPDATED, EVENT_VERSION_V2),
+            (DISPUTE_OPENED, EVENT_VERSION_V2),
+            (DISPUTE_RESOLVED, EVENT_VERSION_V2),
+        ]
+    }
+
+    fn seed_event_schema(env: &Env) {
+        if !env.storage().persistent().has(&DataKey::EventSchema) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::EventSchema, &Self::default_event_schema(env));
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+        admin
+    }
+
+    fn get_program_data_by_id(env: &Env, program_id: &String) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        if env.storage().instance().has(&program_key) {
+            return env
+                .storage()
+                .instance()
+                .get(&program_key)
+                .unwrap_or_else(|| panic!("Program not found"));
+        }
+
+        if env.storage().instance().has(&PROGRAM_DATA) {
+            let program_data: ProgramData = env
+                .storage()
+                .instance()
+                .get(&PROGRAM_DATA)
+                .unwrap_or_else(|| panic!("Program not initialized"));
+            if &program_data.program_id == program_id {
+                return program_data;
             }
         }
 
@@ -1473,7 +2302,12 @@ impl ProgramEscrowContract {
         );
     }
 
-    /// Emergency withdraw all program funds (admin only, must have lock_paused = true)
+    /// Emergency withdraw the program's funds (admin only, must have lock_paused = true)
+    ///
+    /// Only the program's own `remaining_balance` (capped at the contract's
+    /// token balance) is transferred, so other programs sharing the token keep
+    /// their funds. The program's `remaining_balance` is zeroed and its track
+    /// sub-pools are dropped.
     pub fn emergency_withdraw(env: Env, target: Address) {
         if !env.storage().instance().has(&DataKey::Admin) {
             panic!("Not initialized");
@@ -1486,7 +2320,7 @@ impl ProgramEscrowContract {
             panic!("Not paused");
         }
 
-        let program_data: ProgramData = env
+        let mut program_data: ProgramData = env
             .storage()
             .instance()
             .get(&PROGRAM_DATA)
@@ -1495,16 +2329,21 @@ impl ProgramEscrowContract {
 
         let contract_address = env.current_contract_address();
         let balance = token_client.balance(&contract_address);
+        let amount = program_data.remaining_balance.min(balance);
 
-        if balance > 0 {
-            token_client.transfer(&contract_address, &target, &balance);
+        program_data.remaining_balance = 0;
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        Self::clear_tracks(&env, &program_data.program_id);
+
+        if amount > 0 {
+            token_client.transfer(&contract_address, &target, &amount);
             let receipt_id = Self::increment_receipt_id(&env);
             env.events().publish(
                 (symbol_short!("em_wtd"),),
                 EmergencyWithdrawEvent {
                     admin,
                     target: target.clone(),
-                    amount: balance,
+                    amount,
                     timestamp: env.ledger().timestamp(),
                     receipt_id,
                 },
@@ -1635,7 +2474,7 @@ impl ProgramEscrowContract {
         }
     }
 
-    pub fn set_whitelist(env: Env, _address: Address, _whitelisted: bool) {
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
         // Only admin can set whitelist
         let admin: Address = env
             .storage()
@@ -1643,11 +2482,102 @@ impl ProgramEscrowContract {
             .get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("Not initialized"));
         admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address.clone(), whitelisted);
+        env.events().publish(
+            (WHITELIST_CHANGED, address.clone()),
+            WhitelistChanged {
+                address,
+                whitelisted,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Whether `address` is on the anti-abuse whitelist.
+    pub fn get_whitelist_entry(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
+
+    /// List whitelisted addresses in the order they were added.
+    pub fn list_whitelisted(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        anti_abuse::list_whitelisted(&env, offset, limit)
     }
     // ========================================================================
     // Payout Functions
     // ========================================================================
 
+    /// Check a `batch_payout` list without executing it.
+    ///
+    /// Returns `(index, issue_code)` for every problem found, using the
+    /// `BATCH_ISSUE_*` codes; an empty result means the list passes the
+    /// batch's input and balance checks:
+    /// - `BATCH_ISSUE_EMPTY` at index 0 for an empty batch.
+    /// - `BATCH_ISSUE_LENGTH_MISMATCH` at the first index only one vector covers.
+    /// - `BATCH_ISSUE_TOO_MANY_RECIPIENTS` at the first index past the program's cap.
+    /// - `BATCH_ISSUE_INVALID_AMOUNT` for each zero or negative amount.
+    /// - `BATCH_ISSUE_OVERFLOW` where the running total overflows (summing stops there).
+    /// - `BATCH_ISSUE_INSUFFICIENT_BALANCE` where the running total first exceeds
+    ///   the balance available to general payouts.
+    ///
+    /// Pause, dispute, funding-target and velocity state are not checked.
+    /// Read-only; requires no authorization.
+    pub fn validate_batch_payout(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Vec<(u32, u32)> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let mut issues = Vec::new(&env);
+        if recipients.len() == 0 && amounts.len() == 0 {
+            issues.push_back((0, BATCH_ISSUE_EMPTY));
+            return issues;
+        }
+        if recipients.len() != amounts.len() {
+            issues.push_back((
+                recipients.len().min(amounts.len()),
+                BATCH_ISSUE_LENGTH_MISMATCH,
+            ));
+        }
+        let max_recipients = Self::get_max_payout_recipients(env.clone(), program_id);
+        if recipients.len() > max_recipients {
+            issues.push_back((max_recipients, BATCH_ISSUE_TOO_MANY_RECIPIENTS));
+        }
+
+        let available = Self::unreserved_balance(&env, &program_data);
+        let mut total: i128 = 0;
+        let mut over_balance = false;
+        for (index, amount) in amounts.iter().enumerate() {
+            let index = index as u32;
+            if amount <= 0 {
+                issues.push_back((index, BATCH_ISSUE_INVALID_AMOUNT));
+                continue;
+            }
+            total = match total.checked_add(amount) {
+                Some(total) => total,
+                None => {
+                    issues.push_back((index, BATCH_ISSUE_OVERFLOW));
+                    break;
+                }
+            };
+            if !over_balance && total > available {
+                issues.push_back((index, BATCH_ISSUE_INSUFFICIENT_BALANCE));
+                over_balance = true;
+            }
+        }
+        issues
+    }
+
     /// Execute batch payouts to multiple winners.
     ///
     /// This function distributes prizes to multiple recipients in a single atomic transaction.
@@ -1671,7 +2601,7 @@ impl ProgramEscrowContract {
         // 2. Contract initialized
         // 3. Paused (operational state)
         // 4. Authorization
-        // 6. Business logic (sufficient balance)
+        // 6. Business logic (sufficient balance, payout velocity)
         // 7. Circuit breaker check
 
         // 1. Reentrancy guard
@@ -1714,6 +2644,13 @@ impl ProgramEscrowContract {
             panic!("Cannot process empty batch");
         }
 
+        if recipients.len()
+            > Self::get_max_payout_recipients(env.clone(), program_data.program_id.clone())
+        {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Batch exceeds max payout recipients");
+        }
+
         // Calculate total payout amount
         let mut total_payout: i128 = 0;
         for amount in amounts.iter() {
@@ -1727,12 +2664,24 @@ impl ProgramEscrowContract {
             });
         }
 
-        // 6. Business logic: sufficient balance
-        if total_payout > program_data.remaining_balance {
+        // 6. Business logic: sufficient balance outside track sub-pools
+        if total_payout > Self::unreserved_balance(&env, &program_data) {
             reentrancy_guard::clear_entered(&env);
             panic!("Insufficient balance");
         }
 
+        // 6a. Funding target
+        if !Self::funding_target_met(&env, &program_data) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funding target not met");
+        }
+
+        // 6b. Payout velocity limit
+        if !Self::charge_payout_velocity(&env, &program_data.program_id, total_payout) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payout velocity limit exceeded");
+        }
+
         // 7. Circuit breaker check
         if let Err(err_code) = error_recovery::check_and_allow_with_thresholds(&env) {
             reentrancy_guard::clear_entered(&env);
@@ -1820,13 +2769,78 @@ impl ProgramEscrowContract {
         // 2. Contract initialized
         // 3. Paused (operational state)
         // 4. Authorization
-        // 6. Business logic (sufficient balance)
+        // 6. Business logic (sufficient balance, payout velocity)
         // 7. Circuit breaker check
 
         // 1. Reentrancy guard
         reentrancy_guard::check_not_entered(&env);
         reentrancy_guard::set_entered(&env);
 
+        let updated_data = Self::single_payout_guarded(&env, recipient, amount, None);
+
+        // Clear reentrancy guard before returning
+        reentrancy_guard::clear_entered(&env);
+
+        updated_data
+    }
+
+    /// Pay a contract recipient, then invoke `callback_fn(amount)` on it.
+    ///
+    /// Lets winners such as DAO contracts distribute the payout downstream
+    /// (e.g. split it among members) in the same transaction. The payout and
+    /// the callback both run under the reentrancy guard; if the callback
+    /// fails, the whole operation, including the transfer, reverts.
+    ///
+    /// # Arguments
+    /// * `program_id` - Must match the initialized program.
+    /// * `recipient_contract` - Contract receiving the payout and the callback.
+    /// * `amount` - Amount to transfer.
+    /// * `callback_fn` - Function on `recipient_contract` taking the `i128` amount.
+    ///
+    /// # Security
+    /// Same checks as `single_payout`.
+    pub fn single_payout_with_callback(
+        env: Env,
+        program_id: String,
+        recipient_contract: Address,
+        amount: i128,
+        callback_fn: Symbol,
+    ) -> ProgramData {
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let updated_data =
+            Self::single_payout_guarded(&env, recipient_contract.clone(), amount, None);
+        env.invoke_contract::<()>(
+            &recipient_contract,
+            &callback_fn,
+            vec![&env, amount.into_val(&env)],
+        );
+
+        reentrancy_guard::clear_entered(&env);
+
+        updated_data
+    }
+
+    /// Body of `single_payout`; the caller must hold the reentrancy guard.
+    ///
+    /// With `track_id`, the payout is drawn from that track's sub-pool budget
+    /// instead of the unreserved balance.
+    fn single_payout_guarded(
+        env: &Env,
+        recipient: Address,
+        amount: i128,
+        track_id: Option<String>,
+    ) -> ProgramData {
         // 2. Contract must be initialized
         let program_data: ProgramData =
             env.storage()
@@ -1858,10 +2872,43 @@ impl ProgramEscrowContract {
             panic!("Amount must be greater than zero");
         }
 
-        // 6. Business logic: sufficient balance
-        if amount > program_data.remaining_balance {
+        // 6. Business logic: sufficient balance in the track or outside all tracks
+        let track_budget = match &track_id {
+            Some(track_id) => {
+                let budget = Self::track_budget(env, &program_data.program_id, track_id)
+                    .unwrap_or_else(|| {
+                        reentrancy_guard::clear_entered(&env);
+                        panic!("Track not found")
+                    });
+                if amount > budget {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Insufficient track budget");
+                }
+                if amount > program_data.remaining_balance {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Insufficient balance");
+                }
+                Some(budget)
+            }
+            None => {
+                if amount > Self::unreserved_balance(env, &program_data) {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Insufficient balance");
+                }
+                None
+            }
+        };
+
+        // 6a. Funding target
+        if !Self::funding_target_met(&env, &program_data) {
             reentrancy_guard::clear_entered(&env);
-            panic!("Insufficient balance");
+            panic!("Funding target not met");
+        }
+
+        // 6b. Payout velocity limit
+        if !Self::charge_payout_velocity(&env, &program_data.program_id, amount) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payout velocity limit exceeded");
         }
 
         // 7. Circuit breaker check
@@ -1902,6 +2949,9 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &updated_data);
+        if let (Some(track_id), Some(budget)) = (track_id, track_budget) {
+            Self::spend_track_budget(env, &updated_data.program_id, &track_id, budget, amount);
+        }
 
         // Emit Payout event
         env.events().publish(
@@ -1915,10 +2965,155 @@ impl ProgramEscrowContract {
             },
         );
 
-        // Clear reentrancy guard before returning
-        reentrancy_guard::clear_entered(&env);
+        updated_data
+    }
+
+    /// Reserve `budget` of the program's unreserved balance for a named track.
+    ///
+    /// Reserved funds can only be paid out with `single_payout_from_track`;
+    /// `single_payout` and `batch_payout` are limited to the balance outside
+    /// all tracks. Callable by the program's authorized payout key.
+    ///
+    /// # Panics
+    /// * If `budget` is not positive
+    /// * If the track already exists
+    /// * If `budget` exceeds the unreserved balance
+    pub fn create_sub_pool(env: Env, program_id: String, track_id: String, budget: i128) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if budget <= 0 {
+            panic!("Budget must be greater than zero");
+        }
+        let track_key = DataKey::TrackBudget(program_id.clone(), track_id.clone());
+        if env.storage().persistent().has(&track_key) {
+            panic!("Track already exists");
+        }
+        if budget > Self::unreserved_balance(&env, &program_data) {
+            panic!("Insufficient balance");
+        }
+
+        env.storage().persistent().set(&track_key, &budget);
+        let ids_key = DataKey::TrackIds(program_id.clone());
+        let mut track_ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&ids_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        track_ids.push_back(track_id.clone());
+        env.storage().persistent().set(&ids_key, &track_ids);
+        let reserved_key = DataKey::TrackReserved(program_id.clone());
+        let reserved: i128 = env.storage().instance().get(&reserved_key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&reserved_key, &(reserved + budget));
+
+        env.events().publish(
+            (SUB_POOL_CREATED,),
+            SubPoolCreatedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                track_id,
+                budget,
+            },
+        );
+    }
+
+    /// Pay `recipient` out of a track's sub-pool budget.
+    ///
+    /// # Security
+    /// Same checks as `single_payout`, except the amount is checked against
+    /// the track's remaining budget, which it is deducted from.
+    pub fn single_payout_from_track(
+        env: Env,
+        program_id: String,
+        track_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let updated_data = Self::single_payout_guarded(&env, recipient, amount, Some(track_id));
+
+        reentrancy_guard::clear_entered(&env);
+
+        updated_data
+    }
+
+    /// Unspent budget of a track, or 0 if the track does not exist.
+    pub fn get_track_balance(env: Env, program_id: String, track_id: String) -> i128 {
+        Self::track_budget(&env, &program_id, &track_id).unwrap_or(0)
+    }
+
+    fn track_budget(env: &Env, program_id: &String, track_id: &String) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TrackBudget(program_id.clone(), track_id.clone()))
+    }
+
+    fn spend_track_budget(
+        env: &Env,
+        program_id: &String,
+        track_id: &String,
+        budget: i128,
+        amount: i128,
+    ) {
+        env.storage().persistent().set(
+            &DataKey::TrackBudget(program_id.clone(), track_id.clone()),
+            &(budget - amount),
+        );
+        let reserved_key = DataKey::TrackReserved(program_id.clone());
+        let reserved: i128 = env.storage().instance().get(&reserved_key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&reserved_key, &(reserved - amount));
+    }
+
+    /// Drop every track sub-pool of a program along with its reservation.
+    fn clear_tracks(env: &Env, program_id: &String) {
+        let ids_key = DataKey::TrackIds(program_id.clone());
+        let track_ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&ids_key)
+            .unwrap_or_else(|| Vec::new(env));
+        for track_id in track_ids.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::TrackBudget(program_id.clone(), track_id));
+        }
+        env.storage().persistent().remove(&ids_key);
+        env.storage()
+            .instance()
+            .remove(&DataKey::TrackReserved(program_id.clone()));
+    }
 
-        updated_data
+    /// Remaining balance not reserved for any track sub-pool.
+    fn unreserved_balance(env: &Env, program_data: &ProgramData) -> i128 {
+        let reserved: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TrackReserved(program_data.program_id.clone()))
+            .unwrap_or(0);
+        program_data.remaining_balance - reserved
     }
 
     /// Get program information
@@ -1966,18 +3161,71 @@ impl ProgramEscrowContract {
             .instance()
             .get(&PROGRAM_DATA)
             .unwrap_or_else(|| panic!("Program not initialized"));
+        let caller = program_data.authorized_payout_key.clone();
+        Self::create_release_schedule_by(
+            &env,
+            program_data,
+            caller,
+            recipient,
+            amount,
+            release_timestamp,
+        )
+    }
 
-        program_data.authorized_payout_key.require_auth();
-
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
+    /// Create a release schedule as `caller`, which may be the program's
+    /// authorized payout key or its scheduler key (see `set_scheduler_key`).
+    ///
+    /// Lets a treasury role queue releases without being able to pay out
+    /// immediately. Otherwise identical to `create_program_release_schedule`.
+    ///
+    /// # Panics
+    /// * If `caller` is neither the payout key nor the scheduler key
+    pub fn create_prog_schedule_as(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if caller != program_data.authorized_payout_key
+            && Self::get_scheduler_key(env.clone(), program_data.program_id.clone()).as_ref()
+                != Some(&caller)
+        {
+            panic!("Unauthorized: not a scheduling key");
         }
+        Self::create_release_schedule_by(
+            &env,
+            program_data,
+            caller,
+            recipient,
+            amount,
+            release_timestamp,
+        )
+    }
+
+    fn create_release_schedule_by(
+        env: &Env,
+        program_data: ProgramData,
+        caller: Address,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        caller.require_auth();
+
+        Self::validate_schedule_terms(env, &program_data, Some(amount), Some(release_timestamp));
+        let now = env.ledger().timestamp();
 
         let mut schedules: Vec<ProgramReleaseSchedule> = env
             .storage()
             .instance()
             .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+            .unwrap_or_else(|| Vec::new(env));
         let schedule_id: u64 = env
             .storage()
             .instance()
@@ -1999,6 +3247,9 @@ impl ProgramEscrowContract {
         env.storage()
             .instance()
             .set(&NEXT_SCHEDULE_ID, &(schedule_id + 1));
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduleCreatedAt(schedule_id), &now);
 
         // Emit ReleaseScheduled event
         env.events().publish(
@@ -2016,6 +3267,193 @@ impl ProgramEscrowContract {
         schedule
     }
 
+    /// Checks shared by creating and revising a release schedule; `None`
+    /// skips the check for a term that is not being set.
+    fn validate_schedule_terms(
+        env: &Env,
+        program_data: &ProgramData,
+        amount: Option<i128>,
+        release_timestamp: Option<u64>,
+    ) {
+        if Self::program_closed(env, &program_data.program_id) {
+            panic!("Program closed");
+        }
+
+        if let Some(amount) = amount {
+            if amount <= 0 {
+                panic!("Amount must be greater than zero");
+            }
+        }
+
+        if let Some(release_timestamp) = release_timestamp {
+            let now = env.ledger().timestamp();
+            let min_delay =
+                Self::get_schedule_min_delay(env.clone(), program_data.program_id.clone());
+            if min_delay > 0 && release_timestamp < now.saturating_add(min_delay) {
+                panic!("Release timestamp within minimum schedule delay");
+            }
+        }
+    }
+
+    /// Set or clear the program's scheduler key.
+    ///
+    /// The scheduler key can create release schedules through
+    /// `create_prog_schedule_as` but cannot make immediate payouts. Callable
+    /// by the program's authorized payout key.
+    pub fn set_scheduler_key(env: Env, program_id: String, scheduler_key: Option<Address>) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        let key = DataKey::SchedulerKey(program_id);
+        match scheduler_key {
+            Some(scheduler_key) => env.storage().persistent().set(&key, &scheduler_key),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    /// Get the program's scheduler key, if one is set.
+    pub fn get_scheduler_key(env: Env, program_id: String) -> Option<Address> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::SchedulerKey(program_id))
+    }
+
+    /// Set the minimum delay between creating a release schedule and releasing it.
+    ///
+    /// While non-zero, `create_program_release_schedule` (and
+    /// `create_prog_schedule_as`) reject a `release_timestamp` earlier than
+    /// `now + seconds`, and `release_program_schedule_manual` refuses to release
+    /// a schedule until `seconds` have passed since it was created, so a manual
+    /// early release cannot follow scheduling immediately. The delay in force at
+    /// release time applies. Zero (the default) disables the check. Callable by
+    /// the program's authorized payout key.
+    pub fn set_schedule_min_delay(env: Env, program_id: String, seconds: u64) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ScheduleMinDelay(program_id), &seconds);
+    }
+
+    /// Minimum seconds between scheduling and release (zero when unset).
+    pub fn get_schedule_min_delay(env: Env, program_id: String) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScheduleMinDelay(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Revise a pending release schedule before it fires.
+    ///
+    /// Only the provided fields are changed, and they are validated as in
+    /// `create_program_release_schedule`. The revised total of all pending
+    /// schedules must still fit within `remaining_balance`.
+    ///
+    /// # Arguments
+    /// * `program_id` - Program the schedule belongs to
+    /// * `schedule_id` - Schedule to modify
+    /// * `new_amount` - Replacement amount, if any
+    /// * `new_recipient` - Replacement recipient, if any
+    /// * `new_timestamp` - Replacement release timestamp, if any
+    ///
+    /// # Returns
+    /// The updated ProgramReleaseSchedule
+    ///
+    /// # Panics
+    /// * If the schedule does not exist or has already been released
+    /// * If the program has been closed
+    /// * If `new_amount` is not positive
+    /// * If `new_timestamp` falls within the minimum schedule delay
+    /// * If the revised scheduled total exceeds `remaining_balance`
+    pub fn modify_program_release_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+        new_amount: Option<i128>,
+        new_recipient: Option<Address>,
+        new_timestamp: Option<u64>,
+    ) -> ProgramReleaseSchedule {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        let mut schedules = Self::get_release_schedules(env.clone());
+        let index = schedules
+            .iter()
+            .position(|s| s.schedule_id == schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not found")) as u32;
+        let mut schedule = schedules.get(index).unwrap();
+        if schedule.released {
+            panic!("Already released");
+        }
+
+        Self::validate_schedule_terms(&env, &program_data, new_amount, new_timestamp);
+        let revised_total = Self::get_total_scheduled_amount(env.clone()) - schedule.amount
+            + new_amount.unwrap_or(schedule.amount);
+        if revised_total > program_data.remaining_balance {
+            panic!("Insufficient balance");
+        }
+
+        if let Some(amount) = new_amount {
+            schedule.amount = amount;
+        }
+        if let Some(recipient) = new_recipient.clone() {
+            schedule.recipient = recipient;
+        }
+        if let Some(timestamp) = new_timestamp {
+            schedule.release_timestamp = timestamp;
+        }
+
+        schedules.set(index, schedule.clone());
+        env.storage().instance().set(&SCHEDULES, &schedules);
+
+        env.events().publish(
+            (SCHEDULE_MODIFIED,),
+            ScheduleModifiedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                schedule_id,
+                new_amount,
+                new_recipient,
+                new_release_timestamp: new_timestamp,
+            },
+        );
+
+        schedule
+    }
+
     /// Trigger all due schedules where `now >= release_timestamp`.
     pub fn trigger_program_releases(env: Env) -> u32 {
         // Reentrancy guard: Check and set
@@ -2503,6 +3941,41 @@ impl ProgramEscrowContract {
         results
     }
 
+    /// `(schedule_id, release_timestamp)` of the earliest unreleased schedule.
+    ///
+    /// Lets a keeper sleep until the next release instead of polling. Ties go
+    /// to the schedule created first. `None` when nothing is pending.
+    pub fn get_next_due_schedule(env: Env, program_id: String) -> Option<(u64, u64)> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let schedules: Vec<ProgramReleaseSchedule> = env
+            .storage()
+            .instance()
+            .get(&SCHEDULES)
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut next: Option<(u64, u64)> = None;
+        for schedule in schedules.iter() {
+            if schedule.released {
+                continue;
+            }
+            let earlier = match next {
+                Some((_, due)) => schedule.release_timestamp < due,
+                None => true,
+            };
+            if earlier {
+                next = Some((schedule.schedule_id, schedule.release_timestamp));
+            }
+        }
+        next
+    }
+
     /// Get total amount in pending schedules
     pub fn get_total_scheduled_amount(env: Env) -> i128 {
         let schedules: Vec<ProgramReleaseSchedule> = env
@@ -2521,6 +3994,55 @@ impl ProgramEscrowContract {
         total
     }
 
+    /// Get the balance left once every pending schedule has been paid out.
+    ///
+    /// This is `remaining_balance` minus the sum of all unreleased schedule
+    /// amounts, clamped at zero: the amount that can go to ad-hoc
+    /// `single_payout`/`batch_payout` calls without starving scheduled
+    /// releases.
+    pub fn get_projected_balance(env: Env, program_id: String) -> i128 {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        let scheduled = Self::get_total_scheduled_amount(env);
+
+        program_data
+            .remaining_balance
+            .saturating_sub(scheduled)
+            .max(0)
+    }
+
+    /// Get the total a recipient has received from the program.
+    ///
+    /// Every outflow, whether an ad-hoc `single_payout`/`batch_payout` or a
+    /// schedule release (automatic or manual), is recorded in
+    /// `payout_history`, so this sums that history for `recipient`. Released
+    /// schedules are also listed in the release history, which is not added
+    /// again to avoid double counting.
+    pub fn get_total_distributed_to(env: Env, program_id: String, recipient: Address) -> i128 {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let mut total = 0i128;
+        for record in program_data.payout_history.iter() {
+            if record.recipient == recipient {
+                total += record.amount;
+            }
+        }
+        total
+    }
+
     pub fn get_program_count(env: Env) -> u32 {
         if env.storage().instance().has(&PROGRAM_DATA) {
             1
@@ -2537,6 +4059,111 @@ impl ProgramEscrowContract {
         results
     }
 
+    /// Page through the program registry as `(program_id, total_funds, remaining_balance)`.
+    ///
+    /// Covers programs registered with `batch_initialize_programs`. `offset`
+    /// and `limit` index into the registry; entries whose `ProgramData` is
+    /// missing are skipped, so a page may hold fewer than `limit` items.
+    pub fn list_programs_with_balances(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(String, i128, i128)> {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+        let mut results = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(registry.len());
+        for i in offset..end {
+            let program_id = registry.get(i).unwrap();
+            if let Some(program_data) = env
+                .storage()
+                .instance()
+                .get::<DataKey, ProgramData>(&DataKey::Program(program_id.clone()))
+            {
+                results.push_back((
+                    program_id,
+                    program_data.total_funds,
+                    program_data.remaining_balance,
+                ));
+            }
+        }
+        results
+    }
+
+    /// Reconcile recorded program balances against live token balances.
+    ///
+    /// Programs sharing a token are checked together: their recorded
+    /// `remaining_balance` values are summed and compared with the contract's
+    /// live balance of that token. For every token whose recorded sum exceeds
+    /// the live balance, each of its programs is listed as
+    /// `(program_id, remaining_balance, share)`, where `share` is the program's
+    /// pro-rata portion of the live balance. Solvent tokens produce no rows,
+    /// so an empty result means every program is covered.
+    ///
+    /// Covers the registry and the single-program slot.
+    pub fn audit_all_programs(env: Env) -> Vec<(String, i128, i128)> {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+        let mut programs: Vec<ProgramData> = Vec::new(&env);
+        for program_id in registry.iter() {
+            if let Some(program_data) = env
+                .storage()
+                .instance()
+                .get::<DataKey, ProgramData>(&DataKey::Program(program_id))
+            {
+                programs.push_back(program_data);
+            }
+        }
+        if let Some(program_data) = env
+            .storage()
+            .instance()
+            .get::<Symbol, ProgramData>(&PROGRAM_DATA)
+        {
+            if !vec_contains(&registry, &program_data.program_id) {
+                programs.push_back(program_data);
+            }
+        }
+
+        let contract_address = env.current_contract_address();
+        let mut checked_tokens: Vec<Address> = Vec::new(&env);
+        let mut results = Vec::new(&env);
+        for program in programs.iter() {
+            let token_address = program.token_address.clone();
+            if checked_tokens.contains(&token_address) {
+                continue;
+            }
+            checked_tokens.push_back(token_address.clone());
+
+            let mut recorded: i128 = 0;
+            for other in programs.iter() {
+                if other.token_address == token_address {
+                    recorded = recorded.saturating_add(other.remaining_balance);
+                }
+            }
+            let live = token::Client::new(&env, &token_address).balance(&contract_address);
+            if recorded <= live {
+                continue;
+            }
+
+            for other in programs.iter() {
+                if other.token_address == token_address {
+                    let share = live
+                        .checked_mul(other.remaining_balance)
+                        .map(|scaled| scaled / recorded)
+                        .unwrap_or(0);
+                    results.push_back((other.program_id, other.remaining_balance, share));
+                }
+            }
+        }
+        results
+    }
+
     pub fn get_program_release_schedule(env: Env, schedule_id: u64) -> ProgramReleaseSchedule {
         let schedules = Self::get_release_schedules(env);
         for s in schedules.iter() {
@@ -2577,6 +4204,18 @@ impl ProgramEscrowContract {
                     panic!("Already released");
                 }
 
+                let min_delay =
+                    Self::get_schedule_min_delay(env.clone(), program_data.program_id.clone());
+                let created_at: Option<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::ScheduleCreatedAt(schedule_id));
+                if let Some(created_at) = created_at {
+                    if now < created_at.saturating_add(min_delay) {
+                        panic!("Schedule within minimum delay");
+                    }
+                }
+
                 // Transfer funds
                 let token_client = token::Client::new(&env, &program_data.token_address);
                 token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
@@ -2601,6 +4240,11 @@ impl ProgramEscrowContract {
         if let Some(s) = released_schedule {
             let mut updated_program_data = program_data.clone();
             updated_program_data.remaining_balance -= s.amount;
+            updated_program_data.payout_history.push_back(PayoutRecord {
+                recipient: s.recipient.clone(),
+                amount: s.amount,
+                timestamp: now,
+            });
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
@@ -2662,6 +4306,11 @@ impl ProgramEscrowContract {
         if let Some(s) = released_schedule {
             let mut updated_program_data = program_data.clone();
             updated_program_data.remaining_balance -= s.amount;
+            updated_program_data.payout_history.push_back(PayoutRecord {
+                recipient: s.recipient.clone(),
+                amount: s.amount,
+                timestamp: now,
+            });
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
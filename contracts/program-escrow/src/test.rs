@@ -1,7 +1,9 @@
 use super::*;
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
-    token, vec, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+    token, vec,
+    xdr::{FromXdr, ToXdr},
+    Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
 };
 
 fn setup_program(
@@ -169,6 +171,8 @@ fn test_complete_lifecycle_integration() {
 fn test_property_fuzz_balance_invariants() {
     let env = Env::default();
     let (client, _admin, token_client, _token_admin) = setup_program(&env, 1_000_000);
+    // The growing payout history makes the default test budget a moving target.
+    env.budget().reset_unlimited();
 
     let mut seed = 123_u64;
     let mut expected_remaining = 1_000_000_i128;
@@ -1066,6 +1070,134 @@ fn test_batch_register_different_auth_keys_and_tokens() {
     assert!(client.program_exists_by_id(&String::from_str(&env, "prog-b")));
 }
 
+#[test]
+fn test_list_programs_with_balances_paginates_registry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let mut items = Vec::new(&env);
+    for i in 0..5u32 {
+        items.push_back(ProgramInitItem {
+            program_id: make_program_id(&env, i),
+            authorized_payout_key: Address::generate(&env),
+            token_address: Address::generate(&env),
+            reference_hash: None,
+        });
+    }
+    client.batch_initialize_programs(&items);
+
+    // Give one program a balance and drop another's data to simulate a partial write.
+    env.as_contract(&contract_id, || {
+        let key = DataKey::Program(make_program_id(&env, 1));
+        let mut data: ProgramData = env.storage().instance().get(&key).unwrap();
+        data.total_funds = 5_000;
+        data.remaining_balance = 3_500;
+        env.storage().instance().set(&key, &data);
+        env.storage()
+            .instance()
+            .remove(&DataKey::Program(make_program_id(&env, 2)));
+    });
+
+    let page = client.list_programs_with_balances(&0, &3);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            (make_program_id(&env, 0), 0, 0),
+            (make_program_id(&env, 1), 5_000, 3_500),
+        ]
+    );
+
+    let page = client.list_programs_with_balances(&3, &10);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            (make_program_id(&env, 3), 0, 0),
+            (make_program_id(&env, 4), 0, 0),
+        ]
+    );
+    assert!(client.list_programs_with_balances(&5, &10).is_empty());
+}
+
+#[test]
+fn test_list_programs_with_balances_empty_registry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    assert!(client.list_programs_with_balances(&0, &10).is_empty());
+}
+
+#[test]
+fn test_audit_all_programs_flags_underfunded_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let shared_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let solo_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let mut items = Vec::new(&env);
+    for (i, token_address) in [(0u32, &shared_token), (1, &shared_token), (2, &solo_token)] {
+        items.push_back(ProgramInitItem {
+            program_id: make_program_id(&env, i),
+            authorized_payout_key: Address::generate(&env),
+            token_address: token_address.clone(),
+            reference_hash: None,
+        });
+    }
+    client.batch_initialize_programs(&items);
+
+    // Record 4_000 + 2_000 against the shared token and 1_000 against the other.
+    env.as_contract(&contract_id, || {
+        for (i, remaining) in [(0u32, 4_000i128), (1, 2_000), (2, 1_000)] {
+            let key = DataKey::Program(make_program_id(&env, i));
+            let mut data: ProgramData = env.storage().instance().get(&key).unwrap();
+            data.total_funds = remaining;
+            data.remaining_balance = remaining;
+            env.storage().instance().set(&key, &data);
+        }
+    });
+    token::StellarAssetClient::new(&env, &solo_token).mint(&contract_id, &1_000);
+    token::StellarAssetClient::new(&env, &shared_token).mint(&contract_id, &6_000);
+    assert!(client.audit_all_programs().is_empty());
+
+    // Lose 1_500 of the shared token: both of its programs are reported.
+    let sink = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        token::Client::new(&env, &shared_token).transfer(&contract_id, &sink, &1_500);
+    });
+    assert_eq!(
+        client.audit_all_programs(),
+        vec![
+            &env,
+            (make_program_id(&env, 0), 4_000, 3_000),
+            (make_program_id(&env, 1), 2_000, 1_500),
+        ]
+    );
+}
+
+#[test]
+fn test_audit_all_programs_covers_single_program() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 10_000);
+    assert!(client.audit_all_programs().is_empty());
+
+    let sink = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        token_client.transfer(&client.address, &sink, &1);
+    });
+    assert_eq!(
+        client.audit_all_programs(),
+        vec![&env, (String::from_str(&env, "hack-2026"), 10_000, 9_999)]
+    );
+}
+
 #[test]
 fn test_batch_register_events_emitted_per_program() {
     let env = Env::default();
@@ -1793,6 +1925,131 @@ fn test_batch_payout_maximum_batch_size() {
     assert_eq!(total_paid, total_amount);
 }
 
+#[test]
+fn test_batch_payout_oversized_batch_reverts_before_transfers() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(
+        client.get_max_payout_recipients(&program_id),
+        DEFAULT_MAX_PAYOUT_RECIPIENTS
+    );
+
+    client.set_max_payout_recipients(&program_id, &3);
+    assert_eq!(client.get_max_payout_recipients(&program_id), 3);
+
+    let mut recipients = vec![&env];
+    let mut amounts = vec![&env];
+    for _ in 0..4 {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(1_000i128);
+    }
+    assert!(client.try_batch_payout(&recipients, &amounts).is_err());
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    assert_eq!(token_client.balance(&recipients.get(0).unwrap()), 0);
+
+    // At the limit the batch goes through.
+    recipients.pop_back();
+    amounts.pop_back();
+    let data = client.batch_payout(&recipients, &amounts);
+    assert_eq!(data.remaining_balance, 7_000);
+}
+
+#[test]
+#[should_panic(expected = "Batch exceeds max payout recipients")]
+fn test_batch_payout_default_recipient_cap() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 1_000_000);
+
+    let mut recipients = vec![&env];
+    let mut amounts = vec![&env];
+    for _ in 0..=DEFAULT_MAX_PAYOUT_RECIPIENTS {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(1i128);
+    }
+    client.batch_payout(&recipients, &amounts);
+}
+
+#[test]
+fn test_validate_batch_payout_reports_issues_without_paying() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winners = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    let ok = client.validate_batch_payout(
+        &program_id,
+        &winners,
+        &vec![&env, 4_000, 3_000, 2_000, 1_000],
+    );
+    assert!(ok.is_empty());
+
+    let issues =
+        client.validate_batch_payout(&program_id, &winners, &vec![&env, 6_000, 0, 5_000, -1]);
+    assert_eq!(
+        issues,
+        vec![
+            &env,
+            (1, BATCH_ISSUE_INVALID_AMOUNT),
+            (2, BATCH_ISSUE_INSUFFICIENT_BALANCE),
+            (3, BATCH_ISSUE_INVALID_AMOUNT),
+        ]
+    );
+
+    let issues = client.validate_batch_payout(&program_id, &winners, &vec![&env, i128::MAX, 1, 1]);
+    assert_eq!(
+        issues,
+        vec![
+            &env,
+            (3, BATCH_ISSUE_LENGTH_MISMATCH),
+            (0, BATCH_ISSUE_INSUFFICIENT_BALANCE),
+            (1, BATCH_ISSUE_OVERFLOW),
+        ]
+    );
+
+    // Nothing was paid out.
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    assert_eq!(token_client.balance(&winners.get(0).unwrap()), 0);
+}
+
+#[test]
+fn test_validate_batch_payout_batch_shape() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    assert_eq!(
+        client.validate_batch_payout(&program_id, &vec![&env], &vec![&env]),
+        vec![&env, (0, BATCH_ISSUE_EMPTY)]
+    );
+
+    client.set_max_payout_recipients(&program_id, &2);
+    let winners = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    assert_eq!(
+        client.validate_batch_payout(&program_id, &winners, &vec![&env, 1, 1, 1]),
+        vec![&env, (2, BATCH_ISSUE_TOO_MANY_RECIPIENTS)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max payout recipients must be greater than zero")]
+fn test_set_max_payout_recipients_rejects_zero() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 0);
+    client.set_max_payout_recipients(&String::from_str(&env, "hack-2026"), &0);
+}
+
 #[test]
 #[should_panic(expected = "Cannot process empty batch")]
 fn test_batch_payout_empty_batch_panic() {
@@ -2233,3 +2490,1014 @@ fn test_release_schedules_persist_after_simulated_upgrade() {
     assert_eq!(stats_final.scheduled_count, 0);
     assert_eq!(stats_final.remaining_balance, 100_000);
 }
+
+#[test]
+fn test_event_schema_registry_seeded_at_init() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    let schema = client.get_event_schema();
+    let version_of = |topic: &str| {
+        let topic = Symbol::new(&env, topic);
+        schema.iter().find(|(t, _)| *t == topic).map(|(_, v)| v)
+    };
+    assert_eq!(version_of("PrgInit"), Some(2));
+    assert_eq!(version_of("FndsLock"), Some(2));
+    assert_eq!(version_of("BatchPay"), Some(2));
+    assert_eq!(version_of("Payout"), Some(2));
+    assert_eq!(version_of("Unknown"), None);
+}
+
+#[test]
+fn test_event_schema_version_updatable_by_admin() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let before = client.get_event_schema().len();
+
+    client.set_event_schema_version(&Symbol::new(&env, "Payout"), &3);
+    client.set_event_schema_version(&Symbol::new(&env, "NewEvt"), &1);
+
+    let schema = client.get_event_schema();
+    assert_eq!(schema.len(), before + 1);
+    assert!(schema
+        .iter()
+        .any(|(t, v)| t == Symbol::new(&env, "Payout") && v == 3));
+    assert!(schema
+        .iter()
+        .any(|(t, v)| t == Symbol::new(&env, "NewEvt") && v == 1));
+}
+
+#[test]
+fn test_projected_balance_excludes_pending_schedules() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    assert_eq!(client.get_projected_balance(&program_id), 100_000);
+
+    client.create_program_release_schedule(&r1, &30_000, &(now + 100));
+    client.create_program_release_schedule(&r2, &20_000, &(now + 200));
+    assert_eq!(client.get_projected_balance(&program_id), 50_000);
+
+    // Releasing a schedule moves it out of both the balance and the commitments.
+    env.ledger().set_timestamp(now + 150);
+    client.trigger_program_releases();
+    assert_eq!(client.get_remaining_balance(), 70_000);
+    assert_eq!(client.get_projected_balance(&program_id), 50_000);
+
+    // Ad-hoc payouts eat into the free balance.
+    client.single_payout(&Address::generate(&env), &10_000);
+    assert_eq!(client.get_projected_balance(&program_id), 40_000);
+}
+
+#[test]
+fn test_projected_balance_clamps_overcommitted_schedules_to_zero() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    client.create_program_release_schedule(&Address::generate(&env), &25_000, &(now + 100));
+
+    assert_eq!(client.get_projected_balance(&program_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Program not found")]
+fn test_projected_balance_rejects_unknown_program() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 10_000);
+
+    client.get_projected_balance(&String::from_str(&env, "other"));
+}
+
+#[test]
+fn test_modify_release_schedule_updates_only_given_fields() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let original = Address::generate(&env);
+    let corrected = Address::generate(&env);
+
+    let schedule = client.create_program_release_schedule(&original, &30_000, &(now + 100));
+
+    let updated = client.modify_program_release_schedule(
+        &program_id,
+        &schedule.schedule_id,
+        &Some(40_000),
+        &Some(corrected.clone()),
+        &None,
+    );
+    assert_eq!(updated.amount, 40_000);
+    assert_eq!(updated.recipient, corrected);
+    assert_eq!(updated.release_timestamp, now + 100);
+    assert_eq!(
+        client.get_program_release_schedule(&schedule.schedule_id),
+        updated
+    );
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "SchMod"),).into_val(&env));
+    let event: ScheduleModifiedEvent = data.into_val(&env);
+    assert_eq!(event.new_amount, Some(40_000));
+    assert_eq!(event.new_recipient, Some(corrected.clone()));
+    assert_eq!(event.new_release_timestamp, None);
+
+    env.ledger().set_timestamp(now + 100);
+    client.trigger_program_releases();
+    assert_eq!(token_client.balance(&corrected), 40_000);
+    assert_eq!(token_client.balance(&original), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_modify_release_schedule_rejects_overcommitting_balance() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    client.create_program_release_schedule(&Address::generate(&env), &60_000, &(now + 100));
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &30_000, &(now + 200));
+
+    client.modify_program_release_schedule(
+        &program_id,
+        &schedule.schedule_id,
+        &Some(50_000),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Already released")]
+fn test_modify_release_schedule_rejects_released_schedule() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &30_000, &(now + 100));
+    env.ledger().set_timestamp(now + 100);
+    client.trigger_program_releases();
+
+    client.modify_program_release_schedule(
+        &program_id,
+        &schedule.schedule_id,
+        &None,
+        &None,
+        &Some(now + 500),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Program closed")]
+fn test_modify_release_schedule_rejects_closed_program() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &30_000, &(now + 100));
+    client.close_program(&program_id);
+
+    client.modify_program_release_schedule(
+        &program_id,
+        &schedule.schedule_id,
+        &Some(20_000),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Release timestamp within minimum schedule delay")]
+fn test_modify_release_schedule_enforces_min_delay() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    client.set_schedule_min_delay(&program_id, &3_600);
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &30_000, &(now + 7_200));
+
+    client.modify_program_release_schedule(
+        &program_id,
+        &schedule.schedule_id,
+        &None,
+        &None,
+        &Some(now + 60),
+    );
+}
+
+#[test]
+fn test_sync_balance_up_credits_direct_deposits() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    // A direct transfer without a follow-up lock call is invisible to the program.
+    token_admin.mint(&client.address, &7_500);
+    assert_eq!(client.get_remaining_balance(), 50_000);
+
+    assert_eq!(client.sync_balance_up(&program_id), 7_500);
+
+    let info = client.get_program_info();
+    assert_eq!(info.total_funds, 57_500);
+    assert_eq!(info.remaining_balance, 57_500);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "BalSync"),).into_val(&env));
+    let event: BalanceSyncedEvent = data.into_val(&env);
+    assert_eq!(event.credited, 7_500);
+    assert_eq!(event.remaining_balance, 57_500);
+
+    // The credited funds are now spendable.
+    client.single_payout(&Address::generate(&env), &57_500);
+    assert_eq!(client.get_remaining_balance(), 0);
+}
+
+#[test]
+fn test_sync_balance_up_is_noop_without_surplus() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.single_payout(&Address::generate(&env), &20_000);
+
+    assert_eq!(client.sync_balance_up(&program_id), 0);
+    let info = client.get_program_info();
+    assert_eq!(info.total_funds, 50_000);
+    assert_eq!(info.remaining_balance, 30_000);
+}
+
+#[test]
+fn test_emergency_withdraw_only_drains_own_program_share() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 60_000);
+
+    // Program B shares the token and holds its own 40_000 in the same contract.
+    let program_b = String::from_str(&env, "other-2026");
+    let items = vec![
+        &env,
+        ProgramInitItem {
+            program_id: program_b.clone(),
+            authorized_payout_key: admin.clone(),
+            token_address: token_client.address.clone(),
+            reference_hash: None,
+        },
+    ];
+    client.batch_initialize_programs(&items);
+    token_admin.mint(&client.address, &40_000);
+    env.as_contract(&client.address, || {
+        let key = DataKey::Program(program_b.clone());
+        let mut data: ProgramData = env.storage().instance().get(&key).unwrap();
+        data.total_funds = 40_000;
+        data.remaining_balance = 40_000;
+        env.storage().instance().set(&key, &data);
+    });
+
+    client.set_paused(&Some(true), &None, &None, &None::<String>);
+    let target = Address::generate(&env);
+    client.emergency_withdraw(&target);
+
+    assert_eq!(token_client.balance(&target), 60_000);
+    assert_eq!(token_client.balance(&client.address), 40_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("em_wtd"),).into_val(&env));
+    let event: EmergencyWithdrawEvent = data.into_val(&env);
+    assert_eq!(event.amount, 60_000);
+}
+
+#[test]
+fn test_set_whitelist_records_entry_and_emits_audit_event() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    client.set_whitelist(&a, &true);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "wl_chg"), a.clone()).into_val(&env)
+    );
+    let event: WhitelistChanged = data.into_val(&env);
+    assert_eq!(event.address, a);
+    assert!(event.whitelisted);
+    assert_eq!(event.changed_by, admin);
+
+    client.set_whitelist(&b, &true);
+    assert!(client.get_whitelist_entry(&a));
+    assert_eq!(
+        client.list_whitelisted(&0, &10),
+        vec![&env, a.clone(), b.clone()]
+    );
+
+    client.set_whitelist(&a, &false);
+    assert!(!client.get_whitelist_entry(&a));
+    assert_eq!(client.list_whitelisted(&0, &10), vec![&env, b]);
+}
+
+#[test]
+fn test_program_fund_cap_bounds_lock_program_funds() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    assert_eq!(client.get_program_fund_cap(&program_id), None);
+    client.set_program_fund_cap(&program_id, &100_000);
+    assert_eq!(client.get_program_fund_cap(&program_id), Some(100_000));
+
+    token_admin.mint(&client.address, &100_001);
+    client.lock_program_funds(&60_000);
+    client.lock_program_funds(&40_000);
+    assert_eq!(client.get_program_info().total_funds, 100_000);
+
+    let over = client.try_lock_program_funds(&1);
+    assert!(over.is_err());
+    assert_eq!(client.get_program_info().total_funds, 100_000);
+}
+
+#[test]
+#[should_panic(expected = "Program fund cap exceeded")]
+fn test_program_fund_cap_rejects_single_oversized_lock() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.set_program_fund_cap(&program_id, &10_000);
+    token_admin.mint(&client.address, &10_001);
+    client.lock_program_funds(&10_001);
+}
+
+#[test]
+fn test_payout_velocity_limit_caps_each_window() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let start = env.ledger().timestamp();
+
+    assert_eq!(client.get_payout_velocity_remaining(&program_id), None);
+    client.set_payout_velocity_limit(&program_id, &10_000, &3_600);
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(10_000)
+    );
+
+    client.single_payout(&Address::generate(&env), &4_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 3_000, 2_000],
+    );
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(1_000)
+    );
+
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &1_001)
+        .is_err());
+    assert!(client
+        .try_batch_payout(
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &vec![&env, 600, 600],
+        )
+        .is_err());
+    assert_eq!(client.get_remaining_balance(), 91_000);
+
+    // The allowance resets once the window rolls over.
+    env.ledger().set_timestamp(start + 3_600);
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(10_000)
+    );
+    client.single_payout(&Address::generate(&env), &10_000);
+    assert_eq!(client.get_payout_velocity_remaining(&program_id), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "Payout velocity limit exceeded")]
+fn test_payout_velocity_limit_rejects_oversized_payout() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.set_payout_velocity_limit(&program_id, &5_000, &60);
+    client.single_payout(&Address::generate(&env), &5_001);
+}
+
+#[test]
+fn test_payout_velocity_limit_update_keeps_window_spend() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let start = env.ledger().timestamp();
+
+    client.set_payout_velocity_limit(&program_id, &10_000, &3_600);
+    client.single_payout(&Address::generate(&env), &8_000);
+
+    // Re-setting the limit mid-window does not restore the allowance.
+    env.ledger().set_timestamp(start + 1_000);
+    client.set_payout_velocity_limit(&program_id, &10_000, &3_600);
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(2_000)
+    );
+    client.set_payout_velocity_limit(&program_id, &12_000, &3_600);
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(4_000)
+    );
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &4_001)
+        .is_err());
+
+    // The window still rolls over from its original start.
+    env.ledger().set_timestamp(start + 3_600);
+    assert_eq!(
+        client.get_payout_velocity_remaining(&program_id),
+        Some(12_000)
+    );
+}
+
+#[test]
+fn test_funding_target_gates_payouts_until_reached() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    assert_eq!(client.get_funding_progress(&program_id), (0, 0));
+    client.set_funding_target(&program_id, &50_000);
+    token_admin.mint(&client.address, &50_000);
+    client.lock_program_funds(&30_000);
+    assert_eq!(client.get_funding_progress(&program_id), (30_000, 50_000));
+
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &1_000)
+        .is_err());
+    assert!(client
+        .try_batch_payout(&vec![&env, Address::generate(&env)], &vec![&env, 1_000])
+        .is_err());
+    assert_eq!(client.get_remaining_balance(), 30_000);
+
+    client.lock_program_funds(&20_000);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("FundTgt"),).into_val(&env));
+    let reached: FundingTargetReachedEvent = data.into_val(&env);
+    assert_eq!(reached.target, 50_000);
+    assert_eq!(reached.total_funds, 50_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.batch_payout(&vec![&env, Address::generate(&env)], &vec![&env, 1_000]);
+    assert_eq!(client.get_remaining_balance(), 48_000);
+}
+
+#[test]
+fn test_funding_target_reached_event_emitted_once() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.set_funding_target(&program_id, &10_000);
+    token_admin.mint(&client.address, &30_000);
+    client.lock_program_funds(&15_000);
+    client.lock_program_funds(&15_000);
+
+    let count = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| topics == &(symbol_short!("FundTgt"),).into_val(&env))
+        .count();
+    assert_eq!(count, 1);
+}
+
+#[test]
+#[should_panic(expected = "Funding target not met")]
+fn test_funding_target_rejects_single_payout_below_target() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.set_funding_target(&program_id, &10_001);
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+fn test_scheduler_key_can_create_schedules() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let scheduler = Address::generate(&env);
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    assert_eq!(client.get_scheduler_key(&program_id), None);
+    client.set_scheduler_key(&program_id, &Some(scheduler.clone()));
+    assert_eq!(
+        client.get_scheduler_key(&program_id),
+        Some(scheduler.clone())
+    );
+
+    let by_scheduler = client.create_prog_schedule_as(&scheduler, &winner, &1_000, &(now + 10));
+    let by_payout_key = client.create_prog_schedule_as(&admin, &winner, &2_000, &(now + 20));
+    let legacy = client.create_program_release_schedule(&winner, &3_000, &(now + 30));
+    assert_eq!(
+        (
+            by_scheduler.schedule_id,
+            by_payout_key.schedule_id,
+            legacy.schedule_id
+        ),
+        (1, 2, 3)
+    );
+
+    // Clearing the key revokes scheduling rights.
+    client.set_scheduler_key(&program_id, &None);
+    assert!(client
+        .try_create_prog_schedule_as(&scheduler, &winner, &1_000, &(now + 10))
+        .is_err());
+}
+
+/// `ProgramData` exactly as stored before scheduler keys existed.
+#[contracttype]
+#[derive(Clone)]
+struct BaselineProgramData {
+    program_id: String,
+    total_funds: i128,
+    remaining_balance: i128,
+    authorized_payout_key: Address,
+    payout_history: Vec<PayoutRecord>,
+    token_address: Address,
+    initial_liquidity: i128,
+    risk_flags: u32,
+    reference_hash: Option<soroban_sdk::Bytes>,
+}
+
+#[test]
+fn test_baseline_program_data_still_decodes() {
+    let env = Env::default();
+    let (client, admin, token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let info = client.get_program_info();
+    let baseline = BaselineProgramData {
+        program_id: info.program_id.clone(),
+        total_funds: info.total_funds,
+        remaining_balance: info.remaining_balance,
+        authorized_payout_key: info.authorized_payout_key.clone(),
+        payout_history: info.payout_history.clone(),
+        token_address: info.token_address.clone(),
+        initial_liquidity: info.initial_liquidity,
+        risk_flags: info.risk_flags,
+        reference_hash: info.reference_hash.clone(),
+    };
+
+    let blob = baseline.clone().to_xdr(&env);
+    assert_eq!(ProgramData::from_xdr(&env, &blob).unwrap(), info);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&PROGRAM_DATA, &baseline);
+    });
+    assert_eq!(client.get_program_info(), info);
+    assert_eq!(client.get_scheduler_key(&program_id), None);
+
+    let scheduler = Address::generate(&env);
+    let winner = Address::generate(&env);
+    client.set_scheduler_key(&program_id, &Some(scheduler.clone()));
+    let now = env.ledger().timestamp();
+    client.create_prog_schedule_as(&scheduler, &winner, &1_000, &(now + 10));
+    client.single_payout(&winner, &2_000);
+    assert_eq!(token.balance(&winner), 2_000);
+    assert_eq!(client.get_program_info().authorized_payout_key, admin);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: not a scheduling key")]
+fn test_unrelated_key_cannot_create_schedules() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let now = env.ledger().timestamp();
+
+    client.create_prog_schedule_as(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &1_000,
+        &(now + 10),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_scheduler_key_cannot_pay_out() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let scheduler = Address::generate(&env);
+    let winner = Address::generate(&env);
+    client.set_scheduler_key(&program_id, &Some(scheduler.clone()));
+
+    // Only the scheduler signs; single_payout still demands the payout key.
+    env.mock_auths(&[MockAuth {
+        address: &scheduler,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "single_payout",
+            args: (winner.clone(), 1_000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.single_payout(&winner, &1_000);
+}
+
+#[test]
+fn test_schedule_min_delay_boundaries() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    assert_eq!(client.get_schedule_min_delay(&program_id), 0);
+
+    client.set_schedule_min_delay(&program_id, &3_600);
+    assert_eq!(client.get_schedule_min_delay(&program_id), 3_600);
+
+    assert!(client
+        .try_create_program_release_schedule(&winner, &1_000, &(now + 3_599))
+        .is_err());
+    let schedule = client.create_program_release_schedule(&winner, &1_000, &(now + 3_600));
+
+    // A manual early release must also wait out the delay.
+    env.ledger().set_timestamp(now + 3_599);
+    assert!(client
+        .try_release_program_schedule_manual(&schedule.schedule_id)
+        .is_err());
+    env.ledger().set_timestamp(now + 3_600);
+    client.release_program_schedule_manual(&schedule.schedule_id);
+    assert_eq!(token_client.balance(&winner), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Schedule within minimum delay")]
+fn test_schedule_min_delay_blocks_immediate_manual_release() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    client.set_schedule_min_delay(&program_id, &600);
+
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &1_000, &(now + 86_400));
+    client.release_program_schedule_manual(&schedule.schedule_id);
+}
+
+#[test]
+fn test_get_next_due_schedule_tracks_earliest_pending() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    assert_eq!(client.get_next_due_schedule(&program_id), None);
+
+    let late = client.create_program_release_schedule(&winner, &1_000, &(now + 500));
+    let early = client.create_program_release_schedule(&winner, &1_000, &(now + 100));
+    let tied = client.create_program_release_schedule(&winner, &1_000, &(now + 100));
+    assert_eq!(
+        client.get_next_due_schedule(&program_id),
+        Some((early.schedule_id, now + 100))
+    );
+
+    client.release_program_schedule_manual(&early.schedule_id);
+    assert_eq!(
+        client.get_next_due_schedule(&program_id),
+        Some((tied.schedule_id, now + 100))
+    );
+
+    env.ledger().set_timestamp(now + 100);
+    client.trigger_program_releases();
+    assert_eq!(
+        client.get_next_due_schedule(&program_id),
+        Some((late.schedule_id, now + 500))
+    );
+
+    env.ledger().set_timestamp(now + 500);
+    client.trigger_program_releases();
+    assert_eq!(client.get_next_due_schedule(&program_id), None);
+}
+
+#[test]
+fn test_schedule_min_delay_default_allows_immediate_manual_release() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    let schedule = client.create_program_release_schedule(&winner, &1_000, &(now + 86_400));
+    client.release_program_schedule_manual(&schedule.schedule_id);
+    assert_eq!(token_client.balance(&winner), 1_000);
+}
+
+#[test]
+fn test_total_distributed_to_spans_payouts_and_schedule_releases() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let winner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.single_payout(&winner, &1_000);
+    client.batch_payout(
+        &vec![&env, winner.clone(), other.clone()],
+        &vec![&env, 2_000, 5_000],
+    );
+
+    let triggered = client.create_program_release_schedule(&winner, &3_000, &(now + 10));
+    let manual = client.create_program_release_schedule(&winner, &4_000, &(now + 1_000));
+    let by_id = client.create_program_release_schedule(&winner, &500, &(now + 20));
+
+    env.ledger().set_timestamp(now + 15);
+    client.trigger_program_releases();
+    client.release_program_schedule_manual(&manual.schedule_id);
+    env.ledger().set_timestamp(now + 20);
+    client.release_prog_schedule_automatic(&by_id.schedule_id);
+    assert!(
+        client
+            .get_program_release_schedule(&triggered.schedule_id)
+            .released
+    );
+
+    let expected = 1_000 + 2_000 + 3_000 + 4_000 + 500;
+    assert_eq!(
+        client.get_total_distributed_to(&program_id, &winner),
+        expected
+    );
+    assert_eq!(token_client.balance(&winner), expected);
+    assert_eq!(client.get_total_distributed_to(&program_id, &other), 5_000);
+    assert_eq!(
+        client.get_total_distributed_to(&program_id, &Address::generate(&env)),
+        0
+    );
+}
+
+#[test]
+fn test_init_program_idempotent_returns_existing_program() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let payout_key = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(&env, &token_id).mint(&payout_key, &50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let first = client.init_program_idempotent(
+        &program_id,
+        &payout_key,
+        &token_id,
+        &payout_key,
+        &Some(20_000),
+        &None,
+    );
+    assert_eq!(first.total_funds, 20_000);
+
+    // A retry returns the stored program and does not pull liquidity again.
+    let retry = client.init_program_idempotent(
+        &program_id,
+        &payout_key,
+        &token_id,
+        &payout_key,
+        &Some(20_000),
+        &None,
+    );
+    assert_eq!(retry, first);
+    assert_eq!(
+        token::Client::new(&env, &token_id).balance(&contract_id),
+        20_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Conflicting program config")]
+fn test_init_program_idempotent_rejects_conflicting_key() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 0);
+
+    client.init_program_idempotent(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &token_client.address,
+        &admin,
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_close_program_blocks_funding_but_not_payouts() {
+    let env = Env::default();
+    let (client, _admin, token_client, token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let winner = Address::generate(&env);
+
+    let schedule = client.create_program_release_schedule(&winner, &10_000, &(now + 100));
+    assert!(!client.is_program_closed(&program_id));
+
+    client.close_program(&program_id);
+    assert!(client.is_program_closed(&program_id));
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "PrgClose"),).into_val(&env));
+    let event: ProgramClosedEvent = data.into_val(&env);
+    assert_eq!(event.program_id, program_id);
+
+    token_admin.mint(&client.address, &1_000);
+    assert!(client.try_lock_program_funds(&1_000).is_err());
+    assert!(client
+        .try_create_program_release_schedule(&winner, &1_000, &(now + 200))
+        .is_err());
+
+    // Outstanding commitments and ad-hoc payouts still go through.
+    client.single_payout(&winner, &5_000);
+    env.ledger().set_timestamp(now + 100);
+    client.trigger_program_releases();
+    assert!(
+        client
+            .get_program_release_schedule(&schedule.schedule_id)
+            .released
+    );
+    assert_eq!(token_client.balance(&winner), 15_000);
+    assert_eq!(client.get_remaining_balance(), 35_000);
+}
+
+#[test]
+#[should_panic(expected = "Program closed")]
+fn test_close_program_rejects_lock_program_funds() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+
+    client.close_program(&String::from_str(&env, "hack-2026"));
+    token_admin.mint(&client.address, &1_000);
+    client.lock_program_funds(&1_000);
+}
+
+#[test]
+fn test_cancel_program_unwinds_outstanding_schedules() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let paid = client.create_program_release_schedule(&early, &5_000, &now);
+    client.create_program_release_schedule(&late, &10_000, &(now + 1_000));
+    client.create_program_release_schedule(&late, &15_000, &(now + 2_000));
+    client.trigger_program_releases();
+    client.single_payout(&early, &2_000);
+    assert_eq!(client.get_remaining_balance(), 43_000);
+
+    client.set_paused(&Some(true), &Some(true), &None, &None::<String>);
+    assert_eq!(client.cancel_program(&program_id, &organizer), 43_000);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "PrgCncl"),).into_val(&env));
+    let event: ProgramCancelledEvent = data.into_val(&env);
+    assert_eq!(event.refunded, 43_000);
+    assert_eq!(event.cancelled_schedules, 2);
+    assert_eq!(event.refund_recipient, organizer);
+
+    assert_eq!(token_client.balance(&organizer), 43_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_remaining_balance(), 0);
+    assert!(client.is_program_closed(&program_id));
+
+    // Only the released schedule survives; history is untouched.
+    let schedules = client.get_release_schedules();
+    assert_eq!(schedules.len(), 1);
+    assert_eq!(schedules.get(0).unwrap().schedule_id, paid.schedule_id);
+    assert!(client.get_pending_schedules().is_empty());
+    let info = client.get_program_info();
+    assert_eq!(info.total_funds, 50_000);
+    assert_eq!(info.payout_history.len(), 2);
+    assert_eq!(token_client.balance(&early), 7_000);
+    assert_eq!(token_client.balance(&late), 0);
+
+    // Nothing left for a later release run to pay out.
+    client.set_paused(&Some(false), &Some(false), &None, &None::<String>);
+    env.ledger().set_timestamp(now + 2_000);
+    assert_eq!(client.trigger_program_releases(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Program not paused")]
+fn test_cancel_program_requires_pause() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 10_000);
+
+    client.set_paused(&Some(true), &None, &None, &None::<String>);
+    client.cancel_program(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+fn test_require_reference_hash_gates_init_program() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.initialize_contract(&admin);
+    assert!(!client.get_require_reference_hash());
+    client.set_require_reference_hash(&true);
+    assert!(client.get_require_reference_hash());
+
+    let res = client.try_init_program(&program_id, &admin, &token, &admin, &None, &None);
+    assert!(res.is_err());
+    assert!(!client.program_exists());
+
+    let hash = soroban_sdk::Bytes::from_slice(&env, &[7u8; 32]);
+    let program = client.init_program(
+        &program_id,
+        &admin,
+        &token,
+        &admin,
+        &None,
+        &Some(hash.clone()),
+    );
+    assert_eq!(program.reference_hash, Some(hash));
+}
+
+#[test]
+fn test_require_reference_hash_off_allows_init_without_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.initialize_contract(&admin);
+    client.set_require_reference_hash(&true);
+    client.set_require_reference_hash(&false);
+
+    let program = client.init_program(
+        &String::from_str(&env, "hack-2026"),
+        &admin,
+        &token,
+        &admin,
+        &None,
+        &None,
+    );
+    assert_eq!(program.reference_hash, None);
+}
+
+#[test]
+fn test_require_reference_hash_rejects_batch_item_without_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.initialize_contract(&admin);
+    client.set_require_reference_hash(&true);
+
+    let mut items = Vec::new(&env);
+    items.push_back(ProgramInitItem {
+        program_id: String::from_str(&env, "prog-1"),
+        authorized_payout_key: admin.clone(),
+        token_address: token.clone(),
+        reference_hash: Some(soroban_sdk::Bytes::from_slice(&env, &[1u8; 32])),
+    });
+    items.push_back(ProgramInitItem {
+        program_id: String::from_str(&env, "prog-2"),
+        authorized_payout_key: admin.clone(),
+        token_address: token.clone(),
+        reference_hash: None,
+    });
+    let res = client.try_batch_initialize_programs(&items);
+    assert!(matches!(res, Err(Ok(BatchError::ReferenceHashRequired))));
+    assert!(!client.program_exists_by_id(&String::from_str(&env, "prog-1")));
+}
@@ -1593,3 +1593,67 @@ fn test_lock_program_funds_fee_recipient_different_from_admin() {
     assert_eq!(token_client.balance(&fee_recipient), 2_000);
     assert_eq!(token_client.balance(&admin), 0); // Admin receives nothing
 }
+
+/// Fees are routed to the treasury registered for the program's token.
+#[test]
+fn test_lock_program_funds_fee_routed_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let usdc_treasury = Address::generate(&env);
+    let xlm_treasury = Address::generate(&env);
+
+    // Two program instances, each escrowing a different token.
+    let (usdc_client, usdc_contract) = make_client(&env);
+    let (usdc_token, usdc_id) = fund_contract(&env, &usdc_contract, 100_000);
+    let (xlm_client, xlm_contract) = make_client(&env);
+    let (xlm_token, xlm_id) = fund_contract(&env, &xlm_contract, 100_000);
+
+    let admin = Address::generate(&env);
+    for (client, token_id) in [(&usdc_client, &usdc_id), (&xlm_client, &xlm_id)] {
+        let program_id = String::from_str(&env, "hack-2026");
+        client.init_program(&program_id, &admin, token_id, &admin, &None, &None);
+        client.set_fee_recipient_for_token(&usdc_id, &usdc_treasury);
+        client.set_fee_recipient_for_token(&xlm_id, &xlm_treasury);
+        client.set_lock_fee_rate(&200); // 2%
+        client.set_fees_enabled(&true);
+    }
+    assert_eq!(
+        usdc_client.get_fee_recipient_for_token(&usdc_id),
+        Some(usdc_treasury.clone())
+    );
+
+    usdc_client.lock_program_funds(&100_000);
+    xlm_client.lock_program_funds(&50_000);
+
+    assert_eq!(usdc_token.balance(&usdc_treasury), 2_000);
+    assert_eq!(xlm_token.balance(&xlm_treasury), 1_000);
+    assert_eq!(usdc_token.balance(&xlm_treasury), 0);
+    assert_eq!(xlm_token.balance(&usdc_treasury), 0);
+    assert_eq!(usdc_token.balance(&admin), 0);
+    assert_eq!(xlm_token.balance(&admin), 0);
+}
+
+/// A token without a per-token entry falls back to the global fee recipient.
+#[test]
+fn test_lock_program_funds_fee_falls_back_to_global_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id) = make_client(&env);
+    let (token_client, token_id) = fund_contract(&env, &contract_id, 100_000);
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let other_treasury = Address::generate(&env);
+    let other_token = Address::generate(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+
+    client.set_fee_recipient(&fee_recipient);
+    client.set_fee_recipient_for_token(&other_token, &other_treasury);
+    client.set_lock_fee_rate(&200);
+    client.set_fees_enabled(&true);
+    assert_eq!(client.get_fee_recipient_for_token(&token_id), None);
+
+    client.lock_program_funds(&100_000);
+    assert_eq!(token_client.balance(&fee_recipient), 2_000);
+    assert_eq!(token_client.balance(&other_treasury), 0);
+}
@@ -555,9 +555,9 @@ fn test_rbac_emergency_withdraw_requires_lock_paused_not_refund_paused() {
     contract_client.emergency_withdraw(&target);
 }
 
-/// Admin withdraws all funds in multiple programs from same contract
+/// Admin withdraws only the program's locked funds; unaccounted tokens stay put
 #[test]
-fn test_rbac_emergency_withdraw_drains_all_funds() {
+fn test_rbac_emergency_withdraw_drains_only_program_funds() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -607,11 +607,9 @@ fn test_rbac_emergency_withdraw_drains_all_funds() {
     contract_client.set_paused(&Some(true), &None, &None, &None);
     contract_client.emergency_withdraw(&target);
 
-    assert_eq!(token_client.balance(&contract_client.address), 0);
-    assert!(
-        token_client.balance(&target) > 0,
-        "Target should receive withdrawn funds"
-    );
+    assert_eq!(token_client.balance(&contract_client.address), 1000);
+    assert_eq!(token_client.balance(&target), 500);
+    assert_eq!(contract_client.get_remaining_balance(), 0);
 }
 
 /// After emergency_withdraw, admin can unpause and resume normal operations
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! # Payout-with-callback Tests — Program Escrow
+//!
+//! Covers `single_payout_with_callback`:
+//! - The recipient contract receives the funds and its callback sees the amount.
+//! - A failing callback reverts the whole payout, including the transfer.
+//! - A mismatched `program_id` is rejected.
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, Address, Env, String,
+};
+
+/// DAO-style recipient that records every payout callback it receives.
+#[contract]
+struct RecordingRecipient;
+
+#[contractimpl]
+impl RecordingRecipient {
+    pub fn on_payout(env: Env, amount: i128) {
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("received"))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("received"), &(total + amount));
+        let calls: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("calls"), &(calls + 1));
+    }
+
+    pub fn reject(_env: Env, _amount: i128) {
+        panic!("distribution failed");
+    }
+
+    pub fn received(env: Env) -> (u32, i128) {
+        (
+            env.storage()
+                .instance()
+                .get(&symbol_short!("calls"))
+                .unwrap_or(0),
+            env.storage()
+                .instance()
+                .get(&symbol_short!("received"))
+                .unwrap_or(0),
+        )
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::Client<'static>,
+    RecordingRecipientClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token_id = sac.address();
+    let token_client = token::Client::new(env, &token_id);
+
+    let program_id = String::from_str(env, "callback-program");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&contract_id, &10_000);
+    client.lock_program_funds(&10_000);
+
+    let recipient_id = env.register_contract(None, RecordingRecipient);
+    let recipient = RecordingRecipientClient::new(env, &recipient_id);
+
+    (client, token_client, recipient)
+}
+
+#[test]
+fn test_payout_with_callback_pays_and_notifies_recipient() {
+    let env = Env::default();
+    let (client, token_client, recipient) = setup(&env);
+    let program_id = String::from_str(&env, "callback-program");
+
+    let data = client.single_payout_with_callback(
+        &program_id,
+        &recipient.address,
+        &2_500,
+        &symbol_short!("on_payout"),
+    );
+
+    assert_eq!(data.remaining_balance, 7_500);
+    assert_eq!(token_client.balance(&recipient.address), 2_500);
+    assert_eq!(recipient.received(), (1, 2_500));
+    assert_eq!(
+        data.payout_history.last().unwrap().recipient,
+        recipient.address
+    );
+}
+
+#[test]
+fn test_failing_callback_reverts_payout() {
+    let env = Env::default();
+    let (client, token_client, recipient) = setup(&env);
+    let program_id = String::from_str(&env, "callback-program");
+
+    let res = client.try_single_payout_with_callback(
+        &program_id,
+        &recipient.address,
+        &2_500,
+        &symbol_short!("reject"),
+    );
+
+    assert!(res.is_err());
+    assert_eq!(token_client.balance(&recipient.address), 0);
+    assert_eq!(client.get_program_info().remaining_balance, 10_000);
+    assert_eq!(recipient.received(), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Program not found")]
+fn test_payout_with_callback_rejects_unknown_program() {
+    let env = Env::default();
+    let (client, _token_client, recipient) = setup(&env);
+
+    client.single_payout_with_callback(
+        &String::from_str(&env, "other-program"),
+        &recipient.address,
+        &100,
+        &symbol_short!("on_payout"),
+    );
+}
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+//! # Track Sub-pool Tests — Program Escrow
+//!
+//! Covers `create_sub_pool` / `single_payout_from_track`:
+//! - Track payouts draw down the track budget and the program balance.
+//! - Over-spending a track is rejected.
+//! - General payouts cannot touch funds reserved for tracks.
+//! - Reservations cannot exceed the unreserved balance or reuse a track id.
+//! - Track payouts cannot exceed the program's remaining balance.
+//! - Emergency withdrawal and cancellation drop every track.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token_id = sac.address();
+    let token_client = token::Client::new(env, &token_id);
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_id).mint(&contract_id, &10_000);
+    client.lock_program_funds(&10_000);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_track_payout_draws_from_track_budget() {
+    let env = Env::default();
+    let (client, token_client) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+    let gaming = String::from_str(&env, "gaming");
+
+    client.create_sub_pool(&program_id, &defi, &4_000);
+    client.create_sub_pool(&program_id, &gaming, &3_000);
+    assert_eq!(client.get_track_balance(&program_id, &defi), 4_000);
+    assert_eq!(client.get_track_balance(&program_id, &gaming), 3_000);
+
+    let winner = Address::generate(&env);
+    let data = client.single_payout_from_track(&program_id, &defi, &winner, &1_500);
+    assert_eq!(data.remaining_balance, 8_500);
+    assert_eq!(token_client.balance(&winner), 1_500);
+    assert_eq!(client.get_track_balance(&program_id, &defi), 2_500);
+    assert_eq!(client.get_track_balance(&program_id, &gaming), 3_000);
+
+    // A track can be spent down to exactly zero.
+    client.single_payout_from_track(&program_id, &defi, &winner, &2_500);
+    assert_eq!(client.get_track_balance(&program_id, &defi), 0);
+    assert_eq!(
+        client.get_track_balance(&program_id, &String::from_str(&env, "unknown")),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Insufficient track budget")]
+fn test_track_overspend_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+
+    client.create_sub_pool(&program_id, &defi, &1_000);
+    client.single_payout_from_track(&program_id, &defi, &Address::generate(&env), &1_001);
+}
+
+#[test]
+#[should_panic(expected = "Track not found")]
+fn test_payout_from_unknown_track_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.single_payout_from_track(
+        &program_id,
+        &String::from_str(&env, "missing"),
+        &Address::generate(&env),
+        &100,
+    );
+}
+
+#[test]
+fn test_general_payouts_cannot_spend_reserved_funds() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+
+    client.create_sub_pool(&program_id, &defi, &7_000);
+
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &3_001)
+        .is_err());
+    assert!(client
+        .try_batch_payout(
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &vec![&env, 2_000, 1_001],
+        )
+        .is_err());
+
+    client.single_payout(&Address::generate(&env), &3_000);
+    assert_eq!(client.get_remaining_balance(), 7_000);
+    assert_eq!(client.get_track_balance(&program_id, &defi), 7_000);
+}
+
+#[test]
+fn test_sub_pool_reservation_limits() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+    let gaming = String::from_str(&env, "gaming");
+
+    client.create_sub_pool(&program_id, &defi, &6_000);
+    assert!(client
+        .try_create_sub_pool(&program_id, &gaming, &4_001)
+        .is_err());
+    assert!(client
+        .try_create_sub_pool(&program_id, &defi, &100)
+        .is_err());
+    assert!(client
+        .try_create_sub_pool(&program_id, &gaming, &0)
+        .is_err());
+
+    client.create_sub_pool(&program_id, &gaming, &4_000);
+    assert_eq!(client.get_track_balance(&program_id, &defi), 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_track_payout_cannot_exceed_remaining_balance() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+
+    client.create_sub_pool(&program_id, &defi, &4_000);
+    // Simulate a balance drawn down outside the track reservation.
+    env.as_contract(&client.address, || {
+        let mut data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        data.remaining_balance = 1_000;
+        env.storage().instance().set(&PROGRAM_DATA, &data);
+    });
+    client.single_payout_from_track(&program_id, &defi, &Address::generate(&env), &1_001);
+}
+
+#[test]
+fn test_emergency_withdraw_drops_tracks() {
+    let env = Env::default();
+    let (client, token_client) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+
+    client.create_sub_pool(&program_id, &defi, &4_000);
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&Address::generate(&env));
+    assert_eq!(client.get_track_balance(&program_id, &defi), 0);
+    assert!(client
+        .try_single_payout_from_track(&program_id, &defi, &Address::generate(&env), &1)
+        .is_err());
+
+    // Re-funded money is no longer held back by the dropped reservation.
+    client.set_paused(&Some(false), &None, &None, &None);
+    token::StellarAssetClient::new(&env, &token_client.address).mint(&client.address, &2_000);
+    client.lock_program_funds(&2_000);
+    client.single_payout(&Address::generate(&env), &2_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+}
+
+#[test]
+fn test_cancel_program_drops_tracks() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let defi = String::from_str(&env, "defi");
+    let gaming = String::from_str(&env, "gaming");
+
+    client.create_sub_pool(&program_id, &defi, &4_000);
+    client.create_sub_pool(&program_id, &gaming, &1_000);
+    client.set_paused(&Some(true), &Some(true), &None, &None);
+    assert_eq!(
+        client.cancel_program(&program_id, &Address::generate(&env)),
+        10_000
+    );
+    assert_eq!(client.get_track_balance(&program_id, &defi), 0);
+    assert_eq!(client.get_track_balance(&program_id, &gaming), 0);
+}
@@ -1,9 +1,12 @@
 #![cfg(test)]
 
 //! Tests for the `token_math` module: fee calculation with floor rounding,
-//! amount splitting invariant, decimal scaling, and base-unit conversion.
+//! amount splitting invariant, decimal scaling, base-unit conversion, and
+//! decimal normalization between mixed-decimal tokens.
 
 use crate::token_math;
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+use soroban_sdk::Env;
 
 // ===========================================================================
 // 1. calculate_fee — basic behaviour
@@ -245,3 +248,48 @@ fn fee_monotonic_with_amount() {
         prev = fee;
     }
 }
+
+// ===========================================================================
+// 7. scale_amount — mixed-decimal round trips
+// ===========================================================================
+
+#[test]
+fn scale_6_to_7_and_back_is_lossless() {
+    // 12.345678 USDC (6 dec) → 7 dec and back
+    let up = token_math::scale_amount(12_345_678, 6, 7).unwrap();
+    assert_eq!(up, 123_456_780);
+    assert_eq!(token_math::scale_amount(up, 7, 6), Some(12_345_678));
+}
+
+#[test]
+fn scale_7_to_6_and_back_never_gains_value() {
+    for amount in [0_i128, 1, 9, 10, 19, 10_000_005, 123_456_789] {
+        let down = token_math::scale_amount(amount, 7, 6).unwrap();
+        let back = token_math::scale_amount(down, 6, 7).unwrap();
+        assert!(back <= amount, "gained value: {} -> {}", amount, back);
+        // Loss is less than one unit of the coarser (6-decimal) base.
+        assert!(amount - back < 10, "lost {} on {}", amount - back, amount);
+    }
+}
+
+#[test]
+fn scale_overflow_returns_none() {
+    assert_eq!(token_math::scale_amount(i128::MAX / 2, 0, 7), None);
+}
+
+#[test]
+fn normalize_exposed_on_contract() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.normalize_to_decimals(&1_000_000, &6, &7),
+        Some(10_000_000)
+    );
+    assert_eq!(
+        client.normalize_to_decimals(&10_000_009, &7, &6),
+        Some(1_000_000)
+    );
+    assert_eq!(client.normalize_to_decimals(&(i128::MAX / 2), &0, &7), None);
+}